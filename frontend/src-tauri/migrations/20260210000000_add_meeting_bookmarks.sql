@@ -0,0 +1,13 @@
+-- Add meeting_bookmarks table for moments flagged by the user during a recording
+CREATE TABLE IF NOT EXISTS meeting_bookmarks (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    label TEXT NOT NULL,
+    note TEXT,
+    timestamp REAL NOT NULL, -- Seconds from recording start (same clock as transcripts.audio_start_time)
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+-- Create index for faster lookups
+CREATE INDEX IF NOT EXISTS idx_meeting_bookmarks_meeting_id ON meeting_bookmarks(meeting_id);
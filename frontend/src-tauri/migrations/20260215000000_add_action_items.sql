@@ -0,0 +1,20 @@
+-- Add action_items table: tasks extracted from meetings and tracked across them
+CREATE TABLE IF NOT EXISTS action_items (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    meeting_title TEXT NOT NULL, -- Title given to the meeting before the summary renamed it (groups recurring meetings)
+    description TEXT NOT NULL,
+    owner TEXT, -- Participant name as mentioned, or the speaker ("user" / "interlocutor")
+    due_date TEXT, -- YYYY-MM-DD when a date was stated
+    status TEXT NOT NULL DEFAULT 'open', -- open | in_progress | done | cancelled
+    source_segment_id TEXT, -- transcripts.id the item was extracted from
+    source_timestamp REAL, -- Seconds from recording start of that segment
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+-- Create indexes for the cross-meeting filters
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting_id ON action_items(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_action_items_status ON action_items(status);
+CREATE INDEX IF NOT EXISTS idx_action_items_meeting_title ON action_items(meeting_title COLLATE NOCASE);
@@ -0,0 +1,18 @@
+-- Add transcript_embeddings table: speaker-turn chunks of transcripts with their vectors (meeting Q&A)
+CREATE TABLE IF NOT EXISTS transcript_embeddings (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    chunk_index INTEGER NOT NULL, -- Position of the speaker turn in the meeting
+    speaker TEXT, -- "user" / "interlocutor" as stored on the transcripts
+    text TEXT NOT NULL,
+    audio_start_time REAL, -- Seconds from recording start of the first segment in the turn
+    audio_end_time REAL,
+    embedding BLOB NOT NULL, -- L2-normalized little-endian f32 values
+    embedding_model TEXT NOT NULL, -- Backend and model the vector came from (e.g. "builtin-ai:gemma3:1b")
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
+
+-- Vectors of different models are never compared, so lookups go by model
+CREATE INDEX IF NOT EXISTS idx_transcript_embeddings_meeting_id ON transcript_embeddings(meeting_id);
+CREATE INDEX IF NOT EXISTS idx_transcript_embeddings_model ON transcript_embeddings(embedding_model);
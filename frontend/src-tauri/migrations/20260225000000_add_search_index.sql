@@ -0,0 +1,117 @@
+-- Add full-text search over transcripts, summaries and meeting notes
+--
+-- search_documents holds one row per searchable text (kept in sync by triggers on the
+-- source tables) and search_index is an external-content FTS5 index over it.
+
+CREATE TABLE IF NOT EXISTS search_documents (
+    id INTEGER PRIMARY KEY, -- Stable rowid of the FTS5 index
+    source_type TEXT NOT NULL, -- transcript | summary | notes
+    source_id TEXT NOT NULL, -- transcripts.id, or the meeting id for summaries and notes
+    meeting_id TEXT NOT NULL,
+    speaker TEXT, -- Transcripts only: "user" / "interlocutor"
+    audio_start_time REAL, -- Transcripts only: seconds from recording start
+    timestamp TEXT, -- Transcripts only: wall-clock time of the segment
+    content TEXT NOT NULL,
+    UNIQUE (source_type, source_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_search_documents_meeting_id ON search_documents(meeting_id);
+
+-- remove_diacritics 2 folds accents (reunión = reunion) for Spanish and other Latin scripts
+CREATE VIRTUAL TABLE IF NOT EXISTS search_index USING fts5(
+    content,
+    content = 'search_documents',
+    content_rowid = 'id',
+    tokenize = 'unicode61 remove_diacritics 2'
+);
+
+-- Keep the FTS index in sync with search_documents
+CREATE TRIGGER IF NOT EXISTS search_documents_ai AFTER INSERT ON search_documents BEGIN
+    INSERT INTO search_index (rowid, content) VALUES (NEW.id, NEW.content);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_documents_ad AFTER DELETE ON search_documents BEGIN
+    INSERT INTO search_index (search_index, rowid, content) VALUES ('delete', OLD.id, OLD.content);
+END;
+
+CREATE TRIGGER IF NOT EXISTS search_documents_au AFTER UPDATE ON search_documents BEGIN
+    INSERT INTO search_index (search_index, rowid, content) VALUES ('delete', OLD.id, OLD.content);
+    INSERT INTO search_index (rowid, content) VALUES (NEW.id, NEW.content);
+END;
+
+-- Transcripts
+CREATE TRIGGER IF NOT EXISTS transcripts_search_ai AFTER INSERT ON transcripts BEGIN
+    INSERT INTO search_documents (source_type, source_id, meeting_id, speaker, audio_start_time, timestamp, content)
+    VALUES ('transcript', NEW.id, NEW.meeting_id, NEW.speaker, NEW.audio_start_time, NEW.timestamp, NEW.transcript);
+END;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_search_au AFTER UPDATE ON transcripts BEGIN
+    UPDATE search_documents
+    SET meeting_id = NEW.meeting_id, speaker = NEW.speaker, audio_start_time = NEW.audio_start_time,
+        timestamp = NEW.timestamp, content = NEW.transcript
+    WHERE source_type = 'transcript' AND source_id = OLD.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS transcripts_search_ad AFTER DELETE ON transcripts BEGIN
+    DELETE FROM search_documents WHERE source_type = 'transcript' AND source_id = OLD.id;
+END;
+
+-- Summaries: the markdown of the result JSON (older results are indexed as stored)
+CREATE TRIGGER IF NOT EXISTS summary_processes_search_ai AFTER INSERT ON summary_processes
+WHEN NEW.result IS NOT NULL BEGIN
+    INSERT INTO search_documents (source_type, source_id, meeting_id, content)
+    VALUES ('summary', NEW.meeting_id, NEW.meeting_id,
+            CASE WHEN json_valid(NEW.result)
+                 THEN COALESCE(json_extract(NEW.result, '$.markdown'), NEW.result)
+                 ELSE NEW.result END);
+END;
+
+CREATE TRIGGER IF NOT EXISTS summary_processes_search_au AFTER UPDATE OF result ON summary_processes BEGIN
+    DELETE FROM search_documents WHERE source_type = 'summary' AND source_id = OLD.meeting_id;
+    INSERT INTO search_documents (source_type, source_id, meeting_id, content)
+    SELECT 'summary', NEW.meeting_id, NEW.meeting_id,
+           CASE WHEN json_valid(NEW.result)
+                THEN COALESCE(json_extract(NEW.result, '$.markdown'), NEW.result)
+                ELSE NEW.result END
+    WHERE NEW.result IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS summary_processes_search_ad AFTER DELETE ON summary_processes BEGIN
+    DELETE FROM search_documents WHERE source_type = 'summary' AND source_id = OLD.meeting_id;
+END;
+
+-- Meeting notes
+CREATE TRIGGER IF NOT EXISTS meeting_notes_search_ai AFTER INSERT ON meeting_notes
+WHEN NEW.notes_markdown IS NOT NULL BEGIN
+    INSERT INTO search_documents (source_type, source_id, meeting_id, content)
+    VALUES ('notes', NEW.meeting_id, NEW.meeting_id, NEW.notes_markdown);
+END;
+
+CREATE TRIGGER IF NOT EXISTS meeting_notes_search_au AFTER UPDATE OF notes_markdown ON meeting_notes BEGIN
+    DELETE FROM search_documents WHERE source_type = 'notes' AND source_id = OLD.meeting_id;
+    INSERT INTO search_documents (source_type, source_id, meeting_id, content)
+    SELECT 'notes', NEW.meeting_id, NEW.meeting_id, NEW.notes_markdown
+    WHERE NEW.notes_markdown IS NOT NULL;
+END;
+
+CREATE TRIGGER IF NOT EXISTS meeting_notes_search_ad AFTER DELETE ON meeting_notes BEGIN
+    DELETE FROM search_documents WHERE source_type = 'notes' AND source_id = OLD.meeting_id;
+END;
+
+-- Backfill existing data (indexed through the search_documents triggers)
+INSERT OR IGNORE INTO search_documents (source_type, source_id, meeting_id, speaker, audio_start_time, timestamp, content)
+SELECT 'transcript', id, meeting_id, speaker, audio_start_time, timestamp, transcript
+FROM transcripts;
+
+INSERT OR IGNORE INTO search_documents (source_type, source_id, meeting_id, content)
+SELECT 'summary', meeting_id, meeting_id,
+       CASE WHEN json_valid(result)
+            THEN COALESCE(json_extract(result, '$.markdown'), result)
+            ELSE result END
+FROM summary_processes
+WHERE result IS NOT NULL;
+
+INSERT OR IGNORE INTO search_documents (source_type, source_id, meeting_id, content)
+SELECT 'notes', meeting_id, meeting_id, notes_markdown
+FROM meeting_notes
+WHERE notes_markdown IS NOT NULL;
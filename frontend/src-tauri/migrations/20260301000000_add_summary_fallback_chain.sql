@@ -0,0 +1,5 @@
+-- Migration: Add the summary provider fallback chain to settings
+-- Stores a JSON array of {provider, model}, tried in order when the configured
+-- summary provider keeps failing (rate limits, outages, invalid keys)
+
+ALTER TABLE settings ADD COLUMN summaryFallbackChain TEXT;
@@ -0,0 +1,32 @@
+-- Add summary_versions table: every generated, edited or restored summary of a meeting
+-- (summary_processes.result_backup only kept the summary before the last regeneration)
+CREATE TABLE IF NOT EXISTS summary_versions (
+    id TEXT PRIMARY KEY NOT NULL,
+    meeting_id TEXT NOT NULL,
+    version INTEGER NOT NULL, -- 1, 2, ... per meeting
+    source TEXT NOT NULL, -- generated | edited | restored
+    result TEXT NOT NULL, -- JSON, same format as summary_processes.result
+    provider TEXT, -- Provider and model that produced the summary (kept on restore)
+    model TEXT,
+    template_id TEXT,
+    prompt_hash TEXT, -- SHA-256 of the rendered template and custom prompt
+    restored_from INTEGER, -- Version a restored summary was copied from
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE,
+    UNIQUE (meeting_id, version)
+);
+
+-- Existing summaries become the version history of their meeting: the summary kept from
+-- before the last regeneration (result_backup) is version 1, the current one comes next
+INSERT INTO summary_versions (id, meeting_id, version, source, result, created_at)
+SELECT lower(hex(randomblob(16))), meeting_id, 1, 'generated', result_backup,
+       COALESCE(result_backup_timestamp, updated_at)
+FROM summary_processes
+WHERE result_backup IS NOT NULL AND meeting_id IN (SELECT id FROM meetings);
+
+INSERT INTO summary_versions (id, meeting_id, version, source, result, created_at)
+SELECT lower(hex(randomblob(16))), meeting_id,
+       CASE WHEN result_backup IS NOT NULL THEN 2 ELSE 1 END,
+       'generated', result, updated_at
+FROM summary_processes
+WHERE result IS NOT NULL AND meeting_id IN (SELECT id FROM meetings);
@@ -0,0 +1,8 @@
+-- Add conversation_metrics table: objective per-speaker metrics computed from the
+-- transcript timestamps (talk time, pace, turns, interruptions, questions, filler words)
+CREATE TABLE IF NOT EXISTS conversation_metrics (
+    meeting_id TEXT PRIMARY KEY NOT NULL,
+    metrics TEXT NOT NULL, -- JSON (ConversationMetrics)
+    computed_at TEXT NOT NULL,
+    FOREIGN KEY (meeting_id) REFERENCES meetings(id) ON DELETE CASCADE
+);
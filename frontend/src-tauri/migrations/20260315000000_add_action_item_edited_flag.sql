@@ -0,0 +1,5 @@
+-- Mark action items the user changed, so a summary regeneration keeps them as they are
+ALTER TABLE action_items ADD COLUMN edited_by_user INTEGER NOT NULL DEFAULT 0;
+
+-- Items updated after their extraction were edited through api_update_action_item
+UPDATE action_items SET edited_by_user = 1 WHERE status != 'open' OR updated_at != created_at;
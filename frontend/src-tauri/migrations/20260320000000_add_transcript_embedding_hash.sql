@@ -0,0 +1,3 @@
+-- Remember which transcript the vectors were built from, so edited or re-transcribed
+-- meetings get re-indexed. Existing indexes have no hash and are rebuilt once.
+ALTER TABLE transcript_embeddings ADD COLUMN transcript_hash TEXT;
@@ -4,15 +4,16 @@ use tauri::{AppHandle, Runtime};
 
 use crate::{
     database::{
-        models::MeetingModel,
+        models::{MeetingBookmark, MeetingModel},
         repositories::{
+            bookmark::BookmarksRepository,
             meeting::MeetingsRepository,
             setting::SettingsRepository,
             transcript::TranscriptsRepository,
         },
     },
     state::AppState,
-    summary::CustomOpenAIConfig,
+    summary::{providers::resolve_provider_id, CustomOpenAIConfig, FallbackProviderConfig},
 };
 
 use super::client::{get_server_address, make_api_request};
@@ -54,22 +55,42 @@ pub async fn api_get_meetings<R: Runtime>(
     }
 }
 
+/// Full-text search over transcripts, summaries and meeting notes (BM25-ranked)
 #[tauri::command]
 pub async fn api_search_transcripts<R: Runtime>(
     _app: AppHandle<R>,
     state: tauri::State<'_, AppState>,
     query: String,
+    filters: Option<TranscriptSearchFilters>,
     auth_token: Option<String>,
 ) -> Result<Vec<TranscriptSearchResult>, String> {
     log_info!(
-        "api_search_transcripts called with query: '{}', auth_token: {}",
+        "api_search_transcripts called with query: '{}', filters: {:?}, auth_token: {}",
         query,
+        filters,
         auth_token.is_some()
     );
 
+    let filters = filters.unwrap_or_default();
+    for date in [&filters.date_from, &filters.date_to].into_iter().flatten() {
+        chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid date '{}': expected YYYY-MM-DD", date))?;
+    }
+    if let Some(source_types) = &filters.source_types {
+        if let Some(invalid) = source_types
+            .iter()
+            .find(|t| !["transcript", "summary", "notes"].contains(&t.as_str()))
+        {
+            return Err(format!(
+                "Invalid source type '{}'. Must be one of: transcript, summary, notes",
+                invalid
+            ));
+        }
+    }
+
     let pool = state.db_manager.pool();
 
-    match TranscriptsRepository::search_transcripts(pool, &query).await {
+    match TranscriptsRepository::search_transcripts(pool, &query, &filters).await {
         Ok(results) => {
             log_info!(
                 "Search completed successfully with {} results.",
@@ -548,6 +569,23 @@ pub async fn api_get_meeting_metadata<R: Runtime>(
     }
 }
 
+/// Get the bookmarks flagged during a meeting's recording, ordered by time
+#[tauri::command]
+pub async fn api_get_meeting_bookmarks<R: Runtime>(
+    _app: AppHandle<R>,
+    meeting_id: String,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<MeetingBookmark>, String> {
+    log_info!("api_get_meeting_bookmarks called for meeting_id: {}", meeting_id);
+
+    BookmarksRepository::get_bookmarks(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Error retrieving bookmarks for meeting {}: {}", meeting_id, e);
+            format!("Failed to retrieve meeting bookmarks: {}", e)
+        })
+}
+
 /// Get paginated transcripts for a meeting
 #[tauri::command]
 pub async fn api_get_meeting_transcripts<R: Runtime>(
@@ -680,6 +718,12 @@ pub async fn api_save_transcript<R: Runtime>(
 
     let pool = state.db_manager.pool();
 
+    // Bookmarks flagged during the recording live in the meeting folder
+    let bookmarks = folder_path
+        .as_deref()
+        .map(|folder| crate::audio::recording_saver::read_bookmarks_file(std::path::Path::new(folder)))
+        .unwrap_or_default();
+
     // Now, call the repository with the correctly typed data.
     match TranscriptsRepository::save_transcript(
         pool,
@@ -694,6 +738,11 @@ pub async fn api_save_transcript<R: Runtime>(
                 "Successfully saved transcript and created meeting with id: {}",
                 meeting_id
             );
+            if !bookmarks.is_empty() {
+                if let Err(e) = BookmarksRepository::save_bookmarks(pool, &meeting_id, &bookmarks).await {
+                    log_warn!("Failed to save bookmarks for meeting {}: {}", meeting_id, e);
+                }
+            }
             Ok(serde_json::json!({
                 "status": "success",
                 "message": "Transcript saved successfully",
@@ -1099,3 +1148,60 @@ pub async fn api_test_custom_openai_connection<R: Runtime>(
         }
     }
 }
+
+// ===== SUMMARY FALLBACK CHAIN COMMANDS =====
+
+/// Saves the providers tried (in order) when the summary provider keeps failing
+/// An empty list disables the fallback
+#[tauri::command]
+pub async fn api_save_summary_fallback_chain<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    chain: Vec<FallbackProviderConfig>,
+) -> Result<serde_json::Value, String> {
+    log_info!("api_save_summary_fallback_chain called with {} providers", chain.len());
+
+    let mut validated = Vec::with_capacity(chain.len());
+    for entry in chain {
+        let provider = resolve_provider_id(&entry.provider)
+            .ok_or_else(|| format!("Unsupported LLM provider: {}", entry.provider))?;
+        let model = entry.model.trim().to_string();
+        if model.is_empty() && provider != "custom-openai" {
+            return Err(format!("Model name is required for fallback provider {}", provider));
+        }
+        validated.push(FallbackProviderConfig {
+            provider: provider.to_string(),
+            model,
+        });
+    }
+
+    match SettingsRepository::save_summary_fallback_chain(state.db_manager.pool(), &validated).await {
+        Ok(()) => {
+            log_info!("✅ Saved summary fallback chain ({} providers)", validated.len());
+            Ok(serde_json::json!({
+                "status": "success",
+                "message": "Summary fallback chain saved successfully"
+            }))
+        }
+        Err(e) => {
+            log_error!("❌ Failed to save summary fallback chain: {}", e);
+            Err(format!("Failed to save summary fallback chain: {}", e))
+        }
+    }
+}
+
+/// Gets the summary fallback chain (empty when none is configured)
+#[tauri::command]
+pub async fn api_get_summary_fallback_chain<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<Vec<FallbackProviderConfig>, String> {
+    log_info!("api_get_summary_fallback_chain called");
+
+    SettingsRepository::get_summary_fallback_chain(state.db_manager.pool())
+        .await
+        .map_err(|e| {
+            log_error!("❌ Failed to get summary fallback chain: {}", e);
+            format!("Failed to get summary fallback chain: {}", e)
+        })
+}
@@ -20,11 +20,49 @@ pub struct SearchRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TranscriptSearchResult {
+    /// Meeting ID
     pub id: String,
     pub title: String,
+    /// Snippet around the match as plain text
     #[serde(rename = "matchContext")]
     pub match_context: String,
+    /// Segment time for transcripts, meeting creation time otherwise
     pub timestamp: String,
+    /// Snippet with matched terms wrapped in `<mark>` / `</mark>`
+    pub snippet: String,
+    /// "transcript", "summary" or "notes"
+    #[serde(rename = "sourceType")]
+    pub source_type: String,
+    /// Transcript segment ID, or the meeting ID for summaries and notes
+    #[serde(rename = "sourceId")]
+    pub source_id: String,
+    pub speaker: Option<String>,
+    #[serde(rename = "audioStartTime")]
+    pub audio_start_time: Option<f64>,
+    #[serde(rename = "meetingDate")]
+    pub meeting_date: String,
+    /// BM25 score (lower is more relevant)
+    pub rank: f64,
+}
+
+/// Optional filters for transcript search (combined with AND)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct TranscriptSearchFilters {
+    /// Meetings created on or after this date (YYYY-MM-DD)
+    #[serde(rename = "dateFrom")]
+    pub date_from: Option<String>,
+    /// Meetings created on or before this date (YYYY-MM-DD)
+    #[serde(rename = "dateTo")]
+    pub date_to: Option<String>,
+    /// Transcript speaker ("user" or "interlocutor"); excludes summaries and notes
+    pub speaker: Option<String>,
+    #[serde(rename = "meetingId")]
+    pub meeting_id: Option<String>,
+    /// Any of "transcript", "summary", "notes"
+    #[serde(rename = "sourceTypes")]
+    pub source_types: Option<Vec<String>>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
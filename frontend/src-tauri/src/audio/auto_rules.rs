@@ -0,0 +1,729 @@
+// audio/auto_rules.rs
+//
+// Auto-stop and auto-pause rules for a running recording: stop after long
+// silence on both channels, stop when the meeting app exits, hard maximum
+// duration, and pause during long silence. A rule never acts immediately: it
+// is announced first (system notification + `recording-auto-rule-pending`)
+// and only takes effect if the user does not choose "continue" within the
+// grace period, either from the in-app toast or from the tray menu.
+
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::task::JoinHandle;
+
+use crate::meeting_detector::process_monitor::ProcessMonitor;
+use crate::notifications::commands::NotificationManagerState;
+
+use super::recording_lifecycle::{is_recording_active, RECORDING_MANAGER};
+
+/// How often the rules are evaluated
+const CHECK_INTERVAL_SECONDS: u64 = 1;
+/// How often the meeting app process list is refreshed
+const MEETING_APP_CHECK_SECONDS: f64 = 5.0;
+/// A "continue" on the maximum duration rule extends the limit by this much
+const MAX_DURATION_EXTENSION_MINUTES: u32 = 30;
+/// Shortest allowed grace period before a rule takes effect
+const MIN_GRACE_SECONDS: u32 = 10;
+/// Longest allowed grace period before a rule takes effect
+const MAX_GRACE_SECONDS: u32 = 300;
+
+fn default_grace_period_seconds() -> u32 {
+    60
+}
+
+/// Auto-stop / auto-pause rules. Every rule is off by default.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AutoRecordingRules {
+    /// Stop after this many minutes without sound on both channels
+    #[serde(default)]
+    pub stop_after_silence_minutes: Option<u32>,
+    /// Stop when the meeting app seen during the recording is no longer running
+    #[serde(default)]
+    pub stop_when_meeting_app_exits: bool,
+    /// Hard maximum recording length (excluding pauses)
+    #[serde(default)]
+    pub max_duration_minutes: Option<u32>,
+    /// Pause after this many minutes without sound; resumes when sound returns
+    #[serde(default)]
+    pub pause_after_silence_minutes: Option<u32>,
+    /// Time the user has to answer "continue" before a rule takes effect
+    #[serde(default = "default_grace_period_seconds")]
+    pub grace_period_seconds: u32,
+}
+
+impl Default for AutoRecordingRules {
+    fn default() -> Self {
+        Self {
+            stop_after_silence_minutes: None,
+            stop_when_meeting_app_exits: false,
+            max_duration_minutes: None,
+            pause_after_silence_minutes: None,
+            grace_period_seconds: default_grace_period_seconds(),
+        }
+    }
+}
+
+impl AutoRecordingRules {
+    /// Drop zero durations and clamp the grace period into its allowed range
+    pub fn validated(self) -> Self {
+        Self {
+            stop_after_silence_minutes: self.stop_after_silence_minutes.filter(|m| *m > 0),
+            stop_when_meeting_app_exits: self.stop_when_meeting_app_exits,
+            max_duration_minutes: self.max_duration_minutes.filter(|m| *m > 0),
+            pause_after_silence_minutes: self.pause_after_silence_minutes.filter(|m| *m > 0),
+            grace_period_seconds: self.grace_period_seconds.clamp(MIN_GRACE_SECONDS, MAX_GRACE_SECONDS),
+        }
+    }
+
+    pub fn any_enabled(&self) -> bool {
+        self.stop_after_silence_minutes.is_some()
+            || self.stop_when_meeting_app_exits
+            || self.max_duration_minutes.is_some()
+            || self.pause_after_silence_minutes.is_some()
+    }
+}
+
+static AUTO_RECORDING_RULES: Lazy<RwLock<AutoRecordingRules>> =
+    Lazy::new(|| RwLock::new(AutoRecordingRules::default()));
+
+/// Rules used by the next recording
+pub fn current_auto_recording_rules() -> AutoRecordingRules {
+    *AUTO_RECORDING_RULES.read().unwrap()
+}
+
+/// Replace the rules used by the next recording
+pub fn set_current_auto_recording_rules(rules: AutoRecordingRules) {
+    info!("Auto recording rules updated: {:?}", rules);
+    *AUTO_RECORDING_RULES.write().unwrap() = rules;
+}
+
+/// Rule that wants to act on the recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoRule {
+    SilenceStop,
+    MeetingAppExited,
+    MaxDuration,
+    SilencePause,
+}
+
+/// What a rule does once its grace period runs out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AutoRuleAction {
+    Stop,
+    Pause,
+}
+
+impl AutoRule {
+    pub fn action(&self) -> AutoRuleAction {
+        match self {
+            AutoRule::SilencePause => AutoRuleAction::Pause,
+            _ => AutoRuleAction::Stop,
+        }
+    }
+
+    fn message(&self, rules: &AutoRecordingRules) -> String {
+        let grace = rules.grace_period_seconds;
+        match self {
+            AutoRule::SilenceStop => format!(
+                "No se detecta audio desde hace {} minutos. La grabación se detendrá en {} segundos.",
+                rules.stop_after_silence_minutes.unwrap_or(0),
+                grace
+            ),
+            AutoRule::MeetingAppExited => format!(
+                "La aplicación de la reunión se cerró. La grabación se detendrá en {} segundos.",
+                grace
+            ),
+            AutoRule::MaxDuration => format!(
+                "La grabación alcanzó la duración máxima. Se detendrá en {} segundos.",
+                grace
+            ),
+            AutoRule::SilencePause => format!(
+                "No se detecta audio desde hace {} minutos. La grabación se pausará en {} segundos.",
+                rules.pause_after_silence_minutes.unwrap_or(0),
+                grace
+            ),
+        }
+    }
+}
+
+/// Payload of the `recording-auto-rule-pending` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AutoRulePending {
+    pub rule: AutoRule,
+    pub action: AutoRuleAction,
+    pub message: String,
+    /// Seconds until the rule takes effect unless the user continues
+    pub grace_period_seconds: u32,
+}
+
+/// Recording state the rules are evaluated against
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RecordingSnapshot {
+    /// Recording time excluding pauses (seconds)
+    pub active_seconds: f64,
+    /// Time since the last sound on either channel (seconds)
+    pub silence_seconds: f64,
+    pub paused: bool,
+    /// Whether a meeting app is running; None when not checked
+    pub meeting_app_running: Option<bool>,
+}
+
+/// Outcome of one evaluation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuleDecision {
+    /// A rule triggered; announce it and start the grace period
+    Announce(AutoRule),
+    /// The grace period ran out; carry out the rule's action
+    Apply(AutoRule),
+    /// The pending rule no longer holds (sound came back, app reopened)
+    Withdraw(AutoRule),
+    /// Sound returned during an automatic pause
+    Resume,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PendingRule {
+    rule: AutoRule,
+    deadline: f64,
+}
+
+/// State machine for the rules of one recording. Time is passed in (seconds
+/// on any monotonic clock) so the evaluator can be driven by tests.
+pub struct AutoRuleEvaluator {
+    rules: AutoRecordingRules,
+    pending: Option<PendingRule>,
+    /// Silence already acknowledged with "continue"; only silence beyond it counts
+    acknowledged_silence: f64,
+    /// Effective maximum duration (grows by each "continue")
+    max_duration_seconds: Option<f64>,
+    meeting_app_seen: bool,
+    meeting_app_rule_dismissed: bool,
+    /// The current pause was started by the silence rule
+    auto_paused: bool,
+}
+
+impl AutoRuleEvaluator {
+    pub fn new(rules: AutoRecordingRules) -> Self {
+        Self {
+            rules,
+            pending: None,
+            acknowledged_silence: 0.0,
+            max_duration_seconds: rules.max_duration_minutes.map(|m| m as f64 * 60.0),
+            meeting_app_seen: false,
+            meeting_app_rule_dismissed: false,
+            auto_paused: false,
+        }
+    }
+
+    pub fn rules(&self) -> &AutoRecordingRules {
+        &self.rules
+    }
+
+    pub fn pending_rule(&self) -> Option<AutoRule> {
+        self.pending.map(|p| p.rule)
+    }
+
+    /// Evaluate the rules at `now`; at most one decision per call
+    pub fn evaluate(&mut self, now: f64, snapshot: &RecordingSnapshot) -> Option<RuleDecision> {
+        // Sound after acknowledged silence starts a fresh silence period
+        if snapshot.silence_seconds < self.acknowledged_silence {
+            self.acknowledged_silence = 0.0;
+        }
+        if let Some(running) = snapshot.meeting_app_running {
+            self.meeting_app_seen |= running;
+        }
+        if !snapshot.paused {
+            self.auto_paused = false;
+        }
+
+        if self.auto_paused && snapshot.silence_seconds < 1.0 {
+            self.auto_paused = false;
+            if self.pending.is_some_and(|p| p.rule == AutoRule::SilencePause) {
+                self.pending = None;
+            }
+            return Some(RuleDecision::Resume);
+        }
+
+        if let Some(pending) = self.pending {
+            if !self.holds(pending.rule, snapshot) {
+                self.pending = None;
+                return Some(RuleDecision::Withdraw(pending.rule));
+            }
+            if now >= pending.deadline {
+                self.pending = None;
+                if pending.rule == AutoRule::SilencePause {
+                    self.auto_paused = true;
+                }
+                return Some(RuleDecision::Apply(pending.rule));
+            }
+            return None;
+        }
+
+        let triggered = [
+            AutoRule::MaxDuration,
+            AutoRule::MeetingAppExited,
+            AutoRule::SilenceStop,
+            AutoRule::SilencePause,
+        ]
+        .into_iter()
+        .find(|rule| self.holds(*rule, snapshot))?;
+
+        self.pending = Some(PendingRule {
+            rule: triggered,
+            deadline: now + self.rules.grace_period_seconds as f64,
+        });
+        Some(RuleDecision::Announce(triggered))
+    }
+
+    /// The user chose "continue": drop the pending rule and hold it off
+    pub fn continue_recording(&mut self, snapshot: &RecordingSnapshot) -> Option<AutoRule> {
+        let pending = self.pending.take()?;
+        match pending.rule {
+            AutoRule::SilenceStop | AutoRule::SilencePause => {
+                self.acknowledged_silence = snapshot.silence_seconds;
+            }
+            AutoRule::MeetingAppExited => {
+                self.meeting_app_rule_dismissed = true;
+            }
+            AutoRule::MaxDuration => {
+                let extension = MAX_DURATION_EXTENSION_MINUTES as f64 * 60.0;
+                self.max_duration_seconds = Some(snapshot.active_seconds.max(self.max_duration_seconds.unwrap_or(0.0)) + extension);
+            }
+        }
+        Some(pending.rule)
+    }
+
+    /// Whether a rule's condition currently holds
+    fn holds(&self, rule: AutoRule, snapshot: &RecordingSnapshot) -> bool {
+        let silence = snapshot.silence_seconds - self.acknowledged_silence;
+        // A manual pause is the user's decision; silence rules only watch running
+        // recordings and pauses they started themselves
+        let watching_silence = !snapshot.paused || self.auto_paused;
+
+        match rule {
+            AutoRule::MaxDuration => self
+                .max_duration_seconds
+                .is_some_and(|max| snapshot.active_seconds >= max),
+            AutoRule::MeetingAppExited => {
+                self.rules.stop_when_meeting_app_exits
+                    && !self.meeting_app_rule_dismissed
+                    && self.meeting_app_seen
+                    && snapshot.meeting_app_running == Some(false)
+            }
+            AutoRule::SilenceStop => {
+                watching_silence
+                    && self
+                        .rules
+                        .stop_after_silence_minutes
+                        .is_some_and(|m| silence >= m as f64 * 60.0)
+            }
+            AutoRule::SilencePause => {
+                !snapshot.paused
+                    && self
+                        .rules
+                        .pause_after_silence_minutes
+                        .is_some_and(|m| silence >= m as f64 * 60.0)
+            }
+        }
+    }
+}
+
+/// Evaluator of the current recording, shared with the "continue" command
+static AUTO_RULE_EVALUATOR: Lazy<Mutex<Option<AutoRuleEvaluator>>> = Lazy::new(|| Mutex::new(None));
+
+/// Background task evaluating the rules of the current recording
+static AUTO_RULES_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// Current recording state, or None when no recording manager is active
+fn recording_snapshot(meeting_app_running: Option<bool>) -> Option<RecordingSnapshot> {
+    let manager_guard = RECORDING_MANAGER.lock().ok()?;
+    let manager = manager_guard.as_ref()?;
+    Some(RecordingSnapshot {
+        active_seconds: manager.get_active_recording_duration().unwrap_or(0.0),
+        silence_seconds: manager.get_silence_duration(),
+        paused: manager.is_paused(),
+        meeting_app_running,
+    })
+}
+
+/// Start evaluating the configured rules for the recording that just started
+pub fn start_auto_rules<R: Runtime>(app: &AppHandle<R>) {
+    stop_auto_rules();
+
+    let rules = current_auto_recording_rules();
+    if !rules.any_enabled() {
+        info!("No auto-stop / auto-pause rules enabled");
+        return;
+    }
+    info!("⏲️ Auto recording rules active: {:?}", rules);
+
+    if let Ok(mut evaluator) = AUTO_RULE_EVALUATOR.lock() {
+        *evaluator = Some(AutoRuleEvaluator::new(rules));
+    }
+
+    let app = app.clone();
+    let handle = tokio::spawn(async move {
+        run_auto_rules_loop(app, rules.stop_when_meeting_app_exits).await;
+    });
+    if let Ok(mut task) = AUTO_RULES_TASK.lock() {
+        *task = Some(handle);
+    }
+}
+
+/// Stop evaluating rules (recording stopped)
+/// Rule currently waiting out its grace period, if any
+pub fn pending_auto_rule() -> Option<AutoRule> {
+    AUTO_RULE_EVALUATOR
+        .lock()
+        .ok()
+        .and_then(|guard| guard.as_ref().and_then(|evaluator| evaluator.pending_rule()))
+}
+
+pub fn stop_auto_rules() {
+    if let Ok(mut task) = AUTO_RULES_TASK.lock() {
+        if let Some(handle) = task.take() {
+            handle.abort();
+        }
+    }
+    if let Ok(mut evaluator) = AUTO_RULE_EVALUATOR.lock() {
+        *evaluator = None;
+    }
+}
+
+async fn run_auto_rules_loop<R: Runtime>(app: AppHandle<R>, watch_meeting_app: bool) {
+    let started = Instant::now();
+    let mut process_monitor = watch_meeting_app.then(ProcessMonitor::new);
+    let mut meeting_app_running = None;
+    let mut last_app_check: Option<f64> = None;
+
+    loop {
+        tokio::time::sleep(Duration::from_secs(CHECK_INTERVAL_SECONDS)).await;
+        if !is_recording_active() {
+            break;
+        }
+
+        let now = started.elapsed().as_secs_f64();
+        if let Some(monitor) = process_monitor.as_mut() {
+            if !last_app_check.is_some_and(|t| now - t < MEETING_APP_CHECK_SECONDS) {
+                meeting_app_running = Some(monitor.is_meeting_active());
+                last_app_check = Some(now);
+            }
+        }
+
+        let Some(snapshot) = recording_snapshot(meeting_app_running) else {
+            continue;
+        };
+        let (decision, rules) = {
+            let Ok(mut guard) = AUTO_RULE_EVALUATOR.lock() else {
+                break;
+            };
+            let Some(evaluator) = guard.as_mut() else {
+                break;
+            };
+            (evaluator.evaluate(now, &snapshot), *evaluator.rules())
+        };
+
+        match decision {
+            Some(RuleDecision::Announce(rule)) => announce_rule(&app, rule, &rules).await,
+            Some(RuleDecision::Withdraw(rule)) => {
+                info!("⏲️ Auto rule {:?} withdrawn - condition no longer holds", rule);
+                let _ = app.emit("recording-auto-rule-cancelled", serde_json::json!({
+                    "rule": rule,
+                    "reason": "condition_cleared"
+                }));
+                crate::tray::update_tray_menu(&app);
+            }
+            Some(RuleDecision::Apply(rule)) => {
+                info!("⏲️ Auto rule {:?} takes effect", rule);
+                let _ = app.emit("recording-auto-rule-applied", serde_json::json!({
+                    "rule": rule,
+                    "action": rule.action()
+                }));
+                match rule.action() {
+                    AutoRuleAction::Pause => {
+                        if let Err(e) = super::recording_lifecycle::pause_recording(app.clone()).await {
+                            warn!("Auto-pause failed: {}", e);
+                        }
+                    }
+                    AutoRuleAction::Stop => {
+                        // Stopping aborts this task, so run it on its own
+                        let app_for_stop = app.clone();
+                        tauri::async_runtime::spawn(async move {
+                            stop_recording_automatically(app_for_stop).await;
+                        });
+                        break;
+                    }
+                }
+            }
+            Some(RuleDecision::Resume) => {
+                info!("⏲️ Sound detected during auto-pause - resuming");
+                if let Err(e) = super::recording_lifecycle::resume_recording(app.clone()).await {
+                    warn!("Auto-resume failed: {}", e);
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+/// Announce a triggered rule through the frontend event and a system notification
+///
+/// The system notification has no buttons, so it points the user to the tray menu, which
+/// offers "Keep Recording" while the rule is pending (the window may be hidden during a
+/// screen share).
+async fn announce_rule<R: Runtime>(app: &AppHandle<R>, rule: AutoRule, rules: &AutoRecordingRules) {
+    let pending = AutoRulePending {
+        rule,
+        action: rule.action(),
+        message: rule.message(rules),
+        grace_period_seconds: rules.grace_period_seconds,
+    };
+    info!("⏲️ Auto rule {:?} triggered: {}", rule, pending.message);
+
+    if let Err(e) = app.emit("recording-auto-rule-pending", &pending) {
+        error!("Failed to emit recording-auto-rule-pending: {}", e);
+    }
+    crate::tray::update_tray_menu(app);
+
+    if let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() {
+        if let Err(e) = crate::notifications::commands::show_auto_rule_pending_notification(
+            &manager_state,
+            pending.message.clone(),
+            pending.grace_period_seconds,
+        )
+        .await
+        {
+            warn!("Failed to show auto rule notification: {}", e);
+        }
+    }
+}
+
+/// Stop the recording the same way the tray does, then trigger frontend post-processing
+async fn stop_recording_automatically<R: Runtime>(app: AppHandle<R>) {
+    let data_dir = match app.path().app_data_dir() {
+        Ok(dir) => dir,
+        Err(e) => {
+            error!("Failed to get app data dir: {}", e);
+            return;
+        }
+    };
+    let timestamp = chrono::Local::now().format("%Y-%m-%dT%H-%M-%S").to_string();
+    let save_path = data_dir.join(format!("recording-{}.wav", timestamp));
+
+    match super::recording_lifecycle::stop_recording(
+        app.clone(),
+        super::recording_commands::RecordingArgs {
+            save_path: save_path.to_string_lossy().to_string(),
+        },
+    )
+    .await
+    {
+        Ok(_) => {
+            info!("⏲️ Recording stopped by auto rule");
+            if let Err(e) = app.emit("recording-stop-complete", true) {
+                error!("Failed to emit recording-stop-complete event: {}", e);
+            }
+        }
+        Err(e) => error!("Failed to stop recording by auto rule: {}", e),
+    }
+}
+
+/// Keep recording: cancel the pending auto-stop / auto-pause rule
+#[tauri::command]
+pub async fn continue_recording_after_auto_rule<R: Runtime>(app: AppHandle<R>) -> Result<Option<AutoRule>, String> {
+    let snapshot = recording_snapshot(None).unwrap_or_default();
+    let cancelled = {
+        let mut guard = AUTO_RULE_EVALUATOR
+            .lock()
+            .map_err(|e| format!("Auto rule lock poisoned: {}", e))?;
+        guard.as_mut().and_then(|evaluator| evaluator.continue_recording(&snapshot))
+    };
+
+    if let Some(rule) = cancelled {
+        info!("⏲️ User chose to continue recording, auto rule {:?} cancelled", rule);
+        let _ = app.emit("recording-auto-rule-cancelled", serde_json::json!({
+            "rule": rule,
+            "reason": "user"
+        }));
+        crate::tray::update_tray_menu(&app);
+    }
+    Ok(cancelled)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(active_seconds: f64, silence_seconds: f64) -> RecordingSnapshot {
+        RecordingSnapshot {
+            active_seconds,
+            silence_seconds,
+            paused: false,
+            meeting_app_running: None,
+        }
+    }
+
+    #[test]
+    fn test_silence_stop_waits_for_grace_period() {
+        let rules = AutoRecordingRules {
+            stop_after_silence_minutes: Some(10),
+            grace_period_seconds: 30,
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+
+        assert_eq!(evaluator.evaluate(0.0, &snapshot(900.0, 599.0)), None);
+        assert_eq!(
+            evaluator.evaluate(1.0, &snapshot(901.0, 600.0)),
+            Some(RuleDecision::Announce(AutoRule::SilenceStop))
+        );
+        assert_eq!(evaluator.evaluate(20.0, &snapshot(920.0, 619.0)), None);
+        assert_eq!(
+            evaluator.evaluate(31.0, &snapshot(931.0, 630.0)),
+            Some(RuleDecision::Apply(AutoRule::SilenceStop))
+        );
+    }
+
+    #[test]
+    fn test_sound_withdraws_pending_silence_rule() {
+        let rules = AutoRecordingRules {
+            stop_after_silence_minutes: Some(1),
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+
+        assert_eq!(
+            evaluator.evaluate(0.0, &snapshot(100.0, 60.0)),
+            Some(RuleDecision::Announce(AutoRule::SilenceStop))
+        );
+        assert_eq!(
+            evaluator.evaluate(1.0, &snapshot(101.0, 0.2)),
+            Some(RuleDecision::Withdraw(AutoRule::SilenceStop))
+        );
+        assert_eq!(evaluator.pending_rule(), None);
+    }
+
+    #[test]
+    fn test_continue_requires_fresh_silence() {
+        let rules = AutoRecordingRules {
+            stop_after_silence_minutes: Some(1),
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+
+        evaluator.evaluate(0.0, &snapshot(100.0, 60.0));
+        assert_eq!(
+            evaluator.continue_recording(&snapshot(101.0, 61.0)),
+            Some(AutoRule::SilenceStop)
+        );
+        // Another full minute of silence is needed before it triggers again
+        assert_eq!(evaluator.evaluate(30.0, &snapshot(130.0, 90.0)), None);
+        assert_eq!(
+            evaluator.evaluate(62.0, &snapshot(162.0, 121.0)),
+            Some(RuleDecision::Announce(AutoRule::SilenceStop))
+        );
+    }
+
+    #[test]
+    fn test_max_duration_extended_on_continue() {
+        let rules = AutoRecordingRules {
+            max_duration_minutes: Some(60),
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+
+        assert_eq!(
+            evaluator.evaluate(0.0, &snapshot(3600.0, 0.0)),
+            Some(RuleDecision::Announce(AutoRule::MaxDuration))
+        );
+        evaluator.continue_recording(&snapshot(3610.0, 0.0));
+        assert_eq!(evaluator.evaluate(10.0, &snapshot(3700.0, 0.0)), None);
+        assert_eq!(
+            evaluator.evaluate(2000.0, &snapshot(3610.0 + 1800.0, 0.0)),
+            Some(RuleDecision::Announce(AutoRule::MaxDuration))
+        );
+    }
+
+    #[test]
+    fn test_meeting_app_exit_requires_app_seen() {
+        let rules = AutoRecordingRules {
+            stop_when_meeting_app_exits: true,
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+        let with_app = |running| RecordingSnapshot {
+            meeting_app_running: Some(running),
+            ..snapshot(100.0, 0.0)
+        };
+
+        // Never seen: recording an in-person meeting must not stop
+        assert_eq!(evaluator.evaluate(0.0, &with_app(false)), None);
+        assert_eq!(evaluator.evaluate(5.0, &with_app(true)), None);
+        assert_eq!(
+            evaluator.evaluate(10.0, &with_app(false)),
+            Some(RuleDecision::Announce(AutoRule::MeetingAppExited))
+        );
+        evaluator.continue_recording(&with_app(false));
+        assert_eq!(evaluator.evaluate(15.0, &with_app(false)), None);
+    }
+
+    #[test]
+    fn test_auto_pause_resumes_on_sound() {
+        let rules = AutoRecordingRules {
+            pause_after_silence_minutes: Some(2),
+            grace_period_seconds: 10,
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+
+        assert_eq!(
+            evaluator.evaluate(0.0, &snapshot(500.0, 120.0)),
+            Some(RuleDecision::Announce(AutoRule::SilencePause))
+        );
+        assert_eq!(
+            evaluator.evaluate(10.0, &snapshot(510.0, 130.0)),
+            Some(RuleDecision::Apply(AutoRule::SilencePause))
+        );
+
+        let paused = |silence| RecordingSnapshot { paused: true, ..snapshot(510.0, silence) };
+        assert_eq!(evaluator.evaluate(20.0, &paused(140.0)), None);
+        assert_eq!(evaluator.evaluate(21.0, &paused(0.1)), Some(RuleDecision::Resume));
+    }
+
+    #[test]
+    fn test_manual_pause_is_not_auto_resumed_or_stopped() {
+        let rules = AutoRecordingRules {
+            stop_after_silence_minutes: Some(1),
+            pause_after_silence_minutes: Some(1),
+            ..Default::default()
+        };
+        let mut evaluator = AutoRuleEvaluator::new(rules);
+        let paused = |silence| RecordingSnapshot { paused: true, ..snapshot(100.0, silence) };
+
+        assert_eq!(evaluator.evaluate(0.0, &paused(600.0)), None);
+        assert_eq!(evaluator.evaluate(1.0, &paused(0.0)), None);
+    }
+
+    #[test]
+    fn test_validated_rules() {
+        let rules = AutoRecordingRules {
+            stop_after_silence_minutes: Some(0),
+            max_duration_minutes: Some(90),
+            grace_period_seconds: 1,
+            ..Default::default()
+        }
+        .validated();
+
+        assert_eq!(rules.stop_after_silence_minutes, None);
+        assert_eq!(rules.max_duration_minutes, Some(90));
+        assert_eq!(rules.grace_period_seconds, MIN_GRACE_SECONDS);
+        assert!(rules.any_enabled());
+        assert!(!AutoRecordingRules::default().any_enabled());
+    }
+}
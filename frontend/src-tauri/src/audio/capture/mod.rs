@@ -3,6 +3,7 @@
 pub mod microphone;
 pub mod system;
 pub mod backend_config;
+pub mod virtual_file;
 
 #[cfg(target_os = "macos")]
 pub mod core_audio;
@@ -23,6 +24,8 @@ pub use core_audio::{CoreAudioCapture, CoreAudioStream};
 #[cfg(target_os = "windows")]
 pub use wasapi_loopback::{WasapiLoopbackCapture, WasapiLoopbackStream};
 
+pub use virtual_file::{VirtualFileSource, VirtualFileStream};
+
 // Re-export backend configuration
 pub use backend_config::{
     AudioCaptureBackend, BackendConfig, BACKEND_CONFIG,
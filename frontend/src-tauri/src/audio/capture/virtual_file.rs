@@ -0,0 +1,185 @@
+// Virtual (file-backed) audio input
+//
+// Streams a WAV/FLAC/MP4 file into an AudioCapture as if it were a live
+// microphone or system audio device. Used for deterministic end-to-end tests of
+// the pipeline (VAD, echo suppression, stereo mixing, timestamps) without cpal.
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+use crate::audio::devices::{AudioDevice, VIRTUAL_FILE_DEVICE_PREFIX};
+use crate::audio::native_encoder::{decode_audio_file, DecodedAudio};
+use crate::audio::pipeline::AudioCapture;
+use crate::audio::recording_state::RecordingState;
+
+/// Frames delivered per callback, mirroring a typical hardware buffer (10ms at 48kHz)
+const VIRTUAL_CALLBACK_FRAMES: usize = 480;
+
+/// Parsed description of a virtual file device
+#[derive(Debug, Clone, PartialEq)]
+pub struct VirtualFileSource {
+    pub path: PathBuf,
+    /// Playback rate relative to real time (1.0 = real time, 4.0 = four times faster)
+    pub speed: f32,
+}
+
+impl VirtualFileSource {
+    /// Parse a device created with `AudioDevice::virtual_file`
+    pub fn from_device(device: &AudioDevice) -> Option<Self> {
+        let spec = device.name.strip_prefix(VIRTUAL_FILE_DEVICE_PREFIX)?;
+        let (path, speed) = match spec.rsplit_once("?speed=") {
+            Some((path, speed)) => (path, speed.parse::<f32>().ok()?),
+            None => (spec, 1.0),
+        };
+
+        if path.is_empty() || !speed.is_finite() || speed <= 0.0 {
+            return None;
+        }
+
+        Some(Self { path: PathBuf::from(path), speed })
+    }
+
+    /// Decode the whole file up front so playback never blocks on I/O
+    pub fn load(&self) -> Result<DecodedAudio> {
+        let audio = decode_audio_file(&self.path)
+            .map_err(|e| anyhow!("Failed to open virtual device file {}: {}", self.path.display(), e))?;
+        if audio.samples.is_empty() || audio.channels == 0 {
+            return Err(anyhow!("Virtual device file is empty: {}", self.path.display()));
+        }
+        Ok(audio)
+    }
+}
+
+/// Running playback of a virtual device
+pub struct VirtualFileStream {
+    thread: Option<JoinHandle<()>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl VirtualFileStream {
+    /// Start feeding `audio` (decoded from `source`) to `capture` on a dedicated
+    /// thread, paced like a hardware callback at `source.speed` times real time
+    pub fn start(
+        source: VirtualFileSource,
+        audio: DecodedAudio,
+        capture: AudioCapture,
+        state: Arc<RecordingState>,
+    ) -> Result<Self> {
+        let sample_rate = audio.sample_rate;
+        let channels = audio.channels;
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+
+        info!(
+            "📼 Virtual device streaming {} ({:.1}s, {}Hz, {}ch) at {}x",
+            source.path.display(),
+            audio.duration_seconds(),
+            sample_rate,
+            channels,
+            source.speed
+        );
+
+        let thread = std::thread::Builder::new()
+            .name("virtual-audio-device".to_string())
+            .spawn(move || {
+                let frame_len = channels as usize;
+                let chunk_len = VIRTUAL_CALLBACK_FRAMES * frame_len;
+                let faster_than_real_time = source.speed != 1.0;
+                let mut frames_sent = 0usize;
+                let mut played = Duration::ZERO;
+                let mut resumed_at = Instant::now();
+
+                let mut chunks = audio.samples.chunks(chunk_len);
+                while !stop_flag.load(Ordering::SeqCst) {
+                    if !state.is_recording() {
+                        break;
+                    }
+
+                    // Hold the file position while the recording is paused
+                    if state.is_paused() {
+                        played += resumed_at.elapsed();
+                        while state.is_paused() && !stop_flag.load(Ordering::SeqCst) {
+                            std::thread::sleep(Duration::from_millis(10));
+                        }
+                        resumed_at = Instant::now();
+                        continue;
+                    }
+
+                    let Some(chunk) = chunks.next() else { break };
+                    frames_sent += chunk.len() / frame_len;
+                    let position = frames_sent as f64 / sample_rate as f64;
+
+                    if faster_than_real_time {
+                        state.advance_virtual_clock(position);
+                    }
+                    capture.process_audio_data(chunk);
+
+                    // Sleep until this chunk is "due" so pacing does not drift
+                    let due = Duration::from_secs_f64(position / source.speed as f64);
+                    let elapsed = played + resumed_at.elapsed();
+                    if due > elapsed {
+                        std::thread::sleep(due - elapsed);
+                    }
+                }
+
+                info!(
+                    "📼 Virtual device finished {} after {:.2}s of audio",
+                    source.path.display(),
+                    frames_sent as f64 / sample_rate as f64
+                );
+            })
+            .map_err(|e| anyhow!("Failed to spawn virtual device thread: {}", e))?;
+
+        Ok(Self {
+            thread: Some(thread),
+            stop,
+        })
+    }
+
+    /// Whether the whole file has been delivered (or playback was stopped)
+    pub fn is_finished(&self) -> bool {
+        self.thread.as_ref().map_or(true, |t| t.is_finished())
+    }
+
+    /// Stop playback and wait for the feeder thread to exit
+    pub fn stop(mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                warn!("Virtual device thread panicked");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audio::devices::DeviceType;
+    use std::path::Path;
+
+    #[test]
+    fn test_virtual_device_name_round_trip() {
+        let device = AudioDevice::virtual_file(Path::new("/tmp/mic.wav"), DeviceType::Input, 4.0);
+        assert!(device.is_virtual_file());
+        assert_eq!(
+            VirtualFileSource::from_device(&device),
+            Some(VirtualFileSource { path: PathBuf::from("/tmp/mic.wav"), speed: 4.0 })
+        );
+
+        let realtime = AudioDevice::virtual_file(Path::new("/tmp/sys.wav"), DeviceType::Output, 1.0);
+        assert_eq!(VirtualFileSource::from_device(&realtime).unwrap().speed, 1.0);
+
+        // Parsed from the "(input)" form the frontend sends
+        let parsed = AudioDevice::from_name("file:/tmp/mic.wav?speed=2 (input)").unwrap();
+        assert_eq!(VirtualFileSource::from_device(&parsed).unwrap().speed, 2.0);
+
+        assert!(VirtualFileSource::from_device(&AudioDevice::new("MacBook Mic".into(), DeviceType::Input)).is_none());
+        assert!(VirtualFileSource::from_device(&AudioDevice::new("file:/a.wav?speed=0".into(), DeviceType::Input)).is_none());
+    }
+}
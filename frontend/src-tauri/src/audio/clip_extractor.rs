@@ -0,0 +1,296 @@
+// audio/clip_extractor.rs
+//
+// Extracts short clips from a meeting recording, either by explicit time range
+// or by transcript segment IDs (using their audio_start_time/audio_end_time).
+// Clips are always written to a file (the requested path, else the app cache) and
+// returned by path, optionally reduced to a single speaker channel
+// (L = microphone/user, R = system/interlocutor).
+
+use anyhow::{anyhow, Result};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::native_encoder::{decode_audio_range, write_flac, write_wav, AudioFileFormat, DecodedAudio};
+use super::recording_saver::MeetingMetadata;
+use crate::database::repositories::meeting::MeetingsRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+/// Silence inserted between non-contiguous segments when several are joined into one clip
+const SEGMENT_GAP_SECONDS: f64 = 0.3;
+
+/// Which part of the stereo recording to keep
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ClipChannel {
+    /// Keep both channels as recorded (stereo)
+    Both,
+    /// Left channel only (microphone)
+    User,
+    /// Right channel only (system audio)
+    Interlocutor,
+    /// Downmix both channels to mono
+    Mix,
+}
+
+impl ClipChannel {
+    pub fn parse(value: Option<&str>) -> Result<Self> {
+        match value.map(|v| v.trim().to_lowercase()).as_deref() {
+            None | Some("") | Some("both") | Some("stereo") => Ok(Self::Both),
+            Some("user") | Some("mic") | Some("microphone") => Ok(Self::User),
+            Some("interlocutor") | Some("system") => Ok(Self::Interlocutor),
+            Some("mix") | Some("mono") => Ok(Self::Mix),
+            Some(other) => Err(anyhow!("Unknown channel '{}'", other)),
+        }
+    }
+}
+
+/// A time range within the recording, in seconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ClipRange {
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Result of a clip extraction
+#[derive(Debug, Clone, Serialize)]
+pub struct AudioClipResult {
+    /// Path of the written clip file
+    pub output_path: String,
+    /// Ranges of the recording that ended up in the clip
+    pub ranges: Vec<ClipRange>,
+    pub duration_seconds: f64,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+/// Pad, clamp, sort and merge overlapping ranges
+pub fn normalize_ranges(ranges: &[ClipRange], padding_seconds: f64) -> Vec<ClipRange> {
+    let padding = padding_seconds.max(0.0);
+    let mut padded: Vec<ClipRange> = ranges
+        .iter()
+        .filter(|r| r.start.is_finite() && r.end.is_finite() && r.end > r.start)
+        .map(|r| ClipRange {
+            start: (r.start - padding).max(0.0),
+            end: r.end + padding,
+        })
+        .collect();
+    padded.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+    let mut merged: Vec<ClipRange> = Vec::with_capacity(padded.len());
+    for range in padded {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Reduce interleaved audio to the requested channel layout
+pub fn select_channel(samples: &[f32], channels: u16, channel: ClipChannel) -> (Vec<f32>, u16) {
+    if channels < 2 || channel == ClipChannel::Both {
+        return (samples.to_vec(), channels);
+    }
+
+    let stride = channels as usize;
+    let frames = samples.chunks_exact(stride);
+    let mono = match channel {
+        ClipChannel::User => frames.map(|f| f[0]).collect(),
+        ClipChannel::Interlocutor => frames.map(|f| f[1]).collect(),
+        ClipChannel::Mix => frames.map(|f| f.iter().sum::<f32>() / stride as f32).collect(),
+        ClipChannel::Both => unreachable!(),
+    };
+    (mono, 1)
+}
+
+/// Decode the given ranges from `audio_path` and join them into a single clip
+pub fn extract_clip(audio_path: &Path, ranges: &[ClipRange], channel: ClipChannel) -> Result<DecodedAudio> {
+    if ranges.is_empty() {
+        return Err(anyhow!("No audio range to extract"));
+    }
+
+    let mut clip: Option<DecodedAudio> = None;
+    for range in ranges {
+        let audio = decode_audio_range(audio_path, range.start, Some(range.end))?;
+        let (samples, channels) = select_channel(&audio.samples, audio.channels, channel);
+
+        match clip.as_mut() {
+            Some(clip) => {
+                let gap = (SEGMENT_GAP_SECONDS * clip.sample_rate as f64) as usize * clip.channels as usize;
+                clip.samples.extend(std::iter::repeat(0.0).take(gap));
+                clip.samples.extend_from_slice(&samples);
+            }
+            None => {
+                clip = Some(DecodedAudio { samples, sample_rate: audio.sample_rate, channels });
+            }
+        }
+    }
+
+    let clip = clip.ok_or_else(|| anyhow!("No audio range to extract"))?;
+    if clip.samples.is_empty() {
+        return Err(anyhow!("Requested range is outside the recording"));
+    }
+    Ok(clip)
+}
+
+/// Locate the merged recording inside a meeting folder
+pub fn find_recording_file(meeting_folder: &Path) -> Option<PathBuf> {
+    let metadata_path = meeting_folder.join("metadata.json");
+    if let Ok(json) = std::fs::read_to_string(&metadata_path) {
+        if let Ok(metadata) = serde_json::from_str::<MeetingMetadata>(&json) {
+            if !metadata.audio_file.is_empty() {
+                let path = meeting_folder.join(&metadata.audio_file);
+                if path.exists() {
+                    return Some(path);
+                }
+            }
+        }
+    }
+
+    ["audio.mp4", "audio.flac", "audio.wav"]
+        .iter()
+        .map(|name| meeting_folder.join(name))
+        .find(|path| path.exists())
+}
+
+/// Extract audio for a time range or a list of transcript segments of a meeting.
+///
+/// Either `segment_ids` or `start_time`/`end_time` must be given. The clip is
+/// written to `output_path` (WAV or FLAC, by extension), or to a WAV file in the
+/// app cache when no path is given; the result carries the path, never the audio.
+#[tauri::command]
+pub async fn extract_audio_clip<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    start_time: Option<f64>,
+    end_time: Option<f64>,
+    segment_ids: Option<Vec<String>>,
+    channel: Option<String>,
+    padding_seconds: Option<f64>,
+    output_path: Option<String>,
+) -> Result<AudioClipResult, String> {
+    let channel = ClipChannel::parse(channel.as_deref()).map_err(|e| e.to_string())?;
+    let pool = state.db_manager.pool();
+
+    let meeting = MeetingsRepository::get_meeting_metadata(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Database error: {}", e))?;
+
+    let folder_path = meeting
+        .ok_or_else(|| format!("Meeting not found: {}", meeting_id))?
+        .folder_path
+        .ok_or_else(|| "This meeting has no recording folder".to_string())?;
+    let audio_path = find_recording_file(Path::new(&folder_path))
+        .ok_or_else(|| format!("No recording found in {}", folder_path))?;
+
+    let ranges: Vec<ClipRange> = match segment_ids.filter(|ids| !ids.is_empty()) {
+        Some(ids) => {
+            let rows = TranscriptsRepository::get_segment_time_ranges(pool, &meeting_id, &ids)
+                .await
+                .map_err(|e| format!("Database error: {}", e))?;
+            if rows.len() < ids.len() {
+                warn!("Only {} of {} requested segments found for meeting {}", rows.len(), ids.len(), meeting_id);
+            }
+            rows.into_iter()
+                .filter_map(|(id, start, end)| match (start, end) {
+                    (Some(start), Some(end)) => Some(ClipRange { start, end }),
+                    _ => {
+                        warn!("Segment {} has no audio timestamps, skipping", id);
+                        None
+                    }
+                })
+                .collect()
+        }
+        None => match (start_time, end_time) {
+            (Some(start), Some(end)) => vec![ClipRange { start, end }],
+            _ => return Err("Provide either segment_ids or both start_time and end_time".to_string()),
+        },
+    };
+
+    let ranges = normalize_ranges(&ranges, padding_seconds.unwrap_or(0.0));
+    if ranges.is_empty() {
+        return Err("No valid audio range to extract".to_string());
+    }
+
+    let total: f64 = ranges.iter().map(|r| r.end - r.start).sum();
+    let target = match output_path {
+        Some(path) => PathBuf::from(path),
+        None => app
+            .path()
+            .app_cache_dir()
+            .map_err(|e| format!("Failed to resolve cache directory: {}", e))?
+            .join("clips")
+            .join(format!("{}-{}.wav", meeting_id, uuid::Uuid::new_v4())),
+    };
+
+    info!(
+        "Extracting {} range(s) ({:.1}s, channel {:?}) from {}",
+        ranges.len(),
+        total,
+        channel,
+        audio_path.display()
+    );
+
+    let clip_ranges = ranges.clone();
+    let clip = tokio::task::spawn_blocking(move || extract_clip(&audio_path, &clip_ranges, channel))
+        .await
+        .map_err(|e| format!("Clip extraction task failed: {}", e))?
+        .map_err(|e| format!("Failed to extract clip: {}", e))?;
+
+    let duration_seconds = clip.duration_seconds();
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| format!("Failed to create directory: {}", e))?;
+    }
+    let written = match AudioFileFormat::from_path(&target) {
+        Some(AudioFileFormat::Flac) => write_flac(&target, &clip.samples, clip.sample_rate, clip.channels),
+        Some(AudioFileFormat::Wav) => write_wav(&target, &clip.samples, clip.sample_rate, clip.channels),
+        _ => Err(anyhow!("Clips can only be written as .wav or .flac")),
+    };
+    written.map_err(|e| format!("Failed to write clip: {}", e))?;
+    let output_path = target.to_string_lossy().to_string();
+    info!("Saved {:.1}s clip to {}", duration_seconds, output_path);
+
+    Ok(AudioClipResult {
+        output_path,
+        ranges,
+        duration_seconds,
+        sample_rate: clip.sample_rate,
+        channels: clip.channels,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_ranges_merges_overlaps() {
+        let ranges = [
+            ClipRange { start: 10.0, end: 12.0 },
+            ClipRange { start: 0.5, end: 2.0 },
+            ClipRange { start: 11.5, end: 13.0 },
+            ClipRange { start: 5.0, end: 4.0 },
+        ];
+
+        let merged = normalize_ranges(&ranges, 1.0);
+        assert_eq!(
+            merged,
+            vec![ClipRange { start: 0.0, end: 3.0 }, ClipRange { start: 9.0, end: 14.0 }]
+        );
+    }
+
+    #[test]
+    fn test_select_channel() {
+        let stereo = [0.1, 0.9, 0.2, 0.8];
+        assert_eq!(select_channel(&stereo, 2, ClipChannel::User), (vec![0.1, 0.2], 1));
+        assert_eq!(select_channel(&stereo, 2, ClipChannel::Interlocutor), (vec![0.9, 0.8], 1));
+        assert_eq!(select_channel(&stereo, 2, ClipChannel::Both).1, 2);
+        assert_eq!(select_channel(&stereo, 2, ClipChannel::Mix).0.len(), 2);
+        assert_eq!(ClipChannel::parse(Some("System")).unwrap(), ClipChannel::Interlocutor);
+        assert!(ClipChannel::parse(Some("left")).is_err());
+    }
+}
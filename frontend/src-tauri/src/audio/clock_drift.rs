@@ -0,0 +1,378 @@
+// audio/clock_drift.rs
+//
+// Clock-drift compensation between the microphone and system audio streams.
+//
+// Each capture device runs on its own crystal, so "48kHz" on the mic and on the
+// loopback device differ by tens of ppm - several hundred milliseconds over a long
+// meeting. Every stream is therefore measured against the recording clock (sample
+// count vs. chunk arrival time, fitted over a long window to average out callback
+// jitter) and finely resampled so that its sample count follows that clock. Both
+// channels of the stereo recording and both VAD timelines then share one timeline.
+
+use log::{info, warn};
+use std::collections::VecDeque;
+
+/// Minimum spacing between two clock observations
+const OBSERVATION_INTERVAL_SECONDS: f64 = 1.0;
+/// Observations kept for the drift fit (~10 minutes)
+const MAX_OBSERVATIONS: usize = 600;
+/// Observation span needed before the estimate is trusted
+const MIN_FIT_SPAN_SECONDS: f64 = 60.0;
+/// Rate deviations beyond this are not drift (wrong nominal rate, broken clock)
+const MAX_DRIFT_PPM: f64 = 1000.0;
+/// Largest correction applied to the resampling ratio (inaudible pitch change)
+const MAX_RATIO_DEVIATION: f64 = 0.002;
+/// Time over which accumulated misalignment is corrected
+const CORRECTION_SECONDS: f64 = 10.0;
+/// Audio arriving this much later than the clock fit predicts was lost (dropout)
+const DISCONTINUITY_SECONDS: f64 = 0.25;
+
+/// Linear fit of device sample count against recording time
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockFit {
+    /// Measured device rate in samples per second of recording time
+    pub rate: f64,
+    /// Recording time at which the device produced its first sample
+    pub origin: f64,
+}
+
+impl ClockFit {
+    /// Recording time at which the device had produced `samples` samples
+    pub fn time_at(&self, samples: f64) -> f64 {
+        self.origin + samples / self.rate
+    }
+
+    /// Drift relative to the nominal rate, in parts per million
+    pub fn drift_ppm(&self, nominal_rate: u32) -> f64 {
+        (self.rate / nominal_rate as f64 - 1.0) * 1_000_000.0
+    }
+}
+
+/// Estimates the real rate of a device clock from (recording time, sample count) pairs
+pub struct DriftEstimator {
+    nominal_rate: u32,
+    observations: VecDeque<(f64, f64)>,
+}
+
+impl DriftEstimator {
+    pub fn new(nominal_rate: u32) -> Self {
+        Self {
+            nominal_rate,
+            observations: VecDeque::with_capacity(MAX_OBSERVATIONS + 1),
+        }
+    }
+
+    /// Record that `samples` samples had been produced by recording time `time`
+    pub fn observe(&mut self, time: f64, samples: f64) {
+        if let Some(&(last_time, _)) = self.observations.back() {
+            if time - last_time < OBSERVATION_INTERVAL_SECONDS {
+                return;
+            }
+        }
+        self.observations.push_back((time, samples));
+        if self.observations.len() > MAX_OBSERVATIONS {
+            self.observations.pop_front();
+        }
+    }
+
+    /// Current fit, once enough observations are available and the result is plausible
+    pub fn fit(&self) -> Option<ClockFit> {
+        let (first, last) = (self.observations.front()?, self.observations.back()?);
+        if last.0 - first.0 < MIN_FIT_SPAN_SECONDS {
+            return None;
+        }
+
+        // Least squares of samples over time (two-pass for numerical stability)
+        let count = self.observations.len() as f64;
+        let mean_t = self.observations.iter().map(|(t, _)| t).sum::<f64>() / count;
+        let mean_n = self.observations.iter().map(|(_, n)| n).sum::<f64>() / count;
+        let (mut cov, mut var) = (0.0, 0.0);
+        for (t, n) in &self.observations {
+            cov += (t - mean_t) * (n - mean_n);
+            var += (t - mean_t) * (t - mean_t);
+        }
+        if var <= 0.0 {
+            return None;
+        }
+
+        let rate = cov / var;
+        let fit = ClockFit { rate, origin: mean_t - mean_n / rate };
+        if !rate.is_finite() || fit.drift_ppm(self.nominal_rate).abs() > MAX_DRIFT_PPM {
+            return None;
+        }
+        Some(fit)
+    }
+}
+
+/// Variable-ratio resampler for tiny ratio changes (Catmull-Rom interpolation)
+struct FineResampler {
+    input: Vec<f32>,
+    /// Read position in `input`
+    position: f64,
+}
+
+impl FineResampler {
+    fn new() -> Self {
+        // One sample of history so the first output sample can be interpolated
+        Self { input: vec![0.0], position: 1.0 }
+    }
+
+    /// Resample `samples`, producing `ratio` output samples per input sample
+    fn process(&mut self, samples: &[f32], ratio: f64, output: &mut Vec<f32>) {
+        self.input.extend_from_slice(samples);
+        let step = 1.0 / ratio;
+
+        while (self.position as usize) + 2 < self.input.len() {
+            let i = self.position as usize;
+            let t = (self.position - i as f64) as f32;
+            let (p0, p1, p2, p3) = (self.input[i - 1], self.input[i], self.input[i + 1], self.input[i + 2]);
+            let value = p1
+                + 0.5 * t * (p2 - p0
+                    + t * (2.0 * p0 - 5.0 * p1 + 4.0 * p2 - p3
+                        + t * (3.0 * (p1 - p2) + p3 - p0)));
+            output.push(value);
+            self.position += step;
+        }
+
+        // Keep one sample of history before the read position
+        let consumed = (self.position as usize).saturating_sub(1);
+        self.input.drain(..consumed);
+        self.position -= consumed as f64;
+    }
+}
+
+/// Keeps one capture stream on the recording clock
+pub struct DriftCompensator {
+    name: &'static str,
+    nominal_rate: u32,
+    estimator: DriftEstimator,
+    resampler: FineResampler,
+    /// Device samples received, plus samples known to be lost in dropouts
+    input_samples: f64,
+    output_samples: f64,
+    /// Recording time at which the first chunk of the stream started (arrival based)
+    first_chunk_time: Option<f64>,
+    /// Output produced before the current device took over the stream
+    device_output_base: f64,
+    /// Recording time of the current device's first sample (from the first valid fit)
+    start_time: Option<f64>,
+    last_logged_ppm: Option<f64>,
+}
+
+impl DriftCompensator {
+    pub fn new(name: &'static str, nominal_rate: u32) -> Self {
+        Self {
+            name,
+            nominal_rate,
+            estimator: DriftEstimator::new(nominal_rate),
+            resampler: FineResampler::new(),
+            input_samples: 0.0,
+            output_samples: 0.0,
+            first_chunk_time: None,
+            device_output_base: 0.0,
+            start_time: None,
+            last_logged_ppm: None,
+        }
+    }
+
+    /// Compensate one chunk. `active_time` is the recording time (excluding pauses)
+    /// at which the chunk arrived.
+    pub fn process(&mut self, samples: &[f32], active_time: f64) -> Vec<f32> {
+        let mut output = Vec::with_capacity(samples.len() + 16);
+        if samples.is_empty() {
+            return output;
+        }
+
+        if self.first_chunk_time.is_none() {
+            self.first_chunk_time = Some(active_time - samples.len() as f64 / self.nominal_rate as f64);
+        }
+
+        let fit = self.estimator.fit();
+        let mut end_samples = self.input_samples + samples.len() as f64;
+
+        if let Some(fit) = fit {
+            // Audio that arrives far later than the clock predicts was lost; fill the
+            // gap with silence so everything after it stays aligned
+            let lag = active_time - fit.time_at(end_samples);
+            if lag > DISCONTINUITY_SECONDS {
+                warn!("⏱️ {} stream lost ~{:.0}ms of audio, inserting silence to stay aligned", self.name, lag * 1000.0);
+                let lost = lag * fit.rate;
+                self.input_samples += lost;
+                end_samples += lost;
+                let silence = (lag * self.nominal_rate as f64).round() as usize;
+                output.resize(silence, 0.0);
+                self.output_samples += silence as f64;
+            }
+        }
+
+        self.estimator.observe(active_time, end_samples);
+        let ratio = match fit {
+            Some(fit) => self.correction_ratio(fit, end_samples - samples.len() as f64),
+            None => 1.0,
+        };
+
+        let before = output.len();
+        self.resampler.process(samples, ratio, &mut output);
+        self.output_samples += (output.len() - before) as f64;
+        self.input_samples = end_samples;
+        output
+    }
+
+    /// The stream moved to another device whose first chunk starts at recording
+    /// time `chunk_start`. The old device's clock is forgotten and the switch-over
+    /// gap is returned as silence, so the stream's timeline stays continuous.
+    pub fn restart(&mut self, chunk_start: f64) -> Vec<f32> {
+        let mut silence = Vec::new();
+        if let Some(first_chunk_time) = self.first_chunk_time {
+            let expected = (chunk_start - first_chunk_time) * self.nominal_rate as f64;
+            let gap = (expected - self.output_samples).round();
+            if gap > 0.0 {
+                silence.resize(gap as usize, 0.0);
+                self.output_samples += gap;
+            }
+        }
+        info!("⏱️ {} stream switched device, bridged {:.0}ms", self.name,
+              silence.len() as f64 * 1000.0 / self.nominal_rate as f64);
+
+        self.estimator = DriftEstimator::new(self.nominal_rate);
+        self.resampler = FineResampler::new();
+        self.input_samples = 0.0;
+        self.device_output_base = self.output_samples;
+        self.start_time = None;
+        self.last_logged_ppm = None;
+        silence
+    }
+
+    /// Resampling ratio for the next chunk, given the device samples consumed before it
+    fn correction_ratio(&mut self, fit: ClockFit, consumed_samples: f64) -> f64 {
+        let start_time = *self.start_time.get_or_insert(fit.origin);
+
+        let drift_ppm = fit.drift_ppm(self.nominal_rate);
+        let changed = match self.last_logged_ppm {
+            Some(last) => (last - drift_ppm).abs() >= 5.0,
+            None => true,
+        };
+        if changed {
+            info!("⏱️ {} clock drift: {:+.1} ppm ({:.2} Hz actual)", self.name, drift_ppm, fit.rate);
+            self.last_logged_ppm = Some(drift_ppm);
+        }
+
+        // Output that should exist by now according to the recording clock
+        let target = self.device_output_base + (fit.time_at(consumed_samples) - start_time) * self.nominal_rate as f64;
+        let error = self.output_samples - target;
+
+        let base = self.nominal_rate as f64 / fit.rate;
+        let correction = error / (fit.rate * CORRECTION_SECONDS);
+        (base - correction).clamp(1.0 - MAX_RATIO_DEVIATION, 1.0 + MAX_RATIO_DEVIATION)
+    }
+
+    /// Current drift estimate in ppm (None until enough audio has been observed)
+    pub fn drift_ppm(&self) -> Option<f64> {
+        self.estimator.fit().map(|fit| fit.drift_ppm(self.nominal_rate))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48000;
+
+    #[test]
+    fn test_estimator_measures_drift_despite_jitter() {
+        let mut estimator = DriftEstimator::new(RATE);
+        let actual = RATE as f64 * (1.0 + 80e-6);
+        let mut seed = 7u32;
+        for i in 1..=30_000 {
+            // 10ms chunks arriving with up to 8ms of scheduling jitter
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            let jitter = (seed >> 16) as f64 / 65536.0 * 0.008;
+            let samples = i as f64 * 480.0;
+            estimator.observe(samples / actual + 0.02 + jitter, samples);
+        }
+
+        let fit = estimator.fit().unwrap();
+        assert!((fit.drift_ppm(RATE) - 80.0).abs() < 5.0, "{}", fit.drift_ppm(RATE));
+    }
+
+    #[test]
+    fn test_resampler_is_transparent_at_unity_ratio() {
+        let mut resampler = FineResampler::new();
+        let input: Vec<f32> = (0..1000).map(|i| (i as f32 * 0.01).sin()).collect();
+        let mut output = Vec::new();
+        for chunk in input.chunks(97) {
+            resampler.process(chunk, 1.0, &mut output);
+        }
+        assert_eq!(output.len(), input.len() - 2);
+        assert!(output.iter().zip(&input).all(|(a, b)| (a - b).abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_compensator_follows_recording_clock() {
+        // Device runs 200ppm fast: 30 minutes of it carry 360ms too many samples
+        let actual = RATE as f64 * (1.0 + 200e-6);
+        let mut compensator = DriftCompensator::new("test", RATE);
+        let chunk = vec![0.0f32; 480];
+        let mut input = 0.0;
+        let mut output = 0usize;
+        for _ in 0..(30 * 60 * 100) {
+            input += 480.0;
+            output += compensator.process(&chunk, input / actual).len();
+        }
+
+        let wall_seconds = input / actual;
+        let error_ms = (output as f64 / RATE as f64 - wall_seconds) * 1000.0;
+        assert!(error_ms.abs() < 5.0, "misaligned by {:.1}ms", error_ms);
+        assert!((compensator.drift_ppm().unwrap() - 200.0).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_compensator_fills_dropouts() {
+        let mut compensator = DriftCompensator::new("test", RATE);
+        let chunk = vec![0.1f32; 480];
+        let mut time = 0.0;
+        let mut output = 0usize;
+        for i in 0..(120 * 100) {
+            time += 0.01;
+            // Half a second of audio never arrives after 90s
+            if i == 9000 {
+                time += 0.5;
+            }
+            output += compensator.process(&chunk, time).len();
+        }
+        let error_ms = (output as f64 / RATE as f64 - time) * 1000.0;
+        assert!(error_ms.abs() < 5.0, "misaligned by {:.1}ms", error_ms);
+    }
+
+    #[test]
+    fn test_device_switch_keeps_timeline_continuous() {
+        let mut compensator = DriftCompensator::new("test", RATE);
+        let chunk = vec![0.1f32; 480];
+        let mut output = 0usize;
+
+        // Old device runs 300ppm slow for 2 minutes
+        let old_rate = RATE as f64 * (1.0 - 300e-6);
+        let mut input = 0.0;
+        let mut time = 0.0;
+        while time < 120.0 {
+            input += 480.0;
+            time = input / old_rate;
+            output += compensator.process(&chunk, time).len();
+        }
+
+        // New device starts 400ms later and runs 150ppm fast
+        let switch_time = time + 0.4;
+        output += compensator.restart(switch_time).len();
+        let new_rate = RATE as f64 * (1.0 + 150e-6);
+        let mut input = 0.0;
+        while time < 300.0 {
+            input += 480.0;
+            time = switch_time + input / new_rate;
+            output += compensator.process(&chunk, time).len();
+        }
+
+        let error_ms = (output as f64 / RATE as f64 - time) * 1000.0;
+        assert!(error_ms.abs() < 5.0, "misaligned by {:.1}ms", error_ms);
+        assert!((compensator.drift_ppm().unwrap() - 150.0).abs() < 5.0);
+    }
+}
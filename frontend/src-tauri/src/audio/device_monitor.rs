@@ -1,12 +1,40 @@
 // Audio device monitoring for disconnect/reconnect detection
-use std::sync::Arc;
+// and (opt-in) following of the OS default devices
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use anyhow::Result;
 use log::{debug, info, warn, error};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use super::devices::{AudioDevice, list_audio_devices, default_input_device, default_output_device};
+
+/// "Follow system default" policy: when the OS default device changes during a
+/// recording, the capture stream moves to the new default (e.g. a headset that
+/// was plugged in mid-call). Off by default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FollowDefaultDevices {
+    #[serde(default)]
+    pub microphone: bool,
+    #[serde(default)]
+    pub system_audio: bool,
+}
+
+static FOLLOW_DEFAULT_DEVICES: Lazy<RwLock<FollowDefaultDevices>> =
+    Lazy::new(|| RwLock::new(FollowDefaultDevices::default()));
 
-use super::devices::{AudioDevice, list_audio_devices};
+/// Follow-default policy used by the next device monitor
+pub fn current_follow_default_devices() -> FollowDefaultDevices {
+    *FOLLOW_DEFAULT_DEVICES.read().unwrap()
+}
+
+/// Replace the follow-default policy used by the next device monitor
+pub fn set_current_follow_default_devices(policy: FollowDefaultDevices) {
+    info!("Follow-default device policy updated: {:?}", policy);
+    *FOLLOW_DEFAULT_DEVICES.write().unwrap() = policy;
+}
 
 /// Device monitoring events
 #[derive(Debug, Clone)]
@@ -21,6 +49,11 @@ pub enum DeviceEvent {
         device_name: String,
         device_type: DeviceMonitorType,
     },
+    /// The OS default device changed and the recording should follow it
+    DefaultDeviceChanged {
+        device_name: String,
+        device_type: DeviceMonitorType,
+    },
     /// Device list has changed (new device added or removed)
     DeviceListChanged,
 }
@@ -39,6 +72,12 @@ struct MonitoredDevice {
     device_type: DeviceMonitorType,
     consecutive_missing: u32,
     is_bluetooth: bool,
+    /// Follow the OS default device instead of sticking to `name`
+    follow_default: bool,
+    /// OS default at the last check
+    last_default: Option<String>,
+    /// New default seen once, switched to when it is still the default at the next check
+    pending_default: Option<String>,
 }
 
 impl MonitoredDevice {
@@ -53,7 +92,39 @@ impl MonitoredDevice {
             device_type,
             consecutive_missing: 0,
             is_bluetooth,
+            follow_default: false,
+            last_default: None,
+            pending_default: None,
+        }
+    }
+
+    /// Start following the OS default, remembering the current one
+    fn with_follow_default(mut self) -> Self {
+        self.follow_default = true;
+        self.last_default = current_default_device_name(&self.device_type);
+        self
+    }
+
+    /// Check the OS default; returns the new default once a change has been stable
+    /// for two consecutive checks (devices briefly become default while connecting)
+    fn check_default(&mut self, current_default: Option<String>) -> Option<String> {
+        if !self.follow_default || current_default == self.last_default {
+            self.pending_default = None;
+            return None;
+        }
+        let new_default = current_default?;
+
+        if self.pending_default.as_deref() != Some(new_default.as_str()) {
+            self.pending_default = Some(new_default);
+            return None;
+        }
+
+        self.pending_default = None;
+        self.last_default = Some(new_default.clone());
+        if new_default == self.name {
+            return None;
         }
+        Some(new_default)
     }
 
     /// Get appropriate disconnect threshold based on device type
@@ -113,22 +184,30 @@ impl AudioDeviceMonitor {
 
         let mut monitored_devices = Vec::new();
 
+        // Virtual (file-backed) devices never appear in the hardware device list
+        let microphone = microphone.filter(|d| !d.is_virtual_file());
+        let system_audio = system_audio.filter(|d| !d.is_virtual_file());
+
+        let policy = current_follow_default_devices();
+
         if let Some(mic) = microphone {
-            monitored_devices.push(MonitoredDevice::new(
-                mic.name.clone(),
-                DeviceMonitorType::Microphone,
-            ));
-            info!("🔍 Monitoring microphone: '{}' (Bluetooth: {})",
-                  mic.name, monitored_devices.last().unwrap().is_bluetooth);
+            let mut device = MonitoredDevice::new(mic.name.clone(), DeviceMonitorType::Microphone);
+            if policy.microphone {
+                device = device.with_follow_default();
+            }
+            info!("🔍 Monitoring microphone: '{}' (Bluetooth: {}, follow default: {})",
+                  mic.name, device.is_bluetooth, device.follow_default);
+            monitored_devices.push(device);
         }
 
         if let Some(sys) = system_audio {
-            monitored_devices.push(MonitoredDevice::new(
-                sys.name.clone(),
-                DeviceMonitorType::SystemAudio,
-            ));
-            info!("🔍 Monitoring system audio: '{}' (Bluetooth: {})",
-                  sys.name, monitored_devices.last().unwrap().is_bluetooth);
+            let mut device = MonitoredDevice::new(sys.name.clone(), DeviceMonitorType::SystemAudio);
+            if policy.system_audio {
+                device = device.with_follow_default();
+            }
+            info!("🔍 Monitoring system audio: '{}' (Bluetooth: {}, follow default: {})",
+                  sys.name, device.is_bluetooth, device.follow_default);
+            monitored_devices.push(device);
         }
 
         if monitored_devices.is_empty() {
@@ -199,6 +278,24 @@ impl AudioDeviceMonitor {
 
             // Check each monitored device
             for monitored in &mut monitored_devices {
+                // Follow the OS default first: a switch replaces the device being watched
+                if monitored.follow_default {
+                    let current_default = current_default_device_name(&monitored.device_type);
+                    if let Some(new_default) = monitored.check_default(current_default) {
+                        info!("🔀 Default {:?} device changed: '{}' -> '{}'",
+                              monitored.device_type, monitored.name, new_default);
+                        let _ = event_sender.send(DeviceEvent::DefaultDeviceChanged {
+                            device_name: new_default.clone(),
+                            device_type: monitored.device_type.clone(),
+                        });
+                        let mut followed = MonitoredDevice::new(new_default.clone(), monitored.device_type.clone());
+                        followed.follow_default = true;
+                        followed.last_default = Some(new_default);
+                        *monitored = followed;
+                        continue;
+                    }
+                }
+
                 let device_found = current_devices.iter().any(|d| d.name == monitored.name);
 
                 if device_found {
@@ -252,6 +349,21 @@ impl AudioDeviceMonitor {
     }
 }
 
+/// Name of the current OS default device for a monitored device type
+fn current_default_device_name(device_type: &DeviceMonitorType) -> Option<String> {
+    let device = match device_type {
+        DeviceMonitorType::Microphone => default_input_device(),
+        DeviceMonitorType::SystemAudio => default_output_device(),
+    };
+    match device {
+        Ok(device) => Some(device.name),
+        Err(e) => {
+            debug!("No default {:?} device: {}", device_type, e);
+            None
+        }
+    }
+}
+
 impl Default for AudioDeviceMonitor {
     fn default() -> Self {
         Self::new().0
@@ -286,6 +398,26 @@ mod tests {
         assert_eq!(builtin.disconnect_threshold(), 2);
     }
 
+    #[test]
+    fn test_default_change_must_be_stable() {
+        let mut mic = MonitoredDevice::new("Built-in Microphone".to_string(), DeviceMonitorType::Microphone);
+        mic.follow_default = true;
+        mic.last_default = Some("Built-in Microphone".to_string());
+
+        let headset = || Some("USB Headset".to_string());
+        assert_eq!(mic.check_default(headset()), None);
+        assert_eq!(mic.check_default(headset()), headset());
+        assert_eq!(mic.check_default(headset()), None);
+
+        // A default that flickers for one check is ignored
+        assert_eq!(mic.check_default(Some("AirPods".to_string())), None);
+        assert_eq!(mic.check_default(headset()), None);
+
+        mic.follow_default = false;
+        mic.last_default = None;
+        assert_eq!(mic.check_default(headset()), None);
+    }
+
     #[tokio::test]
     async fn test_monitor_creation() {
         let (mut monitor, _receiver) = AudioDeviceMonitor::new();
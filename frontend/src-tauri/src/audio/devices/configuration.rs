@@ -2,6 +2,7 @@ use anyhow::{anyhow, Result};
 use lazy_static::lazy_static;
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::path::Path;
 use std::sync::atomic::AtomicU64;
 
 lazy_static! {
@@ -52,6 +53,10 @@ pub enum DeviceType {
     Output,
 }
 
+/// Name prefix of virtual devices that stream an audio file instead of capturing
+/// from hardware (see `capture::virtual_file`), e.g. `file:/tmp/mic.wav?speed=4`
+pub const VIRTUAL_FILE_DEVICE_PREFIX: &str = "file:";
+
 #[derive(Clone, Eq, PartialEq, Hash, Serialize, Debug)]
 pub struct AudioDevice {
     pub name: String,
@@ -63,6 +68,21 @@ impl AudioDevice {
         AudioDevice { name, device_type }
     }
 
+    /// Create a virtual device that plays `path` as if it were captured live.
+    /// `speed` is the playback rate relative to real time (1.0 = real time).
+    pub fn virtual_file(path: &Path, device_type: DeviceType, speed: f32) -> Self {
+        let mut name = format!("{}{}", VIRTUAL_FILE_DEVICE_PREFIX, path.display());
+        if speed != 1.0 {
+            name.push_str(&format!("?speed={}", speed));
+        }
+        AudioDevice::new(name, device_type)
+    }
+
+    /// Whether this device is a file-backed virtual device
+    pub fn is_virtual_file(&self) -> bool {
+        self.name.starts_with(VIRTUAL_FILE_DEVICE_PREFIX)
+    }
+
     pub fn from_name(name: &str) -> Result<Self> {
         if name.trim().is_empty() {
             return Err(anyhow!("Device name cannot be empty"));
@@ -111,6 +131,10 @@ pub fn parse_audio_device(name: &str) -> Result<AudioDevice> {
 pub async fn get_device_and_config(
     audio_device: &AudioDevice,
 ) -> Result<(cpal::Device, cpal::SupportedStreamConfig)> {
+    if audio_device.is_virtual_file() {
+        return Err(anyhow!("Virtual device has no hardware backing: {}", audio_device.name));
+    }
+
     #[cfg(target_os = "windows")]
     {
         return super::platform::get_windows_device(audio_device);
@@ -1,7 +1,8 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use anyhow::{Result, anyhow};
 use log::{info, warn, error};
 use super::encode::encode_single_audio;
+use super::native_encoder::{self, AudioFileFormat};
 use super::recording_state::AudioChunk;
 use serde::{Serialize, Deserialize};
 
@@ -20,6 +21,8 @@ pub struct IncrementalAudioSaver {
     checkpoint_buffer: Vec<AudioData>,
     checkpoint_interval_samples: usize,  // 30s at 48kHz = 1,440,000 samples (per channel)
     checkpoint_count: u32,
+    checkpoint_files: Vec<PathBuf>,
+    checkpoint_format: AudioFileFormat,  // Mp4 (FFmpeg) or Wav (native fallback)
     checkpoints_dir: PathBuf,
     meeting_folder: PathBuf,
     sample_rate: u32,
@@ -41,7 +44,10 @@ impl IncrementalAudioSaver {
             return Err(anyhow!("Checkpoints directory does not exist: {}", checkpoints_dir.display()));
         }
 
-        info!("IncrementalAudioSaver: {} channels, {}Hz, 30s checkpoints", channels, sample_rate);
+        let checkpoint_format = AudioFileFormat::detect_checkpoint_format();
+
+        info!("IncrementalAudioSaver: {} channels, {}Hz, 30s {} checkpoints",
+              channels, sample_rate, checkpoint_format.extension());
 
         Ok(Self {
             checkpoint_buffer: Vec::new(),
@@ -49,6 +55,8 @@ impl IncrementalAudioSaver {
             // For stereo: 48000 * 30 * 2 = 2,880,000 interleaved samples
             checkpoint_interval_samples: sample_rate as usize * 30 * channels as usize,
             checkpoint_count: 0,
+            checkpoint_files: Vec::new(),
+            checkpoint_format,
             checkpoints_dir,
             meeting_folder,
             sample_rate,
@@ -96,16 +104,29 @@ impl IncrementalAudioSaver {
         }
 
         // Generate checkpoint filename
-        let checkpoint_path = self.checkpoints_dir
-            .join(format!("audio_chunk_{:03}.mp4", self.checkpoint_count));
+        let mut checkpoint_path = self.checkpoints_dir
+            .join(format!("audio_chunk_{:03}.{}", self.checkpoint_count, self.checkpoint_format.extension()));
 
         // Encode and save checkpoint (stereo or mono depending on channels)
-        encode_single_audio(
-            bytemuck::cast_slice(&audio_data),
-            self.sample_rate,
-            self.channels,
-            &checkpoint_path
-        )?;
+        if self.checkpoint_format == AudioFileFormat::Mp4 {
+            if let Err(e) = encode_single_audio(
+                bytemuck::cast_slice(&audio_data),
+                self.sample_rate,
+                self.channels,
+                &checkpoint_path
+            ) {
+                // Never lose audio because FFmpeg broke mid-recording
+                warn!("FFmpeg checkpoint encoding failed ({}), switching to native WAV checkpoints", e);
+                self.checkpoint_format = AudioFileFormat::Wav;
+                checkpoint_path.set_extension(AudioFileFormat::Wav.extension());
+            }
+        }
+
+        if self.checkpoint_format == AudioFileFormat::Wav {
+            native_encoder::write_wav(&checkpoint_path, &audio_data, self.sample_rate, self.channels)?;
+        }
+
+        self.checkpoint_files.push(checkpoint_path);
 
         let duration_seconds = audio_data.len() as f32 / (self.sample_rate as f32 * self.channels as f32);
         self.checkpoint_count += 1;
@@ -120,7 +141,8 @@ impl IncrementalAudioSaver {
 
     /// Finalize the recording: save final checkpoint, merge all checkpoints, cleanup
     ///
-    /// Returns the path to the final merged audio.mp4 file
+    /// Returns the path to the final merged audio file (audio.mp4, or audio.flac
+    /// when FFmpeg is unavailable)
     pub async fn finalize(&mut self) -> Result<PathBuf> {
         info!("Finalizing incremental recording...");
 
@@ -135,9 +157,20 @@ impl IncrementalAudioSaver {
             return Err(anyhow!("No audio checkpoints to merge - recording may have failed"));
         }
 
-        // Merge all checkpoints using FFmpeg concat
-        let final_audio_path = self.meeting_folder.join("audio.mp4");
-        self.merge_checkpoints(&final_audio_path).await?;
+        // Verify checkpoints exist before merging
+        for checkpoint_path in &self.checkpoint_files {
+            if !checkpoint_path.exists() {
+                return Err(anyhow!("Checkpoint file missing: {}", checkpoint_path.display()));
+            }
+        }
+
+        let checkpoint_files = self.checkpoint_files.clone();
+        let meeting_folder = self.meeting_folder.clone();
+        let final_audio_path = tokio::task::spawn_blocking(move || {
+            merge_checkpoint_files(&checkpoint_files, &meeting_folder)
+        })
+        .await
+        .map_err(|e| anyhow!("Checkpoint merge task failed: {}", e))??;
 
         // Clean up checkpoints directory
         info!("Cleaning up {} checkpoint files", self.checkpoint_count);
@@ -151,85 +184,139 @@ impl IncrementalAudioSaver {
         Ok(final_audio_path)
     }
 
-    /// Merge all checkpoint files into final audio.mp4 using FFmpeg concat
-    /// Uses concat demuxer for fast merging without re-encoding
-    async fn merge_checkpoints(&self, output: &PathBuf) -> Result<()> {
-        info!("Merging {} checkpoints into final audio file...", self.checkpoint_count);
+    /// File name the final recording will most likely get (used for initial metadata)
+    pub fn expected_audio_file_name(&self) -> String {
+        final_audio_file_name(self.checkpoint_format)
+    }
 
-        // Create concat list file for FFmpeg
-        let list_file = self.checkpoints_dir.join("concat_list.txt");
-        let mut list_content = String::new();
+    /// Force a checkpoint format (e.g. WAV to avoid FFmpeg entirely)
+    pub fn set_checkpoint_format(&mut self, format: AudioFileFormat) {
+        if format == AudioFileFormat::Flac {
+            warn!("FLAC is only used for merged output, keeping {} checkpoints", self.checkpoint_format.extension());
+            return;
+        }
+        self.checkpoint_format = format;
+    }
 
-        for i in 0..self.checkpoint_count {
-            let checkpoint_path = self.checkpoints_dir
-                .join(format!("audio_chunk_{:03}.mp4", i));
+    /// Get the meeting folder path
+    pub fn get_meeting_folder(&self) -> &PathBuf {
+        &self.meeting_folder
+    }
 
-            // Verify checkpoint exists
-            if !checkpoint_path.exists() {
-                return Err(anyhow!("Checkpoint file missing: {}", checkpoint_path.display()));
-            }
+    /// Get current checkpoint count
+    pub fn get_checkpoint_count(&self) -> u32 {
+        self.checkpoint_count
+    }
+}
 
-            // Use absolute path for FFmpeg (required for safe mode)
-            let abs_path = checkpoint_path.canonicalize()?;
-            list_content.push_str(&format!("file '{}'\n", abs_path.display()));
-        }
+fn final_audio_file_name(checkpoint_format: AudioFileFormat) -> String {
+    match checkpoint_format {
+        AudioFileFormat::Mp4 => "audio.mp4".to_string(),
+        _ => "audio.flac".to_string(),
+    }
+}
 
-        std::fs::write(&list_file, list_content)?;
-
-        let ffmpeg_path = find_ffmpeg_path()
-            .ok_or_else(|| anyhow!("FFmpeg not found. Please install FFmpeg to finalize recordings."))?;
-        info!("Using FFmpeg at: {:?}", ffmpeg_path);
-
-        // Run FFmpeg concat command
-        // Using concat demuxer with copy codec for fast merging (no re-encoding)
-        
-        let mut command = std::process::Command::new(ffmpeg_path);
-        
-        command.args(&[
-            "-f", "concat",          // Use concat demuxer
-            "-safe", "0",            // Allow absolute paths
-            "-i", list_file.to_str().ok_or_else(|| anyhow::anyhow!("List file path contains invalid UTF-8: {:?}", list_file))?,
-            "-c", "copy",            // Copy codec - no re-encoding!
-            "-y",                    // Overwrite output file
-            output.to_str().ok_or_else(|| anyhow::anyhow!("Output path contains invalid UTF-8: {:?}", output))?
-        ]);
-
-        // Hide console window on Windows to prevent CMD popup during finalization
-        #[cfg(target_os = "windows")]
-        {
-            use std::os::windows::process::CommandExt;
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            command.creation_flags(CREATE_NO_WINDOW);
-        }
+/// Returns true for files written by `IncrementalAudioSaver::save_checkpoint`
+fn is_checkpoint_file(path: &Path) -> bool {
+    matches!(
+        AudioFileFormat::from_path(path),
+        Some(AudioFileFormat::Mp4) | Some(AudioFileFormat::Wav)
+    )
+}
 
-        let ffmpeg_output = command.output()?;
+/// Merge checkpoint files (in order) into the final recording inside `meeting_folder`
+///
+/// MP4 checkpoints are concatenated with FFmpeg (no re-encoding) when it is available.
+/// Otherwise - or if FFmpeg fails - checkpoints are decoded in-process and merged into
+/// a lossless audio.flac, so FFmpeg is never required to keep a recording.
+pub fn merge_checkpoint_files(checkpoint_files: &[PathBuf], meeting_folder: &Path) -> Result<PathBuf> {
+    if checkpoint_files.is_empty() {
+        return Err(anyhow!("No audio checkpoints to merge"));
+    }
 
-        if !ffmpeg_output.status.success() {
-            let stderr = String::from_utf8_lossy(&ffmpeg_output.stderr);
-            error!("FFmpeg merge failed: {}", stderr);
-            return Err(anyhow!("FFmpeg concat failed: {}", stderr));
-        }
+    info!("Merging {} checkpoints into final audio file...", checkpoint_files.len());
+
+    let all_mp4 = checkpoint_files
+        .iter()
+        .all(|p| AudioFileFormat::from_path(p) == Some(AudioFileFormat::Mp4));
 
-        // Verify output file was created
-        if !output.exists() {
-            return Err(anyhow!("Merged audio file was not created: {}", output.display()));
+    if all_mp4 {
+        if let Some(ffmpeg_path) = find_ffmpeg_path() {
+            let output = meeting_folder.join(final_audio_file_name(AudioFileFormat::Mp4));
+            match concat_with_ffmpeg(&ffmpeg_path, checkpoint_files, &output) {
+                Ok(()) => return Ok(output),
+                Err(e) => warn!("FFmpeg concat failed, falling back to native FLAC merge: {}", e),
+            }
+        } else {
+            warn!("FFmpeg not found, merging MP4 checkpoints natively into FLAC");
         }
+    }
 
-        info!("Successfully merged {} checkpoints → {}",
-              self.checkpoint_count, output.display());
+    let output = meeting_folder.join(final_audio_file_name(AudioFileFormat::Wav));
+    native_encoder::merge_to_flac(checkpoint_files, &output)?;
 
-        Ok(())
+    if !output.exists() {
+        return Err(anyhow!("Merged audio file was not created: {}", output.display()));
     }
 
-    /// Get the meeting folder path
-    pub fn get_meeting_folder(&self) -> &PathBuf {
-        &self.meeting_folder
+    info!("Successfully merged {} checkpoints → {}", checkpoint_files.len(), output.display());
+    Ok(output)
+}
+
+/// Concatenate MP4 checkpoints using the FFmpeg concat demuxer (copy codec, no re-encoding)
+fn concat_with_ffmpeg(ffmpeg_path: &Path, checkpoint_files: &[PathBuf], output: &Path) -> Result<()> {
+    let checkpoints_dir = checkpoint_files[0]
+        .parent()
+        .ok_or_else(|| anyhow!("Checkpoint has no parent directory"))?;
+
+    // Create concat list file for FFmpeg
+    let list_file = checkpoints_dir.join("concat_list.txt");
+    let mut list_content = String::new();
+
+    for checkpoint_path in checkpoint_files {
+        // Use absolute path for FFmpeg (required for safe mode)
+        let abs_path = checkpoint_path.canonicalize()?;
+        list_content.push_str(&format!("file '{}'\n", abs_path.display()));
     }
 
-    /// Get current checkpoint count
-    pub fn get_checkpoint_count(&self) -> u32 {
-        self.checkpoint_count
+    std::fs::write(&list_file, list_content)?;
+
+    info!("Using FFmpeg at: {:?}", ffmpeg_path);
+
+    let mut command = std::process::Command::new(ffmpeg_path);
+
+    command.args(&[
+        "-f", "concat",          // Use concat demuxer
+        "-safe", "0",            // Allow absolute paths
+        "-i", list_file.to_str().ok_or_else(|| anyhow::anyhow!("List file path contains invalid UTF-8: {:?}", list_file))?,
+        "-c", "copy",            // Copy codec - no re-encoding!
+        "-y",                    // Overwrite output file
+        output.to_str().ok_or_else(|| anyhow::anyhow!("Output path contains invalid UTF-8: {:?}", output))?
+    ]);
+
+    // Hide console window on Windows to prevent CMD popup during finalization
+    #[cfg(target_os = "windows")]
+    {
+        use std::os::windows::process::CommandExt;
+        const CREATE_NO_WINDOW: u32 = 0x08000000;
+        command.creation_flags(CREATE_NO_WINDOW);
+    }
+
+    let ffmpeg_output = command.output()?;
+    let _ = std::fs::remove_file(&list_file);
+
+    if !ffmpeg_output.status.success() {
+        let stderr = String::from_utf8_lossy(&ffmpeg_output.stderr);
+        error!("FFmpeg merge failed: {}", stderr);
+        return Err(anyhow!("FFmpeg concat failed: {}", stderr));
+    }
+
+    // Verify output file was created
+    if !output.exists() {
+        return Err(anyhow!("Merged audio file was not created: {}", output.display()));
     }
+
+    Ok(())
 }
 
 /// Audio recovery status for transcript recovery feature
@@ -266,13 +353,12 @@ pub async fn recover_audio_from_checkpoints(
         });
     }
 
-    // Scan for checkpoint files
-    let mut checkpoint_files: Vec<_> = std::fs::read_dir(&checkpoints_dir)
+    // Scan for checkpoint files (MP4 from FFmpeg or WAV from the native encoder)
+    let mut checkpoint_files: Vec<PathBuf> = std::fs::read_dir(&checkpoints_dir)
         .map_err(|e| format!("Failed to read checkpoints directory: {}", e))?
         .filter_map(|entry| entry.ok())
-        .filter(|entry| {
-            entry.path().extension().and_then(|s| s.to_str()) == Some("mp4")
-        })
+        .map(|entry| entry.path())
+        .filter(|path| is_checkpoint_file(path))
         .collect();
 
     if checkpoint_files.is_empty() {
@@ -286,63 +372,23 @@ pub async fn recover_audio_from_checkpoints(
         });
     }
 
-    // Sort by filename (audio_chunk_000.mp4, audio_chunk_001.mp4, etc.)
-    checkpoint_files.sort_by_key(|entry| entry.path());
+    // Sort by filename (audio_chunk_000.mp4, audio_chunk_001.wav, etc.)
+    checkpoint_files.sort();
 
     let chunk_count = checkpoint_files.len() as u32;
     let estimated_duration = (chunk_count as f64) * 30.0; // 30 seconds per chunk
 
     info!("Found {} checkpoint files, estimated duration: {:.2}s", chunk_count, estimated_duration);
 
-    // Create FFmpeg concat file
-    let concat_file_path = checkpoints_dir.join("concat_list.txt");
-    let mut concat_content = String::new();
-
-    for entry in &checkpoint_files {
-        let path = entry.path().canonicalize()
-            .map_err(|e| format!("Failed to canonicalize path: {}", e))?;
-        concat_content.push_str(&format!("file '{}'\n", path.display()));
-    }
-
-    std::fs::write(&concat_file_path, concat_content)
-        .map_err(|e| format!("Failed to write concat file: {}", e))?;
-
-    // Run FFmpeg to merge chunks
-    let output_path = folder_path.join("audio.mp4");
-    let output_path_str = output_path.to_str()
-        .ok_or("Invalid output path")?
-        .to_string();
-
-    let ffmpeg_path = find_ffmpeg_path()
-        .ok_or_else(|| "FFmpeg not found. Please install FFmpeg to recover audio.".to_string())?;
-    info!("Using FFmpeg at: {:?}", ffmpeg_path);
-
-    let mut command = std::process::Command::new(ffmpeg_path);
-
-    command.args(&[
-        "-f", "concat",
-        "-safe", "0",
-        "-i", concat_file_path.to_str().ok_or_else(|| format!("Concat file path contains invalid UTF-8: {:?}", concat_file_path))?,
-        "-c", "copy",
-        "-y", // Overwrite if exists
-        &output_path_str
-    ]);
-
-    // Hide console window on Windows
-    #[cfg(target_os = "windows")]
-    {
-        use std::os::windows::process::CommandExt;
-        const CREATE_NO_WINDOW: u32 = 0x08000000;
-        command.creation_flags(CREATE_NO_WINDOW);
-    }
-
-    let ffmpeg_result = command.output();
-
-    match ffmpeg_result {
-        Ok(output) if output.status.success() => {
-            // Clean up concat file
-            let _ = std::fs::remove_file(concat_file_path);
+    let merge_result = tokio::task::spawn_blocking(move || {
+        merge_checkpoint_files(&checkpoint_files, &folder_path)
+    })
+    .await
+    .map_err(|e| format!("Audio recovery task failed: {}", e))?;
 
+    match merge_result {
+        Ok(output_path) => {
+            let output_path_str = output_path.to_string_lossy().to_string();
             info!("Successfully recovered audio: {}", output_path_str);
 
             Ok(AudioRecoveryStatus {
@@ -353,25 +399,14 @@ pub async fn recover_audio_from_checkpoints(
                 message: format!("Successfully recovered {} audio chunks", chunk_count),
             })
         }
-        Ok(output) => {
-            let error = String::from_utf8_lossy(&output.stderr);
-            error!("FFmpeg recovery failed: {}", error);
-            Ok(AudioRecoveryStatus {
-                status: "failed".to_string(),
-                chunk_count,
-                estimated_duration_seconds: estimated_duration,
-                audio_file_path: None,
-                message: format!("FFmpeg failed: {}", error),
-            })
-        }
         Err(e) => {
-            error!("Failed to run FFmpeg: {}", e);
+            error!("Audio recovery failed: {}", e);
             Ok(AudioRecoveryStatus {
                 status: "failed".to_string(),
                 chunk_count,
                 estimated_duration_seconds: estimated_duration,
                 audio_file_path: None,
-                message: format!("Failed to run FFmpeg: {}", e),
+                message: format!("Failed to merge audio chunks: {}", e),
             })
         }
     }
@@ -398,7 +433,7 @@ pub async fn cleanup_checkpoints(meeting_folder: String) -> Result<(), String> {
 }
 
 /// Check if a meeting folder has audio checkpoint files
-/// Returns true if .checkpoints/ directory exists and contains .mp4 files
+/// Returns true if .checkpoints/ directory exists and contains .mp4 or .wav files
 #[tauri::command]
 pub async fn has_audio_checkpoints(meeting_folder: String) -> Result<bool, String> {
     let folder_path = PathBuf::from(&meeting_folder);
@@ -409,15 +444,13 @@ pub async fn has_audio_checkpoints(meeting_folder: String) -> Result<bool, Strin
         return Ok(false);
     }
 
-    // Scan for .mp4 checkpoint files
-    let has_mp4_files = std::fs::read_dir(&checkpoints_dir)
+    // Scan for checkpoint files
+    let has_checkpoint_files = std::fs::read_dir(&checkpoints_dir)
         .map_err(|e| format!("Failed to read checkpoints directory: {}", e))?
         .filter_map(|entry| entry.ok())
-        .any(|entry| {
-            entry.path().extension().and_then(|s| s.to_str()) == Some("mp4")
-        });
+        .any(|entry| is_checkpoint_file(&entry.path()));
 
-    Ok(has_mp4_files)
+    Ok(has_checkpoint_files)
 }
 
 #[cfg(test)]
@@ -461,6 +494,34 @@ mod tests {
         assert!(!meeting_folder.join(".checkpoints").exists());
     }
 
+    #[tokio::test]
+    async fn test_native_checkpoints_without_ffmpeg() {
+        let temp_dir = tempdir().unwrap();
+        let meeting_folder = temp_dir.path().join("Native_Test");
+        std::fs::create_dir_all(meeting_folder.join(".checkpoints")).unwrap();
+
+        let mut saver = IncrementalAudioSaver::new(meeting_folder.clone(), 48000, 2).unwrap();
+        saver.set_checkpoint_format(AudioFileFormat::Wav);
+
+        // 35 seconds of audio: one full checkpoint plus a partial final one
+        for _ in 0..70 {
+            saver.add_chunk(AudioChunk {
+                data: vec![0.25f32; 48000],  // 0.5s stereo at 48kHz
+                sample_rate: 48000,
+                device_type: DeviceType::Microphone,
+            }).unwrap();
+        }
+        assert_eq!(saver.checkpoint_count, 1);
+        assert!(meeting_folder.join(".checkpoints/audio_chunk_000.wav").exists());
+
+        let final_path = saver.finalize().await.unwrap();
+        assert_eq!(final_path, meeting_folder.join("audio.flac"));
+
+        let decoded = native_encoder::decode_audio_file(&final_path).unwrap();
+        assert_eq!(decoded.channels, 2);
+        assert!((decoded.duration_seconds() - 35.0).abs() < 0.01);
+    }
+
     #[tokio::test]
     async fn test_empty_recording() {
         let temp_dir = tempdir().unwrap();
@@ -2,6 +2,7 @@
 pub mod audio_processing;
 pub mod encode;
 pub mod ffmpeg;
+pub mod native_encoder;  // Pure-Rust WAV/FLAC encoding (FFmpeg-free fallback)
 pub mod vad;
 
 // Modularized device management
@@ -25,8 +26,13 @@ pub mod recording_commands;
 pub mod recording_preferences;
 pub mod recording_saver;
 pub mod incremental_saver;  // NEW: Incremental audio saving with checkpoints
+pub mod auto_rules;  // Auto-stop / auto-pause rules (silence, meeting app exit, max duration)
+pub mod recording_journal;  // Append-only crash-recovery journal (transcripts, pauses, devices)
+pub mod clip_extractor;  // Audio clips for time ranges / transcript segments
 pub mod level_monitor;
 pub mod simple_level_monitor;
+pub mod quality_monitor;  // Live audio-quality analysis (clipping, silence, dropouts, SNR, bandwidth)
+pub mod clock_drift;  // Mic/system clock-drift estimation and fine resampling
 pub mod buffer_pool;
 pub mod post_processor;
 pub mod hardware_detector;
@@ -88,7 +94,9 @@ pub use hardware_detector::{HardwareProfile, AdaptiveWhisperConfig, PerformanceT
 pub use encode::{
     encode_single_audio, AudioInput
 };
-pub use device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType};
+pub use native_encoder::{AudioFileFormat, DecodedAudio};
+pub use device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType, FollowDefaultDevices};
+pub use auto_rules::{AutoRecordingRules, AutoRule, AutoRuleAction};
 
 // Export device detection and diagnostics
 pub use device_detection::{InputDeviceKind, calculate_buffer_timeout};
@@ -0,0 +1,831 @@
+// audio/native_encoder.rs
+//
+// In-process audio encoding used when FFmpeg is not available.
+// Checkpoints are written as 16-bit PCM WAV and the final recording is
+// merged into a lossless FLAC file, so a recording can always be saved,
+// merged and recovered without an external binary.
+
+use anyhow::{anyhow, Result};
+use log::{debug, info, warn};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use super::ffmpeg::find_ffmpeg_path;
+
+/// Container/codec used for recording files on disk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioFileFormat {
+    /// AAC in MP4 (requires FFmpeg)
+    Mp4,
+    /// 16-bit PCM WAV (native)
+    Wav,
+    /// Lossless FLAC (native)
+    Flac,
+}
+
+impl AudioFileFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            AudioFileFormat::Mp4 => "mp4",
+            AudioFileFormat::Wav => "wav",
+            AudioFileFormat::Flac => "flac",
+        }
+    }
+
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|s| s.to_str())?.to_ascii_lowercase().as_str() {
+            "mp4" | "m4a" => Some(AudioFileFormat::Mp4),
+            "wav" => Some(AudioFileFormat::Wav),
+            "flac" => Some(AudioFileFormat::Flac),
+            _ => None,
+        }
+    }
+
+    /// Whether an external FFmpeg binary is needed to write this format
+    pub fn requires_ffmpeg(&self) -> bool {
+        matches!(self, AudioFileFormat::Mp4)
+    }
+
+    /// Format used for checkpoints: MP4 when FFmpeg is installed, WAV otherwise
+    pub fn detect_checkpoint_format() -> Self {
+        if find_ffmpeg_path().is_some() {
+            AudioFileFormat::Mp4
+        } else {
+            warn!("FFmpeg not available - using native WAV checkpoints and FLAC output");
+            AudioFileFormat::Wav
+        }
+    }
+}
+
+/// Decoded interleaved audio
+#[derive(Debug, Clone)]
+pub struct DecodedAudio {
+    pub samples: Vec<f32>,
+    pub sample_rate: u32,
+    pub channels: u16,
+}
+
+impl DecodedAudio {
+    /// Duration in seconds
+    pub fn duration_seconds(&self) -> f64 {
+        if self.sample_rate == 0 || self.channels == 0 {
+            return 0.0;
+        }
+        self.samples.len() as f64 / (self.sample_rate as f64 * self.channels as f64)
+    }
+}
+
+#[inline]
+fn f32_to_i16(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32).round() as i16
+}
+
+// ============================================================================
+// WAV
+// ============================================================================
+
+/// Write interleaved f32 samples as a 16-bit PCM WAV file
+pub fn write_wav(path: &Path, samples: &[f32], sample_rate: u32, channels: u16) -> Result<()> {
+    if channels == 0 {
+        return Err(anyhow!("Cannot write WAV with zero channels"));
+    }
+    std::fs::write(path, encode_wav_bytes(samples, sample_rate, channels))?;
+    Ok(())
+}
+
+/// Encode interleaved f32 samples as 16-bit PCM WAV bytes (in memory)
+pub fn encode_wav_bytes(samples: &[f32], sample_rate: u32, channels: u16) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let block_align = channels * 2;
+    let byte_rate = sample_rate * block_align as u32;
+
+    let mut out = Vec::with_capacity(44 + samples.len() * 2);
+    out.extend_from_slice(b"RIFF");
+    out.extend_from_slice(&(36 + data_len).to_le_bytes());
+    out.extend_from_slice(b"WAVE");
+    out.extend_from_slice(b"fmt ");
+    out.extend_from_slice(&16u32.to_le_bytes());
+    out.extend_from_slice(&1u16.to_le_bytes());
+    out.extend_from_slice(&channels.to_le_bytes());
+    out.extend_from_slice(&sample_rate.to_le_bytes());
+    out.extend_from_slice(&byte_rate.to_le_bytes());
+    out.extend_from_slice(&block_align.to_le_bytes());
+    out.extend_from_slice(&16u16.to_le_bytes());
+    out.extend_from_slice(b"data");
+    out.extend_from_slice(&data_len.to_le_bytes());
+    for &sample in samples {
+        out.extend_from_slice(&f32_to_i16(sample).to_le_bytes());
+    }
+    out
+}
+
+/// Read a PCM (16-bit) or IEEE float (32-bit) WAV file into interleaved f32 samples
+pub fn read_wav(path: &Path) -> Result<DecodedAudio> {
+    let mut reader = BufReader::new(File::open(path)?);
+
+    let mut riff = [0u8; 12];
+    reader.read_exact(&mut riff)?;
+    if &riff[0..4] != b"RIFF" || &riff[8..12] != b"WAVE" {
+        return Err(anyhow!("Not a WAV file: {}", path.display()));
+    }
+
+    let mut format_tag = 0u16;
+    let mut channels = 0u16;
+    let mut sample_rate = 0u32;
+    let mut bits_per_sample = 0u16;
+
+    loop {
+        let mut chunk_header = [0u8; 8];
+        if reader.read_exact(&mut chunk_header).is_err() {
+            return Err(anyhow!("WAV file has no data chunk: {}", path.display()));
+        }
+        let chunk_id = &chunk_header[0..4];
+        let chunk_len = u32::from_le_bytes([chunk_header[4], chunk_header[5], chunk_header[6], chunk_header[7]]);
+
+        if chunk_id == b"fmt " {
+            let mut fmt = vec![0u8; chunk_len as usize];
+            reader.read_exact(&mut fmt)?;
+            if fmt.len() < 16 {
+                return Err(anyhow!("Invalid WAV fmt chunk"));
+            }
+            format_tag = u16::from_le_bytes([fmt[0], fmt[1]]);
+            channels = u16::from_le_bytes([fmt[2], fmt[3]]);
+            sample_rate = u32::from_le_bytes([fmt[4], fmt[5], fmt[6], fmt[7]]);
+            bits_per_sample = u16::from_le_bytes([fmt[14], fmt[15]]);
+        } else if chunk_id == b"data" {
+            if channels == 0 {
+                return Err(anyhow!("WAV data chunk before fmt chunk"));
+            }
+
+            // Tolerate truncated data chunks (e.g. a checkpoint written during a crash)
+            let mut data = Vec::with_capacity(chunk_len as usize);
+            reader.take(chunk_len as u64).read_to_end(&mut data)?;
+
+            let samples: Vec<f32> = match (format_tag, bits_per_sample) {
+                (1, 16) => data
+                    .chunks_exact(2)
+                    .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+                    .collect(),
+                (3, 32) => data
+                    .chunks_exact(4)
+                    .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                    .collect(),
+                _ => {
+                    return Err(anyhow!(
+                        "Unsupported WAV encoding: format {} with {} bits",
+                        format_tag,
+                        bits_per_sample
+                    ))
+                }
+            };
+
+            return Ok(DecodedAudio { samples, sample_rate, channels });
+        } else {
+            // Chunks are padded to an even number of bytes
+            let skip = chunk_len as i64 + (chunk_len & 1) as i64;
+            reader.seek(SeekFrom::Current(skip))?;
+        }
+    }
+}
+
+// ============================================================================
+// Generic decoding
+// ============================================================================
+
+/// Decode any supported recording file (WAV natively, MP4/AAC and FLAC through symphonia)
+pub fn decode_audio_file(path: &Path) -> Result<DecodedAudio> {
+    decode_audio_range(path, 0.0, None)
+}
+
+/// Keep only the frames of `audio` that fall inside `[start, end)` seconds,
+/// where `offset` is the time of the first frame in `audio`
+fn trim_to_range(audio: &mut DecodedAudio, offset: f64, start: f64, end: Option<f64>) {
+    let channels = audio.channels.max(1) as usize;
+    let rate = audio.sample_rate as f64;
+    let total_frames = audio.samples.len() / channels;
+
+    let first = (((start - offset) * rate).round().max(0.0) as usize).min(total_frames);
+    let last = match end {
+        Some(end) => (((end - offset) * rate).round().max(0.0) as usize).clamp(first, total_frames),
+        None => total_frames,
+    };
+
+    audio.samples.truncate(last * channels);
+    audio.samples.drain(..first * channels);
+}
+
+/// Decode `[start_seconds, end_seconds)` of a recording file.
+///
+/// Seeks where the container allows it, so extracting a short clip from a long
+/// meeting does not decode the whole file. `end_seconds = None` reads to the end.
+pub fn decode_audio_range(path: &Path, start_seconds: f64, end_seconds: Option<f64>) -> Result<DecodedAudio> {
+    let start_seconds = start_seconds.max(0.0);
+    if let Some(end) = end_seconds {
+        if end < start_seconds {
+            return Err(anyhow!("Invalid range: end {:.3}s is before start {:.3}s", end, start_seconds));
+        }
+    }
+
+    if AudioFileFormat::from_path(path) == Some(AudioFileFormat::Wav) {
+        let mut audio = read_wav(path)?;
+        trim_to_range(&mut audio, 0.0, start_seconds, end_seconds);
+        return Ok(audio);
+    }
+
+    use symphonia::core::audio::SampleBuffer;
+    use symphonia::core::codecs::DecoderOptions;
+    use symphonia::core::errors::Error as SymphoniaError;
+    use symphonia::core::formats::{FormatOptions, SeekMode, SeekTo};
+    use symphonia::core::io::MediaSourceStream;
+    use symphonia::core::meta::MetadataOptions;
+    use symphonia::core::probe::Hint;
+    use symphonia::core::units::Time;
+
+    let file = File::open(path)?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(&hint, mss, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(|e| anyhow!("Unsupported audio file {}: {}", path.display(), e))?;
+    let mut format = probed.format;
+
+    let track = format
+        .default_track()
+        .ok_or_else(|| anyhow!("No audio track in {}", path.display()))?;
+    let track_id = track.id;
+    let time_base = track.codec_params.time_base;
+    let mut sample_rate = track.codec_params.sample_rate.unwrap_or(48000);
+    let mut channels = track.codec_params.channels.map(|c| c.count() as u16).unwrap_or(1);
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|e| anyhow!("No decoder for {}: {}", path.display(), e))?;
+
+    if start_seconds > 0.0 {
+        let time = Time::new(start_seconds.trunc() as u64, start_seconds.fract());
+        match format.seek(SeekMode::Accurate, SeekTo::Time { time, track_id: Some(track_id) }) {
+            Ok(_) => decoder.reset(),
+            // Fall back to decoding from the beginning and discarding frames
+            Err(e) => debug!("Seek to {:.3}s failed in {}: {}", start_seconds, path.display(), e),
+        }
+    }
+
+    // Time of the first decoded frame, taken from the first packet's timestamp
+    let mut offset: Option<f64> = None;
+    let mut samples = Vec::new();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+            Err(SymphoniaError::ResetRequired) => break,
+            Err(e) => return Err(anyhow!("Failed to read {}: {}", path.display(), e)),
+        };
+
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let packet_time = match time_base {
+            Some(tb) => {
+                let t = tb.calc_time(packet.ts());
+                t.seconds as f64 + t.frac
+            }
+            None => packet.ts() as f64 / sample_rate as f64,
+        };
+
+        match decoder.decode(&packet) {
+            Ok(decoded) => {
+                let spec = *decoded.spec();
+                sample_rate = spec.rate;
+                channels = spec.channels.count() as u16;
+                offset.get_or_insert(packet_time);
+                let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+                buffer.copy_interleaved_ref(decoded);
+                samples.extend_from_slice(buffer.samples());
+            }
+            Err(SymphoniaError::DecodeError(e)) => {
+                // Skip corrupt packets (e.g. the tail of a checkpoint written during a crash)
+                warn!("Skipping undecodable packet in {}: {}", path.display(), e);
+            }
+            Err(e) => return Err(anyhow!("Failed to decode {}: {}", path.display(), e)),
+        }
+
+        if let Some(end) = end_seconds {
+            if packet_time >= end {
+                break;
+            }
+        }
+    }
+
+    let mut audio = DecodedAudio { samples, sample_rate, channels };
+    trim_to_range(&mut audio, offset.unwrap_or(0.0), start_seconds, end_seconds);
+    Ok(audio)
+}
+
+// ============================================================================
+// FLAC
+// ============================================================================
+
+const FLAC_BLOCK_SIZE: usize = 4096;
+const FLAC_BITS_PER_SAMPLE: u32 = 16;
+const FLAC_MAX_RICE_PARAM: u32 = 14;
+
+/// MSB-first bit writer used for FLAC frames
+struct BitWriter {
+    bytes: Vec<u8>,
+    acc: u64,
+    bits: u32,
+}
+
+impl BitWriter {
+    fn new() -> Self {
+        Self { bytes: Vec::new(), acc: 0, bits: 0 }
+    }
+
+    fn write(&mut self, value: u64, count: u32) {
+        debug_assert!(count <= 32);
+        if count == 0 {
+            return;
+        }
+        self.acc = (self.acc << count) | (value & ((1u64 << count) - 1));
+        self.bits += count;
+        while self.bits >= 8 {
+            self.bits -= 8;
+            self.bytes.push((self.acc >> self.bits) as u8);
+        }
+        self.acc &= (1u64 << self.bits) - 1;
+    }
+
+    fn write_unary(&mut self, mut zeros: u32) {
+        while zeros >= 32 {
+            self.write(0, 32);
+            zeros -= 32;
+        }
+        self.write(1, zeros + 1);
+    }
+
+    fn align(&mut self) {
+        if self.bits > 0 {
+            self.write(0, 8 - self.bits);
+        }
+    }
+}
+
+fn crc8(data: &[u8]) -> u8 {
+    let mut crc = 0u8;
+    for &byte in data {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+fn crc16(data: &[u8]) -> u16 {
+    let mut crc = 0u16;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x8005 } else { crc << 1 };
+        }
+    }
+    crc
+}
+
+/// FLAC's UTF-8-like variable length encoding for frame numbers
+fn write_utf8_number(writer: &mut BitWriter, value: u64) {
+    if value < 0x80 {
+        writer.write(value, 8);
+        return;
+    }
+    let byte_count = match value {
+        v if v < 0x800 => 2,
+        v if v < 0x10000 => 3,
+        v if v < 0x200000 => 4,
+        v if v < 0x4000000 => 5,
+        _ => 6,
+    };
+    let prefix: u64 = (0xFF00u64 >> byte_count) & 0xFF;
+    writer.write(prefix | (value >> (6 * (byte_count - 1))), 8);
+    for i in (0..byte_count - 1).rev() {
+        writer.write(0x80 | ((value >> (6 * i)) & 0x3F), 8);
+    }
+}
+
+fn sample_rate_code(sample_rate: u32) -> u64 {
+    match sample_rate {
+        88200 => 0b0001,
+        176400 => 0b0010,
+        192000 => 0b0011,
+        8000 => 0b0100,
+        16000 => 0b0101,
+        22050 => 0b0110,
+        24000 => 0b0111,
+        32000 => 0b1000,
+        44100 => 0b1001,
+        48000 => 0b1010,
+        96000 => 0b1011,
+        _ => 0b0000, // Taken from STREAMINFO
+    }
+}
+
+fn fixed_residuals(samples: &[i32], order: usize) -> Vec<i32> {
+    (order..samples.len())
+        .map(|i| {
+            let s = samples;
+            match order {
+                0 => s[i],
+                1 => s[i] - s[i - 1],
+                2 => s[i] - 2 * s[i - 1] + s[i - 2],
+                3 => s[i] - 3 * s[i - 1] + 3 * s[i - 2] - s[i - 3],
+                _ => s[i] - 4 * s[i - 1] + 6 * s[i - 2] - 4 * s[i - 3] + s[i - 4],
+            }
+        })
+        .collect()
+}
+
+#[inline]
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+/// Returns (rice parameter, encoded residual bits)
+fn best_rice_parameter(residuals: &[i32]) -> (u32, u64) {
+    let folded: Vec<u32> = residuals.iter().map(|&r| zigzag(r)).collect();
+    (0..=FLAC_MAX_RICE_PARAM)
+        .map(|k| {
+            let bits = folded.iter().map(|&u| (u >> k) as u64 + 1 + k as u64).sum::<u64>();
+            (k, bits)
+        })
+        .min_by_key(|&(_, bits)| bits)
+        .unwrap_or((0, 0))
+}
+
+fn write_subframe(writer: &mut BitWriter, samples: &[i32]) {
+    // CONSTANT subframe (digital silence compresses to a couple of bytes)
+    if samples.iter().all(|&s| s == samples[0]) {
+        writer.write(0, 8);
+        writer.write(samples[0] as u64, FLAC_BITS_PER_SAMPLE);
+        return;
+    }
+
+    let verbatim_bits = samples.len() as u64 * FLAC_BITS_PER_SAMPLE as u64;
+    let max_order = 4.min(samples.len().saturating_sub(1));
+
+    let mut best: Option<(usize, u32, u64, Vec<i32>)> = None;
+    for order in 0..=max_order {
+        let residuals = fixed_residuals(samples, order);
+        let (k, residual_bits) = best_rice_parameter(&residuals);
+        let total = order as u64 * FLAC_BITS_PER_SAMPLE as u64 + 10 + residual_bits;
+        if best.as_ref().map_or(true, |(_, _, bits, _)| total < *bits) {
+            best = Some((order, k, total, residuals));
+        }
+    }
+
+    match best {
+        Some((order, k, bits, residuals)) if bits < verbatim_bits => {
+            // FIXED subframe: 0 | 001ooo | 0
+            writer.write(0, 1);
+            writer.write(0b001000 | order as u64, 6);
+            writer.write(0, 1);
+            for &warmup in &samples[..order] {
+                writer.write(warmup as u64, FLAC_BITS_PER_SAMPLE);
+            }
+            // Rice coding, partition order 0
+            writer.write(0b00, 2);
+            writer.write(0, 4);
+            writer.write(k as u64, 4);
+            for &residual in &residuals {
+                let folded = zigzag(residual);
+                writer.write_unary(folded >> k);
+                writer.write(folded as u64, k);
+            }
+        }
+        _ => {
+            // VERBATIM subframe
+            writer.write(0b00000010, 8);
+            for &sample in samples {
+                writer.write(sample as u64, FLAC_BITS_PER_SAMPLE);
+            }
+        }
+    }
+}
+
+fn streaminfo_bytes(sample_rate: u32, channels: u16, total_frames: u64, min_frame: u32, max_frame: u32) -> Vec<u8> {
+    let mut writer = BitWriter::new();
+    writer.write(FLAC_BLOCK_SIZE as u64, 16);
+    writer.write(FLAC_BLOCK_SIZE as u64, 16);
+    writer.write(min_frame as u64, 24);
+    writer.write(max_frame as u64, 24);
+    writer.write(sample_rate as u64, 20);
+    writer.write((channels - 1) as u64, 3);
+    writer.write((FLAC_BITS_PER_SAMPLE - 1) as u64, 5);
+    writer.write(total_frames >> 32, 4);
+    writer.write(total_frames & 0xFFFF_FFFF, 32);
+    // MD5 signature left unset (all zeros is allowed)
+    for _ in 0..4 {
+        writer.write(0, 32);
+    }
+    writer.bytes
+}
+
+/// Streaming 16-bit FLAC encoder (fixed predictors + Rice coding)
+///
+/// Samples are fed interleaved; STREAMINFO is patched on `finish()`
+/// once the total length is known.
+pub struct FlacEncoder<W: Write + Seek> {
+    writer: W,
+    sample_rate: u32,
+    channels: u16,
+    pending: Vec<i32>,
+    frame_number: u64,
+    total_frames: u64,
+    min_frame_size: u32,
+    max_frame_size: u32,
+}
+
+impl<W: Write + Seek> FlacEncoder<W> {
+    pub fn new(mut writer: W, sample_rate: u32, channels: u16) -> Result<Self> {
+        if !(1..=8).contains(&channels) {
+            return Err(anyhow!("FLAC supports 1-8 channels, got {}", channels));
+        }
+        if sample_rate == 0 || sample_rate >= (1 << 20) {
+            return Err(anyhow!("Invalid FLAC sample rate: {}", sample_rate));
+        }
+
+        writer.write_all(b"fLaC")?;
+        // Last metadata block flag + STREAMINFO type (0) + 34 byte length
+        writer.write_all(&[0x80, 0x00, 0x00, 34])?;
+        writer.write_all(&streaminfo_bytes(sample_rate, channels, 0, 0, 0))?;
+
+        Ok(Self {
+            writer,
+            sample_rate,
+            channels,
+            pending: Vec::with_capacity(FLAC_BLOCK_SIZE * channels as usize),
+            frame_number: 0,
+            total_frames: 0,
+            min_frame_size: u32::MAX,
+            max_frame_size: 0,
+        })
+    }
+
+    /// Add interleaved samples
+    pub fn write_samples(&mut self, samples: &[f32]) -> Result<()> {
+        let block_len = FLAC_BLOCK_SIZE * self.channels as usize;
+        for &sample in samples {
+            self.pending.push(f32_to_i16(sample) as i32);
+            if self.pending.len() == block_len {
+                self.flush_frame()?;
+            }
+        }
+        Ok(())
+    }
+
+    fn flush_frame(&mut self) -> Result<()> {
+        let channels = self.channels as usize;
+        // Drop a trailing partial sample frame so every channel has the same length
+        let usable = self.pending.len() - self.pending.len() % channels;
+        let block_size = usable / channels;
+        if block_size == 0 {
+            self.pending.clear();
+            return Ok(());
+        }
+
+        let mut frame = BitWriter::new();
+        frame.write(0b11111111111110, 14);
+        frame.write(0, 1); // reserved
+        frame.write(0, 1); // fixed block size stream
+        frame.write(0b0111, 4); // block size in 16 bits at end of header
+        frame.write(sample_rate_code(self.sample_rate), 4);
+        frame.write((channels - 1) as u64, 4); // independent channels
+        frame.write(0b100, 3); // 16 bits per sample
+        frame.write(0, 1); // reserved
+        write_utf8_number(&mut frame, self.frame_number);
+        frame.write((block_size - 1) as u64, 16);
+        let header_crc = crc8(&frame.bytes);
+        frame.write(header_crc as u64, 8);
+
+        for channel in 0..channels {
+            let channel_samples: Vec<i32> = self.pending[..usable]
+                .iter()
+                .skip(channel)
+                .step_by(channels)
+                .copied()
+                .collect();
+            write_subframe(&mut frame, &channel_samples);
+        }
+
+        frame.align();
+        let footer_crc = crc16(&frame.bytes);
+        frame.write(footer_crc as u64, 16);
+
+        self.writer.write_all(&frame.bytes)?;
+
+        let frame_size = frame.bytes.len() as u32;
+        self.min_frame_size = self.min_frame_size.min(frame_size);
+        self.max_frame_size = self.max_frame_size.max(frame_size);
+        self.total_frames += block_size as u64;
+        self.frame_number += 1;
+        self.pending.clear();
+
+        Ok(())
+    }
+
+    /// Flush remaining samples, patch STREAMINFO and return the writer
+    pub fn finish(mut self) -> Result<W> {
+        if !self.pending.is_empty() {
+            self.flush_frame()?;
+        }
+
+        let min_frame = if self.min_frame_size == u32::MAX { 0 } else { self.min_frame_size };
+        self.writer.seek(SeekFrom::Start(8))?;
+        self.writer.write_all(&streaminfo_bytes(
+            self.sample_rate,
+            self.channels,
+            self.total_frames,
+            min_frame,
+            self.max_frame_size,
+        ))?;
+        self.writer.seek(SeekFrom::End(0))?;
+        self.writer.flush()?;
+
+        debug!(
+            "FLAC encoder finished: {} frames, {} samples per channel",
+            self.frame_number, self.total_frames
+        );
+
+        Ok(self.writer)
+    }
+}
+
+/// Encode interleaved f32 samples into a FLAC file
+pub fn write_flac(path: &Path, samples: &[f32], sample_rate: u32, channels: u16) -> Result<()> {
+    let mut encoder = FlacEncoder::new(BufWriter::new(File::create(path)?), sample_rate, channels)?;
+    encoder.write_samples(samples)?;
+    encoder.finish()?;
+    Ok(())
+}
+
+/// Decode a list of audio files (in order) and merge them into a single FLAC file
+///
+/// Files are decoded and encoded one at a time so memory stays bounded to a single
+/// checkpoint regardless of recording length. Sample rate and channel count are
+/// taken from the first file.
+pub fn merge_to_flac(inputs: &[PathBuf], output: &Path) -> Result<()> {
+    if inputs.is_empty() {
+        return Err(anyhow!("No audio files to merge"));
+    }
+
+    let temp_output = output.with_extension("flac.tmp");
+    let mut encoder: Option<FlacEncoder<BufWriter<File>>> = None;
+
+    for input in inputs {
+        let audio = match decode_audio_file(input) {
+            Ok(audio) => audio,
+            Err(e) => {
+                // A crash can leave the last checkpoint unreadable - keep everything else
+                warn!("Skipping unreadable audio file {}: {}", input.display(), e);
+                continue;
+            }
+        };
+
+        let encoder = match encoder.as_mut() {
+            Some(encoder) => {
+                if encoder.sample_rate != audio.sample_rate || encoder.channels != audio.channels {
+                    warn!(
+                        "{} has {}Hz/{}ch, expected {}Hz/{}ch - skipping",
+                        input.display(),
+                        audio.sample_rate,
+                        audio.channels,
+                        encoder.sample_rate,
+                        encoder.channels
+                    );
+                    continue;
+                }
+                encoder
+            }
+            None => encoder.insert(FlacEncoder::new(
+                BufWriter::new(File::create(&temp_output)?),
+                audio.sample_rate,
+                audio.channels,
+            )?),
+        };
+
+        encoder.write_samples(&audio.samples)?;
+    }
+
+    let encoder = encoder.ok_or_else(|| anyhow!("None of the {} audio files could be decoded", inputs.len()))?;
+    encoder.finish()?;
+    std::fs::rename(&temp_output, output)?;
+
+    info!("Natively merged {} audio files → {}", inputs.len(), output.display());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn test_signal(frames: usize, channels: u16) -> Vec<f32> {
+        (0..frames * channels as usize)
+            .map(|i| {
+                let frame = (i / channels as usize) as f32;
+                let channel = (i % channels as usize) as f32;
+                ((frame * 0.01 * (channel + 1.0)).sin() * 0.5 * 32767.0).round() / 32767.0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_wav_round_trip() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.wav");
+        let samples = test_signal(4800, 2);
+
+        write_wav(&path, &samples, 48000, 2).unwrap();
+        let decoded = read_wav(&path).unwrap();
+
+        assert_eq!(decoded.sample_rate, 48000);
+        assert_eq!(decoded.channels, 2);
+        assert_eq!(decoded.samples.len(), samples.len());
+        assert!((decoded.duration_seconds() - 0.1).abs() < 1e-9);
+        for (a, b) in samples.iter().zip(decoded.samples.iter()) {
+            assert!((a - b).abs() < 1.0 / 16000.0);
+        }
+    }
+
+    #[test]
+    fn test_flac_is_lossless() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("test.flac");
+        // Not a multiple of the block size, includes a silent section (CONSTANT subframes)
+        let mut samples = test_signal(10_000, 2);
+        samples.extend(std::iter::repeat(0.0).take(9000 * 2));
+
+        write_flac(&path, &samples, 48000, 2).unwrap();
+        let decoded = decode_audio_file(&path).unwrap();
+
+        assert_eq!(decoded.sample_rate, 48000);
+        assert_eq!(decoded.channels, 2);
+        assert_eq!(decoded.samples.len(), samples.len());
+        for (a, b) in samples.iter().zip(decoded.samples.iter()) {
+            assert_eq!(f32_to_i16(*a), f32_to_i16(*b));
+        }
+    }
+
+    #[test]
+    fn test_merge_wav_checkpoints_to_flac() {
+        let dir = tempdir().unwrap();
+        let first = dir.path().join("audio_chunk_000.wav");
+        let second = dir.path().join("audio_chunk_001.wav");
+        write_wav(&first, &test_signal(4800, 2), 48000, 2).unwrap();
+        write_wav(&second, &test_signal(2400, 2), 48000, 2).unwrap();
+
+        let output = dir.path().join("audio.flac");
+        merge_to_flac(&[first, second], &output).unwrap();
+
+        let decoded = decode_audio_file(&output).unwrap();
+        assert_eq!(decoded.samples.len(), (4800 + 2400) * 2);
+    }
+
+    #[test]
+    fn test_decode_range() {
+        let dir = tempdir().unwrap();
+        let samples = test_signal(48000, 2);
+
+        let wav = dir.path().join("range.wav");
+        write_wav(&wav, &samples, 48000, 2).unwrap();
+        let clip = decode_audio_range(&wav, 0.25, Some(0.5)).unwrap();
+        assert_eq!(clip.samples.len(), 12000 * 2);
+        assert_eq!(clip.samples[..], decode_audio_file(&wav).unwrap().samples[12000 * 2..24000 * 2]);
+
+        let flac = dir.path().join("range.flac");
+        write_flac(&flac, &samples, 48000, 2).unwrap();
+        let clip = decode_audio_range(&flac, 0.25, Some(0.5)).unwrap();
+        assert_eq!(clip.samples.len(), 12000 * 2);
+        for (a, b) in clip.samples.iter().zip(samples[12000 * 2..].iter()) {
+            assert_eq!(f32_to_i16(*a), f32_to_i16(*b));
+        }
+
+        // Ranges past the end are clamped
+        assert!(decode_audio_range(&wav, 5.0, Some(6.0)).unwrap().samples.is_empty());
+        assert!(decode_audio_range(&wav, 0.5, Some(0.25)).is_err());
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(AudioFileFormat::from_path(Path::new("a/audio.mp4")), Some(AudioFileFormat::Mp4));
+        assert_eq!(AudioFileFormat::from_path(Path::new("audio_chunk_001.WAV")), Some(AudioFileFormat::Wav));
+        assert_eq!(AudioFileFormat::from_path(Path::new("concat_list.txt")), None);
+        assert!(!AudioFileFormat::Flac.requires_ffmpeg());
+    }
+}
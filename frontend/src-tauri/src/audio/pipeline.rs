@@ -11,7 +11,9 @@ use rubato::{Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolat
 use super::devices::AudioDevice;
 use super::recording_state::{AudioChunk, AudioError, RecordingState, DeviceType};
 use super::audio_processing::{audio_to_mono, LoudnessNormalizer, NoiseSuppressionProcessor, HighPassFilter};
-use super::vad::{ContinuousVadProcessor};
+use super::vad::{create_vad, current_vad_settings, VadSessionStats, VadSettings, VoiceActivityDetector};
+use super::quality_monitor::{AudioQualityMonitor, AudioQualityReport};
+use super::clock_drift::DriftCompensator;
 
 // --- Cross-channel echo suppression constants ---
 /// Maximum time overlap to consider echo (seconds)
@@ -22,6 +24,10 @@ const ECHO_ENERGY_RATIO_THRESHOLD: f32 = 0.3;
 /// Absolute RMS threshold - segments below this are too weak to be direct speech
 const ECHO_ABSOLUTE_RMS_THRESHOLD: f32 = 0.02;
 
+/// Chunk ID of the in-band marker sent when a capture stream is moved to another
+/// device mid-recording (outside the flush signal range)
+pub const STREAM_SWITCH_CHUNK_ID: u64 = u64::MAX - 16;
+
 /// Ring buffer for synchronized audio mixing
 /// Accumulates samples from mic and system streams until we have aligned windows
 struct AudioMixerRingBuffer {
@@ -29,6 +35,18 @@ struct AudioMixerRingBuffer {
     system_buffer: VecDeque<f32>,
     window_size_samples: usize,  // Fixed mixing window (e.g., 50ms)
     max_buffer_size: usize,  // Safety limit (e.g., 100ms)
+    /// How far one stream may run ahead before the other is treated as stalled
+    max_wait_samples: usize,
+    sample_rate: u32,
+    /// Recording time of the first sample on the shared timeline
+    timeline_origin: Option<f64>,
+    /// Samples per channel already extracted from the buffer
+    extracted_samples: u64,
+    mic_started: bool,
+    system_started: bool,
+    /// Silence padded into a stalled stream, reclaimed when its late audio arrives
+    mic_padding_debt: usize,
+    system_padding_debt: usize,
     /// Tracks which device had more energy in the last extracted window
     /// Used for speaker identification in transcription
     last_dominant_device: DeviceType,
@@ -55,10 +73,44 @@ impl AudioMixerRingBuffer {
             system_buffer: VecDeque::with_capacity(max_buffer_size),
             window_size_samples,
             max_buffer_size,
+            max_wait_samples: window_size_samples * 3, // 300ms
+            sample_rate,
+            timeline_origin: None,
+            extracted_samples: 0,
+            mic_started: false,
+            system_started: false,
+            mic_padding_debt: 0,
+            system_padding_debt: 0,
             last_dominant_device: DeviceType::Microphone, // Default to mic
         }
     }
 
+    /// Place a stream's first sample on the shared timeline. `start_time` is the
+    /// recording time (excluding pauses) of that sample. Returns the stream's
+    /// offset from the timeline origin in samples.
+    fn register_stream_start(&mut self, device_type: DeviceType, start_time: f64) -> u64 {
+        let origin = *self.timeline_origin.get_or_insert(start_time);
+        let expected = ((start_time - origin).max(0.0) * self.sample_rate as f64).round() as u64;
+
+        let (buffer, started) = match device_type {
+            DeviceType::Microphone => (&mut self.mic_buffer, &mut self.mic_started),
+            DeviceType::System => (&mut self.system_buffer, &mut self.system_started),
+            DeviceType::Mixed => return 0,
+        };
+        *started = true;
+
+        // Streams that start later are preceded by silence rather than shifted
+        // to the front of the recording
+        let position = self.extracted_samples + buffer.len() as u64;
+        if expected > position {
+            buffer.resize(buffer.len() + (expected - position) as usize, 0.0);
+        }
+        let offset = expected.max(position);
+        info!("🔊 {:?} stream starts {:.0}ms into the recording timeline",
+              device_type, offset as f64 * 1000.0 / self.sample_rate as f64);
+        offset
+    }
+
     fn add_samples(&mut self, device_type: DeviceType, samples: Vec<f32>) {
         // Log buffer health periodically for diagnostics
         // FIX M5: Use AtomicU64 instead of unsafe static to prevent race conditions
@@ -75,6 +127,7 @@ impl AudioMixerRingBuffer {
             DeviceType::System => self.system_buffer.extend(samples),
             DeviceType::Mixed => {} // Mixed chunks are not added to ring buffer
         }
+        self.reclaim_padding();
 
         // CRITICAL FIX: Add warnings before dropping samples
         // This helps diagnose timing issues in production
@@ -98,9 +151,52 @@ impl AudioMixerRingBuffer {
         }
     }
 
+    /// A stream that was padded with silence while stalled delivers the missing
+    /// audio late; drop the equivalent amount so it lines up with the other stream again
+    fn reclaim_padding(&mut self) {
+        if !(self.mic_started && self.system_started) {
+            return;
+        }
+        // Only react to a lead of more than one window, normal jitter is smaller
+        let window = self.window_size_samples;
+
+        let lead = self.mic_buffer.len().saturating_sub(self.system_buffer.len());
+        if lead > window && self.mic_padding_debt > 0 {
+            let drop = lead.min(self.mic_padding_debt);
+            self.mic_buffer.drain(..drop);
+            self.mic_padding_debt -= drop;
+            debug!("🔊 Realigned microphone stream after stall: dropped {} samples", drop);
+        }
+
+        let lead = self.system_buffer.len().saturating_sub(self.mic_buffer.len());
+        if lead > window && self.system_padding_debt > 0 {
+            let drop = lead.min(self.system_padding_debt);
+            self.system_buffer.drain(..drop);
+            self.system_padding_debt -= drop;
+            debug!("🔊 Realigned system stream after stall: dropped {} samples", drop);
+        }
+    }
+
     fn can_mix(&self) -> bool {
-        self.mic_buffer.len() >= self.window_size_samples ||
-        self.system_buffer.len() >= self.window_size_samples
+        // Wait for both streams so they stay sample-aligned, unless one of them
+        // is absent or stalled for longer than the tolerated latency
+        let both_ready = self.mic_buffer.len() >= self.window_size_samples &&
+            self.system_buffer.len() >= self.window_size_samples;
+        both_ready ||
+            self.mic_buffer.len() >= self.max_wait_samples ||
+            self.system_buffer.len() >= self.max_wait_samples
+    }
+
+    /// Take up to `len` samples from a channel, padding with silence
+    /// Zero-padding (silence) is preferred over last-sample-hold to prevent artifacts
+    fn take_padded(buffer: &mut VecDeque<f32>, len: usize, started: bool, padding_debt: &mut usize) -> Vec<f32> {
+        let available = buffer.len().min(len);
+        let mut window: Vec<f32> = buffer.drain(..available).collect();
+        if started {
+            *padding_debt += len - available;
+        }
+        window.resize(len, 0.0);
+        window
     }
 
     fn extract_window(&mut self) -> Option<(Vec<f32>, Vec<f32>)> {
@@ -108,48 +204,10 @@ impl AudioMixerRingBuffer {
             return None;
         }
 
-        // Extract mic window with zero-padding for incomplete buffers
-        // Zero-padding (silence) is preferred over last-sample-hold to prevent artifacts
-
-        // Extract mic window (or pad with zeros if insufficient data)
-        let mic_window = if self.mic_buffer.len() >= self.window_size_samples {
-            // Enough mic data - drain window
-            self.mic_buffer.drain(0..self.window_size_samples).collect()
-        } else if !self.mic_buffer.is_empty() {
-            // Some mic data but not enough - consume all + pad with zeros
-            let available: Vec<f32> = self.mic_buffer.drain(..).collect();
-            let mut padded = Vec::with_capacity(self.window_size_samples);
-            padded.extend_from_slice(&available);
-
-            // Use zero-padding (silence) to prevent repetition artifacts
-            // Zero-padding is inaudible at 48kHz sample rate
-            padded.resize(self.window_size_samples, 0.0);
-
-            padded
-        } else {
-            // No mic data - return silence
-            vec![0.0; self.window_size_samples]
-        };
-
-        // Extract system window (or pad with zeros if insufficient data)
-        let sys_window = if self.system_buffer.len() >= self.window_size_samples {
-            // Enough system data - drain window
-            self.system_buffer.drain(0..self.window_size_samples).collect()
-        } else if !self.system_buffer.is_empty() {
-            // Some system data but not enough - consume all + pad with zeros
-            let available: Vec<f32> = self.system_buffer.drain(..).collect();
-            let mut padded = Vec::with_capacity(self.window_size_samples);
-            padded.extend_from_slice(&available);
-
-            // Use zero-padding (silence) to prevent repetition artifacts
-            // Zero-padding is inaudible at 48kHz sample rate
-            padded.resize(self.window_size_samples, 0.0);
-
-            padded
-        } else {
-            // No system data - return silence
-            vec![0.0; self.window_size_samples]
-        };
+        let window = self.window_size_samples;
+        let mic_window = Self::take_padded(&mut self.mic_buffer, window, self.mic_started, &mut self.mic_padding_debt);
+        let sys_window = Self::take_padded(&mut self.system_buffer, window, self.system_started, &mut self.system_padding_debt);
+        self.extracted_samples += window as u64;
 
         // Calculate which device has more energy (RMS) for speaker identification
         let mic_energy: f32 = mic_window.iter().map(|s| s * s).sum::<f32>() / mic_window.len() as f32;
@@ -172,6 +230,20 @@ impl AudioMixerRingBuffer {
         Some((mic_window, sys_window))
     }
 
+    /// Everything still buffered, both channels padded to the same length
+    /// Used when the pipeline flushes so the tail of the recording is not lost
+    fn drain_remaining(&mut self) -> Option<(Vec<f32>, Vec<f32>)> {
+        let len = self.mic_buffer.len().max(self.system_buffer.len());
+        if len == 0 {
+            return None;
+        }
+        let mut unused_debt = 0;
+        let mic_window = Self::take_padded(&mut self.mic_buffer, len, false, &mut unused_debt);
+        let sys_window = Self::take_padded(&mut self.system_buffer, len, false, &mut unused_debt);
+        self.extracted_samples += len as u64;
+        Some((mic_window, sys_window))
+    }
+
     /// Returns the device type that was dominant in the last extracted window
     /// Previously used for speaker identification; now kept for diagnostics/analytics
     #[allow(dead_code)]
@@ -725,8 +797,22 @@ pub struct AudioPipeline {
     #[allow(dead_code)]  // State management reserved for future enhancements
     state: Arc<RecordingState>,
     // DUAL-CHANNEL VAD: Separate processors for accurate speaker attribution
-    mic_vad_processor: ContinuousVadProcessor,
-    sys_vad_processor: ContinuousVadProcessor,
+    mic_vad_processor: Box<dyn VoiceActivityDetector>,
+    sys_vad_processor: Box<dyn VoiceActivityDetector>,
+    // Per-meeting VAD statistics, shared with the manager
+    vad_stats: Arc<std::sync::Mutex<VadSessionStats>>,
+    // Live audio-quality analysis per channel, report shared with the manager
+    quality_monitor: AudioQualityMonitor,
+    quality_report: Arc<std::sync::Mutex<AudioQualityReport>>,
+    // Clock-drift compensation: both streams are kept on the recording clock
+    mic_drift: DriftCompensator,
+    sys_drift: DriftCompensator,
+    // Where each stream starts on the shared timeline (offset for its VAD timestamps)
+    mic_timeline_offset_ms: Option<f64>,
+    sys_timeline_offset_ms: Option<f64>,
+    // Set by a stream-switch marker: the next chunk comes from a new device
+    mic_switch_pending: bool,
+    sys_switch_pending: bool,
     sample_rate: u32,
     chunk_id_counter: u64,
     // Performance optimization: reduce logging frequency
@@ -762,6 +848,7 @@ impl AudioPipeline {
         mic_device_kind: super::device_detection::InputDeviceKind,
         system_device_name: String,
         system_device_kind: super::device_detection::InputDeviceKind,
+        vad_settings: VadSettings,
     ) -> Result<Self> {
         // Log device characteristics for adaptive buffering
         info!("🎛️ AudioPipeline initializing with device characteristics:");
@@ -778,12 +865,12 @@ impl AudioPipeline {
         // The VAD processor now handles 48kHz->16kHz resampling internally
         // This bridges natural pauses without excessive fragmentation
         // FIX: Increased redemption_time to 600ms to support slow speakers and natural pauses
-        // macOS: 600ms, Windows: 600ms (was 400ms - caused fragmentation for slow speakers)
-
-        let redemption_time = if cfg!(target_os = "macos") { 600 } else { 600 };
+        // Engine, thresholds and timings come from the user's VAD settings (defaults: Silero, 600ms)
+        info!("🎚️ VAD engine: {:?} (mic threshold {:.2}, system threshold {:.2})",
+              vad_settings.engine, vad_settings.microphone.threshold, vad_settings.system.threshold);
 
         // DUAL-CHANNEL: Create separate VAD processor for microphone
-        let mic_vad_processor = ContinuousVadProcessor::new(sample_rate, redemption_time)
+        let mic_vad_processor = create_vad(vad_settings.engine, sample_rate, &vad_settings.microphone)
             .map_err(|e| {
                 error!("Failed to create mic VAD processor: {}", e);
                 anyhow::anyhow!("Mic VAD processor creation failed: {}", e)
@@ -791,13 +878,19 @@ impl AudioPipeline {
         info!("🎤 Mic VAD processor created (dual-channel mode)");
 
         // DUAL-CHANNEL: Create separate VAD processor for system audio
-        let sys_vad_processor = ContinuousVadProcessor::new(sample_rate, redemption_time)
+        let sys_vad_processor = create_vad(vad_settings.engine, sample_rate, &vad_settings.system)
             .map_err(|e| {
                 error!("Failed to create system VAD processor: {}", e);
                 anyhow::anyhow!("System VAD processor creation failed: {}", e)
             })?;
         info!("🔊 System VAD processor created (dual-channel mode)");
 
+        let vad_stats = Arc::new(std::sync::Mutex::new(VadSessionStats {
+            settings: vad_settings,
+            microphone: mic_vad_processor.stats(),
+            system: sys_vad_processor.stats(),
+        }));
+
         info!("✅ Dual-channel VAD pipeline initialized - mic and system audio will be transcribed independently for accurate speaker attribution");
 
         // Initialize professional audio mixing components (for WAV recording only)
@@ -813,6 +906,15 @@ impl AudioPipeline {
             state,
             mic_vad_processor,
             sys_vad_processor,
+            vad_stats,
+            quality_monitor: AudioQualityMonitor::new(),
+            quality_report: Arc::new(std::sync::Mutex::new(AudioQualityReport::default())),
+            mic_drift: DriftCompensator::new("Microphone", sample_rate),
+            sys_drift: DriftCompensator::new("System audio", sample_rate),
+            mic_timeline_offset_ms: None,
+            sys_timeline_offset_ms: None,
+            mic_switch_pending: false,
+            sys_switch_pending: false,
             sample_rate,
             chunk_id_counter: 0,
             // Performance optimization: reduce logging frequency
@@ -861,6 +963,18 @@ impl AudioPipeline {
                         continue;
                     }
 
+                    // Stream switch marker: everything after it comes from the new device
+                    if chunk.chunk_id == STREAM_SWITCH_CHUNK_ID {
+                        info!("🔀 {:?} stream switched to another device", chunk.device_type);
+                        match chunk.device_type {
+                            DeviceType::Microphone => self.mic_switch_pending = true,
+                            DeviceType::System => self.sys_switch_pending = true,
+                            DeviceType::Mixed => {}
+                        }
+                        self.quality_monitor.restart_stream(chunk.device_type);
+                        continue;
+                    }
+
                     // PERFORMANCE OPTIMIZATION: Eliminate per-chunk logging overhead
                     // Logging in hot paths causes severe performance degradation
                     self.processed_chunks += 1;
@@ -897,10 +1011,23 @@ impl AudioPipeline {
                     self.current_timestamp = chunk_timestamp;
                     let chunk_device_type = chunk.device_type.clone();
 
+                    // Audio-quality analysis (clipping, silence, dropouts, SNR, bandwidth)
+                    let paused_seconds = self.state.get_total_pause_duration();
+                    for warning in self.quality_monitor.process_chunk(&chunk, paused_seconds) {
+                        self.state.report_quality_warning(&warning);
+                    }
+
+                    // Clock-drift compensation: place the stream on the shared timeline and
+                    // resample it to follow the recording clock, so VAD timestamps and the
+                    // stereo recording stay aligned over long meetings
+                    let samples = self.align_to_timeline(&chunk, chunk_timestamp - paused_seconds);
+                    let mic_offset_ms = self.mic_timeline_offset_ms.unwrap_or(0.0);
+                    let sys_offset_ms = self.sys_timeline_offset_ms.unwrap_or(0.0);
+
                     // STEP 1: Per-channel VAD for transcription (BEFORE mixing)
                     match &chunk_device_type {
                         DeviceType::Microphone => {
-                            match self.mic_vad_processor.process_audio(&chunk.data) {
+                            match self.mic_vad_processor.process_audio(&samples) {
                                 Ok(speech_segments) => {
                                     for segment in speech_segments {
                                         let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
@@ -923,7 +1050,7 @@ impl AudioPipeline {
                                             let transcription_chunk = AudioChunk {
                                                 data: segment.samples,
                                                 sample_rate: 16000,
-                                                timestamp: segment.start_timestamp_ms / 1000.0,
+                                                timestamp: (segment.start_timestamp_ms + mic_offset_ms) / 1000.0,
                                                 chunk_id: self.chunk_id_counter,
                                                 device_type: DeviceType::Microphone,  // STRUCTURAL: always mic
                                             };
@@ -944,7 +1071,7 @@ impl AudioPipeline {
                             }
                         }
                         DeviceType::System => {
-                            match self.sys_vad_processor.process_audio(&chunk.data) {
+                            match self.sys_vad_processor.process_audio(&samples) {
                                 Ok(speech_segments) => {
                                     for segment in speech_segments {
                                         let duration_ms = segment.end_timestamp_ms - segment.start_timestamp_ms;
@@ -967,7 +1094,7 @@ impl AudioPipeline {
                                             let transcription_chunk = AudioChunk {
                                                 data: segment.samples,
                                                 sample_rate: 16000,
-                                                timestamp: segment.start_timestamp_ms / 1000.0,
+                                                timestamp: (segment.start_timestamp_ms + sys_offset_ms) / 1000.0,
                                                 chunk_id: self.chunk_id_counter,
                                                 device_type: DeviceType::System,  // STRUCTURAL: always system
                                             };
@@ -999,28 +1126,20 @@ impl AudioPipeline {
                               self.echo_suppressed_mic, self.echo_suppressed_sys,
                               self.mic_recent_rms, self.sys_recent_rms);
                         self.last_echo_report_time = std::time::Instant::now();
+                        self.publish_vad_stats();
+                        self.quality_monitor.log_summary();
+                        self.publish_quality_report();
+                        if let (Some(mic_ppm), Some(sys_ppm)) = (self.mic_drift.drift_ppm(), self.sys_drift.drift_ppm()) {
+                            info!("⏱️ Clock drift: mic {:+.1} ppm, system {:+.1} ppm (relative {:+.1} ppm)",
+                                  mic_ppm, sys_ppm, mic_ppm - sys_ppm);
+                        }
                     }
 
                     // STEP 2: Add to ring buffer and create STEREO recording (L=mic, R=system)
-                    self.ring_buffer.add_samples(chunk_device_type, chunk.data);
+                    self.ring_buffer.add_samples(chunk_device_type, samples);
                     while self.ring_buffer.can_mix() {
                         if let Some((mic_window, sys_window)) = self.ring_buffer.extract_window() {
-                            // Interleave as stereo: Left = microphone, Right = system audio
-                            let mut stereo = Vec::with_capacity(mic_window.len() * 2);
-                            for (m, s) in mic_window.iter().zip(sys_window.iter()) {
-                                stereo.push(*m); // Left channel = microphone (user)
-                                stereo.push(*s); // Right channel = system (interlocutor)
-                            }
-                            if let Some(ref sender) = self.recording_sender_for_mixed {
-                                let recording_chunk = AudioChunk {
-                                    data: stereo,
-                                    sample_rate: self.sample_rate,
-                                    timestamp: chunk_timestamp,
-                                    chunk_id: self.chunk_id_counter,
-                                    device_type: DeviceType::Mixed,
-                                };
-                                let _ = sender.send(recording_chunk);
-                            }
+                            self.send_stereo_window(&mic_window, &sys_window, chunk_timestamp);
                         }
                     }
                 }
@@ -1038,7 +1157,10 @@ impl AudioPipeline {
         // Flush any remaining VAD segments
         self.flush_remaining_audio()?;
 
-        info!("VAD-driven audio pipeline ended");
+        let stats = self.vad_stats.lock().unwrap().clone();
+        info!("VAD-driven audio pipeline ended (mic: {} segments, {:.0}% speech; system: {} segments, {:.0}% speech)",
+              stats.microphone.segments, stats.microphone.speech_ratio() * 100.0,
+              stats.system.segments, stats.system.speech_ratio() * 100.0);
         Ok(())
     }
 
@@ -1083,7 +1205,7 @@ impl AudioPipeline {
                         let transcription_chunk = AudioChunk {
                             data: segment.samples,
                             sample_rate: 16000,
-                            timestamp: segment.start_timestamp_ms / 1000.0,
+                            timestamp: (segment.start_timestamp_ms + self.mic_timeline_offset_ms.unwrap_or(0.0)) / 1000.0,
                             chunk_id: self.chunk_id_counter,
                             device_type: DeviceType::Microphone,
                         };
@@ -1114,7 +1236,7 @@ impl AudioPipeline {
                         let transcription_chunk = AudioChunk {
                             data: segment.samples,
                             sample_rate: 16000,
-                            timestamp: segment.start_timestamp_ms / 1000.0,
+                            timestamp: (segment.start_timestamp_ms + self.sys_timeline_offset_ms.unwrap_or(0.0)) / 1000.0,
                             chunk_id: self.chunk_id_counter,
                             device_type: DeviceType::System,
                         };
@@ -1134,15 +1256,86 @@ impl AudioPipeline {
             }
         }
 
+        // Write out the audio still waiting in the ring buffer for the other stream
+        if let Some((mic_window, sys_window)) = self.ring_buffer.drain_remaining() {
+            self.send_stereo_window(&mic_window, &sys_window, self.current_timestamp);
+        }
+
+        self.publish_vad_stats();
+        self.publish_quality_report();
         Ok(())
     }
 
+    /// Put a capture chunk on the shared recording timeline: register where the stream
+    /// starts, bridge device switches with silence and compensate clock drift.
+    /// `active_time` is the recording time (excluding pauses) at which the chunk arrived.
+    fn align_to_timeline(&mut self, chunk: &AudioChunk, active_time: f64) -> Vec<f32> {
+        let chunk_start = active_time - chunk.data.len() as f64 / chunk.sample_rate as f64;
+        let (drift, offset_ms, switch_pending) = match chunk.device_type {
+            DeviceType::Microphone => (&mut self.mic_drift, &mut self.mic_timeline_offset_ms, &mut self.mic_switch_pending),
+            DeviceType::System => (&mut self.sys_drift, &mut self.sys_timeline_offset_ms, &mut self.sys_switch_pending),
+            DeviceType::Mixed => return Vec::new(),
+        };
+
+        if offset_ms.is_none() {
+            let offset = self.ring_buffer.register_stream_start(chunk.device_type, chunk_start);
+            *offset_ms = Some(offset as f64 * 1000.0 / self.sample_rate as f64);
+        }
+
+        let mut samples = Vec::new();
+        if *switch_pending {
+            *switch_pending = false;
+            samples = drift.restart(chunk_start);
+        }
+        samples.extend(drift.process(&chunk.data, active_time));
+        samples
+    }
+
+    /// Interleave one mixing window as stereo and send it to the recorder
+    fn send_stereo_window(&self, mic_window: &[f32], sys_window: &[f32], timestamp: f64) {
+        // Interleave as stereo: Left = microphone, Right = system audio
+        let mut stereo = Vec::with_capacity(mic_window.len() * 2);
+        for (m, s) in mic_window.iter().zip(sys_window.iter()) {
+            stereo.push(*m); // Left channel = microphone (user)
+            stereo.push(*s); // Right channel = system (interlocutor)
+        }
+        if let Some(ref sender) = self.recording_sender_for_mixed {
+            let recording_chunk = AudioChunk {
+                data: stereo,
+                sample_rate: self.sample_rate,
+                timestamp,
+                chunk_id: self.chunk_id_counter,
+                device_type: DeviceType::Mixed,
+            };
+            let _ = sender.send(recording_chunk);
+        }
+    }
+
+    /// Copy the current audio-quality report to the shared per-meeting snapshot
+    fn publish_quality_report(&self) {
+        if let Ok(mut report) = self.quality_report.lock() {
+            *report = self.quality_monitor.report();
+        }
+    }
+
+    /// Copy the current VAD statistics to the shared per-meeting snapshot
+    fn publish_vad_stats(&self) {
+        if let Ok(mut stats) = self.vad_stats.lock() {
+            stats.microphone = self.mic_vad_processor.stats();
+            stats.system = self.sys_vad_processor.stats();
+        }
+    }
+
 }
 
 /// Simple audio pipeline manager
 pub struct AudioPipelineManager {
     pipeline_handle: Option<JoinHandle<Result<()>>>,
     audio_sender: Option<mpsc::UnboundedSender<AudioChunk>>,
+    vad_stats: Option<Arc<std::sync::Mutex<VadSessionStats>>>,
+    quality_report: Option<Arc<std::sync::Mutex<AudioQualityReport>>>,
+    /// VAD settings overriding the user's settings (see `with_vad_settings`)
+    vad_settings: Option<VadSettings>,
 }
 
 impl AudioPipelineManager {
@@ -1150,9 +1343,28 @@ impl AudioPipelineManager {
         Self {
             pipeline_handle: None,
             audio_sender: None,
+            vad_stats: None,
+            quality_report: None,
+            vad_settings: None,
         }
     }
 
+    /// Use these VAD settings instead of the user's current ones
+    pub fn with_vad_settings(mut self, vad_settings: VadSettings) -> Self {
+        self.vad_settings = Some(vad_settings);
+        self
+    }
+
+    /// Audio-quality report of the current (or last) recording
+    pub fn quality_report(&self) -> Option<AudioQualityReport> {
+        self.quality_report.as_ref().and_then(|report| report.lock().ok().map(|r| r.clone()))
+    }
+
+    /// VAD settings and statistics of the current (or last) recording
+    pub fn vad_stats(&self) -> Option<VadSessionStats> {
+        self.vad_stats.as_ref().and_then(|stats| stats.lock().ok().map(|s| s.clone()))
+    }
+
     /// Start the audio pipeline with device information for adaptive buffering
     pub fn start(
         &mut self,
@@ -1188,11 +1400,14 @@ impl AudioPipelineManager {
             mic_device_kind,
             system_device_name,
             system_device_kind,
+            self.vad_settings.clone().unwrap_or_else(current_vad_settings),
         )?;
 
         // CRITICAL FIX: Connect recording sender to receive pre-mixed audio
         // This ensures both mic AND system audio are captured in recordings
         pipeline.recording_sender_for_mixed = recording_sender;
+        self.vad_stats = Some(pipeline.vad_stats.clone());
+        self.quality_report = Some(pipeline.quality_report.clone());
 
         let handle = tokio::spawn(async move {
             pipeline.run().await
@@ -0,0 +1,666 @@
+// audio/quality_monitor.rs
+//
+// Live audio-quality analysis for the recording pipeline. Each channel is
+// checked for clipping, sustained digital silence, sample gaps (dropouts/xruns),
+// low signal-to-noise ratio and narrowband (Bluetooth HFP / telephone) spectra.
+// Problems are surfaced as typed `audio-quality-warning` events while recording
+// and summarised in a per-meeting report stored in metadata.json.
+
+use log::{info, warn};
+use realfft::{RealFftPlanner, RealToComplex};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+
+use super::recording_state::{AudioChunk, DeviceType};
+
+/// Samples at or above this magnitude count as clipped
+const CLIP_LEVEL: f32 = 0.999;
+/// Clipped-sample ratio within one second that triggers a warning
+const CLIP_WARNING_RATIO: f64 = 0.001;
+/// Clipped-sample ratio within one second that is reported as critical
+const CLIP_CRITICAL_RATIO: f64 = 0.01;
+/// Samples below this magnitude count as digital silence
+const DIGITAL_SILENCE_LEVEL: f32 = 1e-6;
+/// Sustained digital silence on the microphone that triggers a warning
+const SILENCE_WARNING_SECONDS: f64 = 5.0;
+/// Audio missing (by chunk timestamps) that counts as a dropout
+const DROPOUT_THRESHOLD_SECONDS: f64 = 0.1;
+/// Window over which late chunks may catch up before missing audio counts as lost
+const DROPOUT_SETTLE_SECONDS: f64 = 0.5;
+/// Time constant for following slow clock drift between device and wall clock
+const DRIFT_TRACKING_SECONDS: f64 = 20.0;
+/// Frame length for level statistics
+const LEVEL_FRAME_SECONDS: f64 = 0.03;
+/// Level frames per SNR estimate (~10s)
+const SNR_WINDOW_FRAMES: usize = 333;
+/// Estimated SNR below this triggers a warning
+const SNR_WARNING_DB: f64 = 10.0;
+/// Frames louder than this (dBFS) indicate the channel carries signal
+const ACTIVE_LEVEL_DB: f64 = -45.0;
+/// FFT size for bandwidth estimation (85ms at 48kHz)
+const SPECTRUM_FFT_SIZE: usize = 4096;
+/// One spectrum is taken per this much audio
+const SPECTRUM_INTERVAL_SECONDS: f64 = 0.25;
+/// Active spectra averaged per bandwidth estimate
+const SPECTRUM_AVERAGE_FRAMES: usize = 40;
+/// Content this far below the spectral peak (dB) is ignored when measuring bandwidth
+const BANDWIDTH_FLOOR_DB: f64 = 60.0;
+/// Bandwidth below this is considered narrowband (8/16kHz Bluetooth HFP)
+const NARROWBAND_CUTOFF_HZ: f64 = 8500.0;
+/// Minimum time between two warnings of the same kind on the same channel
+const WARNING_COOLDOWN_SECONDS: f64 = 60.0;
+/// Warnings kept in the per-meeting report
+const MAX_REPORTED_WARNINGS: usize = 100;
+
+/// Channel a quality problem was detected on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QualityChannel {
+    Microphone,
+    System,
+}
+
+/// Kind of quality problem
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QualityIssue {
+    /// Input level too high, samples hitting full scale
+    Clipping,
+    /// Exact zeros for several seconds (muted or broken hardware)
+    DigitalSilence,
+    /// Audio missing between callbacks (buffer underrun / xrun)
+    Dropout,
+    /// Background noise close to the signal level
+    LowSnr,
+    /// No content above ~8kHz (Bluetooth headset profile, telephone audio)
+    Narrowband,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QualitySeverity {
+    Warning,
+    Critical,
+}
+
+/// Payload of the `audio-quality-warning` event
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AudioQualityWarning {
+    pub channel: QualityChannel,
+    pub issue: QualityIssue,
+    pub severity: QualitySeverity,
+    pub message: String,
+    /// Measured value: ratio (clipping), seconds (silence, dropout), dB (SNR) or Hz (bandwidth)
+    pub value: f64,
+    /// Recording time in seconds when the problem was detected
+    pub timestamp: f64,
+}
+
+/// Quality summary of one channel
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChannelQualityReport {
+    pub analysed_seconds: f64,
+    pub clipped_samples: u64,
+    pub clipping_ratio: f64,
+    pub silence_seconds: f64,
+    pub longest_silence_seconds: f64,
+    pub dropouts: u64,
+    pub dropout_seconds: f64,
+    /// Latest SNR estimate (dB)
+    pub snr_db: Option<f64>,
+    pub min_snr_db: Option<f64>,
+    /// Latest estimated audio bandwidth (Hz)
+    pub bandwidth_hz: Option<f64>,
+    pub narrowband: bool,
+    pub warnings: u64,
+}
+
+/// Per-meeting audio quality report
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AudioQualityReport {
+    pub microphone: ChannelQualityReport,
+    pub system: ChannelQualityReport,
+    /// Warnings emitted during the recording (oldest first, capped)
+    pub warnings: Vec<AudioQualityWarning>,
+}
+
+impl QualityIssue {
+    fn message(&self, channel: QualityChannel, value: f64) -> String {
+        let source = match channel {
+            QualityChannel::Microphone => "Microphone",
+            QualityChannel::System => "System audio",
+        };
+        match self {
+            QualityIssue::Clipping => format!(
+                "{} is clipping ({:.1}% of samples at full scale) - lower the input gain",
+                source,
+                value * 100.0
+            ),
+            QualityIssue::DigitalSilence => format!(
+                "{} has delivered pure digital silence for {:.0}s - check that it is not muted",
+                source, value
+            ),
+            QualityIssue::Dropout => format!(
+                "{} dropped {:.0}ms of audio - the system may be overloaded",
+                source,
+                value * 1000.0
+            ),
+            QualityIssue::LowSnr => format!(
+                "{} is noisy (estimated SNR {:.0} dB) - move closer to the microphone or reduce background noise",
+                source, value
+            ),
+            QualityIssue::Narrowband => format!(
+                "{} is narrowband (~{:.1} kHz) - a Bluetooth headset in call mode reduces transcription accuracy",
+                source,
+                value / 1000.0
+            ),
+        }
+    }
+}
+
+/// Detects audio lost between callbacks by comparing chunk timestamps with
+/// the amount of audio received. Late chunks that catch up are not counted,
+/// and slow clock drift is followed rather than reported.
+struct GapDetector {
+    base_timestamp: f64,
+    base_pause: f64,
+    samples_since_base: u64,
+    /// Reference offset (seconds of audio missing) considered normal
+    baseline: f64,
+    /// Recent (timestamp, missing) pairs
+    recent: VecDeque<(f64, f64)>,
+    last_timestamp: f64,
+}
+
+impl GapDetector {
+    fn new(timestamp: f64, paused_seconds: f64) -> Self {
+        Self {
+            base_timestamp: timestamp,
+            base_pause: paused_seconds,
+            samples_since_base: 0,
+            baseline: 0.0,
+            recent: VecDeque::new(),
+            last_timestamp: timestamp,
+        }
+    }
+
+    /// Feed one chunk (timestamped at its arrival); returns the seconds of audio lost, if any
+    fn observe(&mut self, samples: usize, sample_rate: u32, timestamp: f64, paused_seconds: f64) -> Option<f64> {
+        self.samples_since_base += samples as u64;
+        let received = self.samples_since_base as f64 / sample_rate as f64;
+        let missing = (timestamp - self.base_timestamp) - received - (paused_seconds - self.base_pause);
+
+        // Follow slow drift of the device clock against the recording clock
+        let dt = (timestamp - self.last_timestamp).max(0.0);
+        self.last_timestamp = timestamp;
+
+        self.recent.push_back((timestamp, missing));
+        while self.recent.front().is_some_and(|(t, _)| timestamp - t > DROPOUT_SETTLE_SECONDS) {
+            self.recent.pop_front();
+        }
+
+        // Only audio that stays missing for the whole settle window is lost
+        let settled = self.recent.iter().map(|(_, m)| *m).fold(f64::INFINITY, f64::min);
+        let window_full = self.recent.front().is_some_and(|(t, _)| timestamp - t >= DROPOUT_SETTLE_SECONDS * 0.9);
+
+        if settled < self.baseline {
+            // Audio ahead of the clock (device faster, or burst delivery)
+            self.baseline = settled;
+        } else if window_full && settled - self.baseline > DROPOUT_THRESHOLD_SECONDS {
+            let lost = settled - self.baseline;
+            self.baseline = settled;
+            return Some(lost);
+        } else {
+            self.baseline += (settled - self.baseline) * (dt / DRIFT_TRACKING_SECONDS).min(1.0);
+        }
+        None
+    }
+}
+
+/// Running quality analysis of one channel
+pub struct ChannelQualityAnalyzer {
+    channel: QualityChannel,
+    report: ChannelQualityReport,
+    sample_rate: u32,
+    total_samples: u64,
+    // Clipping, per one-second window
+    clip_window_samples: u64,
+    clip_window_clipped: u64,
+    // Digital silence
+    silence_run_samples: u64,
+    silence_reported: bool,
+    // Dropouts
+    gaps: Option<GapDetector>,
+    // Level statistics for SNR
+    frame: Vec<f32>,
+    frame_levels_db: VecDeque<f64>,
+    frames_since_snr: usize,
+    // Bandwidth estimation
+    fft: Option<Arc<dyn RealToComplex<f32>>>,
+    spectrum_input: VecDeque<f32>,
+    spectrum_sum: Vec<f64>,
+    spectra: usize,
+    samples_since_spectrum: usize,
+    last_warning: HashMap<QualityIssue, f64>,
+}
+
+impl ChannelQualityAnalyzer {
+    pub fn new(channel: QualityChannel) -> Self {
+        Self {
+            channel,
+            report: ChannelQualityReport::default(),
+            sample_rate: 48000,
+            total_samples: 0,
+            clip_window_samples: 0,
+            clip_window_clipped: 0,
+            silence_run_samples: 0,
+            silence_reported: false,
+            gaps: None,
+            frame: Vec::new(),
+            frame_levels_db: VecDeque::with_capacity(SNR_WINDOW_FRAMES + 1),
+            frames_since_snr: 0,
+            fft: None,
+            spectrum_input: VecDeque::with_capacity(SPECTRUM_FFT_SIZE + 1),
+            spectrum_sum: vec![0.0; SPECTRUM_FFT_SIZE / 2 + 1],
+            spectra: 0,
+            samples_since_spectrum: 0,
+            last_warning: HashMap::new(),
+        }
+    }
+
+    /// Analyse one mono chunk; `timestamp` is the recording time at which it
+    /// arrived and `paused_seconds` the total pause time so far
+    pub fn process(
+        &mut self,
+        samples: &[f32],
+        sample_rate: u32,
+        timestamp: f64,
+        paused_seconds: f64,
+    ) -> Vec<AudioQualityWarning> {
+        let mut warnings = Vec::new();
+        if samples.is_empty() || sample_rate == 0 {
+            return warnings;
+        }
+        self.sample_rate = sample_rate;
+
+        self.check_dropouts(samples.len(), timestamp, paused_seconds, &mut warnings);
+
+        for &sample in samples {
+            self.total_samples += 1;
+            self.check_clipping(sample, timestamp, &mut warnings);
+            self.check_silence(sample, timestamp, &mut warnings);
+
+            self.frame.push(sample);
+            if self.frame.len() >= (LEVEL_FRAME_SECONDS * sample_rate as f64) as usize {
+                self.finish_level_frame(timestamp, &mut warnings);
+            }
+
+            self.spectrum_input.push_back(sample);
+            if self.spectrum_input.len() > SPECTRUM_FFT_SIZE {
+                self.spectrum_input.pop_front();
+            }
+            self.samples_since_spectrum += 1;
+            if self.samples_since_spectrum >= (SPECTRUM_INTERVAL_SECONDS * sample_rate as f64) as usize {
+                self.samples_since_spectrum = 0;
+                self.analyse_spectrum(timestamp, &mut warnings);
+            }
+        }
+
+        self.report.analysed_seconds = self.total_samples as f64 / sample_rate as f64;
+        self.report.clipping_ratio = self.report.clipped_samples as f64 / self.total_samples.max(1) as f64;
+        warnings
+    }
+
+    pub fn report(&self) -> ChannelQualityReport {
+        let mut report = self.report.clone();
+        let current_silence = self.silence_run_samples as f64 / self.sample_rate as f64;
+        report.longest_silence_seconds = report.longest_silence_seconds.max(current_silence);
+        report
+    }
+
+    fn check_dropouts(&mut self, len: usize, timestamp: f64, paused_seconds: f64, warnings: &mut Vec<AudioQualityWarning>) {
+        let Some(gaps) = self.gaps.as_mut() else {
+            self.gaps = Some(GapDetector::new(timestamp, paused_seconds));
+            return;
+        };
+
+        if let Some(lost) = gaps.observe(len, self.sample_rate, timestamp, paused_seconds) {
+            self.report.dropouts += 1;
+            self.report.dropout_seconds += lost;
+            let severity = if lost >= 1.0 { QualitySeverity::Critical } else { QualitySeverity::Warning };
+            self.warn(QualityIssue::Dropout, severity, lost, timestamp, warnings);
+        }
+    }
+
+    fn check_clipping(&mut self, sample: f32, timestamp: f64, warnings: &mut Vec<AudioQualityWarning>) {
+        self.clip_window_samples += 1;
+        if sample.abs() >= CLIP_LEVEL {
+            self.clip_window_clipped += 1;
+            self.report.clipped_samples += 1;
+        }
+
+        if self.clip_window_samples >= self.sample_rate as u64 {
+            let ratio = self.clip_window_clipped as f64 / self.clip_window_samples as f64;
+            self.clip_window_samples = 0;
+            self.clip_window_clipped = 0;
+
+            if ratio > CLIP_WARNING_RATIO {
+                let severity = if ratio > CLIP_CRITICAL_RATIO { QualitySeverity::Critical } else { QualitySeverity::Warning };
+                self.warn(QualityIssue::Clipping, severity, ratio, timestamp, warnings);
+            }
+        }
+    }
+
+    fn check_silence(&mut self, sample: f32, timestamp: f64, warnings: &mut Vec<AudioQualityWarning>) {
+        if sample.abs() >= DIGITAL_SILENCE_LEVEL {
+            if self.silence_run_samples > 0 {
+                let run = self.silence_run_samples as f64 / self.sample_rate as f64;
+                self.report.longest_silence_seconds = self.report.longest_silence_seconds.max(run);
+                self.silence_run_samples = 0;
+                self.silence_reported = false;
+            }
+            return;
+        }
+
+        self.silence_run_samples += 1;
+        self.report.silence_seconds += 1.0 / self.sample_rate as f64;
+
+        // Silent system audio just means nothing is playing; only the mic is suspicious
+        let run = self.silence_run_samples as f64 / self.sample_rate as f64;
+        if self.channel == QualityChannel::Microphone && !self.silence_reported && run >= SILENCE_WARNING_SECONDS {
+            self.silence_reported = true;
+            self.warn(QualityIssue::DigitalSilence, QualitySeverity::Critical, run, timestamp, warnings);
+        }
+    }
+
+    fn finish_level_frame(&mut self, timestamp: f64, warnings: &mut Vec<AudioQualityWarning>) {
+        let energy = self.frame.iter().map(|&x| (x as f64) * (x as f64)).sum::<f64>() / self.frame.len() as f64;
+        self.frame.clear();
+
+        // Digital silence says nothing about the noise floor
+        let level_db = 10.0 * (energy + 1e-12).log10();
+        if level_db < -100.0 {
+            return;
+        }
+
+        self.frame_levels_db.push_back(level_db);
+        if self.frame_levels_db.len() > SNR_WINDOW_FRAMES {
+            self.frame_levels_db.pop_front();
+        }
+
+        self.frames_since_snr += 1;
+        if self.frames_since_snr < SNR_WINDOW_FRAMES || self.frame_levels_db.len() < SNR_WINDOW_FRAMES / 2 {
+            return;
+        }
+        self.frames_since_snr = 0;
+
+        // Signal level ~ 90th percentile of frame levels, noise floor ~ 10th percentile
+        let mut levels: Vec<f64> = self.frame_levels_db.iter().copied().collect();
+        levels.sort_by(|a, b| a.total_cmp(b));
+        let noise_db = levels[levels.len() / 10];
+        let signal_db = levels[levels.len() * 9 / 10];
+        if signal_db < ACTIVE_LEVEL_DB {
+            return;
+        }
+
+        let snr = signal_db - noise_db;
+        self.report.snr_db = Some(snr);
+        self.report.min_snr_db = Some(self.report.min_snr_db.map_or(snr, |min| min.min(snr)));
+        if snr < SNR_WARNING_DB {
+            self.warn(QualityIssue::LowSnr, QualitySeverity::Warning, snr, timestamp, warnings);
+        }
+    }
+
+    fn analyse_spectrum(&mut self, timestamp: f64, warnings: &mut Vec<AudioQualityWarning>) {
+        if self.spectrum_input.len() < SPECTRUM_FFT_SIZE {
+            return;
+        }
+
+        let energy = self.spectrum_input.iter().map(|&x| (x as f64) * (x as f64)).sum::<f64>() / SPECTRUM_FFT_SIZE as f64;
+        if 10.0 * (energy + 1e-12).log10() < ACTIVE_LEVEL_DB {
+            return;
+        }
+
+        let fft = self
+            .fft
+            .get_or_insert_with(|| RealFftPlanner::<f32>::new().plan_fft_forward(SPECTRUM_FFT_SIZE))
+            .clone();
+        let n = SPECTRUM_FFT_SIZE as f32;
+        let mut input: Vec<f32> = self
+            .spectrum_input
+            .iter()
+            .enumerate()
+            .map(|(i, &x)| x * (0.5 - 0.5 * (2.0 * std::f32::consts::PI * i as f32 / n).cos()))
+            .collect();
+        let mut output = fft.make_output_vec();
+        if fft.process(&mut input, &mut output).is_err() {
+            return;
+        }
+
+        for (sum, bin) in self.spectrum_sum.iter_mut().zip(output.iter()) {
+            *sum += bin.norm_sqr() as f64;
+        }
+        self.spectra += 1;
+        if self.spectra < SPECTRUM_AVERAGE_FRAMES {
+            return;
+        }
+
+        let bandwidth = estimate_bandwidth(&self.spectrum_sum, self.sample_rate);
+        self.spectrum_sum.iter_mut().for_each(|v| *v = 0.0);
+        self.spectra = 0;
+
+        let Some(bandwidth) = bandwidth else { return };
+        let narrowband = bandwidth < NARROWBAND_CUTOFF_HZ;
+        self.report.bandwidth_hz = Some(bandwidth);
+        self.report.narrowband = narrowband;
+        if narrowband {
+            self.warn(QualityIssue::Narrowband, QualitySeverity::Warning, bandwidth, timestamp, warnings);
+        }
+    }
+
+    fn warn(
+        &mut self,
+        issue: QualityIssue,
+        severity: QualitySeverity,
+        value: f64,
+        timestamp: f64,
+        warnings: &mut Vec<AudioQualityWarning>,
+    ) {
+        if let Some(last) = self.last_warning.get(&issue) {
+            if timestamp - last < WARNING_COOLDOWN_SECONDS {
+                return;
+            }
+        }
+        self.last_warning.insert(issue, timestamp);
+        self.report.warnings += 1;
+
+        let warning = AudioQualityWarning {
+            channel: self.channel,
+            issue,
+            severity,
+            message: issue.message(self.channel, value),
+            value,
+            timestamp,
+        };
+        warn!("🩺 Audio quality [{:?}] at {:.1}s: {}", severity, timestamp, warning.message);
+        warnings.push(warning);
+    }
+}
+
+/// Highest frequency with content within `BANDWIDTH_FLOOR_DB` of the spectral peak
+fn estimate_bandwidth(power: &[f64], sample_rate: u32) -> Option<f64> {
+    let bin_hz = sample_rate as f64 / SPECTRUM_FFT_SIZE as f64;
+    // Ignore DC and rumble below 100Hz
+    let first_bin = (100.0 / bin_hz).ceil() as usize;
+    let peak = power.iter().skip(first_bin).copied().fold(0.0, f64::max);
+    if peak <= 0.0 {
+        return None;
+    }
+
+    let floor = peak * 10f64.powf(-BANDWIDTH_FLOOR_DB / 10.0);
+    let top_bin = power.iter().rposition(|&p| p > floor)?;
+    Some((top_bin as f64 + 0.5) * bin_hz)
+}
+
+/// Quality analysis for both recording channels
+pub struct AudioQualityMonitor {
+    microphone: ChannelQualityAnalyzer,
+    system: ChannelQualityAnalyzer,
+    warnings: Vec<AudioQualityWarning>,
+}
+
+impl AudioQualityMonitor {
+    pub fn new() -> Self {
+        Self {
+            microphone: ChannelQualityAnalyzer::new(QualityChannel::Microphone),
+            system: ChannelQualityAnalyzer::new(QualityChannel::System),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Analyse a pipeline chunk and return any new warnings
+    pub fn process_chunk(&mut self, chunk: &AudioChunk, paused_seconds: f64) -> Vec<AudioQualityWarning> {
+        let analyzer = match chunk.device_type {
+            DeviceType::Microphone => &mut self.microphone,
+            DeviceType::System => &mut self.system,
+            DeviceType::Mixed => return Vec::new(),
+        };
+
+        let warnings = analyzer.process(&chunk.data, chunk.sample_rate, chunk.timestamp, paused_seconds);
+        for warning in &warnings {
+            if self.warnings.len() < MAX_REPORTED_WARNINGS {
+                self.warnings.push(warning.clone());
+            }
+        }
+        warnings
+    }
+
+    /// A channel moved to another capture device: the switch-over is not a dropout
+    pub fn restart_stream(&mut self, device_type: DeviceType) {
+        match device_type {
+            DeviceType::Microphone => self.microphone.gaps = None,
+            DeviceType::System => self.system.gaps = None,
+            DeviceType::Mixed => {}
+        }
+    }
+
+    pub fn report(&self) -> AudioQualityReport {
+        AudioQualityReport {
+            microphone: self.microphone.report(),
+            system: self.system.report(),
+            warnings: self.warnings.clone(),
+        }
+    }
+
+    pub fn log_summary(&self) {
+        let report = self.report();
+        info!(
+            "🩺 Audio quality: mic clipping={:.3}%, dropouts={}, snr={:?}, bandwidth={:?} | system clipping={:.3}%, dropouts={}, bandwidth={:?}",
+            report.microphone.clipping_ratio * 100.0,
+            report.microphone.dropouts,
+            report.microphone.snr_db.map(|v| v.round()),
+            report.microphone.bandwidth_hz.map(|v| v.round()),
+            report.system.clipping_ratio * 100.0,
+            report.system.dropouts,
+            report.system.bandwidth_hz.map(|v| v.round()),
+        );
+    }
+}
+
+impl Default for AudioQualityMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RATE: u32 = 48000;
+
+    /// Feed `signal` in 10ms chunks with matching timestamps
+    fn feed(analyzer: &mut ChannelQualityAnalyzer, seconds: f64, start: f64, mut signal: impl FnMut(f64) -> f32) -> Vec<AudioQualityWarning> {
+        let chunk = (RATE / 100) as usize;
+        let total = (seconds * RATE as f64) as usize;
+        let mut warnings = Vec::new();
+        let mut offset = 0;
+        while offset < total {
+            let samples: Vec<f32> = (offset..offset + chunk).map(|i| signal(i as f64 / RATE as f64)).collect();
+            offset += chunk;
+            warnings.extend(analyzer.process(&samples, RATE, start + offset as f64 / RATE as f64, 0.0));
+        }
+        warnings
+    }
+
+    fn noise(seed: &mut u32) -> f32 {
+        *seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+        (*seed >> 8) as f32 / (1u32 << 24) as f32 * 2.0 - 1.0
+    }
+
+    #[test]
+    fn test_clipping_and_silence_warnings() {
+        let mut mic = ChannelQualityAnalyzer::new(QualityChannel::Microphone);
+        let warnings = feed(&mut mic, 2.0, 0.0, |t| (2.0 * (2.0 * std::f64::consts::PI * 200.0 * t).sin()).clamp(-1.0, 1.0) as f32);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert_eq!(warnings[0].issue, QualityIssue::Clipping);
+        assert_eq!(warnings[0].severity, QualitySeverity::Critical);
+
+        let warnings = feed(&mut mic, 6.0, 2.0, |_| 0.0);
+        assert_eq!(warnings.iter().filter(|w| w.issue == QualityIssue::DigitalSilence).count(), 1);
+        assert!(mic.report().longest_silence_seconds >= 5.9);
+
+        // Silent system audio is normal and never warned about
+        let mut system = ChannelQualityAnalyzer::new(QualityChannel::System);
+        assert!(feed(&mut system, 6.0, 0.0, |_| 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_dropout_detection() {
+        let mut mic = ChannelQualityAnalyzer::new(QualityChannel::Microphone);
+        let quiet = |t: f64| (0.01 * (2.0 * std::f64::consts::PI * 300.0 * t).sin()) as f32;
+        assert!(feed(&mut mic, 2.0, 0.0, quiet).is_empty());
+
+        // 300ms of audio never arrives
+        let warnings = feed(&mut mic, 2.0, 2.3, quiet);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+        assert_eq!(warnings[0].issue, QualityIssue::Dropout);
+        assert!((warnings[0].value - 0.3).abs() < 0.05, "{}", warnings[0].value);
+
+        let report = mic.report();
+        assert_eq!(report.dropouts, 1);
+    }
+
+    #[test]
+    fn test_late_chunks_are_not_dropouts() {
+        let mut gaps = GapDetector::new(0.0, 0.0);
+        let mut lost = None;
+        for i in 1..200 {
+            // Every 50th chunk arrives 150ms late, the next ones catch up
+            let jitter = if i % 50 == 0 { 0.15 } else { 0.0 };
+            lost = lost.or(gaps.observe(480, RATE, i as f64 * 0.01 + jitter, 0.0));
+        }
+        assert_eq!(lost, None);
+    }
+
+    #[test]
+    fn test_narrowband_detection() {
+        let mut seed = 1;
+        let mut wide = ChannelQualityAnalyzer::new(QualityChannel::Microphone);
+        let warnings = feed(&mut wide, 11.0, 0.0, |_| 0.1 * noise(&mut seed));
+        assert!(warnings.iter().all(|w| w.issue != QualityIssue::Narrowband), "{:?}", warnings);
+        assert!(wide.report().bandwidth_hz.unwrap() > 20000.0);
+
+        // Tones below 4kHz only, like 8kHz Bluetooth HFP audio resampled to 48kHz
+        let mut narrow = ChannelQualityAnalyzer::new(QualityChannel::Microphone);
+        let warnings = feed(&mut narrow, 11.0, 0.0, |t| {
+            [300.0, 900.0, 1700.0, 3100.0]
+                .iter()
+                .map(|f| 0.05 * (2.0 * std::f64::consts::PI * f * t).sin())
+                .sum::<f64>() as f32
+        });
+        assert!(warnings.iter().any(|w| w.issue == QualityIssue::Narrowband), "{:?}", warnings);
+        assert!(narrow.report().narrowband);
+    }
+}
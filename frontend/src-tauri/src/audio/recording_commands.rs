@@ -7,7 +7,7 @@ use anyhow::Result;
 use log::{error, info, warn};
 use serde::{Deserialize, Serialize};
 use std::sync::atomic::Ordering;
-use tauri::{AppHandle, Runtime};
+use tauri::{AppHandle, Emitter, Runtime};
 
 use super::{
     DeviceEvent,
@@ -160,6 +160,55 @@ pub async fn get_recording_meeting_name() -> Result<Option<String>, String> {
     }
 }
 
+// ============================================================================
+// BOOKMARK COMMANDS
+// ============================================================================
+
+/// Bookmark the current moment of the active recording (e.g. "important", "action item")
+#[tauri::command]
+pub async fn add_recording_bookmark<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    note: Option<String>,
+) -> Result<crate::audio::recording_saver::Bookmark, String> {
+    let label = label.trim().to_string();
+    if label.is_empty() {
+        return Err("Bookmark label cannot be empty".to_string());
+    }
+    let note = note.map(|n| n.trim().to_string()).filter(|n| !n.is_empty());
+
+    if !IS_RECORDING.load(Ordering::SeqCst) {
+        return Err("No recording is currently active".to_string());
+    }
+
+    let bookmark = {
+        let manager_guard = RECORDING_MANAGER.lock().map_err(|e| format!("Recording manager lock poisoned: {}", e))?;
+        match manager_guard.as_ref() {
+            Some(manager) => manager.add_bookmark(label, note),
+            None => return Err("No recording manager found".to_string()),
+        }
+    };
+
+    if let Err(e) = app.emit("recording-bookmark-added", &bookmark) {
+        warn!("Failed to emit recording-bookmark-added event: {}", e);
+    }
+
+    info!("Bookmark '{}' added at {:.1}s", bookmark.label, bookmark.timestamp);
+    Ok(bookmark)
+}
+
+/// Get bookmarks added during the current recording session
+#[tauri::command]
+pub async fn get_recording_bookmarks() -> Result<Vec<crate::audio::recording_saver::Bookmark>, String> {
+    let manager_guard = RECORDING_MANAGER.lock().map_err(|e| format!("Recording manager lock poisoned: {}", e))?;
+
+    if let Some(manager) = manager_guard.as_ref() {
+        Ok(manager.get_bookmarks())
+    } else {
+        Ok(Vec::new())
+    }
+}
+
 // ============================================================================
 // DEVICE MONITORING COMMANDS (AirPods/Bluetooth disconnect/reconnect support)
 // ============================================================================
@@ -176,6 +225,12 @@ pub enum DeviceEventResponse {
         device_name: String,
         device_type: String,
     },
+    /// The recording followed the OS default to a new device
+    DefaultDeviceChanged {
+        device_name: String,
+        device_type: String,
+        switched: bool,
+    },
     DeviceListChanged,
 }
 
@@ -194,6 +249,13 @@ impl From<DeviceEvent> for DeviceEventResponse {
                     device_type: format!("{:?}", device_type),
                 }
             }
+            DeviceEvent::DefaultDeviceChanged { device_name, device_type } => {
+                DeviceEventResponse::DefaultDeviceChanged {
+                    device_name,
+                    device_type: format!("{:?}", device_type),
+                    switched: false,
+                }
+            }
             DeviceEvent::DeviceListChanged => DeviceEventResponse::DeviceListChanged,
         }
     }
@@ -215,20 +277,54 @@ pub struct DisconnectedDeviceInfo {
 
 /// Poll for audio device events (disconnect/reconnect)
 /// Should be called periodically (every 1-2 seconds) by frontend during recording
+/// "Follow system default" switches are carried out here before the event is returned
 #[tauri::command]
 pub async fn poll_audio_device_events() -> Result<Option<DeviceEventResponse>, String> {
-    let mut manager_guard = RECORDING_MANAGER.lock().map_err(|e| format!("Recording manager lock poisoned: {}", e))?;
-
-    if let Some(manager) = manager_guard.as_mut() {
-        if let Some(event) = manager.poll_device_events() {
-            info!("📱 Device event polled: {:?}", event);
-            Ok(Some(event.into()))
-        } else {
-            Ok(None)
+    let event = {
+        let mut manager_guard = RECORDING_MANAGER.lock().map_err(|e| format!("Recording manager lock poisoned: {}", e))?;
+        match manager_guard.as_mut() {
+            Some(manager) => manager.poll_device_events(),
+            None => None,
         }
-    } else {
-        Ok(None)
-    }
+    };
+
+    let Some(event) = event else {
+        return Ok(None);
+    };
+    info!("📱 Device event polled: {:?}", event);
+
+    let DeviceEvent::DefaultDeviceChanged { device_name, device_type } = &event else {
+        return Ok(Some(event.into()));
+    };
+
+    let (name, monitor_type) = (device_name.clone(), device_type.clone());
+    let result = tokio::task::spawn_blocking(move || {
+        tokio::runtime::Handle::current().block_on(async {
+            let mut manager_guard = RECORDING_MANAGER.lock()
+                .map_err(|e| anyhow::anyhow!("Recording manager lock poisoned: {}", e))?;
+            if let Some(manager) = manager_guard.as_mut() {
+                manager.switch_to_default_device(&name, monitor_type).await
+            } else {
+                Ok(false)
+            }
+        })
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?;
+
+    let switched = match result {
+        Ok(switched) => switched,
+        Err(e) => {
+            error!("Failed to follow default device '{}': {}", device_name, e);
+            false
+        }
+    };
+
+    Ok(Some(DeviceEventResponse::DefaultDeviceChanged {
+        device_name: device_name.clone(),
+        device_type: format!("{:?}", device_type),
+        switched,
+    }))
 }
 
 /// Get current reconnection status
@@ -170,6 +170,12 @@ pub async fn initialize_recording<R: Runtime>(
         let _ = app_for_error.emit("recording-error", error.user_message());
     });
 
+    // Forward live audio-quality warnings to the frontend
+    let app_for_quality = app.clone();
+    manager.set_quality_warning_callback(move |warning| {
+        let _ = app_for_quality.emit("audio-quality-warning", warning);
+    });
+
     // Start recording with resolved devices
     let transcription_receiver = manager
         .start_recording(microphone_device, system_device, auto_save)
@@ -197,6 +203,12 @@ pub async fn initialize_recording<R: Runtime>(
     // Register transcript-update event listener for history persistence
     register_transcript_listener(app);
 
+    // Evaluate auto-stop / auto-pause rules while recording
+    super::auto_rules::start_auto_rules(app);
+
+    // Keep a rolling "so far" summary while recording (when enabled)
+    crate::summary::live_summary::start_live_summary(app);
+
     Ok(())
 }
 
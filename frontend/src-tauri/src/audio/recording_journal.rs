@@ -0,0 +1,578 @@
+// audio/recording_journal.rs
+//
+// Append-only, crash-safe journal for an in-progress recording.
+//
+// Every transcript segment, bookmark, pause/resume, device change and metadata update is
+// appended as one JSON line to `recording_journal.jsonl` inside the meeting folder
+// and synced to disk immediately. After a crash, the journal plus the audio
+// checkpoints are enough to rebuild the complete meeting (audio, transcripts.json,
+// metadata.json and DB rows) on the next launch.
+
+use anyhow::{anyhow, Result};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tauri::{AppHandle, Runtime};
+
+use super::incremental_saver::{merge_checkpoint_files, AudioRecoveryStatus};
+use super::native_encoder::AudioFileFormat;
+use super::recording_saver::{write_bookmarks_file, Bookmark, MeetingMetadata, TranscriptSegment};
+use crate::database::repositories::bookmark::BookmarksRepository;
+use crate::database::repositories::transcript::TranscriptsRepository;
+use crate::state::AppState;
+
+/// File name of the journal inside the meeting folder
+pub const JOURNAL_FILE_NAME: &str = "recording_journal.jsonl";
+
+/// A single event recorded during a recording session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JournalEvent {
+    SessionStarted {
+        meeting_name: Option<String>,
+        sample_rate: u32,
+        channels: u16,
+    },
+    TranscriptSegment {
+        segment: TranscriptSegment,
+    },
+    Paused {
+        recording_time: Option<f64>, // Active recording seconds at the time of the pause
+    },
+    Resumed {
+        recording_time: Option<f64>,
+    },
+    DeviceChanged {
+        device_type: String, // "microphone" | "system_audio"
+        device_name: Option<String>,
+        reason: String, // "disconnected" | "reconnected" | "switched"
+    },
+    MetadataUpdated {
+        metadata: MeetingMetadata,
+    },
+    BookmarkAdded {
+        bookmark: Bookmark,
+    },
+    Completed {
+        duration_seconds: Option<f64>,
+    },
+    Recovered {
+        meeting_id: Option<String>,
+    },
+}
+
+/// Journal line: wall-clock timestamp plus the event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalRecord {
+    pub at: String, // RFC3339
+    #[serde(flatten)]
+    pub event: JournalEvent,
+}
+
+/// Append-only writer for the recording journal
+pub struct RecordingJournal {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl RecordingJournal {
+    /// Open (or create) the journal in a meeting folder for appending
+    pub fn open(meeting_folder: &Path) -> Result<Self> {
+        let path = meeting_folder.join(JOURNAL_FILE_NAME);
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        info!("Recording journal opened: {}", path.display());
+        Ok(Self { path, file: Mutex::new(file) })
+    }
+
+    /// Append an event and sync it to disk before returning
+    pub fn append(&self, event: JournalEvent) -> Result<()> {
+        let record = JournalRecord {
+            at: chrono::Utc::now().to_rfc3339(),
+            event,
+        };
+        let mut line = serde_json::to_string(&record)?;
+        line.push('\n');
+
+        let mut file = self.file
+            .lock()
+            .map_err(|e| anyhow!("Journal lock poisoned: {}", e))?;
+        file.write_all(line.as_bytes())?;
+        file.sync_data()?;
+        Ok(())
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+/// Read all journal records, skipping lines that cannot be parsed
+/// (the last line may be truncated if the app crashed mid-write)
+pub fn read_journal(path: &Path) -> Result<Vec<JournalRecord>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+
+    for (index, line) in reader.lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                warn!("Stopping journal read at line {}: {}", index + 1, e);
+                break;
+            }
+        };
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str::<JournalRecord>(&line) {
+            Ok(record) => records.push(record),
+            Err(e) => warn!("Skipping corrupt journal line {}: {}", index + 1, e),
+        }
+    }
+
+    Ok(records)
+}
+
+/// A device change replayed from the journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalDeviceChange {
+    pub at: String,
+    pub device_type: String,
+    pub device_name: Option<String>,
+    pub reason: String,
+}
+
+/// State of a meeting rebuilt by replaying its journal
+#[derive(Debug, Clone, Default)]
+pub struct JournalReplay {
+    pub meeting_name: Option<String>,
+    pub started_at: Option<String>,
+    pub last_event_at: Option<String>,
+    pub metadata: Option<MeetingMetadata>,
+    /// Segments with the wall-clock time they were journaled, upserted by sequence_id
+    pub segments: Vec<(String, TranscriptSegment)>,
+    pub bookmarks: Vec<Bookmark>,
+    pub pause_count: u32,
+    pub device_changes: Vec<JournalDeviceChange>,
+    pub completed: bool,
+    pub recovered: bool,
+    pub duration_seconds: Option<f64>,
+}
+
+impl JournalReplay {
+    pub fn from_records(records: &[JournalRecord]) -> Self {
+        let mut replay = JournalReplay::default();
+
+        for record in records {
+            if replay.started_at.is_none() {
+                replay.started_at = Some(record.at.clone());
+            }
+            replay.last_event_at = Some(record.at.clone());
+
+            match &record.event {
+                JournalEvent::SessionStarted { meeting_name, .. } => {
+                    replay.meeting_name = meeting_name.clone();
+                }
+                JournalEvent::TranscriptSegment { segment } => {
+                    match replay.segments.iter_mut().find(|(_, s)| s.sequence_id == segment.sequence_id) {
+                        Some(existing) => existing.1 = segment.clone(),
+                        None => replay.segments.push((record.at.clone(), segment.clone())),
+                    }
+                }
+                JournalEvent::Paused { .. } => replay.pause_count += 1,
+                JournalEvent::Resumed { .. } => {}
+                JournalEvent::DeviceChanged { device_type, device_name, reason } => {
+                    replay.device_changes.push(JournalDeviceChange {
+                        at: record.at.clone(),
+                        device_type: device_type.clone(),
+                        device_name: device_name.clone(),
+                        reason: reason.clone(),
+                    });
+                }
+                JournalEvent::MetadataUpdated { metadata } => {
+                    if metadata.meeting_name.is_some() {
+                        replay.meeting_name = metadata.meeting_name.clone();
+                    }
+                    replay.metadata = Some(metadata.clone());
+                }
+                JournalEvent::BookmarkAdded { bookmark } => {
+                    replay.bookmarks.push(bookmark.clone());
+                }
+                JournalEvent::Completed { duration_seconds } => {
+                    replay.completed = true;
+                    replay.duration_seconds = *duration_seconds;
+                }
+                JournalEvent::Recovered { .. } => replay.recovered = true,
+            }
+        }
+
+        replay.segments.sort_by(|(_, a), (_, b)| {
+            a.audio_start_time
+                .partial_cmp(&b.audio_start_time)
+                .unwrap_or(std::cmp::Ordering::Equal)
+                .then(a.sequence_id.cmp(&b.sequence_id))
+        });
+
+        replay
+    }
+
+    /// True when the recording never reached a clean stop and was not recovered yet
+    pub fn needs_recovery(&self) -> bool {
+        !self.completed && !self.recovered
+    }
+
+    /// Best duration estimate: explicit duration, else end of the last segment
+    pub fn estimated_duration_seconds(&self) -> f64 {
+        self.duration_seconds.unwrap_or_else(|| {
+            self.segments
+                .iter()
+                .map(|(_, s)| s.audio_end_time)
+                .fold(0.0, f64::max)
+        })
+    }
+}
+
+// ============================================================================
+// RECOVERY COMMANDS
+// ============================================================================
+
+/// A meeting folder whose recording was interrupted
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterruptedRecording {
+    pub meeting_folder: String,
+    pub meeting_name: Option<String>,
+    pub started_at: Option<String>,
+    pub last_event_at: Option<String>,
+    pub segment_count: usize,
+    pub estimated_duration_seconds: f64,
+    pub has_audio_checkpoints: bool,
+}
+
+/// Result of rebuilding a meeting from its journal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalRecoveryResult {
+    pub meeting_id: String,
+    pub meeting_name: String,
+    pub meeting_folder: String,
+    pub segment_count: usize,
+    pub audio: AudioRecoveryStatus,
+}
+
+fn checkpoint_files(meeting_folder: &Path) -> Vec<PathBuf> {
+    let checkpoints_dir = meeting_folder.join(".checkpoints");
+    let mut files: Vec<PathBuf> = std::fs::read_dir(&checkpoints_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    matches!(
+                        AudioFileFormat::from_path(path),
+                        Some(AudioFileFormat::Mp4) | Some(AudioFileFormat::Wav)
+                    )
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+    files
+}
+
+fn write_json_atomic(folder: &Path, file_name: &str, value: &serde_json::Value) -> Result<()> {
+    let final_path = folder.join(file_name);
+    let temp_path = folder.join(format!(".{}.tmp", file_name));
+    std::fs::write(&temp_path, serde_json::to_string_pretty(value)?)?;
+    std::fs::rename(&temp_path, &final_path)?;
+    Ok(())
+}
+
+/// Scan the recordings folders for meetings whose journal shows an unfinished recording
+#[tauri::command]
+pub async fn list_interrupted_recordings<R: Runtime>(
+    app: AppHandle<R>,
+) -> Result<Vec<InterruptedRecording>, String> {
+    let mut base_folders = vec![super::recording_preferences::get_default_recordings_folder()];
+    if let Ok(prefs) = super::recording_preferences::load_recording_preferences(&app).await {
+        if !base_folders.contains(&prefs.save_folder) {
+            base_folders.push(prefs.save_folder);
+        }
+    }
+
+    let mut interrupted = Vec::new();
+
+    for base in base_folders {
+        let entries = match std::fs::read_dir(&base) {
+            Ok(entries) => entries,
+            Err(_) => continue,
+        };
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let folder = entry.path();
+            let journal_path = folder.join(JOURNAL_FILE_NAME);
+            if !journal_path.is_file() {
+                continue;
+            }
+
+            let records = match read_journal(&journal_path) {
+                Ok(records) => records,
+                Err(e) => {
+                    warn!("Failed to read journal {}: {}", journal_path.display(), e);
+                    continue;
+                }
+            };
+
+            let replay = JournalReplay::from_records(&records);
+            if !replay.needs_recovery() {
+                continue;
+            }
+
+            interrupted.push(InterruptedRecording {
+                meeting_folder: folder.to_string_lossy().to_string(),
+                meeting_name: replay.meeting_name.clone(),
+                started_at: replay.started_at.clone(),
+                last_event_at: replay.last_event_at.clone(),
+                segment_count: replay.segments.len(),
+                estimated_duration_seconds: replay.estimated_duration_seconds(),
+                has_audio_checkpoints: !checkpoint_files(&folder).is_empty(),
+            });
+        }
+    }
+
+    info!("Found {} interrupted recordings", interrupted.len());
+    Ok(interrupted)
+}
+
+/// Rebuild a complete meeting (audio, transcripts.json, metadata.json and DB rows)
+/// from the journal and audio checkpoints of an interrupted recording
+#[tauri::command]
+pub async fn recover_meeting_from_journal(
+    state: tauri::State<'_, AppState>,
+    meeting_folder: String,
+) -> Result<JournalRecoveryResult, String> {
+    info!("Recovering meeting from journal: {}", meeting_folder);
+
+    let folder = PathBuf::from(&meeting_folder);
+    let journal_path = folder.join(JOURNAL_FILE_NAME);
+    let records = read_journal(&journal_path)
+        .map_err(|e| format!("Failed to read recording journal: {}", e))?;
+    let replay = JournalReplay::from_records(&records);
+
+    if replay.recovered {
+        return Err("This meeting has already been recovered".to_string());
+    }
+    if !replay.needs_recovery() {
+        // Stopped cleanly: its transcripts and bookmarks are already saved
+        return Err("This recording finished normally and does not need recovery".to_string());
+    }
+
+    // 1. Audio: merge checkpoints (natively if FFmpeg is missing)
+    let checkpoints = checkpoint_files(&folder);
+    let chunk_count = checkpoints.len() as u32;
+    let audio = if checkpoints.is_empty() {
+        AudioRecoveryStatus {
+            status: "none".to_string(),
+            chunk_count: 0,
+            estimated_duration_seconds: 0.0,
+            audio_file_path: None,
+            message: "No audio checkpoints found".to_string(),
+        }
+    } else {
+        let merge_folder = folder.clone();
+        let merge_result = tokio::task::spawn_blocking(move || merge_checkpoint_files(&checkpoints, &merge_folder))
+            .await
+            .map_err(|e| format!("Audio recovery task failed: {}", e))?;
+
+        match merge_result {
+            Ok(path) => {
+                if let Err(e) = std::fs::remove_dir_all(folder.join(".checkpoints")) {
+                    warn!("Failed to clean up checkpoints after recovery: {}", e);
+                }
+                AudioRecoveryStatus {
+                    status: "success".to_string(),
+                    chunk_count,
+                    estimated_duration_seconds: chunk_count as f64 * 30.0,
+                    audio_file_path: Some(path.to_string_lossy().to_string()),
+                    message: format!("Successfully recovered {} audio chunks", chunk_count),
+                }
+            }
+            Err(e) => {
+                error!("Audio merge failed during journal recovery: {}", e);
+                AudioRecoveryStatus {
+                    status: "failed".to_string(),
+                    chunk_count,
+                    estimated_duration_seconds: chunk_count as f64 * 30.0,
+                    audio_file_path: None,
+                    message: format!("Failed to merge audio chunks: {}", e),
+                }
+            }
+        }
+    };
+
+    // 2. Transcripts
+    let segments: Vec<TranscriptSegment> = replay.segments.iter().map(|(_, s)| s.clone()).collect();
+    write_json_atomic(&folder, "transcripts.json", &serde_json::json!({
+        "version": "1.0",
+        "segments": segments,
+        "last_updated": chrono::Utc::now().to_rfc3339(),
+        "total_segments": segments.len(),
+        "recovered_from_journal": true
+    }))
+    .map_err(|e| format!("Failed to write transcripts.json: {}", e))?;
+
+    if !replay.bookmarks.is_empty() {
+        write_bookmarks_file(&folder, &replay.bookmarks)
+            .map_err(|e| format!("Failed to write bookmarks.json: {}", e))?;
+    }
+
+    // 3. Metadata
+    let meeting_name = replay
+        .meeting_name
+        .clone()
+        .unwrap_or_else(|| "Recovered meeting".to_string());
+    let duration = replay.estimated_duration_seconds();
+
+    if let Some(mut metadata) = replay.metadata.clone() {
+        metadata.status = "recovered".to_string();
+        metadata.completed_at = replay.last_event_at.clone();
+        metadata.duration_seconds = Some(duration);
+        if let Some(path) = audio.audio_file_path.as_ref() {
+            if let Some(file_name) = Path::new(path).file_name() {
+                metadata.audio_file = file_name.to_string_lossy().to_string();
+            }
+        }
+        let value = serde_json::to_value(&metadata).map_err(|e| e.to_string())?;
+        write_json_atomic(&folder, "metadata.json", &value)
+            .map_err(|e| format!("Failed to write metadata.json: {}", e))?;
+    }
+
+    // 4. Database rows
+    let db_segments: Vec<crate::api::TranscriptSegment> = replay
+        .segments
+        .iter()
+        .map(|(at, s)| crate::api::TranscriptSegment {
+            id: s.id.clone(),
+            text: s.text.clone(),
+            timestamp: at.clone(),
+            audio_start_time: Some(s.audio_start_time),
+            audio_end_time: Some(s.audio_end_time),
+            duration: Some(s.duration),
+            source_type: s.source_type.clone(),
+        })
+        .collect();
+
+    let meeting_id = TranscriptsRepository::save_transcript(
+        state.db_manager.pool(),
+        &meeting_name,
+        &db_segments,
+        Some(meeting_folder.clone()),
+    )
+    .await
+    .map_err(|e| format!("Failed to save recovered meeting: {}", e))?;
+
+    if let Err(e) = BookmarksRepository::save_bookmarks(state.db_manager.pool(), &meeting_id, &replay.bookmarks).await {
+        warn!("Failed to save recovered bookmarks: {}", e);
+    }
+
+    // 5. Mark the journal as recovered so the meeting is not offered again
+    match RecordingJournal::open(&folder) {
+        Ok(journal) => {
+            if let Err(e) = journal.append(JournalEvent::Recovered { meeting_id: Some(meeting_id.clone()) }) {
+                warn!("Failed to mark journal as recovered: {}", e);
+            }
+        }
+        Err(e) => warn!("Failed to reopen journal to mark recovery: {}", e),
+    }
+
+    info!(
+        "✅ Recovered meeting '{}' ({}) with {} segments from journal",
+        meeting_name,
+        meeting_id,
+        segments.len()
+    );
+
+    Ok(JournalRecoveryResult {
+        meeting_id,
+        meeting_name,
+        meeting_folder,
+        segment_count: segments.len(),
+        audio,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn segment(sequence_id: u64, text: &str, start: f64) -> TranscriptSegment {
+        TranscriptSegment {
+            id: format!("seg_{}", sequence_id),
+            text: text.to_string(),
+            audio_start_time: start,
+            audio_end_time: start + 2.0,
+            duration: 2.0,
+            display_time: "[00:00]".to_string(),
+            confidence: 0.9,
+            sequence_id,
+            source_type: Some("user".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_replay_survives_truncated_last_line() {
+        let dir = tempdir().unwrap();
+        let journal = RecordingJournal::open(dir.path()).unwrap();
+        journal.append(JournalEvent::SessionStarted {
+            meeting_name: Some("Weekly sync".to_string()),
+            sample_rate: 48000,
+            channels: 2,
+        }).unwrap();
+        journal.append(JournalEvent::TranscriptSegment { segment: segment(1, "hola", 0.0) }).unwrap();
+        journal.append(JournalEvent::TranscriptSegment { segment: segment(2, "adios", 4.0) }).unwrap();
+        // Updated text for an existing sequence id replaces the earlier one
+        journal.append(JournalEvent::TranscriptSegment { segment: segment(1, "hola a todos", 0.0) }).unwrap();
+        journal.append(JournalEvent::Paused { recording_time: Some(6.0) }).unwrap();
+        journal.append(JournalEvent::BookmarkAdded {
+            bookmark: Bookmark {
+                id: "bm_1".to_string(),
+                label: "important".to_string(),
+                note: None,
+                timestamp: 5.5,
+                created_at: "2026-01-01T00:00:05Z".to_string(),
+            },
+        }).unwrap();
+        drop(journal);
+
+        // Simulate a crash in the middle of a write
+        let path = dir.path().join(JOURNAL_FILE_NAME);
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"{\"at\":\"2026-01-01T00:00:00Z\",\"type\":\"transcript_seg").unwrap();
+
+        let records = read_journal(&path).unwrap();
+        assert_eq!(records.len(), 6);
+
+        let replay = JournalReplay::from_records(&records);
+        assert!(replay.needs_recovery());
+        assert_eq!(replay.meeting_name.as_deref(), Some("Weekly sync"));
+        assert_eq!(replay.segments.len(), 2);
+        assert_eq!(replay.segments[0].1.text, "hola a todos");
+        assert_eq!(replay.pause_count, 1);
+        assert_eq!(replay.bookmarks.len(), 1);
+        assert_eq!(replay.bookmarks[0].timestamp, 5.5);
+        assert_eq!(replay.estimated_duration_seconds(), 6.0);
+    }
+
+    #[test]
+    fn test_completed_journal_does_not_need_recovery() {
+        let dir = tempdir().unwrap();
+        let journal = RecordingJournal::open(dir.path()).unwrap();
+        journal.append(JournalEvent::TranscriptSegment { segment: segment(1, "hello", 0.0) }).unwrap();
+        journal.append(JournalEvent::Completed { duration_seconds: Some(12.5) }).unwrap();
+
+        let replay = JournalReplay::from_records(&read_journal(journal.path()).unwrap());
+        assert!(!replay.needs_recovery());
+        assert_eq!(replay.estimated_duration_seconds(), 12.5);
+    }
+}
@@ -194,6 +194,12 @@ pub async fn stop_recording<R: Runtime>(
         return Ok(());
     }
 
+    // No auto rule may act on a recording that is shutting down
+    super::auto_rules::stop_auto_rules();
+
+    // The last rolling summary stays in the meeting folder for the final summary
+    crate::summary::live_summary::stop_live_summary();
+
     // Emit shutdown progress to frontend
     let _ = app.emit(
         "recording-shutdown-progress",
@@ -13,7 +13,8 @@ use super::devices::{default_input_device, default_output_device};
 use super::recording_state::{RecordingState, AudioChunk, DeviceType as RecordingDeviceType};
 use super::pipeline::AudioPipelineManager;
 use super::stream::AudioStreamManager;
-use super::recording_saver::RecordingSaver;
+use super::recording_saver::{DeviceSwitch, RecordingSaver};
+use super::recording_journal::JournalEvent;
 use super::device_monitor::{AudioDeviceMonitor, DeviceEvent, DeviceMonitorType};
 
 /// Stream manager type enumeration
@@ -292,6 +293,14 @@ impl RecordingManager {
         let recording_duration = self.state.get_active_recording_duration();
         info!("Recording duration from state: {:?}s", recording_duration);
 
+        // Record VAD and audio-quality statistics with the meeting
+        if let Some(vad_stats) = self.pipeline_manager.vad_stats() {
+            self.recording_saver.set_vad_stats(vad_stats);
+        }
+        if let Some(quality_report) = self.pipeline_manager.quality_report() {
+            self.recording_saver.set_quality_report(quality_report);
+        }
+
         // Save the recording with actual duration
         match self.recording_saver.stop_and_save(app, recording_duration).await {
             Ok(Some(file_path)) => {
@@ -331,6 +340,13 @@ impl RecordingManager {
             error!("Error stopping audio pipeline: {}", e);
         }
 
+        if let Some(vad_stats) = self.pipeline_manager.vad_stats() {
+            self.recording_saver.set_vad_stats(vad_stats);
+        }
+        if let Some(quality_report) = self.pipeline_manager.quality_report() {
+            self.recording_saver.set_quality_report(quality_report);
+        }
+
         // Save the recording with actual duration
         match self.recording_saver.stop_and_save(app, recording_duration).await {
             Ok(Some(file_path)) => {
@@ -362,13 +378,21 @@ impl RecordingManager {
     /// Pause the current recording session
     pub fn pause_recording(&self) -> Result<()> {
         info!("Pausing recording");
-        self.state.pause_recording()
+        self.state.pause_recording()?;
+        self.recording_saver.record_journal_event(JournalEvent::Paused {
+            recording_time: self.state.get_active_recording_duration(),
+        });
+        Ok(())
     }
 
     /// Resume the current recording session
     pub fn resume_recording(&self) -> Result<()> {
         info!("Resuming recording");
-        self.state.resume_recording()
+        self.state.resume_recording()?;
+        self.recording_saver.record_journal_event(JournalEvent::Resumed {
+            recording_time: self.state.get_active_recording_duration(),
+        });
+        Ok(())
     }
 
     /// Check if recording is currently paused
@@ -401,6 +425,11 @@ impl RecordingManager {
         self.state.get_total_pause_duration()
     }
 
+    /// Get seconds since the last sound on either channel
+    pub fn get_silence_duration(&self) -> f64 {
+        self.state.get_silence_duration()
+    }
+
     /// Get current pause duration if paused
     pub fn get_current_pause_duration(&self) -> Option<f64> {
         self.state.get_current_pause_duration()
@@ -424,6 +453,14 @@ impl RecordingManager {
         self.state.set_error_callback(callback);
     }
 
+    /// Set callback for live audio-quality warnings
+    pub fn set_quality_warning_callback<F>(&self, callback: F)
+    where
+        F: Fn(&super::quality_monitor::AudioQualityWarning) + Send + Sync + 'static,
+    {
+        self.state.set_quality_warning_callback(callback);
+    }
+
     /// Check if there's a fatal error
     pub fn has_fatal_error(&self) -> bool {
         self.state.has_fatal_error()
@@ -444,6 +481,25 @@ impl RecordingManager {
         self.recording_saver.add_transcript_chunk(text);
     }
 
+    /// Bookmark the current moment of the recording
+    /// The timestamp uses the active recording clock, matching `TranscriptSegment.audio_start_time`
+    pub fn add_bookmark(&self, label: String, note: Option<String>) -> super::recording_saver::Bookmark {
+        let bookmark = super::recording_saver::Bookmark {
+            id: format!("bm_{}", uuid::Uuid::new_v4()),
+            label,
+            note,
+            timestamp: self.state.get_active_recording_duration().unwrap_or(0.0),
+            created_at: chrono::Utc::now().to_rfc3339(),
+        };
+        self.recording_saver.add_bookmark(bookmark.clone());
+        bookmark
+    }
+
+    /// Get bookmarks added during the current recording session
+    pub fn get_bookmarks(&self) -> Vec<super::recording_saver::Bookmark> {
+        self.recording_saver.get_bookmarks()
+    }
+
     /// Get accumulated transcript segments from current recording session
     /// Used for syncing frontend state after page reload during active recording
     pub fn get_transcript_segments(&self) -> Vec<super::recording_saver::TranscriptSegment> {
@@ -564,6 +620,12 @@ impl RecordingManager {
     pub async fn handle_device_disconnect(&mut self, device_name: String, device_type: DeviceMonitorType) {
         warn!("📱 Device disconnected: {} ({:?})", device_name, device_type);
 
+        self.recording_saver.record_journal_event(JournalEvent::DeviceChanged {
+            device_type: journal_device_type(&device_type).to_string(),
+            device_name: Some(device_name.clone()),
+            reason: "disconnected".to_string(),
+        });
+
         // Mark state as reconnecting (keeps recording alive but in waiting state)
         let device = match device_type {
             DeviceMonitorType::Microphone => self.state.get_microphone_device(),
@@ -585,12 +647,18 @@ impl RecordingManager {
     /// Handle a device reconnect event
     pub async fn handle_device_reconnect(&mut self, device_name: String, device_type: DeviceMonitorType) -> Result<()> {
         info!("📱 Device reconnected: {} ({:?})", device_name, device_type);
+        let journal_type = journal_device_type(&device_type);
 
         // Attempt to reconnect the device
         match self.attempt_device_reconnect(&device_name, device_type).await {
             Ok(true) => {
                 info!("✅ Successfully reconnected device: {}", device_name);
                 self.state.stop_reconnecting();
+                self.recording_saver.record_journal_event(JournalEvent::DeviceChanged {
+                    device_type: journal_type.to_string(),
+                    device_name: Some(device_name),
+                    reason: "reconnected".to_string(),
+                });
                 Ok(())
             }
             Ok(false) => {
@@ -604,6 +672,47 @@ impl RecordingManager {
         }
     }
 
+    /// Move a capture stream to the new OS default device ("follow system default")
+    /// Returns false when nothing had to be switched
+    pub async fn switch_to_default_device(&mut self, device_name: &str, device_type: DeviceMonitorType) -> Result<bool> {
+        if !self.state.is_recording() {
+            return Ok(false);
+        }
+
+        let (recording_device_type, current) = match device_type {
+            DeviceMonitorType::Microphone => (RecordingDeviceType::Microphone, self.state.get_microphone_device()),
+            DeviceMonitorType::SystemAudio => (RecordingDeviceType::System, self.state.get_system_device()),
+        };
+        let previous_name = current.map(|device| device.name.clone());
+        if previous_name.as_deref() == Some(device_name) {
+            return Ok(false);
+        }
+
+        // Look the device up with the matching direction (input for mic, output for loopback)
+        let wanted_type = match device_type {
+            DeviceMonitorType::Microphone => super::devices::DeviceType::Input,
+            DeviceMonitorType::SystemAudio => super::devices::DeviceType::Output,
+        };
+        let device = list_audio_devices().await?
+            .into_iter()
+            .find(|d| d.name == device_name && d.device_type == wanted_type)
+            .ok_or_else(|| anyhow::anyhow!("Default device '{}' is not available", device_name))?;
+
+        let at_seconds = self.state.get_active_recording_duration().unwrap_or(0.0);
+        self.stream_manager.replace_stream(recording_device_type, Arc::new(device)).await?;
+
+        info!("🔀 Now recording {:?} from the system default '{}' (was {:?})",
+              device_type, device_name, previous_name);
+        self.recording_saver.record_device_switch(DeviceSwitch {
+            device_type: journal_device_type(&device_type).to_string(),
+            from: previous_name,
+            to: device_name.to_string(),
+            at_seconds,
+            reason: "default_changed".to_string(),
+        });
+        Ok(true)
+    }
+
     /// Check if currently attempting to reconnect
     pub fn is_reconnecting(&self) -> bool {
         self.state.is_reconnecting()
@@ -615,6 +724,14 @@ impl RecordingManager {
     }
 }
 
+/// Device type label used in the recording journal
+fn journal_device_type(device_type: &DeviceMonitorType) -> &'static str {
+    match device_type {
+        DeviceMonitorType::Microphone => "microphone",
+        DeviceMonitorType::SystemAudio => "system_audio",
+    }
+}
+
 impl Default for RecordingManager {
     fn default() -> Self {
         Self::new()
@@ -10,6 +10,10 @@ use log::error;
 
 #[cfg(target_os = "macos")]
 use crate::audio::capture::AudioCaptureBackend;
+use crate::audio::vad::{set_current_vad_settings, VadSettings};
+use crate::audio::device_monitor::{set_current_follow_default_devices, FollowDefaultDevices};
+use crate::audio::auto_rules::{set_current_auto_recording_rules, AutoRecordingRules};
+use crate::summary::live_summary::{set_current_live_summary_settings, LiveSummarySettings};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct RecordingPreferences {
@@ -23,6 +27,18 @@ pub struct RecordingPreferences {
     #[cfg(target_os = "macos")]
     #[serde(default)]
     pub system_audio_backend: Option<String>,
+    /// VAD engine and per-channel sensitivity
+    #[serde(default)]
+    pub vad: VadSettings,
+    /// Switch to the OS default mic / output when it changes during a recording
+    #[serde(default)]
+    pub follow_default_devices: FollowDefaultDevices,
+    /// Auto-stop / auto-pause rules (silence, meeting app exit, max duration)
+    #[serde(default)]
+    pub auto_rules: AutoRecordingRules,
+    /// Rolling "so far" summary while recording
+    #[serde(default)]
+    pub live_summary: LiveSummarySettings,
 }
 
 impl Default for RecordingPreferences {
@@ -35,6 +51,10 @@ impl Default for RecordingPreferences {
             preferred_system_device: None,
             #[cfg(target_os = "macos")]
             system_audio_backend: Some("coreaudio".to_string()),
+            vad: VadSettings::default(),
+            follow_default_devices: FollowDefaultDevices::default(),
+            auto_rules: AutoRecordingRules::default(),
+            live_summary: LiveSummarySettings::default(),
         }
     }
 }
@@ -129,6 +149,12 @@ pub async fn load_recording_preferences<R: Runtime>(
         RecordingPreferences::default()
     };
 
+    // Apply VAD settings, device policy, auto rules and live summary so the next recording picks them up
+    set_current_vad_settings(prefs.vad.clone());
+    set_current_follow_default_devices(prefs.follow_default_devices);
+    set_current_auto_recording_rules(prefs.auto_rules);
+    set_current_live_summary_settings(prefs.live_summary);
+
     info!("Loaded recording preferences: save_folder={:?}, auto_save={}, format={}, mic={:?}, system={:?}",
           prefs.save_folder, prefs.auto_save, prefs.file_format,
           prefs.preferred_mic_device, prefs.preferred_system_device);
@@ -172,6 +198,11 @@ pub async fn save_recording_preferences<R: Runtime>(
         }
     }
 
+    set_current_vad_settings(preferences.vad.clone());
+    set_current_follow_default_devices(preferences.follow_default_devices);
+    set_current_auto_recording_rules(preferences.auto_rules);
+    set_current_live_summary_settings(preferences.live_summary);
+
     // Ensure the directory exists
     ensure_recordings_directory(&preferences.save_folder)?;
 
@@ -198,6 +229,118 @@ pub async fn set_recording_preferences<R: Runtime>(
         .map_err(|e| format!("Failed to save recording preferences: {}", e))
 }
 
+/// Get the VAD engine and per-channel sensitivity settings
+#[tauri::command]
+pub async fn get_vad_settings<R: Runtime>(app: AppHandle<R>) -> Result<VadSettings, String> {
+    load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.vad)
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))
+}
+
+/// Update the VAD settings; they take effect from the next recording
+#[tauri::command]
+pub async fn set_vad_settings<R: Runtime>(
+    app: AppHandle<R>,
+    settings: VadSettings,
+) -> Result<VadSettings, String> {
+    let mut preferences = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+
+    preferences.vad = VadSettings {
+        engine: settings.engine,
+        microphone: settings.microphone.validated(),
+        system: settings.system.validated(),
+    };
+
+    save_recording_preferences(&app, &preferences)
+        .await
+        .map_err(|e| format!("Failed to save recording preferences: {}", e))?;
+    Ok(preferences.vad)
+}
+
+/// Get the "follow system default" policy for mic and system audio
+#[tauri::command]
+pub async fn get_follow_default_devices<R: Runtime>(app: AppHandle<R>) -> Result<FollowDefaultDevices, String> {
+    load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.follow_default_devices)
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))
+}
+
+/// Update the "follow system default" policy; it applies from the next recording
+#[tauri::command]
+pub async fn set_follow_default_devices<R: Runtime>(
+    app: AppHandle<R>,
+    policy: FollowDefaultDevices,
+) -> Result<FollowDefaultDevices, String> {
+    let mut preferences = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+
+    preferences.follow_default_devices = policy;
+
+    save_recording_preferences(&app, &preferences)
+        .await
+        .map_err(|e| format!("Failed to save recording preferences: {}", e))?;
+    Ok(preferences.follow_default_devices)
+}
+
+/// Get the auto-stop / auto-pause rules
+#[tauri::command]
+pub async fn get_auto_recording_rules<R: Runtime>(app: AppHandle<R>) -> Result<AutoRecordingRules, String> {
+    load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.auto_rules)
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))
+}
+
+/// Update the auto-stop / auto-pause rules; they apply from the next recording
+#[tauri::command]
+pub async fn set_auto_recording_rules<R: Runtime>(
+    app: AppHandle<R>,
+    rules: AutoRecordingRules,
+) -> Result<AutoRecordingRules, String> {
+    let mut preferences = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+
+    preferences.auto_rules = rules.validated();
+
+    save_recording_preferences(&app, &preferences)
+        .await
+        .map_err(|e| format!("Failed to save recording preferences: {}", e))?;
+    Ok(preferences.auto_rules)
+}
+
+/// Get the live summary settings
+#[tauri::command]
+pub async fn get_live_summary_settings<R: Runtime>(app: AppHandle<R>) -> Result<LiveSummarySettings, String> {
+    load_recording_preferences(&app)
+        .await
+        .map(|prefs| prefs.live_summary)
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))
+}
+
+/// Update the live summary settings; they apply from the next recording
+#[tauri::command]
+pub async fn set_live_summary_settings<R: Runtime>(
+    app: AppHandle<R>,
+    settings: LiveSummarySettings,
+) -> Result<LiveSummarySettings, String> {
+    let mut preferences = load_recording_preferences(&app)
+        .await
+        .map_err(|e| format!("Failed to load recording preferences: {}", e))?;
+
+    preferences.live_summary = settings.validated();
+
+    save_recording_preferences(&app, &preferences)
+        .await
+        .map_err(|e| format!("Failed to save recording preferences: {}", e))?;
+    Ok(preferences.live_summary)
+}
+
 #[tauri::command]
 pub async fn get_default_recordings_folder_path() -> Result<String, String> {
     let path = get_default_recordings_folder();
@@ -10,6 +10,9 @@ use std::path::PathBuf;
 use super::recording_state::AudioChunk;
 use super::audio_processing::create_meeting_folder;
 use super::incremental_saver::IncrementalAudioSaver;
+use super::recording_journal::{JournalEvent, RecordingJournal};
+use super::vad::VadSessionStats;
+use super::quality_monitor::AudioQualityReport;
 
 /// Structured transcript segment for JSON export
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -39,12 +42,44 @@ pub struct MeetingMetadata {
     pub transcript_file: String,
     pub sample_rate: u32,
     pub status: String,  // "recording", "completed", "error"
+    /// VAD settings and per-channel statistics of the recording
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vad_stats: Option<VadSessionStats>,
+    /// Audio-quality summary and warnings of the recording
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quality_report: Option<AudioQualityReport>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceInfo {
     pub microphone: Option<String>,
     pub system_audio: Option<String>,
+    /// Devices switched to during the recording (e.g. following the OS default)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub switches: Vec<DeviceSwitch>,
+}
+
+/// One mid-recording change of capture device
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeviceSwitch {
+    pub device_type: String, // "microphone" | "system_audio"
+    pub from: Option<String>,
+    pub to: String,
+    /// Recording time of the switch in seconds
+    pub at_seconds: f64,
+    pub reason: String, // "default_changed"
+}
+
+/// Moment flagged by the user during a live recording ("important", "action item", ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub id: String,
+    pub label: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+    /// Seconds from recording start, same clock as `TranscriptSegment.audio_start_time`
+    pub timestamp: f64,
+    pub created_at: String, // RFC3339 wall-clock time
 }
 
 /// New recording saver using incremental saving strategy
@@ -54,8 +89,10 @@ pub struct RecordingSaver {
     meeting_name: Option<String>,
     metadata: Option<MeetingMetadata>,
     transcript_segments: Arc<Mutex<Vec<TranscriptSegment>>>,
+    bookmarks: Arc<Mutex<Vec<Bookmark>>>,
     chunk_receiver: Option<mpsc::UnboundedReceiver<AudioChunk>>,
     is_saving: Arc<Mutex<bool>>,
+    journal: Option<Arc<RecordingJournal>>,
 }
 
 impl RecordingSaver {
@@ -66,8 +103,19 @@ impl RecordingSaver {
             meeting_name: None,
             metadata: None,
             transcript_segments: Arc::new(Mutex::new(Vec::new())),
+            bookmarks: Arc::new(Mutex::new(Vec::new())),
             chunk_receiver: None,
             is_saving: Arc::new(Mutex::new(false)),
+            journal: None,
+        }
+    }
+
+    /// Append an event to the crash-recovery journal (no-op before the meeting folder exists)
+    pub fn record_journal_event(&self, event: JournalEvent) {
+        if let Some(journal) = &self.journal {
+            if let Err(e) = journal.append(event) {
+                warn!("Failed to append to recording journal: {}", e);
+            }
         }
     }
 
@@ -92,6 +140,58 @@ impl RecordingSaver {
         }
     }
 
+    /// Log a mid-recording device switch in the metadata `devices` section
+    pub fn record_device_switch(&mut self, switch: DeviceSwitch) {
+        self.record_journal_event(JournalEvent::DeviceChanged {
+            device_type: switch.device_type.clone(),
+            device_name: Some(switch.to.clone()),
+            reason: "switched".to_string(),
+        });
+
+        if let Some(ref mut metadata) = self.metadata {
+            match switch.device_type.as_str() {
+                "microphone" => metadata.devices.microphone = Some(switch.to.clone()),
+                _ => metadata.devices.system_audio = Some(switch.to.clone()),
+            }
+            metadata.devices.switches.push(switch);
+
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with device switch: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Record the VAD settings and statistics of this recording in metadata.json
+    pub fn set_vad_stats(&mut self, stats: VadSessionStats) {
+        if let Some(ref mut metadata) = self.metadata {
+            metadata.vad_stats = Some(stats);
+
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with VAD stats: {}", e);
+                }
+            }
+        }
+    }
+
+    /// Record the audio-quality report of this recording in metadata.json
+    pub fn set_quality_report(&mut self, report: AudioQualityReport) {
+        if let Some(ref mut metadata) = self.metadata {
+            metadata.quality_report = Some(report);
+
+            if let Some(folder) = &self.meeting_folder {
+                let metadata_clone = metadata.clone();
+                if let Err(e) = self.write_metadata(folder, &metadata_clone) {
+                    warn!("Failed to update metadata with quality report: {}", e);
+                }
+            }
+        }
+    }
+
     /// Add or update a structured transcript segment (upserts based on sequence_id)
     /// Also saves incrementally to disk
     pub fn add_transcript_segment(&self, segment: TranscriptSegment) {
@@ -111,6 +211,9 @@ impl RecordingSaver {
             error!("Failed to lock transcript segments for adding segment {}", segment.id);
         }
 
+        // Journal first: survives a crash even if transcripts.json is mid-rewrite
+        self.record_journal_event(JournalEvent::TranscriptSegment { segment: segment.clone() });
+
         // NEW: Save incrementally to disk
         if let Some(folder) = &self.meeting_folder {
             if let Err(e) = self.write_transcripts_json(folder) {
@@ -119,6 +222,25 @@ impl RecordingSaver {
         }
     }
 
+    /// Add a bookmark and persist it to the journal and bookmarks.json
+    pub fn add_bookmark(&self, bookmark: Bookmark) {
+        if let Ok(mut bookmarks) = self.bookmarks.lock() {
+            bookmarks.push(bookmark.clone());
+            info!("Added bookmark '{}' at {:.1}s - total bookmarks: {}",
+                  bookmark.label, bookmark.timestamp, bookmarks.len());
+        } else {
+            error!("Failed to lock bookmarks for adding bookmark {}", bookmark.id);
+        }
+
+        self.record_journal_event(JournalEvent::BookmarkAdded { bookmark });
+
+        if let Some(folder) = &self.meeting_folder {
+            if let Err(e) = self.write_bookmarks_json(folder) {
+                warn!("Failed to write bookmarks.json: {}", e);
+            }
+        }
+    }
+
     /// Legacy method for backward compatibility - converts text to basic segment
     pub fn add_transcript_chunk(&self, text: String) {
         let segment = TranscriptSegment {
@@ -236,14 +358,33 @@ impl RecordingSaver {
         // Create meeting folder structure (with or without .checkpoints/ subdirectory)
         let meeting_folder = create_meeting_folder(&base_folder, meeting_name, create_checkpoints)?;
 
+        // Open the crash-recovery journal before anything else is written
+        match RecordingJournal::open(&meeting_folder) {
+            Ok(journal) => {
+                let journal = Arc::new(journal);
+                if let Err(e) = journal.append(JournalEvent::SessionStarted {
+                    meeting_name: Some(meeting_name.to_string()),
+                    sample_rate: 48000,
+                    channels: 2,
+                }) {
+                    warn!("Failed to write journal session start: {}", e);
+                }
+                self.journal = Some(journal);
+            }
+            Err(e) => warn!("Failed to open recording journal (crash recovery limited to audio): {}", e),
+        }
+
         // Only initialize incremental saver if checkpoints are needed (auto_save is true)
-        if create_checkpoints {
+        let audio_file = if create_checkpoints {
             let incremental_saver = IncrementalAudioSaver::new(meeting_folder.clone(), 48000, 2)?;
+            let audio_file = incremental_saver.expected_audio_file_name();
             self.incremental_saver = Some(Arc::new(AsyncMutex::new(incremental_saver)));
             info!("✅ Incremental audio saver initialized for meeting: {}", meeting_name);
+            audio_file
         } else {
             info!("⚠️  Skipped incremental audio saver (auto-save disabled)");
-        }
+            String::new()
+        };
 
         // Create initial metadata
         let metadata = MeetingMetadata {
@@ -256,11 +397,14 @@ impl RecordingSaver {
             devices: DeviceInfo {
                 microphone: None,  // Could be enhanced to store actual device names
                 system_audio: None,
+                switches: Vec::new(),
             },
-            audio_file: if create_checkpoints { "audio.mp4".to_string() } else { "".to_string() },
+            audio_file,
             transcript_file: "transcripts.json".to_string(),
             sample_rate: 48000,
             status: "recording".to_string(),
+            vad_stats: None,
+            quality_report: None,
         };
 
         // Write initial metadata.json
@@ -274,6 +418,8 @@ impl RecordingSaver {
 
     /// Write metadata.json to disk (atomic write with temp file)
     fn write_metadata(&self, folder: &PathBuf, metadata: &MeetingMetadata) -> Result<()> {
+        self.record_journal_event(JournalEvent::MetadataUpdated { metadata: metadata.clone() });
+
         let metadata_path = folder.join("metadata.json");
         let temp_path = folder.join(".metadata.json.tmp");
 
@@ -339,6 +485,12 @@ impl RecordingSaver {
         Ok(())
     }
 
+    /// Write bookmarks.json to disk (atomic write with temp file)
+    fn write_bookmarks_json(&self, folder: &PathBuf) -> Result<()> {
+        let bookmarks_clone = self.get_bookmarks();
+        write_bookmarks_file(folder, &bookmarks_clone)
+    }
+
     // in frontend/src-tauri/src/audio/recording_saver.rs
     pub fn get_stats(&self) -> (usize, u32) {
         if let Some(ref saver) = self.incremental_saver {
@@ -378,10 +530,11 @@ impl RecordingSaver {
         if !should_save_audio {
             info!("⚠️  No audio saver initialized (auto-save was disabled) - skipping audio finalization");
             info!("✅ Transcripts and metadata already saved incrementally");
+            self.record_journal_event(JournalEvent::Completed { duration_seconds: recording_duration });
             return Ok(None);
         }
 
-        // Finalize incremental saver (merge checkpoints into final audio.mp4)
+        // Finalize incremental saver (merge checkpoints into final audio.mp4 / audio.flac)
         let final_audio_path = if let Some(saver_arc) = &self.incremental_saver {
             let mut saver = saver_arc.lock().await;
             match saver.finalize().await {
@@ -420,6 +573,11 @@ impl RecordingSaver {
             metadata.status = "completed".to_string();
             metadata.completed_at = Some(chrono::Utc::now().to_rfc3339());
 
+            // Record the actual file name (audio.flac when saved without FFmpeg)
+            if let Some(file_name) = final_audio_path.file_name() {
+                metadata.audio_file = file_name.to_string_lossy().to_string();
+            }
+
             // Use actual recording duration from RecordingState (more accurate than transcript segments)
             // Falls back to last transcript segment if duration not provided
             metadata.duration_seconds = recording_duration.or_else(|| {
@@ -448,6 +606,8 @@ impl RecordingSaver {
                 .map(|f| f.to_string_lossy().to_string())
         });
 
+        self.record_journal_event(JournalEvent::Completed { duration_seconds: recording_duration });
+
         if let Err(e) = app.emit("recording-saved", &save_event) {
             warn!("Failed to emit recording-saved event: {}", e);
         }
@@ -474,12 +634,59 @@ impl RecordingSaver {
         }
     }
 
+    /// Get bookmarks added so far, in creation order
+    pub fn get_bookmarks(&self) -> Vec<Bookmark> {
+        if let Ok(bookmarks) = self.bookmarks.lock() {
+            bookmarks.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
     /// Get meeting name (for reload sync)
     pub fn get_meeting_name(&self) -> Option<String> {
         self.meeting_name.clone()
     }
 }
 
+/// Write the bookmarks of a meeting folder to bookmarks.json (atomic write with temp file)
+pub fn write_bookmarks_file(folder: &std::path::Path, bookmarks: &[Bookmark]) -> Result<()> {
+    let bookmarks_path = folder.join("bookmarks.json");
+    let temp_path = folder.join(".bookmarks.json.tmp");
+
+    let json = serde_json::json!({
+        "version": "1.0",
+        "bookmarks": bookmarks,
+        "last_updated": chrono::Utc::now().to_rfc3339(),
+    });
+
+    std::fs::write(&temp_path, serde_json::to_string_pretty(&json)?)?;
+    std::fs::rename(&temp_path, &bookmarks_path)?; // Atomic
+
+    Ok(())
+}
+
+/// Read bookmarks.json from a meeting folder (empty when the meeting has none)
+pub fn read_bookmarks_file(folder: &std::path::Path) -> Vec<Bookmark> {
+    let path = folder.join("bookmarks.json");
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(_) => return Vec::new(),
+    };
+
+    match serde_json::from_str::<serde_json::Value>(&content)
+        .ok()
+        .and_then(|json| json.get("bookmarks").cloned())
+        .map(serde_json::from_value::<Vec<Bookmark>>)
+    {
+        Some(Ok(bookmarks)) => bookmarks,
+        _ => {
+            warn!("Failed to parse {}", path.display());
+            Vec::new()
+        }
+    }
+}
+
 impl Default for RecordingSaver {
     fn default() -> Self {
         Self::new()
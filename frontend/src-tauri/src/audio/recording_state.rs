@@ -6,6 +6,10 @@ use anyhow::Result;
 
 use super::devices::AudioDevice;
 use super::buffer_pool::AudioBufferPool;
+use super::quality_monitor::AudioQualityWarning;
+
+/// Chunk RMS above this (-45 dBFS) counts as sound for the silence-based auto rules
+const SOUND_RMS_LEVEL: f32 = 0.0056;
 
 /// Device type for audio chunks
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -117,6 +121,7 @@ pub struct RecordingState {
     last_error: Mutex<Option<AudioError>>,
     // FIX C2: Usar Arc en lugar de Box para poder clonar y liberar lock antes de llamar
     error_callback: Mutex<Option<Arc<dyn Fn(&AudioError) + Send + Sync>>>,
+    quality_warning_callback: Mutex<Option<Arc<dyn Fn(&AudioQualityWarning) + Send + Sync>>>,
 
     // Statistics
     stats: Mutex<RecordingStats>,
@@ -126,6 +131,11 @@ pub struct RecordingState {
     // Pause time tracking
     pause_start: Mutex<Option<Instant>>,
     total_pause_duration: Mutex<std::time::Duration>,
+    // Last chunk carrying sound on either channel (tracked while paused too)
+    last_sound: Mutex<Option<Instant>>,
+    // Simulated recording clock driven by virtual (file-backed) devices playing
+    // faster than real time; overrides the wall clock when set
+    virtual_clock: Mutex<Option<f64>>,
 }
 
 impl RecordingState {
@@ -143,10 +153,13 @@ impl RecordingState {
             recoverable_error_count: AtomicU32::new(0),
             last_error: Mutex::new(None),
             error_callback: Mutex::new(None),
+            quality_warning_callback: Mutex::new(None),
             stats: Mutex::new(RecordingStats::default()),
             recording_start: Mutex::new(None),
             pause_start: Mutex::new(None),
             total_pause_duration: Mutex::new(std::time::Duration::ZERO),
+            last_sound: Mutex::new(None),
+            virtual_clock: Mutex::new(None),
         })
     }
 
@@ -154,6 +167,8 @@ impl RecordingState {
     pub fn start_recording(&self) -> Result<()> {
         self.is_recording.store(true, Ordering::SeqCst);
         *self.recording_start.lock().unwrap() = Some(Instant::now());
+        *self.last_sound.lock().unwrap() = None;
+        *self.virtual_clock.lock().unwrap() = None;
         self.error_count.store(0, Ordering::SeqCst);
         self.recoverable_error_count.store(0, Ordering::SeqCst);
         *self.last_error.lock().unwrap() = None;
@@ -283,7 +298,23 @@ impl RecordingState {
         *self.audio_sender.lock().unwrap() = Some(sender);
     }
 
+    /// Send an in-band control marker to the pipeline (delivered even while paused)
+    pub fn send_pipeline_marker(&self, chunk: AudioChunk) -> Result<()> {
+        match self.audio_sender.lock().unwrap().as_ref() {
+            Some(sender) => sender.send(chunk).map_err(|_| anyhow::anyhow!("Failed to send pipeline marker")),
+            None => Err(anyhow::anyhow!("Audio pipeline not ready")),
+        }
+    }
+
     pub fn send_audio_chunk(&self, chunk: AudioChunk) -> Result<()> {
+        // Track sound before the pause check so an auto-pause can resume on it
+        if !chunk.data.is_empty() {
+            let rms = (chunk.data.iter().map(|&x| x * x).sum::<f32>() / chunk.data.len() as f32).sqrt();
+            if rms > SOUND_RMS_LEVEL {
+                *self.last_sound.lock().unwrap() = Some(Instant::now());
+            }
+        }
+
         // Don't send audio chunks when paused
         if self.is_paused() {
             return Ok(()); // Silently discard chunks while paused
@@ -347,6 +378,21 @@ impl RecordingState {
         }
     }
 
+    // Audio quality warnings (informational, never stop the recording)
+    pub fn set_quality_warning_callback<F>(&self, callback: F)
+    where
+        F: Fn(&AudioQualityWarning) + Send + Sync + 'static,
+    {
+        *self.quality_warning_callback.lock().unwrap() = Some(Arc::new(callback));
+    }
+
+    pub fn report_quality_warning(&self, warning: &AudioQualityWarning) {
+        let callback_clone = self.quality_warning_callback.lock().unwrap().clone();
+        if let Some(callback) = callback_clone {
+            callback(warning);
+        }
+    }
+
     pub fn get_error_count(&self) -> u32 {
         self.error_count.load(Ordering::SeqCst)
     }
@@ -373,13 +419,16 @@ impl RecordingState {
     }
 
     pub fn get_recording_duration(&self) -> Option<f64> {
-        self.recording_start
-            .lock()
-            .unwrap()
-            .map(|start| start.elapsed().as_secs_f64())
+        let start = (*self.recording_start.lock().unwrap())?;
+        Some(self.get_virtual_clock().unwrap_or_else(|| start.elapsed().as_secs_f64()))
     }
 
     pub fn get_active_recording_duration(&self) -> Option<f64> {
+        if let Some(position) = self.get_virtual_clock() {
+            // Virtual devices do not advance while paused
+            return self.recording_start.lock().unwrap().map(|_| position);
+        }
+
         self.recording_start.lock().unwrap().map(|start| {
             let total_duration = start.elapsed().as_secs_f64();
             let pause_duration = self.get_total_pause_duration();
@@ -396,10 +445,30 @@ impl RecordingState {
         })
     }
 
+    /// Advance the simulated recording clock to `position` seconds (never moves backwards).
+    /// Used by virtual devices so timestamps match the file position, not wall time.
+    pub fn advance_virtual_clock(&self, position: f64) {
+        let mut clock = self.virtual_clock.lock().unwrap();
+        *clock = Some(clock.map_or(position, |current| current.max(position)));
+    }
+
+    pub fn get_virtual_clock(&self) -> Option<f64> {
+        *self.virtual_clock.lock().unwrap()
+    }
+
     pub fn get_total_pause_duration(&self) -> f64 {
         self.total_pause_duration.lock().unwrap().as_secs_f64()
     }
 
+    /// Seconds since the last sound on either channel (since start if none yet)
+    pub fn get_silence_duration(&self) -> f64 {
+        let last_sound = *self.last_sound.lock().unwrap();
+        last_sound
+            .or(*self.recording_start.lock().unwrap())
+            .map(|t| t.elapsed().as_secs_f64())
+            .unwrap_or(0.0)
+    }
+
     pub fn get_current_pause_duration(&self) -> Option<f64> {
         if self.is_paused() {
             self.pause_start
@@ -426,10 +495,13 @@ impl RecordingState {
         *self.audio_sender.lock().unwrap() = None;
         *self.last_error.lock().unwrap() = None;
         *self.error_callback.lock().unwrap() = None;
+        *self.quality_warning_callback.lock().unwrap() = None;
         *self.stats.lock().unwrap() = RecordingStats::default();
         *self.recording_start.lock().unwrap() = None;
         *self.pause_start.lock().unwrap() = None;
         *self.total_pause_duration.lock().unwrap() = std::time::Duration::ZERO;
+        *self.last_sound.lock().unwrap() = None;
+        *self.virtual_clock.lock().unwrap() = None;
         self.error_count.store(0, Ordering::SeqCst);
         self.recoverable_error_count.store(0, Ordering::SeqCst);
 
@@ -453,10 +525,13 @@ impl Default for RecordingState {
             recoverable_error_count: AtomicU32::new(0),
             last_error: Mutex::new(None),
             error_callback: Mutex::new(None),
+            quality_warning_callback: Mutex::new(None),
             stats: Mutex::new(RecordingStats::default()),
             recording_start: Mutex::new(None),
             pause_start: Mutex::new(None),
             total_pause_duration: Mutex::new(std::time::Duration::ZERO),
+            last_sound: Mutex::new(None),
+            virtual_clock: Mutex::new(None),
         }
     }
 }
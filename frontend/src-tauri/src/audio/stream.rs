@@ -6,9 +6,9 @@ use log::{error, info, warn};
 use tokio::sync::mpsc;
 
 use super::devices::{AudioDevice, get_device_and_config};
-use super::pipeline::AudioCapture;
-use super::recording_state::{RecordingState, DeviceType};
-use super::capture::{AudioCaptureBackend, get_current_backend};
+use super::pipeline::{AudioCapture, STREAM_SWITCH_CHUNK_ID};
+use super::recording_state::{AudioChunk, RecordingState, DeviceType};
+use super::capture::{AudioCaptureBackend, get_current_backend, VirtualFileSource, VirtualFileStream};
 
 #[cfg(target_os = "macos")]
 use super::capture::CoreAudioCapture;
@@ -22,6 +22,8 @@ pub enum StreamBackend {
     CoreAudio {
         task: Option<tokio::task::JoinHandle<()>>,
     },
+    /// File-backed virtual device (tests and simulations)
+    VirtualFile(VirtualFileStream),
 }
 
 // SAFETY: While Stream doesn't implement Send, we ensure it's only accessed
@@ -61,6 +63,11 @@ impl AudioStream {
         info!("🎵 Stream: Creating audio stream for device: {} with backend: {:?}, device_type: {:?}",
               device.name, backend_type, device_type);
 
+        // Virtual devices bypass every hardware backend
+        if let Some(source) = VirtualFileSource::from_device(&device) {
+            return Self::create_virtual_file_stream(device, source, state, device_type, recording_sender);
+        }
+
         // For system audio devices, use the selected backend
         // For microphone devices, always use CPAL
         #[cfg(target_os = "macos")]
@@ -140,6 +147,34 @@ impl AudioStream {
         })
     }
 
+    /// Create a stream that plays an audio file through the normal capture path
+    fn create_virtual_file_stream(
+        device: Arc<AudioDevice>,
+        source: VirtualFileSource,
+        state: Arc<RecordingState>,
+        device_type: DeviceType,
+        recording_sender: Option<mpsc::UnboundedSender<super::recording_state::AudioChunk>>,
+    ) -> Result<Self> {
+        let audio = source.load()?;
+
+        let capture = AudioCapture::new(
+            device.clone(),
+            state.clone(),
+            audio.sample_rate,
+            audio.channels,
+            device_type,
+            recording_sender,
+        );
+
+        let stream = VirtualFileStream::start(source, audio, capture, state)?;
+        info!("📼 Virtual stream started for device: {}", device.name);
+
+        Ok(Self {
+            device,
+            backend: StreamBackend::VirtualFile(stream),
+        })
+    }
+
     /// Create a Core Audio stream (macOS only)
     #[cfg(target_os = "macos")]
     async fn create_core_audio_stream(
@@ -316,6 +351,14 @@ impl AudioStream {
         &self.device
     }
 
+    /// Whether the stream has run out of audio (only virtual devices ever finish)
+    pub fn is_finished(&self) -> bool {
+        match &self.backend {
+            StreamBackend::VirtualFile(stream) => stream.is_finished(),
+            _ => false,
+        }
+    }
+
     /// Stop the stream
     pub fn stop(self) -> Result<()> {
         info!("Stopping audio stream for device: {}", self.device.name);
@@ -343,6 +386,9 @@ impl AudioStream {
                     info!("Core Audio task aborted");
                 }
             }
+            StreamBackend::VirtualFile(stream) => {
+                stream.stop();
+            }
         }
 
         // Explicitly drop self.device Arc reference
@@ -426,6 +472,67 @@ impl AudioStreamManager {
         Ok(())
     }
 
+    /// Move one capture stream to another device mid-recording
+    ///
+    /// The old stream is stopped first and a switch marker is queued for the pipeline,
+    /// so chunks of the two devices never interleave and the pipeline can bridge the
+    /// switch-over gap on the recording timeline. If the new device fails to open,
+    /// the previous device is restored.
+    pub async fn replace_stream(&mut self, device_type: DeviceType, device: Arc<AudioDevice>) -> Result<()> {
+        let slot = match device_type {
+            DeviceType::Microphone => &mut self.microphone_stream,
+            DeviceType::System => &mut self.system_stream,
+            DeviceType::Mixed => return Err(anyhow::anyhow!("The mixed stream has no capture device")),
+        };
+
+        let previous = slot.take();
+        let previous_device = previous.as_ref().map(|stream| stream.device.clone());
+        if let Some(stream) = previous {
+            if let Err(e) = stream.stop() {
+                warn!("Failed to stop {:?} stream before switching device: {}", device_type, e);
+            }
+        }
+
+        let marker = AudioChunk {
+            data: vec![],
+            sample_rate: 48000,
+            timestamp: self.state.get_recording_duration().unwrap_or(0.0),
+            chunk_id: STREAM_SWITCH_CHUNK_ID,
+            device_type,
+        };
+        if let Err(e) = self.state.send_pipeline_marker(marker) {
+            warn!("Failed to send stream switch marker: {}", e);
+        }
+
+        info!("🔀 Switching {:?} stream to: {}", device_type, device.name);
+        match AudioStream::create(device.clone(), self.state.clone(), device_type, None).await {
+            Ok(stream) => {
+                *slot = Some(stream);
+                match device_type {
+                    DeviceType::System => self.state.set_system_device(device),
+                    _ => self.state.set_microphone_device(device),
+                }
+                info!("✅ {:?} stream switched successfully", device_type);
+                Ok(())
+            }
+            Err(e) => {
+                error!("❌ Failed to open {} for {:?} stream: {}", device.name, device_type, e);
+                if let Some(previous_device) = previous_device {
+                    match AudioStream::create(previous_device.clone(), self.state.clone(), device_type, None).await {
+                        Ok(stream) => {
+                            *slot = Some(stream);
+                            info!("↩️ Restored previous {:?} device: {}", device_type, previous_device.name);
+                        }
+                        Err(restore_error) => {
+                            error!("❌ Failed to restore previous {:?} device: {}", device_type, restore_error);
+                        }
+                    }
+                }
+                Err(e)
+            }
+        }
+    }
+
     /// Stop all audio streams
     pub fn stop_streams(&mut self) -> Result<()> {
         info!("Stopping all audio streams");
@@ -472,6 +579,15 @@ impl AudioStreamManager {
     pub fn has_active_streams(&self) -> bool {
         self.microphone_stream.is_some() || self.system_stream.is_some()
     }
+
+    /// Whether every active stream has run out of audio (virtual devices only)
+    pub fn all_streams_finished(&self) -> bool {
+        self.has_active_streams()
+            && [&self.microphone_stream, &self.system_stream]
+                .into_iter()
+                .flatten()
+                .all(|stream| stream.is_finished())
+    }
 }
 
 impl Drop for AudioStreamManager {
@@ -31,6 +31,7 @@ pub use engine::{
 };
 pub use worker::{
     start_transcription_task,
+    start_transcription_task_with_engine,
     reset_speech_detected_flag,
     TranscriptUpdate
 };
@@ -71,442 +71,466 @@ pub fn start_transcription_task<R: Runtime>(
             }
         };
 
-        // Create parallel workers for faster processing while preserving ALL chunks
-        const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
-
-        // FIX: Bounded channel con backpressure - evita memory leak en conversaciones muy largas
-        // 2000 chunks = ~2 minutos de audio en cola máximo (a 60ms por chunk)
-        // Si se llena, el sender esperará (backpressure) en vez de perder datos
-        let (work_sender, work_receiver) = tokio::sync::mpsc::channel::<AudioChunk>(2000);
-        let work_receiver = Arc::new(tokio::sync::Mutex::new(work_receiver));
-
-        // Track completion: AtomicU64 for chunks queued, completed, and dropped
-        let chunks_queued = Arc::new(AtomicU64::new(0));
-        let chunks_completed = Arc::new(AtomicU64::new(0));
-        let chunks_dropped = Arc::new(AtomicU64::new(0)); // FIX: Track dropped chunks for debugging
-        let input_finished = Arc::new(AtomicBool::new(false));
-
-        info!("📊 Starting {} transcription worker{} (serial mode for ordered emission)", NUM_WORKERS, if NUM_WORKERS == 1 { "" } else { "s" });
-
-        // Check if this is a streaming provider (Deepgram persistent WS)
-        let is_streaming = transcription_engine.is_streaming_provider();
-        if is_streaming {
-            info!("Streaming provider detected - reader task handles event emission");
-        }
+        run_transcription_workers(app, transcription_receiver, transcription_engine).await;
+    })
+}
 
-        // Spawn worker tasks
-        let mut worker_handles = Vec::new();
-        for worker_id in 0..NUM_WORKERS {
-            let engine_clone = match &transcription_engine {
-                TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
-                TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
-                TranscriptionEngine::Moonshine(e) => TranscriptionEngine::Moonshine(e.clone()),
-                TranscriptionEngine::Deepgram { mic, sys } => TranscriptionEngine::Deepgram { mic: mic.clone(), sys: sys.clone() },
-                TranscriptionEngine::Provider(p) => TranscriptionEngine::Provider(p.clone()),
-            };
-            let app_clone = app.clone();
-            let work_receiver_clone = work_receiver.clone();
-            let chunks_completed_clone = chunks_completed.clone();
-            let input_finished_clone = input_finished.clone();
-            let chunks_queued_clone = chunks_queued.clone();
-            let is_streaming_worker = is_streaming;
-
-            let worker_handle = tokio::spawn(async move {
-                info!("👷 Worker {} started", worker_id);
-
-                // PRE-VALIDATE model state to avoid repeated async calls per chunk
-                let initial_model_loaded = engine_clone.is_model_loaded().await;
-                let current_model = engine_clone
-                    .get_current_model()
-                    .await
-                    .unwrap_or_else(|| "unknown".to_string());
-
-                let engine_name = engine_clone.provider_name();
-
-                if initial_model_loaded {
-                    info!(
-                        "✅ Worker {} pre-validation: {} model '{}' is loaded and ready",
-                        worker_id, engine_name, current_model
-                    );
-                } else {
-                    warn!("⚠️ Worker {} pre-validation: {} model not loaded - chunks may be skipped", worker_id, engine_name);
-                }
+/// Run the transcription worker pool with an already initialized engine.
+///
+/// Split from `start_transcription_task` so tests and simulations can inject their
+/// own `TranscriptionProvider` instead of loading a model.
+pub fn start_transcription_task_with_engine<R: Runtime>(
+    app: AppHandle<R>,
+    transcription_receiver: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+    transcription_engine: TranscriptionEngine,
+) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        info!("🚀 Starting transcription task with provided engine: {}", transcription_engine.provider_name());
+        run_transcription_workers(app, transcription_receiver, transcription_engine).await;
+    })
+}
 
-                loop {
-                    // Try to get a chunk to process
-                    let chunk = {
-                        let mut receiver = work_receiver_clone.lock().await;
-                        receiver.recv().await
-                    };
+/// Dispatch chunks to the workers and wait until every queued chunk is processed
+async fn run_transcription_workers<R: Runtime>(
+    app: AppHandle<R>,
+    transcription_receiver: tokio::sync::mpsc::UnboundedReceiver<AudioChunk>,
+    transcription_engine: TranscriptionEngine,
+) {
+    // Create parallel workers for faster processing while preserving ALL chunks
+    const NUM_WORKERS: usize = 1; // Serial processing ensures transcripts emit in chronological order
+
+    // FIX: Bounded channel con backpressure - evita memory leak en conversaciones muy largas
+    // 2000 chunks = ~2 minutos de audio en cola máximo (a 60ms por chunk)
+    // Si se llena, el sender esperará (backpressure) en vez de perder datos
+    let (work_sender, work_receiver) = tokio::sync::mpsc::channel::<AudioChunk>(2000);
+    let work_receiver = Arc::new(tokio::sync::Mutex::new(work_receiver));
+
+    // Track completion: AtomicU64 for chunks queued, completed, and dropped
+    let chunks_queued = Arc::new(AtomicU64::new(0));
+    let chunks_completed = Arc::new(AtomicU64::new(0));
+    let chunks_dropped = Arc::new(AtomicU64::new(0)); // FIX: Track dropped chunks for debugging
+    let input_finished = Arc::new(AtomicBool::new(false));
+
+    info!("📊 Starting {} transcription worker{} (serial mode for ordered emission)", NUM_WORKERS, if NUM_WORKERS == 1 { "" } else { "s" });
+
+    // Check if this is a streaming provider (Deepgram persistent WS)
+    let is_streaming = transcription_engine.is_streaming_provider();
+    if is_streaming {
+        info!("Streaming provider detected - reader task handles event emission");
+    }
 
-                    match chunk {
-                        Some(chunk) => {
-                            // PERFORMANCE OPTIMIZATION: Reduce logging in hot path
-                            // Only log every 10th chunk per worker to reduce I/O overhead
-                            let should_log_this_chunk = chunk.chunk_id % 10 == 0;
+    // Spawn worker tasks
+    let mut worker_handles = Vec::new();
+    for worker_id in 0..NUM_WORKERS {
+        let engine_clone = match &transcription_engine {
+            TranscriptionEngine::Whisper(e) => TranscriptionEngine::Whisper(e.clone()),
+            TranscriptionEngine::Parakeet(e) => TranscriptionEngine::Parakeet(e.clone()),
+            TranscriptionEngine::Moonshine(e) => TranscriptionEngine::Moonshine(e.clone()),
+            TranscriptionEngine::Deepgram { mic, sys } => TranscriptionEngine::Deepgram { mic: mic.clone(), sys: sys.clone() },
+            TranscriptionEngine::Provider(p) => TranscriptionEngine::Provider(p.clone()),
+        };
+        let app_clone = app.clone();
+        let work_receiver_clone = work_receiver.clone();
+        let chunks_completed_clone = chunks_completed.clone();
+        let input_finished_clone = input_finished.clone();
+        let chunks_queued_clone = chunks_queued.clone();
+        let is_streaming_worker = is_streaming;
+
+        let worker_handle = tokio::spawn(async move {
+            info!("👷 Worker {} started", worker_id);
+
+            // PRE-VALIDATE model state to avoid repeated async calls per chunk
+            let initial_model_loaded = engine_clone.is_model_loaded().await;
+            let current_model = engine_clone
+                .get_current_model()
+                .await
+                .unwrap_or_else(|| "unknown".to_string());
 
-                            if should_log_this_chunk {
-                                info!(
-                                    "👷 Worker {} processing chunk {} with {} samples",
-                                    worker_id,
-                                    chunk.chunk_id,
-                                    chunk.data.len()
-                                );
-                            }
+            let engine_name = engine_clone.provider_name();
 
-                            // Check if model is still loaded before processing
-                            if !engine_clone.is_model_loaded().await {
-                                warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
-                                // Still count as completed even if we can't process
-                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                continue;
-                            }
+            if initial_model_loaded {
+                info!(
+                    "✅ Worker {} pre-validation: {} model '{}' is loaded and ready",
+                    worker_id, engine_name, current_model
+                );
+            } else {
+                warn!("⚠️ Worker {} pre-validation: {} model not loaded - chunks may be skipped", worker_id, engine_name);
+            }
 
-                            let chunk_timestamp = chunk.timestamp;
-                            let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
-                            // Capture device_type before chunk is moved (for speaker identification and routing)
-                            let chunk_device_type = chunk.device_type.clone();
-                            let chunk_source_type = match chunk_device_type {
-                                crate::audio::recording_state::DeviceType::Microphone => Some("user".to_string()),
-                                crate::audio::recording_state::DeviceType::System => Some("interlocutor".to_string()),
-                                crate::audio::recording_state::DeviceType::Mixed => None, // Mixed audio should not be transcribed
-                            };
-
-                            // For streaming providers (Deepgram), queue chunk metadata
-                            // so the reader task can associate transcripts with correct speaker/timestamps
-                            if is_streaming_worker {
-                                let audio_start_time = chunk_timestamp;
-                                let audio_end_time = chunk_timestamp + chunk_duration;
-                                engine_clone.queue_chunk_info(
-                                    &chunk_device_type,
-                                    audio_start_time,
-                                    audio_end_time,
-                                    chunk_duration,
-                                ).await;
-                            }
+            loop {
+                // Try to get a chunk to process
+                let chunk = {
+                    let mut receiver = work_receiver_clone.lock().await;
+                    receiver.recv().await
+                };
+
+                match chunk {
+                    Some(chunk) => {
+                        // PERFORMANCE OPTIMIZATION: Reduce logging in hot path
+                        // Only log every 10th chunk per worker to reduce I/O overhead
+                        let should_log_this_chunk = chunk.chunk_id % 10 == 0;
+
+                        if should_log_this_chunk {
+                            info!(
+                                "👷 Worker {} processing chunk {} with {} samples",
+                                worker_id,
+                                chunk.chunk_id,
+                                chunk.data.len()
+                            );
+                        }
+
+                        // Check if model is still loaded before processing
+                        if !engine_clone.is_model_loaded().await {
+                            warn!("⚠️ Worker {}: Model unloaded, but continuing to preserve chunk {}", worker_id, chunk.chunk_id);
+                            // Still count as completed even if we can't process
+                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                            continue;
+                        }
 
-                            // Transcribe with provider-agnostic approach
-                            match transcribe_chunk_with_provider(
-                                &engine_clone,
-                                chunk,
+                        let chunk_timestamp = chunk.timestamp;
+                        let chunk_duration = chunk.data.len() as f64 / chunk.sample_rate as f64;
+                        // Capture device_type before chunk is moved (for speaker identification and routing)
+                        let chunk_device_type = chunk.device_type.clone();
+                        let chunk_source_type = match chunk_device_type {
+                            crate::audio::recording_state::DeviceType::Microphone => Some("user".to_string()),
+                            crate::audio::recording_state::DeviceType::System => Some("interlocutor".to_string()),
+                            crate::audio::recording_state::DeviceType::Mixed => None, // Mixed audio should not be transcribed
+                        };
+
+                        // For streaming providers (Deepgram), queue chunk metadata
+                        // so the reader task can associate transcripts with correct speaker/timestamps
+                        if is_streaming_worker {
+                            let audio_start_time = chunk_timestamp;
+                            let audio_end_time = chunk_timestamp + chunk_duration;
+                            engine_clone.queue_chunk_info(
                                 &chunk_device_type,
-                                &app_clone,
-                            )
-                            .await
-                            {
-                                Ok((transcript, confidence_opt, is_partial)) => {
-                                    // Provider-aware confidence threshold
-                                    let confidence_threshold = match &engine_clone {
-                                        TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
-                                        TranscriptionEngine::Deepgram { .. } => 0.3,
-                                        TranscriptionEngine::Parakeet(_) | TranscriptionEngine::Moonshine(_) => 0.0, // Parakeet/Moonshine have no confidence, accept all
-                                    };
+                                audio_start_time,
+                                audio_end_time,
+                                chunk_duration,
+                            ).await;
+                        }
+
+                        // Transcribe with provider-agnostic approach
+                        match transcribe_chunk_with_provider(
+                            &engine_clone,
+                            chunk,
+                            &chunk_device_type,
+                            &app_clone,
+                        )
+                        .await
+                        {
+                            Ok((transcript, confidence_opt, is_partial)) => {
+                                // Provider-aware confidence threshold
+                                let confidence_threshold = match &engine_clone {
+                                    TranscriptionEngine::Whisper(_) | TranscriptionEngine::Provider(_) => 0.3,
+                                    TranscriptionEngine::Deepgram { .. } => 0.3,
+                                    TranscriptionEngine::Parakeet(_) | TranscriptionEngine::Moonshine(_) => 0.0, // Parakeet/Moonshine have no confidence, accept all
+                                };
+
+                                let confidence_str = match confidence_opt {
+                                    Some(c) => format!("{:.2}", c),
+                                    None => "N/A".to_string(),
+                                };
+
+                                info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
+                                      worker_id, transcript, confidence_str, is_partial, confidence_threshold);
+
+                                // Check confidence threshold (or accept if no confidence provided)
+                                let meets_threshold = confidence_opt.map_or(true, |c| c >= confidence_threshold);
+
+                                if !transcript.trim().is_empty() && meets_threshold {
+                                    // PERFORMANCE: Only log transcription results, not every processing step
+                                    info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
+                                          worker_id, transcript, confidence_str, is_partial);
+
+                                    // Emit speech-detected event for frontend UX (only on first detection per session)
+                                    // This is lightweight and provides better user feedback
+                                    let current_flag = SPEECH_DETECTED_EMITTED.load(Ordering::SeqCst);
+                                    info!("🔍 Checking speech-detected flag: current={}, will_emit={}", current_flag, !current_flag);
+
+                                    if !current_flag {
+                                        SPEECH_DETECTED_EMITTED.store(true, Ordering::SeqCst);
+                                        match app_clone.emit("speech-detected", serde_json::json!({
+                                            "message": "Speech activity detected"
+                                        })) {
+                                            Ok(_) => info!("🎤 ✅ First speech detected - successfully emitted speech-detected event"),
+                                            Err(e) => error!("🎤 ❌ Failed to emit speech-detected event: {}", e),
+                                        }
+                                    } else {
+                                        info!("🔍 Speech already detected in this session, not re-emitting");
+                                    }
 
-                                    let confidence_str = match confidence_opt {
-                                        Some(c) => format!("{:.2}", c),
-                                        None => "N/A".to_string(),
+                                    // Generate sequence ID and calculate timestamps FIRST
+                                    let sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
+                                    let audio_start_time = chunk_timestamp; // Already in seconds from recording start
+                                    let audio_end_time = chunk_timestamp + chunk_duration;
+
+                                    // Save structured transcript segment to recording manager (only final results)
+                                    // Save ALL segments (partial and final) to ensure complete JSON
+                                    // Create structured segment with full timestamp data
+                                    // NOTE: This is now handled via the transcript-update event emission below
+                                    // The recording_commands module listens to these events and saves them
+                                    // This decouples the transcription worker from direct RECORDING_MANAGER access
+
+                                    // Emit transcript update with NEW recording-relative timestamps
+
+                                    let update = TranscriptUpdate {
+                                        text: transcript,
+                                        timestamp: format_current_timestamp(), // Wall-clock for reference
+                                        source: "Audio".to_string(),
+                                        sequence_id,
+                                        chunk_start_time: chunk_timestamp, // Legacy compatibility
+                                        is_partial,
+                                        confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
+                                        // NEW: Recording-relative timestamps for sync
+                                        audio_start_time,
+                                        audio_end_time,
+                                        duration: chunk_duration,
+                                        // NEW: Speaker identification (user=mic, interlocutor=system)
+                                        source_type: chunk_source_type.clone(),
                                     };
 
-                                    info!("🔍 Worker {} transcription result: text='{}', confidence={}, partial={}, threshold={:.2}",
-                                          worker_id, transcript, confidence_str, is_partial, confidence_threshold);
-
-                                    // Check confidence threshold (or accept if no confidence provided)
-                                    let meets_threshold = confidence_opt.map_or(true, |c| c >= confidence_threshold);
-
-                                    if !transcript.trim().is_empty() && meets_threshold {
-                                        // PERFORMANCE: Only log transcription results, not every processing step
-                                        info!("✅ Worker {} transcribed: {} (confidence: {}, partial: {})",
-                                              worker_id, transcript, confidence_str, is_partial);
-
-                                        // Emit speech-detected event for frontend UX (only on first detection per session)
-                                        // This is lightweight and provides better user feedback
-                                        let current_flag = SPEECH_DETECTED_EMITTED.load(Ordering::SeqCst);
-                                        info!("🔍 Checking speech-detected flag: current={}, will_emit={}", current_flag, !current_flag);
-
-                                        if !current_flag {
-                                            SPEECH_DETECTED_EMITTED.store(true, Ordering::SeqCst);
-                                            match app_clone.emit("speech-detected", serde_json::json!({
-                                                "message": "Speech activity detected"
-                                            })) {
-                                                Ok(_) => info!("🎤 ✅ First speech detected - successfully emitted speech-detected event"),
-                                                Err(e) => error!("🎤 ❌ Failed to emit speech-detected event: {}", e),
-                                            }
-                                        } else {
-                                            info!("🔍 Speech already detected in this session, not re-emitting");
-                                        }
+                                    println!("📤 [WORKER] Emitiendo transcript-update: '{}' (seq: {}, partial: {}, confidence: {:.2})",
+                                             update.text, update.sequence_id, update.is_partial, update.confidence);
 
-                                        // Generate sequence ID and calculate timestamps FIRST
-                                        let sequence_id = SEQUENCE_COUNTER.fetch_add(1, Ordering::SeqCst);
-                                        let audio_start_time = chunk_timestamp; // Already in seconds from recording start
-                                        let audio_end_time = chunk_timestamp + chunk_duration;
-
-                                        // Save structured transcript segment to recording manager (only final results)
-                                        // Save ALL segments (partial and final) to ensure complete JSON
-                                        // Create structured segment with full timestamp data
-                                        // NOTE: This is now handled via the transcript-update event emission below
-                                        // The recording_commands module listens to these events and saves them
-                                        // This decouples the transcription worker from direct RECORDING_MANAGER access
-
-                                        // Emit transcript update with NEW recording-relative timestamps
-
-                                        let update = TranscriptUpdate {
-                                            text: transcript,
-                                            timestamp: format_current_timestamp(), // Wall-clock for reference
-                                            source: "Audio".to_string(),
-                                            sequence_id,
-                                            chunk_start_time: chunk_timestamp, // Legacy compatibility
-                                            is_partial,
-                                            confidence: confidence_opt.unwrap_or(0.85), // Default for providers without confidence
-                                            // NEW: Recording-relative timestamps for sync
-                                            audio_start_time,
-                                            audio_end_time,
-                                            duration: chunk_duration,
-                                            // NEW: Speaker identification (user=mic, interlocutor=system)
-                                            source_type: chunk_source_type.clone(),
-                                        };
-
-                                        println!("📤 [WORKER] Emitiendo transcript-update: '{}' (seq: {}, partial: {}, confidence: {:.2})",
-                                                 update.text, update.sequence_id, update.is_partial, update.confidence);
-
-                                        match app_clone.emit("transcript-update", &update) {
-                                            Ok(_) => {
-                                                println!("✅ [WORKER] Evento transcript-update emitido correctamente");
-                                            }
-                                            Err(e) => {
-                                                println!("❌ [WORKER] Error emitiendo transcript-update: {}", e);
-                                                error!(
-                                                    "Worker {}: Failed to emit transcript update: {}",
-                                                    worker_id, e
-                                                );
-                                            }
+                                    match app_clone.emit("transcript-update", &update) {
+                                        Ok(_) => {
+                                            println!("✅ [WORKER] Evento transcript-update emitido correctamente");
                                         }
-                                        // PERFORMANCE: Removed verbose logging of every emission
-                                    } else if !transcript.trim().is_empty() && should_log_this_chunk
-                                    {
-                                        // PERFORMANCE: Only log low-confidence results occasionally
-                                        if let Some(c) = confidence_opt {
-                                            info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
+                                        Err(e) => {
+                                            println!("❌ [WORKER] Error emitiendo transcript-update: {}", e);
+                                            error!(
+                                                "Worker {}: Failed to emit transcript update: {}",
+                                                worker_id, e
+                                            );
                                         }
                                     }
+                                    // PERFORMANCE: Removed verbose logging of every emission
+                                } else if !transcript.trim().is_empty() && should_log_this_chunk
+                                {
+                                    // PERFORMANCE: Only log low-confidence results occasionally
+                                    if let Some(c) = confidence_opt {
+                                        info!("Worker {} low-confidence transcription (confidence: {:.2}), skipping", worker_id, c);
+                                    }
                                 }
-                                Err(e) => {
-                                    // Improved error handling with specific cases
-                                    match e {
-                                        TranscriptionError::AudioTooShort { .. } => {
-                                            // Skip silently, this is expected for very short chunks
-                                            info!("Worker {}: {}", worker_id, e);
-                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                            continue;
-                                        }
-                                        TranscriptionError::ModelNotLoaded => {
-                                            warn!("Worker {}: Model unloaded during transcription", worker_id);
-                                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
-                                            continue;
-                                        }
-                                        _ => {
-                                            warn!("Worker {}: Transcription failed: {}", worker_id, e);
-                                            let _ = app_clone.emit("transcription-warning", e.to_string());
-                                        }
+                            }
+                            Err(e) => {
+                                // Improved error handling with specific cases
+                                match e {
+                                    TranscriptionError::AudioTooShort { .. } => {
+                                        // Skip silently, this is expected for very short chunks
+                                        info!("Worker {}: {}", worker_id, e);
+                                        chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                        continue;
+                                    }
+                                    TranscriptionError::ModelNotLoaded => {
+                                        warn!("Worker {}: Model unloaded during transcription", worker_id);
+                                        chunks_completed_clone.fetch_add(1, Ordering::SeqCst);
+                                        continue;
+                                    }
+                                    _ => {
+                                        warn!("Worker {}: Transcription failed: {}", worker_id, e);
+                                        let _ = app_clone.emit("transcription-warning", e.to_string());
                                     }
                                 }
                             }
+                        }
 
-                            // Mark chunk as completed
-                            let completed =
-                                chunks_completed_clone.fetch_add(1, Ordering::SeqCst) + 1;
-                            let queued = chunks_queued_clone.load(Ordering::SeqCst);
+                        // Mark chunk as completed
+                        let completed =
+                            chunks_completed_clone.fetch_add(1, Ordering::SeqCst) + 1;
+                        let queued = chunks_queued_clone.load(Ordering::SeqCst);
+
+                        // PERFORMANCE: Only log progress every 5th chunk to reduce I/O overhead
+                        if completed % 5 == 0 || should_log_this_chunk {
+                            info!(
+                                "Worker {}: Progress {}/{} chunks ({:.1}%)",
+                                worker_id,
+                                completed,
+                                queued,
+                                (completed as f64 / queued.max(1) as f64 * 100.0)
+                            );
+                        }
 
-                            // PERFORMANCE: Only log progress every 5th chunk to reduce I/O overhead
-                            if completed % 5 == 0 || should_log_this_chunk {
+                        // Emit progress event for frontend
+                        let progress_percentage = if queued > 0 {
+                            (completed as f64 / queued as f64 * 100.0) as u32
+                        } else {
+                            100
+                        };
+
+                        let _ = app_clone.emit("transcription-progress", serde_json::json!({
+                            "worker_id": worker_id,
+                            "chunks_completed": completed,
+                            "chunks_queued": queued,
+                            "progress_percentage": progress_percentage,
+                            "message": format!("Worker {} processing... ({}/{})", worker_id, completed, queued)
+                        }));
+                    }
+                    None => {
+                        // No more chunks available
+                        if input_finished_clone.load(Ordering::SeqCst) {
+                            // Double-check that all queued chunks are actually completed
+                            let final_queued = chunks_queued_clone.load(Ordering::SeqCst);
+                            let final_completed = chunks_completed_clone.load(Ordering::SeqCst);
+
+                            if final_completed >= final_queued {
                                 info!(
-                                    "Worker {}: Progress {}/{} chunks ({:.1}%)",
-                                    worker_id,
-                                    completed,
-                                    queued,
-                                    (completed as f64 / queued.max(1) as f64 * 100.0)
+                                    "👷 Worker {} finishing - all {}/{} chunks processed",
+                                    worker_id, final_completed, final_queued
                                 );
-                            }
-
-                            // Emit progress event for frontend
-                            let progress_percentage = if queued > 0 {
-                                (completed as f64 / queued as f64 * 100.0) as u32
-                            } else {
-                                100
-                            };
-
-                            let _ = app_clone.emit("transcription-progress", serde_json::json!({
-                                "worker_id": worker_id,
-                                "chunks_completed": completed,
-                                "chunks_queued": queued,
-                                "progress_percentage": progress_percentage,
-                                "message": format!("Worker {} processing... ({}/{})", worker_id, completed, queued)
-                            }));
-                        }
-                        None => {
-                            // No more chunks available
-                            if input_finished_clone.load(Ordering::SeqCst) {
-                                // Double-check that all queued chunks are actually completed
-                                let final_queued = chunks_queued_clone.load(Ordering::SeqCst);
-                                let final_completed = chunks_completed_clone.load(Ordering::SeqCst);
-
-                                if final_completed >= final_queued {
-                                    info!(
-                                        "👷 Worker {} finishing - all {}/{} chunks processed",
-                                        worker_id, final_completed, final_queued
-                                    );
-                                    break;
-                                } else {
-                                    warn!("👷 Worker {} detected potential chunk loss: {}/{} completed, waiting...", worker_id, final_completed, final_queued);
-                                    // AGGRESSIVE POLLING: Reduced from 50ms to 5ms for faster chunk detection during shutdown
-                                    tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
-                                }
+                                break;
                             } else {
-                                // AGGRESSIVE POLLING: Reduced from 10ms to 1ms for faster response during shutdown
-                                tokio::time::sleep(tokio::time::Duration::from_millis(1)).await;
+                                warn!("👷 Worker {} detected potential chunk loss: {}/{} completed, waiting...", worker_id, final_completed, final_queued);
+                                // AGGRESSIVE POLLING: Reduced from 50ms to 5ms for faster chunk detection during shutdown
+                                tokio::time::sleep(tokio::time::Duration::from_millis(5)).await;
                             }
+                        } else {
+                            // AGGRESSIVE POLLING: Reduced from 10ms to 1ms for faster response during shutdown
+                            tokio::time::sleep(tokio::time::Duration::from_millis(1)).await;
                         }
                     }
                 }
+            }
 
-                info!("👷 Worker {} completed", worker_id);
-            });
+            info!("👷 Worker {} completed", worker_id);
+        });
 
-            worker_handles.push(worker_handle);
-        }
+        worker_handles.push(worker_handle);
+    }
 
-        // Main dispatcher: receive chunks and distribute to workers
-        let mut receiver = transcription_receiver;
-        let chunks_dropped_dispatcher = chunks_dropped.clone();
-        while let Some(chunk) = receiver.recv().await {
-            let chunk_id = chunk.chunk_id;
-            let queued = chunks_queued.fetch_add(1, Ordering::SeqCst) + 1;
-            info!(
-                "📥 Dispatching chunk {} to workers (total queued: {})",
-                chunk_id, queued
-            );
+    // Main dispatcher: receive chunks and distribute to workers
+    let mut receiver = transcription_receiver;
+    let chunks_dropped_dispatcher = chunks_dropped.clone();
+    while let Some(chunk) = receiver.recv().await {
+        let chunk_id = chunk.chunk_id;
+        let queued = chunks_queued.fetch_add(1, Ordering::SeqCst) + 1;
+        info!(
+            "📥 Dispatching chunk {} to workers (total queued: {})",
+            chunk_id, queued
+        );
 
-            // FIX: Bounded channel con backpressure - espera si la cola está llena
-            // Usa timeout para detectar si el worker está bloqueado
-            match tokio::time::timeout(
-                tokio::time::Duration::from_secs(5),
-                work_sender.send(chunk)
-            ).await {
-                Ok(Ok(())) => {
-                    // Chunk enviado exitosamente
-                }
-                Ok(Err(_)) => {
-                    // Channel cerrado - workers terminaron
-                    error!("❌ Channel closed - workers terminated unexpectedly");
-                    break;
-                }
-                Err(_) => {
-                    // Timeout - cola llena por más de 5 segundos
-                    error!("⚠️ Chunk {} dropped - queue full for >5s (backpressure timeout)", chunk_id);
-                    chunks_dropped_dispatcher.fetch_add(1, Ordering::SeqCst);
-                }
+        // FIX: Bounded channel con backpressure - espera si la cola está llena
+        // Usa timeout para detectar si el worker está bloqueado
+        match tokio::time::timeout(
+            tokio::time::Duration::from_secs(5),
+            work_sender.send(chunk)
+        ).await {
+            Ok(Ok(())) => {
+                // Chunk enviado exitosamente
             }
+            Ok(Err(_)) => {
+                // Channel cerrado - workers terminaron
+                error!("❌ Channel closed - workers terminated unexpectedly");
+                break;
+            }
+            Err(_) => {
+                // Timeout - cola llena por más de 5 segundos
+                error!("⚠️ Chunk {} dropped - queue full for >5s (backpressure timeout)", chunk_id);
+                chunks_dropped_dispatcher.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    }
+
+    // Signal that input is finished
+    input_finished.store(true, Ordering::SeqCst);
+    drop(work_sender); // Close the channel to signal workers
+
+    let total_chunks_queued = chunks_queued.load(Ordering::SeqCst);
+    info!("📭 Input finished with {} total chunks queued. Waiting for all {} workers to complete...",
+          total_chunks_queued, NUM_WORKERS);
+
+    // Emit final chunk count to frontend
+    let _ = app.emit("transcription-queue-complete", serde_json::json!({
+        "total_chunks": total_chunks_queued,
+        "message": format!("{} chunks queued for processing - waiting for completion", total_chunks_queued)
+    }));
+
+    // Wait for all workers to complete
+    for (worker_id, handle) in worker_handles.into_iter().enumerate() {
+        if let Err(e) = handle.await {
+            error!("Worker {} panicked: {:?}", worker_id, e);
+        } else {
+            info!("Worker {} completed successfully", worker_id);
         }
+    }
 
-        // Signal that input is finished
-        input_finished.store(true, Ordering::SeqCst);
-        drop(work_sender); // Close the channel to signal workers
+    // Close persistent stream for streaming providers (e.g., Deepgram)
+    // This sends CloseStream and waits for the reader task to process remaining responses
+    if is_streaming {
+        info!("Closing persistent stream after all workers completed");
+        transcription_engine.close_stream().await;
+    }
 
-        let total_chunks_queued = chunks_queued.load(Ordering::SeqCst);
-        info!("📭 Input finished with {} total chunks queued. Waiting for all {} workers to complete...",
-              total_chunks_queued, NUM_WORKERS);
+    // Final verification with retry logic to catch any stragglers
+    let mut verification_attempts = 0;
+    const MAX_VERIFICATION_ATTEMPTS: u32 = 10;
 
-        // Emit final chunk count to frontend
-        let _ = app.emit("transcription-queue-complete", serde_json::json!({
-            "total_chunks": total_chunks_queued,
-            "message": format!("{} chunks queued for processing - waiting for completion", total_chunks_queued)
-        }));
+    loop {
+        let final_queued = chunks_queued.load(Ordering::SeqCst);
+        let final_completed = chunks_completed.load(Ordering::SeqCst);
+        let final_dropped = chunks_dropped.load(Ordering::SeqCst);
 
-        // Wait for all workers to complete
-        for (worker_id, handle) in worker_handles.into_iter().enumerate() {
-            if let Err(e) = handle.await {
-                error!("Worker {} panicked: {:?}", worker_id, e);
+        if final_queued == final_completed + final_dropped {
+            if final_dropped == 0 {
+                info!(
+                    "🎉 ALL {} chunks processed successfully - ZERO chunks lost!",
+                    final_completed
+                );
             } else {
-                info!("Worker {} completed successfully", worker_id);
+                warn!(
+                    "⚠️ {} chunks processed, {} dropped due to backpressure",
+                    final_completed, final_dropped
+                );
             }
-        }
 
-        // Close persistent stream for streaming providers (e.g., Deepgram)
-        // This sends CloseStream and waits for the reader task to process remaining responses
-        if is_streaming {
-            info!("Closing persistent stream after all workers completed");
-            transcription_engine.close_stream().await;
-        }
-
-        // Final verification with retry logic to catch any stragglers
-        let mut verification_attempts = 0;
-        const MAX_VERIFICATION_ATTEMPTS: u32 = 10;
-
-        loop {
-            let final_queued = chunks_queued.load(Ordering::SeqCst);
-            let final_completed = chunks_completed.load(Ordering::SeqCst);
-            let final_dropped = chunks_dropped.load(Ordering::SeqCst);
-
-            if final_queued == final_completed + final_dropped {
-                if final_dropped == 0 {
-                    info!(
-                        "🎉 ALL {} chunks processed successfully - ZERO chunks lost!",
-                        final_completed
-                    );
-                } else {
-                    warn!(
-                        "⚠️ {} chunks processed, {} dropped due to backpressure",
-                        final_completed, final_dropped
-                    );
-                }
+            // FIX: Emit transcription summary with loss metrics for debugging
+            let loss_percentage = if final_queued > 0 {
+                (final_dropped as f64 / final_queued as f64) * 100.0
+            } else {
+                0.0
+            };
 
-                // FIX: Emit transcription summary with loss metrics for debugging
-                let loss_percentage = if final_queued > 0 {
-                    (final_dropped as f64 / final_queued as f64) * 100.0
-                } else {
-                    0.0
-                };
+            let _ = app.emit("transcription-summary", serde_json::json!({
+                "chunks_queued": final_queued,
+                "chunks_completed": final_completed,
+                "chunks_dropped": final_dropped,
+                "loss_percentage": loss_percentage,
+                "status": if final_dropped == 0 { "success" } else { "partial_loss" }
+            }));
+
+            break;
+        } else if verification_attempts < MAX_VERIFICATION_ATTEMPTS {
+            verification_attempts += 1;
+            warn!("⚠️ Chunk count mismatch (attempt {}): {} queued, {} completed, {} dropped - waiting for stragglers...",
+                 verification_attempts, final_queued, final_completed, final_dropped);
+
+            // Wait a bit for any remaining chunks to be processed
+            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        } else {
+            let processing_loss = final_queued.saturating_sub(final_completed + final_dropped);
+            error!(
+                "❌ CRITICAL: After {} attempts, chunk loss detected: {} queued, {} completed, {} dropped, {} lost in processing",
+                MAX_VERIFICATION_ATTEMPTS, final_queued, final_completed, final_dropped, processing_loss
+            );
 
-                let _ = app.emit("transcription-summary", serde_json::json!({
+            // Emit critical error event with full metrics
+            let _ = app.emit(
+                "transcript-chunk-loss-detected",
+                serde_json::json!({
                     "chunks_queued": final_queued,
                     "chunks_completed": final_completed,
                     "chunks_dropped": final_dropped,
-                    "loss_percentage": loss_percentage,
-                    "status": if final_dropped == 0 { "success" } else { "partial_loss" }
-                }));
-
-                break;
-            } else if verification_attempts < MAX_VERIFICATION_ATTEMPTS {
-                verification_attempts += 1;
-                warn!("⚠️ Chunk count mismatch (attempt {}): {} queued, {} completed, {} dropped - waiting for stragglers...",
-                     verification_attempts, final_queued, final_completed, final_dropped);
-
-                // Wait a bit for any remaining chunks to be processed
-                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-            } else {
-                let processing_loss = final_queued.saturating_sub(final_completed + final_dropped);
-                error!(
-                    "❌ CRITICAL: After {} attempts, chunk loss detected: {} queued, {} completed, {} dropped, {} lost in processing",
-                    MAX_VERIFICATION_ATTEMPTS, final_queued, final_completed, final_dropped, processing_loss
-                );
-
-                // Emit critical error event with full metrics
-                let _ = app.emit(
-                    "transcript-chunk-loss-detected",
-                    serde_json::json!({
-                        "chunks_queued": final_queued,
-                        "chunks_completed": final_completed,
-                        "chunks_dropped": final_dropped,
-                        "chunks_lost_in_processing": processing_loss,
-                        "total_loss": final_dropped + processing_loss,
-                        "message": "Some transcript chunks may have been lost during shutdown"
-                    }),
-                );
-                break;
-            }
+                    "chunks_lost_in_processing": processing_loss,
+                    "total_loss": final_dropped + processing_loss,
+                    "message": "Some transcript chunks may have been lost during shutdown"
+                }),
+            );
+            break;
         }
+    }
 
-        info!("✅ Parallel transcription task completed - all workers finished, ready for model unload");
-    })
+    info!("✅ Parallel transcription task completed - all workers finished, ready for model unload");
 }
 
 /// Transcribe audio chunk using the appropriate provider (Whisper, Parakeet, or trait-based)
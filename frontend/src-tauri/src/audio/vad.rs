@@ -1,9 +1,16 @@
 use anyhow::{anyhow, Result};
 use silero_rs::{VadConfig, VadSession, VadTransition};
-use log::{debug, info};
+use log::{debug, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::sync::RwLock;
 use std::time::Duration;
 
+/// All VAD engines run at 16kHz
+const VAD_SAMPLE_RATE: u32 = 16000;
+const VAD_SAMPLES_PER_MS: f64 = VAD_SAMPLE_RATE as f64 / 1000.0;
+
 /// Represents a complete speech segment detected by VAD
 #[derive(Debug, Clone)]
 pub struct SpeechSegment {
@@ -13,6 +20,249 @@ pub struct SpeechSegment {
     pub confidence: f32,
 }
 
+impl SpeechSegment {
+    pub fn duration_ms(&self) -> f64 {
+        self.end_timestamp_ms - self.start_timestamp_ms
+    }
+}
+
+// ============================================================================
+// VAD SETTINGS AND STATISTICS
+// ============================================================================
+
+/// Available voice activity detection engines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VadEngineKind {
+    /// Silero neural VAD (accurate, ~1-2% CPU per channel)
+    #[default]
+    Silero,
+    /// Adaptive energy detector (near-zero CPU, for low-end machines)
+    Energy,
+}
+
+/// VAD tuning for one channel (microphone or system audio)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChannelVadSettings {
+    /// Speech threshold 0.0-1.0 (higher = less sensitive)
+    pub threshold: f32,
+    /// Speech shorter than this is discarded
+    pub min_speech_ms: u32,
+    /// Silence needed before a segment ends (bridges natural pauses)
+    pub redemption_ms: u32,
+    /// Longer segments are split for transcription (0 = no limit, the default)
+    pub max_segment_ms: u32,
+}
+
+impl Default for ChannelVadSettings {
+    fn default() -> Self {
+        Self {
+            threshold: 0.50,
+            min_speech_ms: 150,
+            redemption_ms: 600,
+            max_segment_ms: 0,
+        }
+    }
+}
+
+impl ChannelVadSettings {
+    /// Clamp values to ranges the engines can work with
+    pub fn validated(&self) -> Self {
+        Self {
+            threshold: self.threshold.clamp(0.05, 0.95),
+            min_speech_ms: self.min_speech_ms.min(5_000),
+            redemption_ms: self.redemption_ms.clamp(100, 5_000),
+            max_segment_ms: if self.max_segment_ms == 0 { 0 } else { self.max_segment_ms.max(1_000) },
+        }
+    }
+}
+
+/// VAD configuration for a recording (engine plus per-channel tuning)
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct VadSettings {
+    pub engine: VadEngineKind,
+    pub microphone: ChannelVadSettings,
+    pub system: ChannelVadSettings,
+}
+
+static VAD_SETTINGS: Lazy<RwLock<VadSettings>> = Lazy::new(|| RwLock::new(VadSettings::default()));
+
+/// VAD settings used for the next recording
+pub fn current_vad_settings() -> VadSettings {
+    VAD_SETTINGS.read().unwrap().clone()
+}
+
+/// Replace the VAD settings used for the next recording
+pub fn set_current_vad_settings(settings: VadSettings) {
+    info!("VAD settings updated: {:?}", settings);
+    *VAD_SETTINGS.write().unwrap() = settings;
+}
+
+/// Per-channel VAD statistics, recorded per meeting for tuning
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VadStats {
+    pub engine: String,
+    /// Audio analysed (ms)
+    pub audio_ms: f64,
+    /// Audio emitted as speech (ms)
+    pub speech_ms: f64,
+    pub segments: u64,
+    /// Speech bursts shorter than `min_speech_ms` (energy engine only)
+    pub discarded_short_segments: u64,
+    /// Extra segments created by `max_segment_ms` splitting
+    pub split_segments: u64,
+    pub longest_segment_ms: f64,
+}
+
+impl VadStats {
+    fn new(engine: &str) -> Self {
+        Self { engine: engine.to_string(), ..Default::default() }
+    }
+
+    /// Fraction of analysed audio classified as speech
+    pub fn speech_ratio(&self) -> f64 {
+        if self.audio_ms > 0.0 { self.speech_ms / self.audio_ms } else { 0.0 }
+    }
+
+    fn record_segment(&mut self, segment: &SpeechSegment) {
+        let duration = segment.duration_ms();
+        self.segments += 1;
+        self.speech_ms += duration;
+        self.longest_segment_ms = self.longest_segment_ms.max(duration);
+    }
+}
+
+/// VAD settings and statistics of a whole recording session
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VadSessionStats {
+    pub settings: VadSettings,
+    pub microphone: VadStats,
+    pub system: VadStats,
+}
+
+/// Common interface of all VAD engines.
+///
+/// Input is mono audio at the rate given at construction; emitted segments
+/// carry 16kHz samples and timestamps relative to the first processed sample.
+pub trait VoiceActivityDetector: Send {
+    /// Feed audio and return any speech segments completed by it
+    fn process_audio(&mut self, samples: &[f32]) -> Result<Vec<SpeechSegment>>;
+
+    /// End any ongoing speech and return the remaining segments
+    fn flush(&mut self) -> Result<Vec<SpeechSegment>>;
+
+    /// Engine name for logs and statistics
+    fn name(&self) -> &'static str;
+
+    /// Statistics since construction
+    fn stats(&self) -> VadStats;
+}
+
+/// Create a VAD engine for one channel. Falls back to the energy engine if
+/// Silero cannot be initialised, so a recording never fails because of VAD.
+pub fn create_vad(
+    engine: VadEngineKind,
+    input_sample_rate: u32,
+    settings: &ChannelVadSettings,
+) -> Result<Box<dyn VoiceActivityDetector>> {
+    let settings = settings.validated();
+    match engine {
+        VadEngineKind::Silero => match ContinuousVadProcessor::with_settings(input_sample_rate, &settings) {
+            Ok(processor) => Ok(Box::new(processor)),
+            Err(e) => {
+                warn!("Silero VAD unavailable ({}), falling back to energy VAD", e);
+                Ok(Box::new(EnergyVadProcessor::new(input_sample_rate, &settings)))
+            }
+        },
+        VadEngineKind::Energy => Ok(Box::new(EnergyVadProcessor::new(input_sample_rate, &settings))),
+    }
+}
+
+/// Split segments longer than `max_segment_ms` into equal parts.
+/// Returns the number of extra segments created.
+fn split_long_segments(segments: &mut Vec<SpeechSegment>, max_segment_ms: u32) -> u64 {
+    if max_segment_ms == 0 {
+        return 0;
+    }
+
+    let mut extra = 0;
+    let mut result = Vec::with_capacity(segments.len());
+    for segment in segments.drain(..) {
+        let duration = segment.duration_ms();
+        if duration <= max_segment_ms as f64 || segment.samples.is_empty() {
+            result.push(segment);
+            continue;
+        }
+
+        let parts = (duration / max_segment_ms as f64).ceil() as usize;
+        let samples_per_part = segment.samples.len().div_ceil(parts);
+        let ms_per_sample = duration / segment.samples.len() as f64;
+        for (i, samples) in segment.samples.chunks(samples_per_part).enumerate() {
+            let start = segment.start_timestamp_ms + (i * samples_per_part) as f64 * ms_per_sample;
+            result.push(SpeechSegment {
+                samples: samples.to_vec(),
+                start_timestamp_ms: start,
+                end_timestamp_ms: start + samples.len() as f64 * ms_per_sample,
+                confidence: segment.confidence,
+            });
+        }
+        extra += parts as u64 - 1;
+    }
+    *segments = result;
+    extra
+}
+
+/// Downsample mono audio to 16kHz with a light moving-average anti-alias filter
+fn downsample_to_16k(samples: &[f32], sample_rate: u32) -> Vec<f32> {
+    if sample_rate == VAD_SAMPLE_RATE {
+        return samples.to_vec();
+    }
+
+    // Calculate downsampling ratio
+    let ratio = sample_rate as f64 / 16000.0;
+    let output_len = (samples.len() as f64 / ratio) as usize;
+    let mut resampled = Vec::with_capacity(output_len);
+
+    // Apply simple low-pass filter before downsampling to reduce aliasing
+    let cutoff_freq = 0.4; // Normalized frequency (0.4 * Nyquist)
+    let mut filtered_samples = Vec::with_capacity(samples.len());
+
+    // Simple moving average filter (basic low-pass)
+    let filter_size = (sample_rate as f64 / (cutoff_freq * sample_rate as f64)) as usize;
+    let filter_size = filter_size.clamp(1, 5); // Limit filter size
+
+    for i in 0..samples.len() {
+        let start = i.saturating_sub(filter_size);
+        let end = std::cmp::min(i + filter_size + 1, samples.len());
+        let sum: f32 = samples[start..end].iter().sum();
+        filtered_samples.push(sum / (end - start) as f32);
+    }
+
+    // Linear interpolation downsampling
+    for i in 0..output_len {
+        let source_pos = i as f64 * ratio;
+        let source_index = source_pos as usize;
+        let fraction = source_pos - source_index as f64;
+
+        if source_index + 1 < filtered_samples.len() {
+            // Linear interpolation
+            let sample1 = filtered_samples[source_index];
+            let sample2 = filtered_samples[source_index + 1];
+            let interpolated = sample1 + (sample2 - sample1) * fraction as f32;
+            resampled.push(interpolated);
+        } else if source_index < filtered_samples.len() {
+            resampled.push(filtered_samples[source_index]);
+        }
+    }
+
+    debug!("Resampled from {} samples ({}Hz) to {} samples (16kHz) with anti-aliasing",
+           samples.len(), sample_rate, resampled.len());
+
+    resampled
+}
+
 /// Processes audio in 30ms chunks but returns complete speech segments
 pub struct ContinuousVadProcessor {
     session: VadSession,
@@ -26,12 +276,24 @@ pub struct ContinuousVadProcessor {
     speech_start_sample: usize,
     // State tracking for smart logging
     last_logged_state: bool,
+    max_segment_ms: u32,
+    stats: VadStats,
 }
 
 impl ContinuousVadProcessor {
     pub fn new(input_sample_rate: u32, redemption_time_ms: u32) -> Result<Self> {
-        // Silero VAD MUST use 16kHz - this is hardcoded requirement
-        const VAD_SAMPLE_RATE: u32 = 16000;
+        Self::with_settings(
+            input_sample_rate,
+            &ChannelVadSettings {
+                redemption_ms: redemption_time_ms,
+                ..ChannelVadSettings::default()
+            },
+        )
+    }
+
+    /// Create a Silero processor with explicit per-channel tuning
+    pub fn with_settings(input_sample_rate: u32, settings: &ChannelVadSettings) -> Result<Self> {
+        let redemption_time_ms = settings.redemption_ms;
 
         // Use STRICT settings to prevent silence from reaching Whisper
         let mut config = VadConfig::default();
@@ -40,8 +302,9 @@ impl ContinuousVadProcessor {
         // CONTINUOUS SPEECH FIX: Tuned for capturing complete 5+ second utterances
         // Previous: 0.55/0.40 with 400ms redemption was fragmenting speech into 40ms segments
         // New: More lenient thresholds + longer redemption for continuous speech
-        config.positive_speech_threshold = 0.50;  // Silero default - good for continuous speech
-        config.negative_speech_threshold = 0.35;  // Silero default - allows natural pauses
+        // Defaults (0.50/0.35) are the Silero defaults; the negative threshold follows the positive one
+        config.positive_speech_threshold = settings.threshold;
+        config.negative_speech_threshold = (settings.threshold - 0.15).max(0.01);
 
         // CRITICAL FIX: Removed redemption_time capping to support long continuous speech
         // Previous: capped at 400ms, causing VAD to fragment 5-second speech into 40ms segments
@@ -52,10 +315,10 @@ impl ContinuousVadProcessor {
 
         // FIX: Reduced from 250ms to 150ms to capture short words ("sí", "no", "ok")
         // Whisper can handle segments >100ms, so 150ms is safe while preserving short affirmations
-        config.min_speech_time = Duration::from_millis(150);  // Capture short words
+        config.min_speech_time = Duration::from_millis(settings.min_speech_ms as u64);  // Capture short words
 
-        debug!("Creating VAD session with: sample_rate={}Hz, redemption={}ms, min_speech={}ms, input_rate={}Hz",
-               VAD_SAMPLE_RATE, redemption_time_ms, 150, input_sample_rate);
+        debug!("Creating VAD session with: sample_rate={}Hz, threshold={:.2}, redemption={}ms, min_speech={}ms, input_rate={}Hz",
+               VAD_SAMPLE_RATE, settings.threshold, redemption_time_ms, settings.min_speech_ms, input_sample_rate);
 
         let session = VadSession::new(config)
             .map_err(|e| anyhow!("Failed to create VAD session: {:?}", e))?;
@@ -78,6 +341,8 @@ impl ContinuousVadProcessor {
             speech_start_sample: 0,
             // Initialize state tracking
             last_logged_state: false,
+            max_segment_ms: settings.max_segment_ms,
+            stats: VadStats::new("silero"),
         })
     }
 
@@ -85,11 +350,7 @@ impl ContinuousVadProcessor {
     /// Handles resampling from input sample rate to 16kHz for VAD processing
     pub fn process_audio(&mut self, samples: &[f32]) -> Result<Vec<SpeechSegment>> {
         // Resample to 16kHz if needed
-        let resampled_audio = if self.sample_rate == 16000 {
-            samples.to_vec()
-        } else {
-            self.resample_to_16k(samples)?
-        };
+        let resampled_audio = downsample_to_16k(samples, self.sample_rate);
 
         self.buffer.extend_from_slice(&resampled_audio);
         let mut completed_segments = Vec::new();
@@ -105,57 +366,16 @@ impl ContinuousVadProcessor {
             }
         }
 
+        self.finish_segments(&mut completed_segments);
         Ok(completed_segments)
     }
 
-    /// Improved resampling from input sample rate to 16kHz with anti-aliasing
-    /// Uses linear interpolation and basic low-pass filtering for better quality
-    fn resample_to_16k(&self, samples: &[f32]) -> Result<Vec<f32>> {
-        if self.sample_rate == 16000 {
-            return Ok(samples.to_vec());
-        }
-
-        // Calculate downsampling ratio
-        let ratio = self.sample_rate as f64 / 16000.0;
-        let output_len = (samples.len() as f64 / ratio) as usize;
-        let mut resampled = Vec::with_capacity(output_len);
-
-        // Apply simple low-pass filter before downsampling to reduce aliasing
-        let cutoff_freq = 0.4; // Normalized frequency (0.4 * Nyquist)
-        let mut filtered_samples = Vec::with_capacity(samples.len());
-        
-        // Simple moving average filter (basic low-pass)
-        let filter_size = (self.sample_rate as f64 / (cutoff_freq * self.sample_rate as f64)) as usize;
-        let filter_size = std::cmp::max(1, std::cmp::min(filter_size, 5)); // Limit filter size
-        
-        for i in 0..samples.len() {
-            let start = if i >= filter_size { i - filter_size } else { 0 };
-            let end = std::cmp::min(i + filter_size + 1, samples.len());
-            let sum: f32 = samples[start..end].iter().sum();
-            filtered_samples.push(sum / (end - start) as f32);
+    /// Apply the maximum segment length and update statistics
+    fn finish_segments(&mut self, segments: &mut Vec<SpeechSegment>) {
+        self.stats.split_segments += split_long_segments(segments, self.max_segment_ms);
+        for segment in segments.iter() {
+            self.stats.record_segment(segment);
         }
-
-        // Linear interpolation downsampling
-        for i in 0..output_len {
-            let source_pos = i as f64 * ratio;
-            let source_index = source_pos as usize;
-            let fraction = source_pos - source_index as f64;
-            
-            if source_index + 1 < filtered_samples.len() {
-                // Linear interpolation
-                let sample1 = filtered_samples[source_index];
-                let sample2 = filtered_samples[source_index + 1];
-                let interpolated = sample1 + (sample2 - sample1) * fraction as f32;
-                resampled.push(interpolated);
-            } else if source_index < filtered_samples.len() {
-                resampled.push(filtered_samples[source_index]);
-            }
-        }
-
-        debug!("Resampled from {} samples ({}Hz) to {} samples (16kHz) with anti-aliasing",
-               samples.len(), self.sample_rate, resampled.len());
-
-        Ok(resampled)
     }
 
     /// Flush any remaining audio and return final speech segments
@@ -198,6 +418,7 @@ impl ContinuousVadProcessor {
             completed_segments.push(segment);
         }
 
+        self.finish_segments(&mut completed_segments);
         Ok(completed_segments)
     }
 
@@ -258,10 +479,226 @@ impl ContinuousVadProcessor {
         }
 
         self.processed_samples += chunk.len();
+        self.stats.audio_ms += chunk.len() as f64 / VAD_SAMPLES_PER_MS;
         Ok(())
     }
 }
 
+impl VoiceActivityDetector for ContinuousVadProcessor {
+    fn process_audio(&mut self, samples: &[f32]) -> Result<Vec<SpeechSegment>> {
+        ContinuousVadProcessor::process_audio(self, samples)
+    }
+
+    fn flush(&mut self) -> Result<Vec<SpeechSegment>> {
+        ContinuousVadProcessor::flush(self)
+    }
+
+    fn name(&self) -> &'static str {
+        "silero"
+    }
+
+    fn stats(&self) -> VadStats {
+        self.stats.clone()
+    }
+}
+
+// ============================================================================
+// ENERGY VAD
+// ============================================================================
+
+/// Frame length of the energy detector (30ms at 16kHz, same as Silero)
+const ENERGY_FRAME_SAMPLES: usize = 480;
+/// Audio kept before the first voiced frame so word onsets are not clipped
+const ENERGY_PRE_SPEECH_PAD_MS: f64 = 150.0;
+/// Trailing silence kept at the end of a segment
+const ENERGY_POST_SPEECH_PAD_MS: f64 = 400.0;
+/// Frames quieter than this are never speech
+const ENERGY_ABSOLUTE_FLOOR_DB: f32 = -55.0;
+/// Level above the noise floor (dB) that maps to a score of 1.0
+const ENERGY_SCORE_RANGE_DB: f32 = 20.0;
+
+/// Lightweight VAD based on frame energy relative to an adaptive noise floor.
+///
+/// Much cheaper than Silero and good enough for quiet rooms and system audio;
+/// `threshold` is the fraction of `ENERGY_SCORE_RANGE_DB` a frame must rise
+/// above the noise floor to count as speech (0.5 = 10 dB).
+pub struct EnergyVadProcessor {
+    sample_rate: u32,
+    settings: ChannelVadSettings,
+    buffer: Vec<f32>,
+    noise_floor_db: Option<f32>,
+    pre_roll: VecDeque<f32>,
+    current_speech: Vec<f32>,
+    in_speech: bool,
+    speech_start_sample: usize,
+    voiced_samples: usize,
+    trailing_silence_samples: usize,
+    processed_samples: usize,
+    speech_segments: Vec<SpeechSegment>,
+    stats: VadStats,
+}
+
+impl EnergyVadProcessor {
+    pub fn new(input_sample_rate: u32, settings: &ChannelVadSettings) -> Self {
+        info!("Energy VAD processor created: input={}Hz, threshold={:.2}, redemption={}ms",
+              input_sample_rate, settings.threshold, settings.redemption_ms);
+        Self {
+            sample_rate: input_sample_rate,
+            settings: settings.validated(),
+            buffer: Vec::with_capacity(ENERGY_FRAME_SAMPLES * 2),
+            noise_floor_db: None,
+            pre_roll: VecDeque::new(),
+            current_speech: Vec::new(),
+            in_speech: false,
+            speech_start_sample: 0,
+            voiced_samples: 0,
+            trailing_silence_samples: 0,
+            processed_samples: 0,
+            speech_segments: Vec::new(),
+            stats: VadStats::new("energy"),
+        }
+    }
+
+    fn ms_to_samples(ms: f64) -> usize {
+        (ms * VAD_SAMPLES_PER_MS) as usize
+    }
+
+    /// Classify a frame and update the adaptive noise floor
+    fn is_voiced(&mut self, frame: &[f32]) -> bool {
+        let rms = (frame.iter().map(|&x| x * x).sum::<f32>() / frame.len().max(1) as f32).sqrt();
+        let level_db = 20.0 * (rms + 1e-9).log10();
+
+        let floor = *self.noise_floor_db.get_or_insert(level_db);
+        let score = ((level_db - floor) / ENERGY_SCORE_RANGE_DB).clamp(0.0, 1.0);
+        let voiced = level_db > ENERGY_ABSOLUTE_FLOOR_DB && score >= self.settings.threshold;
+
+        // Fall quickly to quieter levels, rise slowly (barely at all during speech)
+        let new_floor = if level_db < floor {
+            floor * 0.7 + level_db * 0.3
+        } else {
+            floor + (level_db - floor) * if voiced { 0.001 } else { 0.02 }
+        };
+        self.noise_floor_db = Some(new_floor.max(-100.0));
+
+        voiced
+    }
+
+    fn process_frame(&mut self, frame: &[f32]) {
+        let voiced = self.is_voiced(frame);
+
+        if !self.in_speech {
+            if voiced {
+                self.in_speech = true;
+                self.current_speech = self.pre_roll.drain(..).collect();
+                self.speech_start_sample = self.processed_samples - self.current_speech.len();
+                self.current_speech.extend_from_slice(frame);
+                self.voiced_samples = frame.len();
+                self.trailing_silence_samples = 0;
+            } else {
+                self.pre_roll.extend(frame.iter().copied());
+                let max_pre_roll = Self::ms_to_samples(ENERGY_PRE_SPEECH_PAD_MS);
+                while self.pre_roll.len() > max_pre_roll {
+                    self.pre_roll.pop_front();
+                }
+            }
+        } else {
+            self.current_speech.extend_from_slice(frame);
+            if voiced {
+                self.voiced_samples += frame.len();
+                self.trailing_silence_samples = 0;
+            } else {
+                self.trailing_silence_samples += frame.len();
+            }
+
+            if self.trailing_silence_samples >= Self::ms_to_samples(self.settings.redemption_ms as f64) {
+                // Keep only the post-speech pad of the trailing silence
+                let excess = self
+                    .trailing_silence_samples
+                    .saturating_sub(Self::ms_to_samples(ENERGY_POST_SPEECH_PAD_MS));
+                let keep = self.current_speech.len() - excess;
+                self.current_speech.truncate(keep);
+                self.end_segment();
+            } else if self.settings.max_segment_ms > 0
+                && self.current_speech.len() >= Self::ms_to_samples(self.settings.max_segment_ms as f64)
+            {
+                // Cut long monologues so transcription latency stays bounded
+                self.stats.split_segments += 1;
+                self.end_segment();
+                self.in_speech = true;
+                self.speech_start_sample = self.processed_samples + frame.len();
+            }
+        }
+
+        self.processed_samples += frame.len();
+        self.stats.audio_ms += frame.len() as f64 / VAD_SAMPLES_PER_MS;
+    }
+
+    /// Emit the current speech (if long enough) and reset to silence
+    fn end_segment(&mut self) {
+        let samples = std::mem::take(&mut self.current_speech);
+        let voiced_ms = self.voiced_samples as f64 / VAD_SAMPLES_PER_MS;
+        self.in_speech = false;
+        self.voiced_samples = 0;
+        self.trailing_silence_samples = 0;
+
+        if samples.is_empty() {
+            return;
+        }
+        if voiced_ms < self.settings.min_speech_ms as f64 {
+            self.stats.discarded_short_segments += 1;
+            return;
+        }
+
+        let start_ms = self.speech_start_sample as f64 / VAD_SAMPLES_PER_MS;
+        let segment = SpeechSegment {
+            start_timestamp_ms: start_ms,
+            end_timestamp_ms: start_ms + samples.len() as f64 / VAD_SAMPLES_PER_MS,
+            samples,
+            confidence: 0.7,
+        };
+        debug!("Energy VAD: speech segment {:.1}ms", segment.duration_ms());
+        self.stats.record_segment(&segment);
+        self.speech_segments.push(segment);
+    }
+}
+
+impl VoiceActivityDetector for EnergyVadProcessor {
+    fn process_audio(&mut self, samples: &[f32]) -> Result<Vec<SpeechSegment>> {
+        let resampled = downsample_to_16k(samples, self.sample_rate);
+        self.buffer.extend_from_slice(&resampled);
+
+        let mut offset = 0;
+        while self.buffer.len() - offset >= ENERGY_FRAME_SAMPLES {
+            let frame: Vec<f32> = self.buffer[offset..offset + ENERGY_FRAME_SAMPLES].to_vec();
+            self.process_frame(&frame);
+            offset += ENERGY_FRAME_SAMPLES;
+        }
+        self.buffer.drain(..offset);
+
+        Ok(std::mem::take(&mut self.speech_segments))
+    }
+
+    fn flush(&mut self) -> Result<Vec<SpeechSegment>> {
+        if !self.buffer.is_empty() {
+            let mut frame = std::mem::take(&mut self.buffer);
+            frame.resize(ENERGY_FRAME_SAMPLES, 0.0);
+            self.process_frame(&frame);
+        }
+        if self.in_speech {
+            self.end_segment();
+        }
+        Ok(std::mem::take(&mut self.speech_segments))
+    }
+
+    fn name(&self) -> &'static str {
+        "energy"
+    }
+
+    fn stats(&self) -> VadStats {
+        self.stats.clone()
+    }
+}
+
 /// Legacy function for backward compatibility - now uses the optimized approach
 pub fn extract_speech_16k(samples_mono_16k: &[f32]) -> Result<Vec<f32>> {
     let mut processor = ContinuousVadProcessor::new(16000, 400)?;
@@ -315,4 +752,84 @@ pub fn get_speech_chunks(samples_mono_16k: &[f32], redemption_time_ms: u32) -> R
     Ok(segments)
 }
 
- 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(ms: usize, amplitude: f32) -> Vec<f32> {
+        (0..ms * 16)
+            .map(|i| amplitude * (2.0 * std::f32::consts::PI * 220.0 * i as f32 / 16000.0).sin())
+            .collect()
+    }
+
+    fn silence(ms: usize) -> Vec<f32> {
+        vec![0.0005; ms * 16]
+    }
+
+    fn run(vad: &mut dyn VoiceActivityDetector, audio: &[f32]) -> Vec<SpeechSegment> {
+        let mut segments = Vec::new();
+        for chunk in audio.chunks(160) {
+            segments.extend(vad.process_audio(chunk).unwrap());
+        }
+        segments.extend(vad.flush().unwrap());
+        segments
+    }
+
+    #[test]
+    fn test_energy_vad_detects_speech_burst() {
+        let audio = [silence(1000), tone(1000, 0.3), silence(1500)].concat();
+        let mut vad = EnergyVadProcessor::new(16000, &ChannelVadSettings::default());
+
+        let segments = run(&mut vad, &audio);
+        assert_eq!(segments.len(), 1);
+        assert!((segments[0].start_timestamp_ms - 850.0).abs() < 60.0, "{}", segments[0].start_timestamp_ms);
+        assert!((segments[0].end_timestamp_ms - 2400.0).abs() < 60.0, "{}", segments[0].end_timestamp_ms);
+
+        let stats = vad.stats();
+        assert_eq!(stats.segments, 1);
+        assert!((stats.audio_ms - 3500.0).abs() < 40.0);
+    }
+
+    #[test]
+    fn test_energy_vad_discards_short_blips_and_splits_long_speech() {
+        let settings = ChannelVadSettings { max_segment_ms: 2000, ..ChannelVadSettings::default() };
+        let audio = [silence(1000), tone(60, 0.3), silence(1000), tone(5000, 0.3), silence(1000)].concat();
+        let mut vad = EnergyVadProcessor::new(16000, &settings);
+
+        let segments = run(&mut vad, &audio);
+        let stats = vad.stats();
+        assert_eq!(stats.discarded_short_segments, 1);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(stats.split_segments, 2);
+        assert!(segments.iter().all(|s| s.duration_ms() <= 2000.0 + 30.0));
+        assert!(segments.windows(2).all(|w| w[1].start_timestamp_ms >= w[0].end_timestamp_ms - 1.0));
+    }
+
+    #[test]
+    fn test_split_long_segments() {
+        let mut segments = vec![SpeechSegment {
+            samples: vec![0.1; 16 * 5000],
+            start_timestamp_ms: 1000.0,
+            end_timestamp_ms: 6000.0,
+            confidence: 0.9,
+        }];
+        assert_eq!(split_long_segments(&mut segments, 2000), 2);
+        assert_eq!(segments.len(), 3);
+        assert_eq!(segments[0].start_timestamp_ms, 1000.0);
+        assert!((segments[2].end_timestamp_ms - 6000.0).abs() < 0.01);
+        assert_eq!(split_long_segments(&mut segments, 0), 0);
+    }
+
+    #[test]
+    fn test_vad_settings_defaults() {
+        let settings: VadSettings = serde_json::from_str(r#"{"engine":"energy","system":{"threshold":0.7}}"#).unwrap();
+        assert_eq!(settings.engine, VadEngineKind::Energy);
+        assert_eq!(settings.microphone, ChannelVadSettings::default());
+        assert_eq!(settings.system.threshold, 0.7);
+        assert_eq!(settings.system.redemption_ms, 600);
+
+        let clamped = ChannelVadSettings { threshold: 2.0, redemption_ms: 10, ..Default::default() }.validated();
+        assert_eq!(clamped.threshold, 0.95);
+        assert_eq!(clamped.redemption_ms, 100);
+    }
+}
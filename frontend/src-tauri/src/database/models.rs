@@ -39,6 +39,66 @@ pub struct Transcript {
     pub speaker: Option<String>,
 }
 
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct MeetingBookmark {
+    pub id: String,
+    pub meeting_id: String,
+    pub label: String,
+    pub note: Option<String>,
+    // Recording-relative seconds, comparable with Transcript.audio_start_time
+    pub timestamp: f64,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub id: String,
+    pub meeting_id: String,
+    // Title of the meeting when the items were extracted (recurring meetings share it)
+    pub meeting_title: String,
+    pub description: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    // "open", "in_progress", "done" or "cancelled"
+    pub status: String,
+    pub source_segment_id: Option<String>,
+    pub source_timestamp: Option<f64>,
+    // Changed by the user; regenerating the summary keeps the item as it is
+    pub edited_by_user: bool,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
+pub struct SummaryVersion {
+    pub id: String,
+    pub meeting_id: String,
+    // 1, 2, ... per meeting (the highest one is the current summary)
+    pub version: i64,
+    // "generated", "edited" or "restored"
+    pub source: String,
+    pub result: String, // JSON
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub template_id: Option<String>,
+    pub prompt_hash: Option<String>,
+    pub restored_from: Option<i64>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct TranscriptEmbedding {
+    pub meeting_id: String,
+    // Joined from meetings for citations
+    pub meeting_title: String,
+    pub speaker: Option<String>,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    // L2-normalized little-endian f32 values
+    pub embedding: Vec<u8>,
+}
+
 #[derive(Debug, Clone, FromRow, Serialize, Deserialize)]
 pub struct SummaryProcess {
     pub meeting_id: String,
@@ -91,6 +151,9 @@ pub struct Setting {
     #[sqlx(rename = "openRouterApiKey")]
     #[serde(rename = "openRouterApiKey")]
     pub open_router_api_key: Option<String>,
+    #[sqlx(rename = "geminiApiKey")]
+    #[serde(rename = "geminiApiKey")]
+    pub gemini_api_key: Option<String>,
     #[sqlx(rename = "ollamaEndpoint")]
     #[serde(rename = "ollamaEndpoint")]
     pub ollama_endpoint: Option<String>,
@@ -0,0 +1,359 @@
+use crate::database::models::ActionItem;
+use crate::summary::action_items::ExtractedActionItem;
+use chrono::Utc;
+use log::info as log_info;
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+/// Valid values of `action_items.status`
+pub const ACTION_ITEM_STATUSES: &[&str] = &["open", "in_progress", "done", "cancelled"];
+
+/// Statuses of items that still need work (carried into the next meeting)
+const PENDING_STATUSES: &[&str] = &["open", "in_progress"];
+
+/// Filters for listing action items across meetings (all optional, combined with AND)
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ActionItemFilter {
+    pub status: Option<Vec<String>>,
+    pub meeting_id: Option<String>,
+    /// Matches recurring meetings by the title they were extracted under
+    pub meeting_title: Option<String>,
+    /// Substring of the owner name (case-insensitive)
+    pub owner: Option<String>,
+    /// Items due on or before this date (YYYY-MM-DD)
+    pub due_before: Option<String>,
+    /// Substring of the description (case-insensitive)
+    pub search: Option<String>,
+}
+
+/// Fields to change on an action item; an empty owner or due date clears it
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct ActionItemUpdate {
+    pub description: Option<String>,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub status: Option<String>,
+}
+
+pub struct ActionItemsRepository;
+
+impl ActionItemsRepository {
+    /// Stores the items extracted from a meeting, merging them with the previous extraction.
+    ///
+    /// New items are matched to stored ones by source segment, then by description. Matched
+    /// items keep their id and status; items the user edited are kept exactly as they are,
+    /// even when the new extraction no longer contains them. Unmatched unedited items are
+    /// removed.
+    pub async fn replace_meeting_items(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        meeting_title: &str,
+        items: &[ExtractedActionItem],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        let existing = sqlx::query_as::<_, ActionItem>(
+            "SELECT * FROM action_items WHERE meeting_id = ? ORDER BY created_at ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(&mut *transaction)
+        .await?;
+        let matches = match_existing_items(&existing, items);
+
+        let now = Utc::now().to_rfc3339();
+        for (item, matched) in items.iter().zip(&matches) {
+            match matched.map(|index| &existing[index]) {
+                Some(stored) if stored.edited_by_user => {}
+                Some(stored) => {
+                    sqlx::query(
+                        "UPDATE action_items SET description = ?, owner = ?, due_date = ?,
+                             source_segment_id = ?, source_timestamp = ?, updated_at = ?
+                         WHERE id = ?",
+                    )
+                    .bind(&item.description)
+                    .bind(&item.owner)
+                    .bind(&item.due_date)
+                    .bind(&item.source_segment_id)
+                    .bind(item.source_timestamp)
+                    .bind(&now)
+                    .bind(&stored.id)
+                    .execute(&mut *transaction)
+                    .await?;
+                }
+                None => {
+                    sqlx::query(
+                        "INSERT INTO action_items (id, meeting_id, meeting_title, description, owner, due_date, status,
+                             source_segment_id, source_timestamp, created_at, updated_at)
+                         VALUES (?, ?, ?, ?, ?, ?, 'open', ?, ?, ?, ?)",
+                    )
+                    .bind(format!("ai_{}", Uuid::new_v4()))
+                    .bind(meeting_id)
+                    .bind(meeting_title)
+                    .bind(&item.description)
+                    .bind(&item.owner)
+                    .bind(&item.due_date)
+                    .bind(&item.source_segment_id)
+                    .bind(item.source_timestamp)
+                    .bind(&now)
+                    .bind(&now)
+                    .execute(&mut *transaction)
+                    .await?;
+                }
+            }
+        }
+
+        // Items the new extraction dropped, unless the user worked on them
+        let mut removed = 0;
+        for (index, stored) in existing.iter().enumerate() {
+            if stored.edited_by_user || matches.contains(&Some(index)) {
+                continue;
+            }
+            sqlx::query("DELETE FROM action_items WHERE id = ?")
+                .bind(&stored.id)
+                .execute(&mut *transaction)
+                .await?;
+            removed += 1;
+        }
+
+        transaction.commit().await?;
+        log_info!(
+            "Saved {} action items for meeting_id: {} ({} previous items removed)",
+            items.len(),
+            meeting_id,
+            removed
+        );
+        Ok(())
+    }
+
+    /// Lists action items across all meetings, pending first, then by due date.
+    pub async fn list(
+        pool: &SqlitePool,
+        filter: &ActionItemFilter,
+    ) -> Result<Vec<ActionItem>, sqlx::Error> {
+        let mut conditions: Vec<String> = Vec::new();
+        let mut binds: Vec<String> = Vec::new();
+
+        if let Some(statuses) = filter.status.as_ref().filter(|s| !s.is_empty()) {
+            conditions.push(format!("status IN ({})", vec!["?"; statuses.len()].join(", ")));
+            binds.extend(statuses.iter().cloned());
+        }
+        if let Some(meeting_id) = &filter.meeting_id {
+            conditions.push("meeting_id = ?".to_string());
+            binds.push(meeting_id.clone());
+        }
+        if let Some(title) = &filter.meeting_title {
+            conditions.push("meeting_title = ? COLLATE NOCASE".to_string());
+            binds.push(title.trim().to_string());
+        }
+        if let Some(owner) = filter.owner.as_ref().filter(|o| !o.trim().is_empty()) {
+            conditions.push("owner LIKE ?".to_string());
+            binds.push(format!("%{}%", owner.trim()));
+        }
+        if let Some(due_before) = &filter.due_before {
+            conditions.push("due_date IS NOT NULL AND due_date <= ?".to_string());
+            binds.push(due_before.clone());
+        }
+        if let Some(search) = filter.search.as_ref().filter(|s| !s.trim().is_empty()) {
+            conditions.push("description LIKE ?".to_string());
+            binds.push(format!("%{}%", search.trim()));
+        }
+
+        let where_clause = if conditions.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", conditions.join(" AND "))
+        };
+        let sql = format!(
+            "SELECT * FROM action_items {}
+             ORDER BY CASE WHEN status IN ('open', 'in_progress') THEN 0 ELSE 1 END,
+                      due_date IS NULL, due_date ASC, created_at DESC",
+            where_clause
+        );
+
+        let mut query = sqlx::query_as::<_, ActionItem>(&sql);
+        for value in &binds {
+            query = query.bind(value);
+        }
+        query.fetch_all(pool).await
+    }
+
+    /// Title the meeting's items were first extracted under, if any.
+    pub async fn get_series_title(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<String>, sqlx::Error> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT meeting_title FROM action_items WHERE meeting_id = ? LIMIT 1")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await?;
+        Ok(row.map(|(title,)| title))
+    }
+
+    /// Pending items of earlier meetings that had the same title (case-insensitive).
+    pub async fn get_pending_for_title(
+        pool: &SqlitePool,
+        meeting_title: &str,
+        exclude_meeting_id: &str,
+    ) -> Result<Vec<ActionItem>, sqlx::Error> {
+        let sql = format!(
+            "SELECT * FROM action_items
+             WHERE meeting_title = ? COLLATE NOCASE AND meeting_id != ? AND status IN ({})
+             ORDER BY created_at ASC",
+            vec!["?"; PENDING_STATUSES.len()].join(", ")
+        );
+
+        let mut query = sqlx::query_as::<_, ActionItem>(&sql)
+            .bind(meeting_title.trim())
+            .bind(exclude_meeting_id);
+        for status in PENDING_STATUSES {
+            query = query.bind(*status);
+        }
+        query.fetch_all(pool).await
+    }
+
+    /// Applies an update and returns the item, or None if it does not exist.
+    pub async fn update(
+        pool: &SqlitePool,
+        id: &str,
+        update: &ActionItemUpdate,
+    ) -> Result<Option<ActionItem>, sqlx::Error> {
+        let now = Utc::now().to_rfc3339();
+        let clear_if_empty = |value: &Option<String>| {
+            value
+                .as_ref()
+                .map(|v| v.trim().to_string())
+                .map(|v| if v.is_empty() { None } else { Some(v) })
+        };
+
+        // Each column keeps its value unless the update provides one
+        let result = sqlx::query(
+            "UPDATE action_items SET
+                 description = COALESCE(?, description),
+                 owner = CASE WHEN ? THEN ? ELSE owner END,
+                 due_date = CASE WHEN ? THEN ? ELSE due_date END,
+                 status = COALESCE(?, status),
+                 edited_by_user = 1,
+                 updated_at = ?
+             WHERE id = ?",
+        )
+        .bind(update.description.as_ref().map(|d| d.trim().to_string()))
+        .bind(update.owner.is_some())
+        .bind(clear_if_empty(&update.owner).flatten())
+        .bind(update.due_date.is_some())
+        .bind(clear_if_empty(&update.due_date).flatten())
+        .bind(&update.status)
+        .bind(&now)
+        .bind(id)
+        .execute(pool)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        sqlx::query_as::<_, ActionItem>("SELECT * FROM action_items WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+    }
+}
+
+/// For each extracted item, the index of the stored item it replaces
+///
+/// A stored item matches at most one new item: first one from the same source segment,
+/// else one with the same description (case-insensitive).
+fn match_existing_items(existing: &[ActionItem], items: &[ExtractedActionItem]) -> Vec<Option<usize>> {
+    let mut taken = vec![false; existing.len()];
+    let mut matches = vec![None; items.len()];
+    let normalize = |description: &str| description.trim().to_lowercase();
+
+    // Same segment first, so reworded items still find their stored version
+    for (item, matched) in items.iter().zip(matches.iter_mut()) {
+        let Some(segment) = &item.source_segment_id else {
+            continue;
+        };
+        if let Some(index) = (0..existing.len())
+            .find(|&i| !taken[i] && existing[i].source_segment_id.as_ref() == Some(segment))
+        {
+            taken[index] = true;
+            *matched = Some(index);
+        }
+    }
+
+    for (item, matched) in items.iter().zip(matches.iter_mut()) {
+        if matched.is_some() {
+            continue;
+        }
+        let description = normalize(&item.description);
+        if let Some(index) =
+            (0..existing.len()).find(|&i| !taken[i] && normalize(&existing[i].description) == description)
+        {
+            taken[index] = true;
+            *matched = Some(index);
+        }
+    }
+
+    matches
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(id: &str, description: &str, segment: Option<&str>) -> ActionItem {
+        ActionItem {
+            id: id.to_string(),
+            meeting_id: "meeting".to_string(),
+            meeting_title: "Semanal".to_string(),
+            description: description.to_string(),
+            owner: None,
+            due_date: None,
+            status: "done".to_string(),
+            source_segment_id: segment.map(str::to_string),
+            source_timestamp: None,
+            edited_by_user: true,
+            created_at: String::new(),
+            updated_at: String::new(),
+        }
+    }
+
+    fn extracted(description: &str, segment: Option<&str>) -> ExtractedActionItem {
+        ExtractedActionItem {
+            description: description.to_string(),
+            owner: None,
+            due_date: None,
+            source_segment_id: segment.map(str::to_string),
+            source_timestamp: None,
+        }
+    }
+
+    #[test]
+    fn test_match_by_segment_then_description() {
+        let existing = vec![
+            stored("a", "Enviar el reporte", Some("transcript-2")),
+            stored("b", "Revisar presupuesto", None),
+            stored("c", "Llamar al proveedor", Some("transcript-9")),
+        ];
+        let items = vec![
+            // Reworded, same segment
+            extracted("Mandar el reporte a Ana", Some("transcript-2")),
+            extracted("revisar presupuesto ", Some("transcript-5")),
+            extracted("Preparar la demo", Some("transcript-7")),
+        ];
+
+        assert_eq!(match_existing_items(&existing, &items), vec![Some(0), Some(1), None]);
+    }
+
+    #[test]
+    fn test_stored_item_matches_once() {
+        let existing = vec![stored("a", "Enviar el reporte", Some("transcript-2"))];
+        let items = vec![
+            extracted("Enviar el reporte", Some("transcript-2")),
+            extracted("Enviar el reporte", None),
+        ];
+
+        assert_eq!(match_existing_items(&existing, &items), vec![Some(0), None]);
+    }
+}
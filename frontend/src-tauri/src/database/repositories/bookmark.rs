@@ -0,0 +1,58 @@
+use crate::audio::recording_saver::Bookmark;
+use crate::database::models::MeetingBookmark;
+use log::info as log_info;
+use sqlx::SqlitePool;
+
+pub struct BookmarksRepository;
+
+impl BookmarksRepository {
+    /// Stores the bookmarks of a meeting, replacing any previously saved ones.
+    pub async fn save_bookmarks(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        bookmarks: &[Bookmark],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("DELETE FROM meeting_bookmarks WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        for bookmark in bookmarks {
+            sqlx::query(
+                "INSERT INTO meeting_bookmarks (id, meeting_id, label, note, timestamp, created_at)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&bookmark.id)
+            .bind(meeting_id)
+            .bind(&bookmark.label)
+            .bind(&bookmark.note)
+            .bind(bookmark.timestamp)
+            .bind(&bookmark.created_at)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        log_info!(
+            "Saved {} bookmarks for meeting_id: {}",
+            bookmarks.len(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// Retrieves the bookmarks of a meeting ordered by recording time.
+    pub async fn get_bookmarks(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<MeetingBookmark>, sqlx::Error> {
+        sqlx::query_as::<_, MeetingBookmark>(
+            "SELECT * FROM meeting_bookmarks WHERE meeting_id = ? ORDER BY timestamp ASC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+}
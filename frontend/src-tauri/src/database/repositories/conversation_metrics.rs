@@ -0,0 +1,49 @@
+use crate::summary::conversation_metrics::ConversationMetrics;
+use chrono::Utc;
+use log::{info as log_info, warn as log_warn};
+use sqlx::SqlitePool;
+
+pub struct ConversationMetricsRepository;
+
+impl ConversationMetricsRepository {
+    /// Stores the metrics of a meeting, replacing the previous ones.
+    pub async fn save(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        metrics: &ConversationMetrics,
+    ) -> Result<(), sqlx::Error> {
+        let metrics_json = serde_json::to_string(metrics)
+            .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize metrics: {}", e)))?;
+
+        sqlx::query(
+            "INSERT INTO conversation_metrics (meeting_id, metrics, computed_at) VALUES (?, ?, ?)
+             ON CONFLICT(meeting_id) DO UPDATE SET metrics = excluded.metrics, computed_at = excluded.computed_at",
+        )
+        .bind(meeting_id)
+        .bind(metrics_json)
+        .bind(Utc::now().to_rfc3339())
+        .execute(pool)
+        .await?;
+
+        log_info!("Saved conversation metrics for meeting_id: {}", meeting_id);
+        Ok(())
+    }
+
+    /// Retrieves the stored metrics of a meeting (None if never computed or unreadable).
+    pub async fn get(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Option<ConversationMetrics>, sqlx::Error> {
+        let row: Option<(String,)> =
+            sqlx::query_as("SELECT metrics FROM conversation_metrics WHERE meeting_id = ?")
+                .bind(meeting_id)
+                .fetch_optional(pool)
+                .await?;
+
+        Ok(row.and_then(|(metrics,)| {
+            serde_json::from_str(&metrics)
+                .map_err(|e| log_warn!("Stored metrics for {} are not readable: {}", meeting_id, e))
+                .ok()
+        }))
+    }
+}
@@ -1,5 +1,10 @@
+pub mod action_item;
+pub mod bookmark;
+pub mod conversation_metrics;
 pub mod meeting;
 pub mod setting;
 pub mod summary;
+pub mod summary_version;
 pub mod transcript;
 pub mod transcript_chunk;
+pub mod transcript_embedding;
@@ -1,5 +1,5 @@
 use crate::database::models::{Setting, TranscriptSetting};
-use crate::summary::CustomOpenAIConfig;
+use crate::summary::{CustomOpenAIConfig, FallbackProviderConfig};
 use sqlx::SqlitePool;
 
 #[derive(serde::Deserialize, Debug)]
@@ -25,7 +25,7 @@ pub struct SaveTranscriptConfigRequest {
 pub struct SettingsRepository;
 
 // Transcript providers: localWhisper, deepgram, elevenLabs, groq, openai
-// Summary providers: openai, claude, ollama, groq, added openrouter
+// Summary providers: openai, claude, ollama, groq, added openrouter, gemini
 // NOTE: Handle data exclusion in the higher layer as this is database abstraction layer(using SELECT *)
 
 impl SettingsRepository {
@@ -85,6 +85,7 @@ impl SettingsRepository {
             "ollama" => "ollamaApiKey",
             "groq" => "groqApiKey",
             "openrouter" => "openRouterApiKey",
+            "gemini" => "geminiApiKey",
             "builtin-ai" => return Ok(()), // No API key needed
             _ => {
                 return Err(sqlx::Error::Protocol(
@@ -123,6 +124,7 @@ impl SettingsRepository {
             "groq" => "groqApiKey",
             "claude" => "anthropicApiKey",
             "openrouter" => "openRouterApiKey",
+            "gemini" => "geminiApiKey",
             "builtin-ai" => return Ok(None), // No API key needed
             _ => {
                 return Err(sqlx::Error::Protocol(
@@ -254,6 +256,7 @@ impl SettingsRepository {
             "groq" => "groqApiKey",
             "claude" => "anthropicApiKey",
             "openrouter" => "openRouterApiKey",
+            "gemini" => "geminiApiKey",
             "builtin-ai" => return Ok(()), // No API key needed
             _ => {
                 return Err(sqlx::Error::Protocol(
@@ -350,4 +353,55 @@ impl SettingsRepository {
 
         Ok(())
     }
+
+    // ===== SUMMARY FALLBACK CHAIN METHODS =====
+
+    /// Gets the providers tried (in order) when the summary provider fails
+    ///
+    /// # Returns
+    /// * `Ok(Vec<FallbackProviderConfig>)` - Configured chain (empty when none is stored)
+    /// * `Err(sqlx::Error)` - Database error or invalid JSON
+    pub async fn get_summary_fallback_chain(
+        pool: &SqlitePool,
+    ) -> std::result::Result<Vec<FallbackProviderConfig>, sqlx::Error> {
+        let chain_json: Option<Option<String>> =
+            sqlx::query_scalar("SELECT summaryFallbackChain FROM settings WHERE id = '1' LIMIT 1")
+                .fetch_optional(pool)
+                .await?;
+
+        match chain_json.flatten() {
+            Some(json) => serde_json::from_str(&json).map_err(|e| {
+                sqlx::Error::Protocol(format!("Invalid JSON in summaryFallbackChain: {}", e).into())
+            }),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Saves the summary fallback chain as JSON (an empty chain clears it)
+    pub async fn save_summary_fallback_chain(
+        pool: &SqlitePool,
+        chain: &[FallbackProviderConfig],
+    ) -> std::result::Result<(), sqlx::Error> {
+        let chain_json = if chain.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(chain).map_err(|e| {
+                sqlx::Error::Protocol(format!("Failed to serialize fallback chain to JSON: {}", e).into())
+            })?)
+        };
+
+        sqlx::query(
+            r#"
+            INSERT INTO settings (id, provider, model, whisperModel, summaryFallbackChain)
+            VALUES ('1', 'openai', 'gpt-4o-2024-11-20', 'large-v3', $1)
+            ON CONFLICT(id) DO UPDATE SET
+                summaryFallbackChain = excluded.summaryFallbackChain
+            "#,
+        )
+        .bind(chain_json)
+        .execute(pool)
+        .await?;
+
+        Ok(())
+    }
 }
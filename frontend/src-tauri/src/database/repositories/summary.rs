@@ -1,4 +1,7 @@
 use crate::database::models::SummaryProcess;
+use crate::database::repositories::summary_version::{
+    SummaryVersionDetails, SummaryVersionsRepository, VERSION_EDITED, VERSION_GENERATED,
+};
 use chrono::Utc;
 use serde_json::Value;
 use sqlx::SqlitePool;
@@ -46,15 +49,26 @@ impl SummaryProcessesRepository {
             transaction.rollback().await?;
             return Ok(false);
         }
+        let result_json = result_json.unwrap();
         let now = Utc::now();
 
         sqlx::query("UPDATE summary_processes SET result = ?, updated_at = ? WHERE meeting_id = ?")
-            .bind(&result_json.unwrap())
+            .bind(&result_json)
             .bind(now)
             .bind(meeting_id)
             .execute(&mut *transaction)
             .await?;
 
+        SummaryVersionsRepository::add(
+            &mut *transaction,
+            meeting_id,
+            VERSION_EDITED,
+            &result_json,
+            &SummaryVersionDetails::default(),
+            None,
+        )
+        .await?;
+
         sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
             .bind(now)
             .bind(meeting_id)
@@ -124,11 +138,13 @@ impl SummaryProcessesRepository {
         result: Value, // Keep this as Value to handle both old and new formats if needed
         chunk_count: i64,
         processing_time: f64,
+        version: &SummaryVersionDetails,
     ) -> Result<(), sqlx::Error> {
         let now = Utc::now();
         let result_str = serde_json::to_string(&result)
             .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize result: {}", e)))?;
 
+        let mut transaction = pool.begin().await?;
         sqlx::query(
             r#"
             UPDATE summary_processes
@@ -136,14 +152,26 @@ impl SummaryProcessesRepository {
             WHERE meeting_id = ?
             "#
         )
-        .bind(result_str)
+        .bind(&result_str)
         .bind(now)
         .bind(now)
         .bind(chunk_count)
         .bind(processing_time)
         .bind(meeting_id)
-        .execute(pool)
+        .execute(&mut *transaction)
         .await?;
+
+        // Every generated summary is kept in the version history
+        SummaryVersionsRepository::add(
+            &mut *transaction,
+            meeting_id,
+            VERSION_GENERATED,
+            &result_str,
+            version,
+            None,
+        )
+        .await?;
+        transaction.commit().await?;
         log_info!(
             "Summary completed and backup cleared for meeting_id: {}",
             meeting_id
@@ -186,6 +214,31 @@ impl SummaryProcessesRepository {
         Ok(())
     }
 
+    /// Stores `value` under `key` in the process metadata (other keys are kept)
+    pub async fn set_process_metadata(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        key: &str,
+        value: &Value,
+    ) -> Result<(), sqlx::Error> {
+        let value_str = serde_json::to_string(value)
+            .map_err(|e| sqlx::Error::Protocol(format!("Failed to serialize metadata: {}", e)))?;
+
+        sqlx::query(
+            r#"
+            UPDATE summary_processes
+            SET metadata = json_set(COALESCE(metadata, '{}'), '$.' || ?, json(?))
+            WHERE meeting_id = ?
+            "#,
+        )
+        .bind(key)
+        .bind(value_str)
+        .bind(meeting_id)
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
     pub async fn update_process_cancelled(
         pool: &SqlitePool,
         meeting_id: &str,
@@ -0,0 +1,177 @@
+use crate::database::models::SummaryVersion;
+use chrono::Utc;
+use log::info as log_info;
+use sqlx::{SqliteConnection, SqlitePool};
+use uuid::Uuid;
+
+/// How a summary version was produced
+pub const VERSION_GENERATED: &str = "generated";
+pub const VERSION_EDITED: &str = "edited";
+pub const VERSION_RESTORED: &str = "restored";
+
+/// What produced a generated summary (empty for edits)
+#[derive(Debug, Clone, Default)]
+pub struct SummaryVersionDetails {
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    pub template_id: Option<String>,
+    pub prompt_hash: Option<String>,
+}
+
+pub struct SummaryVersionsRepository;
+
+impl SummaryVersionsRepository {
+    /// All versions of a meeting's summary, newest first
+    pub async fn list(
+        pool: &SqlitePool,
+        meeting_id: &str,
+    ) -> Result<Vec<SummaryVersion>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC",
+        )
+        .bind(meeting_id)
+        .fetch_all(pool)
+        .await
+    }
+
+    pub async fn get(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+    ) -> Result<Option<SummaryVersion>, sqlx::Error> {
+        sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND version = ?",
+        )
+        .bind(meeting_id)
+        .bind(version)
+        .fetch_optional(pool)
+        .await
+    }
+
+    /// Records `result` as the next version of the meeting's summary.
+    ///
+    /// Saving an edit without changes does not add a version; the latest one is returned.
+    pub async fn add(
+        conn: &mut SqliteConnection,
+        meeting_id: &str,
+        source: &str,
+        result: &str,
+        details: &SummaryVersionDetails,
+        restored_from: Option<i64>,
+    ) -> Result<SummaryVersion, sqlx::Error> {
+        let latest = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? ORDER BY version DESC LIMIT 1",
+        )
+        .bind(meeting_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        if let Some(latest) = latest.as_ref() {
+            if source == VERSION_EDITED && latest.result == result {
+                return Ok(latest.clone());
+            }
+        }
+
+        let version = SummaryVersion {
+            id: Uuid::new_v4().to_string(),
+            meeting_id: meeting_id.to_string(),
+            version: latest.map_or(1, |latest| latest.version + 1),
+            source: source.to_string(),
+            result: result.to_string(),
+            provider: details.provider.clone(),
+            model: details.model.clone(),
+            template_id: details.template_id.clone(),
+            prompt_hash: details.prompt_hash.clone(),
+            restored_from,
+            created_at: Utc::now().to_rfc3339(),
+        };
+
+        sqlx::query(
+            "INSERT INTO summary_versions (id, meeting_id, version, source, result, provider, model,
+                 template_id, prompt_hash, restored_from, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&version.id)
+        .bind(&version.meeting_id)
+        .bind(version.version)
+        .bind(&version.source)
+        .bind(&version.result)
+        .bind(&version.provider)
+        .bind(&version.model)
+        .bind(&version.template_id)
+        .bind(&version.prompt_hash)
+        .bind(version.restored_from)
+        .bind(&version.created_at)
+        .execute(&mut *conn)
+        .await?;
+
+        log_info!(
+            "Recorded summary version {} ({}) for meeting_id: {}",
+            version.version,
+            version.source,
+            meeting_id
+        );
+        Ok(version)
+    }
+
+    /// Makes an earlier version the current summary again.
+    ///
+    /// The restored summary is recorded as a new version, so the history stays linear and
+    /// the summary it replaced can be restored in turn. Returns None if the version does
+    /// not exist.
+    pub async fn restore(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        version: i64,
+    ) -> Result<Option<SummaryVersion>, sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        let Some(source) = sqlx::query_as::<_, SummaryVersion>(
+            "SELECT * FROM summary_versions WHERE meeting_id = ? AND version = ?",
+        )
+        .bind(meeting_id)
+        .bind(version)
+        .fetch_optional(&mut *transaction)
+        .await?
+        else {
+            transaction.rollback().await?;
+            return Ok(None);
+        };
+
+        let now = Utc::now();
+        sqlx::query(
+            "UPDATE summary_processes SET status = 'completed', result = ?, error = NULL, updated_at = ?
+             WHERE meeting_id = ?",
+        )
+        .bind(&source.result)
+        .bind(now)
+        .bind(meeting_id)
+        .execute(&mut *transaction)
+        .await?;
+
+        sqlx::query("UPDATE meetings SET updated_at = ? WHERE id = ?")
+            .bind(now)
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let details = SummaryVersionDetails {
+            provider: source.provider.clone(),
+            model: source.model.clone(),
+            template_id: source.template_id.clone(),
+            prompt_hash: source.prompt_hash.clone(),
+        };
+        let restored = Self::add(
+            &mut *transaction,
+            meeting_id,
+            VERSION_RESTORED,
+            &source.result,
+            &details,
+            Some(version),
+        )
+        .await?;
+
+        transaction.commit().await?;
+        Ok(Some(restored))
+    }
+}
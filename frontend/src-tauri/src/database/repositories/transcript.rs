@@ -1,9 +1,30 @@
-use crate::api::{TranscriptSearchResult, TranscriptSegment};
+use crate::api::{TranscriptSearchFilters, TranscriptSearchResult, TranscriptSegment};
 use chrono::Utc;
-use sqlx::{Connection, Error as SqlxError, SqlitePool};
+use sqlx::{Connection, Error as SqlxError, FromRow, SqlitePool};
 use tracing::{error, info};
 use uuid::Uuid;
 
+/// Markers around matched terms in search snippets
+const HIGHLIGHT_START: &str = "<mark>";
+const HIGHLIGHT_END: &str = "</mark>";
+
+const DEFAULT_SEARCH_LIMIT: u32 = 50;
+const MAX_SEARCH_LIMIT: u32 = 200;
+
+#[derive(Debug, FromRow)]
+struct SearchRow {
+    meeting_id: String,
+    title: String,
+    source_type: String,
+    source_id: String,
+    speaker: Option<String>,
+    audio_start_time: Option<f64>,
+    timestamp: String,
+    meeting_date: String,
+    snippet: String,
+    score: f64,
+}
+
 pub struct TranscriptsRepository;
 
 impl TranscriptsRepository {
@@ -83,65 +104,153 @@ impl TranscriptsRepository {
         Ok(meeting_id)
     }
 
-    /// Searches for a query string within the transcripts.
-    /// It returns a list of matching transcripts with context.
+    /// Full-text search over transcripts, summaries and meeting notes.
+    ///
+    /// Every word of the query must match (accent-insensitive, the last word as a prefix).
+    /// Results are ranked by BM25 and carry a highlighted snippet.
     pub async fn search_transcripts(
         pool: &SqlitePool,
         query: &str,
+        filters: &TranscriptSearchFilters,
     ) -> Result<Vec<TranscriptSearchResult>, SqlxError> {
-        if query.trim().is_empty() {
-            return Ok(Vec::new());
+        let match_query = match build_match_query(query) {
+            Some(match_query) => match_query,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut conditions = vec!["search_index MATCH ?".to_string()];
+        let mut binds = vec![match_query];
+
+        if let Some(date_from) = &filters.date_from {
+            conditions.push("substr(m.created_at, 1, 10) >= ?".to_string());
+            binds.push(date_from.clone());
+        }
+        if let Some(date_to) = &filters.date_to {
+            conditions.push("substr(m.created_at, 1, 10) <= ?".to_string());
+            binds.push(date_to.clone());
+        }
+        if let Some(speaker) = &filters.speaker {
+            conditions.push("d.speaker = ?".to_string());
+            binds.push(speaker.clone());
+        }
+        if let Some(meeting_id) = &filters.meeting_id {
+            conditions.push("d.meeting_id = ?".to_string());
+            binds.push(meeting_id.clone());
+        }
+        if let Some(source_types) = filters.source_types.as_ref().filter(|t| !t.is_empty()) {
+            conditions.push(format!(
+                "d.source_type IN ({})",
+                vec!["?"; source_types.len()].join(", ")
+            ));
+            binds.extend(source_types.iter().cloned());
         }
 
-        let search_query = format!("%{}%", query.to_lowercase());
+        let sql = format!(
+            "SELECT d.meeting_id, m.title, d.source_type, d.source_id, d.speaker, d.audio_start_time,
+                    COALESCE(d.timestamp, m.created_at) AS timestamp, m.created_at AS meeting_date,
+                    snippet(search_index, 0, '{}', '{}', '…', 24) AS snippet,
+                    bm25(search_index) AS score
+             FROM search_index
+             JOIN search_documents d ON d.id = search_index.rowid
+             JOIN meetings m ON m.id = d.meeting_id
+             WHERE {}
+             ORDER BY score
+             LIMIT ? OFFSET ?",
+            HIGHLIGHT_START,
+            HIGHLIGHT_END,
+            conditions.join(" AND ")
+        );
 
-        let rows = sqlx::query_as::<_, (String, String, String, String)>(
-            "SELECT m.id, m.title, t.transcript, t.timestamp
-             FROM meetings m
-             JOIN transcripts t ON m.id = t.meeting_id
-             WHERE LOWER(t.transcript) LIKE ?",
-        )
-        .bind(&search_query)
-        .fetch_all(pool)
-        .await?;
+        let mut search = sqlx::query_as::<_, SearchRow>(&sql);
+        for value in &binds {
+            search = search.bind(value);
+        }
+        let rows = search
+            .bind(filters.limit.unwrap_or(DEFAULT_SEARCH_LIMIT).min(MAX_SEARCH_LIMIT) as i64)
+            .bind(filters.offset.unwrap_or(0) as i64)
+            .fetch_all(pool)
+            .await?;
 
-        let results = rows
+        Ok(rows
             .into_iter()
-            .map(|(id, title, transcript, timestamp)| {
-                let match_context = Self::get_match_context(&transcript, query);
-                TranscriptSearchResult {
-                    id,
-                    title,
-                    match_context,
-                    timestamp,
-                }
+            .map(|row| TranscriptSearchResult {
+                id: row.meeting_id,
+                title: row.title,
+                match_context: row
+                    .snippet
+                    .replace(HIGHLIGHT_START, "")
+                    .replace(HIGHLIGHT_END, ""),
+                timestamp: row.timestamp,
+                snippet: row.snippet,
+                source_type: row.source_type,
+                source_id: row.source_id,
+                speaker: row.speaker,
+                audio_start_time: row.audio_start_time,
+                meeting_date: row.meeting_date,
+                rank: row.score,
             })
-            .collect();
-
-        Ok(results)
+            .collect())
     }
 
-    /// Helper function to extract a snippet of text around the first match of a query.
-    fn get_match_context(transcript: &str, query: &str) -> String {
-        let transcript_lower = transcript.to_lowercase();
-        let query_lower = query.to_lowercase();
-
-        match transcript_lower.find(&query_lower) {
-            Some(match_index) => {
-                let start_index = match_index.saturating_sub(100);
-                let end_index = (match_index + query.len() + 100).min(transcript.len());
-
-                let mut context = String::new();
-                if start_index > 0 {
-                    context.push_str("...");
-                }
-                context.push_str(&transcript[start_index..end_index]);
-                if end_index < transcript.len() {
-                    context.push_str("...");
-                }
-                context
-            }
-            None => transcript.chars().take(200).collect(), // Fallback to the start of the transcript
+    /// Returns `(id, audio_start_time, audio_end_time)` for the given transcript ids
+    /// of a meeting, ordered by their position in the recording.
+    pub async fn get_segment_time_ranges(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        segment_ids: &[String],
+    ) -> Result<Vec<(String, Option<f64>, Option<f64>)>, SqlxError> {
+        if segment_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = vec!["?"; segment_ids.len()].join(", ");
+        let sql = format!(
+            "SELECT id, audio_start_time, audio_end_time
+             FROM transcripts
+             WHERE meeting_id = ? AND id IN ({})
+             ORDER BY audio_start_time ASC",
+            placeholders
+        );
+
+        let mut query = sqlx::query_as::<_, (String, Option<f64>, Option<f64>)>(&sql).bind(meeting_id);
+        for id in segment_ids {
+            query = query.bind(id);
         }
+
+        query.fetch_all(pool).await
+    }
+}
+
+/// Turns free text into an FTS5 query: each word quoted (so punctuation and FTS
+/// operators in user input are literal), all required, the last one as a prefix.
+fn build_match_query(query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| term.replace('"', ""))
+        .filter(|term| term.chars().any(char::is_alphanumeric))
+        .map(|term| format!("\"{}\"", term))
+        .collect();
+
+    if terms.is_empty() {
+        return None;
+    }
+    Some(format!("{}*", terms.join(" ")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_match_query_quotes_terms() {
+        assert_eq!(
+            build_match_query("precio  reunión"),
+            Some("\"precio\" \"reunión\"*".to_string())
+        );
+        assert_eq!(
+            build_match_query("\"NOT\" a-b OR"),
+            Some("\"NOT\" \"a-b\" \"OR\"*".to_string())
+        );
+        assert_eq!(build_match_query("  \" - "), None);
     }
 }
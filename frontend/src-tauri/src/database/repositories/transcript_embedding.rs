@@ -0,0 +1,109 @@
+use crate::database::models::TranscriptEmbedding;
+use crate::summary::meeting_qa::SpeakerTurn;
+use chrono::Utc;
+use log::info as log_info;
+use sqlx::SqlitePool;
+use uuid::Uuid;
+
+pub struct TranscriptEmbeddingsRepository;
+
+impl TranscriptEmbeddingsRepository {
+    /// Meetings with transcripts, each with the transcript hash its `embedding_model` vectors
+    /// were built from (`None` when the meeting is not indexed for that model).
+    pub async fn get_index_candidates(
+        pool: &SqlitePool,
+        embedding_model: &str,
+    ) -> Result<Vec<(String, Option<String>)>, sqlx::Error> {
+        sqlx::query_as(
+            "SELECT m.id,
+                    (SELECT e.transcript_hash FROM transcript_embeddings e
+                     WHERE e.meeting_id = m.id AND e.embedding_model = ?
+                     LIMIT 1) AS transcript_hash
+             FROM meetings m
+             WHERE EXISTS (SELECT 1 FROM transcripts t WHERE t.meeting_id = m.id)
+             ORDER BY m.created_at DESC",
+        )
+        .bind(embedding_model)
+        .fetch_all(pool)
+        .await
+    }
+
+    /// Stores the speaker turns of a meeting with their vectors and the hash of the transcript
+    /// they came from, replacing any previous index (including vectors of other models).
+    pub async fn replace_meeting_embeddings(
+        pool: &SqlitePool,
+        meeting_id: &str,
+        embedding_model: &str,
+        transcript_hash: &str,
+        chunks: &[(SpeakerTurn, Vec<f32>)],
+    ) -> Result<(), sqlx::Error> {
+        let mut transaction = pool.begin().await?;
+
+        sqlx::query("DELETE FROM transcript_embeddings WHERE meeting_id = ?")
+            .bind(meeting_id)
+            .execute(&mut *transaction)
+            .await?;
+
+        let now = Utc::now().to_rfc3339();
+        for (index, (turn, embedding)) in chunks.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO transcript_embeddings (id, meeting_id, chunk_index, speaker, text,
+                     audio_start_time, audio_end_time, embedding, embedding_model, transcript_hash,
+                     created_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(format!("emb_{}", Uuid::new_v4()))
+            .bind(meeting_id)
+            .bind(index as i64)
+            .bind(&turn.speaker)
+            .bind(&turn.text)
+            .bind(turn.audio_start_time)
+            .bind(turn.audio_end_time)
+            .bind(encode_embedding(embedding))
+            .bind(embedding_model)
+            .bind(transcript_hash)
+            .bind(&now)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+        log_info!(
+            "Saved {} transcript embeddings for meeting_id: {}",
+            chunks.len(),
+            meeting_id
+        );
+        Ok(())
+    }
+
+    /// All indexed chunks of `embedding_model`, with the title of their meeting.
+    pub async fn list_for_model(
+        pool: &SqlitePool,
+        embedding_model: &str,
+    ) -> Result<Vec<TranscriptEmbedding>, sqlx::Error> {
+        sqlx::query_as::<_, TranscriptEmbedding>(
+            "SELECT e.meeting_id, m.title AS meeting_title, e.speaker, e.text,
+                    e.audio_start_time, e.audio_end_time, e.embedding
+             FROM transcript_embeddings e
+             JOIN meetings m ON m.id = e.meeting_id
+             WHERE e.embedding_model = ?
+             ORDER BY e.meeting_id, e.chunk_index",
+        )
+        .bind(embedding_model)
+        .fetch_all(pool)
+        .await
+    }
+}
+
+/// Serializes a vector as little-endian f32 bytes
+pub fn encode_embedding(embedding: &[f32]) -> Vec<u8> {
+    embedding.iter().flat_map(|value| value.to_le_bytes()).collect()
+}
+
+/// Inverse of [`encode_embedding`]
+pub fn decode_embedding(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
@@ -49,6 +49,7 @@ pub mod onboarding;
 pub mod openrouter;
 pub mod parakeet_engine;
 pub mod moonshine_engine;
+pub mod shortcuts;
 pub mod state;
 pub mod summary;
 pub mod tray;
@@ -413,6 +414,15 @@ pub fn run() {
         .plugin(tauri_plugin_updater::Builder::new().build())
         .plugin(tauri_plugin_process::init())
         .plugin(tauri_plugin_deep_link::init())
+        .plugin(
+            tauri_plugin_global_shortcut::Builder::new()
+                .with_handler(|app, shortcut, event| {
+                    if event.state() == tauri_plugin_global_shortcut::ShortcutState::Pressed {
+                        shortcuts::handle_shortcut(app, shortcut);
+                    }
+                })
+                .build(),
+        )
         .plugin(tauri_plugin_single_instance::init(|app, argv, _cwd| {
             // On Windows/Linux, deep-link URLs arrive as CLI args on a new instance.
             // The single-instance plugin (with deep-link feature) forwards them here.
@@ -478,6 +488,9 @@ pub fn run() {
                         log::error!("Failed to initialize notification manager: {}", e);
                     }
                 }
+
+                // Register the saved global shortcuts (after notifications so conflicts can be shown)
+                shortcuts::initialize_shortcuts(&app_for_notif).await;
             });
 
             // Initialize database FIRST (handles first launch detection and conditional setup)
@@ -718,6 +731,8 @@ pub fn run() {
             audio::recording_commands::get_meeting_folder_path,
             // Reload sync commands (retrieve transcript history and meeting name)
             audio::recording_commands::get_transcript_history,
+            audio::recording_commands::add_recording_bookmark,
+            audio::recording_commands::get_recording_bookmarks,
             audio::recording_commands::get_recording_meeting_name,
             // Device monitoring commands (AirPods/Bluetooth disconnect/reconnect)
             audio::recording_commands::poll_audio_device_events,
@@ -729,6 +744,10 @@ pub fn run() {
             audio::incremental_saver::recover_audio_from_checkpoints,
             audio::incremental_saver::cleanup_checkpoints,
             audio::incremental_saver::has_audio_checkpoints,
+            // Journal-based meeting recovery (audio + transcripts + metadata + DB rows)
+            audio::recording_journal::list_interrupted_recordings,
+            audio::recording_journal::recover_meeting_from_journal,
+            audio::clip_extractor::extract_audio_clip,
             console_utils::show_console,
             console_utils::hide_console,
             console_utils::toggle_console,
@@ -752,6 +771,7 @@ pub fn run() {
             api::api_delete_meeting,
             api::api_get_meeting,
             api::api_get_meeting_metadata,
+            api::api_get_meeting_bookmarks,
             api::api_get_meeting_transcripts,
             api::api_save_meeting_title,
             api::api_save_transcript,
@@ -763,15 +783,34 @@ pub fn run() {
             api::api_save_custom_openai_config,
             api::api_get_custom_openai_config,
             api::api_test_custom_openai_connection,
+            // Summary fallback chain commands
+            api::api_save_summary_fallback_chain,
+            api::api_get_summary_fallback_chain,
             // Summary commands
             summary::api_process_transcript,
             summary::api_get_summary,
             summary::api_save_meeting_summary,
             summary::api_cancel_summary,
+            summary::api_get_conversation_metrics,
+            // Action item commands
+            summary::api_list_action_items,
+            summary::api_update_action_item,
+            // Summary version history commands
+            summary::api_list_summary_versions,
+            summary::api_diff_summary_versions,
+            summary::api_restore_summary_version,
+            // Live summary commands
+            summary::api_get_live_summary,
+            summary::api_cancel_live_summary,
+            // Meeting Q&A commands
+            summary::api_ask_meetings,
+            summary::api_index_meetings_for_qa,
             // Template commands
             summary::api_list_templates,
             summary::api_get_template_details,
             summary::api_validate_template,
+            summary::api_import_template,
+            summary::api_export_template,
             // Built-in AI commands
             summary::summary_engine::builtin_ai_list_models,
             summary::summary_engine::builtin_ai_get_model_info,
@@ -782,8 +821,18 @@ pub fn run() {
             summary::summary_engine::builtin_ai_get_available_summary_model,
             summary::summary_engine::builtin_ai_get_recommended_model,
             openrouter::get_openrouter_models,
+            summary::api_get_gemini_models,
             audio::recording_preferences::get_recording_preferences,
             audio::recording_preferences::set_recording_preferences,
+            audio::recording_preferences::get_vad_settings,
+            audio::recording_preferences::set_vad_settings,
+            audio::recording_preferences::get_follow_default_devices,
+            audio::recording_preferences::set_follow_default_devices,
+            audio::recording_preferences::get_auto_recording_rules,
+            audio::recording_preferences::set_auto_recording_rules,
+            audio::auto_rules::continue_recording_after_auto_rule,
+            audio::recording_preferences::get_live_summary_settings,
+            audio::recording_preferences::set_live_summary_settings,
             audio::recording_preferences::get_default_recordings_folder_path,
             audio::recording_preferences::open_recordings_folder,
             audio::recording_preferences::select_recording_folder,
@@ -794,6 +843,10 @@ pub fn run() {
             // Language preference commands
             get_language_preference,
             set_language_preference,
+            // Global shortcut commands
+            shortcuts::get_global_shortcuts,
+            shortcuts::set_global_shortcuts,
+            shortcuts::check_global_shortcut_conflicts,
             // Notification system commands
             notifications::commands::get_notification_settings,
             notifications::commands::set_notification_settings,
@@ -430,6 +430,49 @@ pub async fn show_recording_resumed_notification(
     }
 }
 
+/// Show pending auto-stop / auto-pause notification (internal use)
+pub async fn show_auto_rule_pending_notification<R: Runtime>(
+    manager_state: &NotificationManagerState<R>,
+    message: String,
+    grace_period_seconds: u32,
+) -> Result<()> {
+    let manager_lock = manager_state.read().await;
+    if let Some(manager) = manager_lock.as_ref() {
+        manager.show_auto_rule_pending(message, grace_period_seconds as u64).await
+    } else {
+        log_error!("Cannot show auto rule notification: manager not initialized");
+        Ok(())
+    }
+}
+
+/// Show global shortcut feedback notification (internal use)
+pub async fn show_shortcut_feedback_notification<R: Runtime>(
+    manager_state: &NotificationManagerState<R>,
+    message: String,
+) -> Result<()> {
+    let manager_lock = manager_state.read().await;
+    if let Some(manager) = manager_lock.as_ref() {
+        manager.show_shortcut_feedback(message).await
+    } else {
+        log_error!("Cannot show shortcut feedback notification: manager not initialized");
+        Ok(())
+    }
+}
+
+/// Show live summary update notification (internal use)
+pub async fn show_live_summary_notification<R: Runtime>(
+    manager_state: &NotificationManagerState<R>,
+    message: String,
+) -> Result<()> {
+    let manager_lock = manager_state.read().await;
+    if let Some(manager) = manager_lock.as_ref() {
+        manager.show_live_summary_updated(message).await
+    } else {
+        log_error!("Cannot show live summary notification: manager not initialized");
+        Ok(())
+    }
+}
+
 /// Show transcription complete notification (internal use)
 pub async fn show_transcription_complete_notification(
     manager_state: &NotificationManagerState<Wry>,
@@ -172,6 +172,24 @@ impl<R: Runtime> NotificationManager<R> {
         self.show_notification(notification).await
     }
 
+    /// Show a pending auto-stop / auto-pause notification (the in-app toast offers "continue")
+    pub async fn show_auto_rule_pending(&self, message: String, grace_period_seconds: u64) -> Result<()> {
+        let notification = Notification::auto_rule_pending(message, grace_period_seconds);
+        self.show_notification(notification).await
+    }
+
+    /// Show a short confirmation for an action triggered by a global shortcut
+    pub async fn show_shortcut_feedback(&self, message: String) -> Result<()> {
+        let notification = Notification::shortcut_feedback(message);
+        self.show_notification(notification).await
+    }
+
+    /// Show that the rolling summary of the current recording was refreshed
+    pub async fn show_live_summary_updated(&self, message: String) -> Result<()> {
+        let notification = Notification::live_summary_updated(message);
+        self.show_notification(notification).await
+    }
+
     /// Show a system error notification
     pub async fn show_system_error(&self, error: String) -> Result<()> {
         let settings = self.settings.read().await;
@@ -300,6 +318,9 @@ impl<R: Runtime> NotificationManager<R> {
             NotificationType::TranscriptionComplete => settings.notification_preferences.show_transcription_complete,
             NotificationType::MeetingReminder(_) => settings.notification_preferences.show_meeting_reminders,
             NotificationType::SystemError(_) => settings.notification_preferences.show_system_errors,
+            NotificationType::RecordingAutoRule(_) => true, // The user must get a chance to keep recording
+            NotificationType::ShortcutFeedback(_) => true, // Only feedback while the app window may be hidden
+            NotificationType::LiveSummaryUpdated(_) => true, // Opt-in through the live summary settings
             NotificationType::Test => true, // Always show test notifications
         }
     }
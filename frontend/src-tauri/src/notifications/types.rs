@@ -21,6 +21,9 @@ pub enum NotificationType {
     RecordingResumed,
     TranscriptionComplete,
     MeetingReminder(u64), // Duration in minutes
+    RecordingAutoRule(String), // Message of the pending auto-stop / auto-pause rule
+    ShortcutFeedback(String), // Confirmation of an action triggered by a global shortcut
+    LiveSummaryUpdated(String), // The rolling summary of the current recording was refreshed
     SystemError(String),
     Test, // For testing notifications
 }
@@ -176,6 +179,30 @@ impl Notification {
             .with_timeout(NotificationTimeout::Seconds(10))
     }
 
+    /// System notifications show no buttons, so the body points to the "Keep Recording"
+    /// tray item, which works even when the main window is hidden
+    pub fn auto_rule_pending(message: impl Into<String>, grace_period_seconds: u64) -> Self {
+        let message = message.into();
+        let body = format!("{} Para seguir grabando, elige \"Keep Recording\" en el menú de la bandeja.", message);
+        Notification::new("Maity", body, NotificationType::RecordingAutoRule(message))
+            .with_priority(NotificationPriority::High)
+            .with_timeout(NotificationTimeout::Seconds(grace_period_seconds))
+    }
+
+    pub fn shortcut_feedback(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Notification::new("Maity", message.clone(), NotificationType::ShortcutFeedback(message))
+            .with_priority(NotificationPriority::Low)
+            .with_timeout(NotificationTimeout::Seconds(2))
+    }
+
+    pub fn live_summary_updated(message: impl Into<String>) -> Self {
+        let message = message.into();
+        Notification::new("Maity", message.clone(), NotificationType::LiveSummaryUpdated(message))
+            .with_priority(NotificationPriority::Low)
+            .with_timeout(NotificationTimeout::Seconds(5))
+    }
+
     pub fn system_error(error: impl Into<String>) -> Self {
         let error_string = error.into();
         Notification::new(
@@ -15,8 +15,8 @@ static DOWNLOADING_MODELS: Lazy<Arc<RwLock<HashSet<String>>>> = Lazy::new(|| {
     Arc::new(RwLock::new(HashSet::new()))
 });
 
-// Global cache for model metadata (5 minute TTL)
-static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
+// Global cache for model metadata (5 minute TTL), shared with the summary provider
+pub(crate) static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
     ModelMetadataCache::new(Duration::from_secs(300))
 });
 
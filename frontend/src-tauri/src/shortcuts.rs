@@ -0,0 +1,413 @@
+// shortcuts.rs
+//
+// Configurable global keyboard shortcuts for recording control.
+//
+// Shortcuts work while the app window is hidden or another app is focused (e.g. during
+// a screen-shared call). Each action is routed to the same handler the tray menu uses,
+// and a short notification confirms what happened.
+
+use anyhow::Result;
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+use std::sync::RwLock;
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut};
+use tauri_plugin_store::StoreExt;
+
+use crate::notifications::commands::NotificationManagerState;
+
+/// Store file holding the shortcut settings
+const SHORTCUTS_STORE: &str = "global-shortcuts.json";
+
+/// Recording action that can be bound to a global shortcut
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutAction {
+    ToggleRecording,
+    PauseResume,
+    StopRecording,
+    Bookmark,
+}
+
+impl ShortcutAction {
+    pub const ALL: [ShortcutAction; 4] = [
+        ShortcutAction::ToggleRecording,
+        ShortcutAction::PauseResume,
+        ShortcutAction::StopRecording,
+        ShortcutAction::Bookmark,
+    ];
+
+    fn display_name(&self) -> &'static str {
+        match self {
+            ShortcutAction::ToggleRecording => "Iniciar/detener grabación",
+            ShortcutAction::PauseResume => "Pausar/reanudar",
+            ShortcutAction::StopRecording => "Detener grabación",
+            ShortcutAction::Bookmark => "Marcar momento",
+        }
+    }
+}
+
+/// Global shortcut bindings (accelerators like "CmdOrCtrl+Alt+R"), opt-in
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GlobalShortcutSettings {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub toggle_recording: Option<String>,
+    #[serde(default)]
+    pub pause_resume: Option<String>,
+    #[serde(default)]
+    pub stop_recording: Option<String>,
+    #[serde(default)]
+    pub bookmark: Option<String>,
+}
+
+impl Default for GlobalShortcutSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            toggle_recording: Some("CmdOrCtrl+Alt+Shift+R".to_string()),
+            pause_resume: Some("CmdOrCtrl+Alt+Shift+P".to_string()),
+            stop_recording: Some("CmdOrCtrl+Alt+Shift+S".to_string()),
+            bookmark: Some("CmdOrCtrl+Alt+Shift+B".to_string()),
+        }
+    }
+}
+
+impl GlobalShortcutSettings {
+    /// Accelerator bound to an action (None when unbound or blank)
+    pub fn binding(&self, action: ShortcutAction) -> Option<&str> {
+        let binding = match action {
+            ShortcutAction::ToggleRecording => &self.toggle_recording,
+            ShortcutAction::PauseResume => &self.pause_resume,
+            ShortcutAction::StopRecording => &self.stop_recording,
+            ShortcutAction::Bookmark => &self.bookmark,
+        };
+        binding.as_deref().map(str::trim).filter(|b| !b.is_empty())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ShortcutConflictReason {
+    /// The accelerator could not be parsed
+    Invalid,
+    /// Another action already uses the same key combination
+    Duplicate,
+    /// The OS refused the registration (usually taken by another app)
+    InUse,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutConflict {
+    pub action: ShortcutAction,
+    pub accelerator: String,
+    pub reason: ShortcutConflictReason,
+    /// Action already bound to the same combination (for `Duplicate`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflicting_action: Option<ShortcutAction>,
+}
+
+/// Result of (re)registering the shortcuts with the OS
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ShortcutRegistrationReport {
+    pub registered: Vec<ShortcutAction>,
+    pub conflicts: Vec<ShortcutConflict>,
+}
+
+/// Shortcuts currently registered by the app, used to route key presses
+static REGISTERED_SHORTCUTS: Lazy<RwLock<Vec<(Shortcut, ShortcutAction)>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Parse the bindings, returning the valid ones and the invalid / duplicate conflicts
+fn parse_bindings(settings: &GlobalShortcutSettings) -> (Vec<(Shortcut, ShortcutAction, String)>, Vec<ShortcutConflict>) {
+    let mut parsed: Vec<(Shortcut, ShortcutAction, String)> = Vec::new();
+    let mut conflicts = Vec::new();
+
+    for action in ShortcutAction::ALL {
+        let Some(accelerator) = settings.binding(action) else {
+            continue;
+        };
+
+        let shortcut = match Shortcut::from_str(accelerator) {
+            Ok(shortcut) => shortcut,
+            Err(_) => {
+                conflicts.push(ShortcutConflict {
+                    action,
+                    accelerator: accelerator.to_string(),
+                    reason: ShortcutConflictReason::Invalid,
+                    conflicting_action: None,
+                });
+                continue;
+            }
+        };
+
+        // Compare parsed shortcuts so "Shift+Ctrl+B" and "ctrl+shift+b" collide
+        if let Some((_, existing, _)) = parsed.iter().find(|(s, _, _)| *s == shortcut) {
+            conflicts.push(ShortcutConflict {
+                action,
+                accelerator: accelerator.to_string(),
+                reason: ShortcutConflictReason::Duplicate,
+                conflicting_action: Some(*existing),
+            });
+            continue;
+        }
+
+        parsed.push((shortcut, action, accelerator.to_string()));
+    }
+
+    (parsed, conflicts)
+}
+
+/// Invalid or duplicate bindings (does not query the OS)
+pub fn find_conflicts(settings: &GlobalShortcutSettings) -> Vec<ShortcutConflict> {
+    parse_bindings(settings).1
+}
+
+/// Replace the registered shortcuts with the given settings
+pub fn register_shortcuts<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &GlobalShortcutSettings,
+) -> ShortcutRegistrationReport {
+    let global_shortcut = app.global_shortcut();
+    let mut registered = match REGISTERED_SHORTCUTS.write() {
+        Ok(guard) => guard,
+        Err(e) => {
+            error!("Global shortcut registry lock poisoned: {}", e);
+            return ShortcutRegistrationReport::default();
+        }
+    };
+
+    for (shortcut, action) in registered.drain(..) {
+        if let Err(e) = global_shortcut.unregister(shortcut) {
+            warn!("Failed to unregister shortcut for {:?}: {}", action, e);
+        }
+    }
+
+    let mut report = ShortcutRegistrationReport::default();
+    if !settings.enabled {
+        info!("Global shortcuts disabled");
+        return report;
+    }
+
+    let (parsed, conflicts) = parse_bindings(settings);
+    report.conflicts = conflicts;
+
+    for (shortcut, action, accelerator) in parsed {
+        match global_shortcut.register(shortcut) {
+            Ok(()) => {
+                info!("Registered global shortcut {} for {:?}", accelerator, action);
+                registered.push((shortcut, action));
+                report.registered.push(action);
+            }
+            Err(e) => {
+                warn!("Global shortcut {} for {:?} is not available: {}", accelerator, action, e);
+                report.conflicts.push(ShortcutConflict {
+                    action,
+                    accelerator,
+                    reason: ShortcutConflictReason::InUse,
+                    conflicting_action: None,
+                });
+            }
+        }
+    }
+
+    report
+}
+
+/// Dispatch a pressed global shortcut to its recording action
+pub fn handle_shortcut<R: Runtime>(app: &AppHandle<R>, shortcut: &Shortcut) {
+    let action = match REGISTERED_SHORTCUTS.read() {
+        Ok(registered) => registered.iter().find(|(s, _)| s == shortcut).map(|(_, a)| *a),
+        Err(_) => None,
+    };
+    let Some(action) = action else {
+        return;
+    };
+
+    info!("Global shortcut pressed: {:?}", action);
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let is_recording = crate::audio::recording_commands::is_recording().await;
+
+        // Start / stop already show their own recording notifications
+        let feedback = match action {
+            ShortcutAction::ToggleRecording => {
+                crate::tray::toggle_recording_handler(&app_clone);
+                None
+            }
+            _ if !is_recording => Some("No hay ninguna grabación activa".to_string()),
+            ShortcutAction::PauseResume => {
+                if crate::audio::recording_commands::is_recording_paused().await {
+                    crate::tray::resume_recording_handler(&app_clone);
+                    Some("Grabación reanudada".to_string())
+                } else {
+                    crate::tray::pause_recording_handler(&app_clone);
+                    Some("Grabación pausada".to_string())
+                }
+            }
+            ShortcutAction::StopRecording => {
+                crate::tray::stop_recording_handler(&app_clone);
+                None
+            }
+            ShortcutAction::Bookmark => {
+                crate::tray::bookmark_handler(&app_clone, "important");
+                Some("Momento marcado como importante".to_string())
+            }
+        };
+
+        if let Some(message) = feedback {
+            show_feedback(&app_clone, message).await;
+        }
+    });
+}
+
+async fn show_feedback<R: Runtime>(app: &AppHandle<R>, message: String) {
+    if let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() {
+        if let Err(e) =
+            crate::notifications::commands::show_shortcut_feedback_notification(&manager_state, message).await
+        {
+            warn!("Failed to show shortcut feedback notification: {}", e);
+        }
+    }
+}
+
+/// Load shortcut settings from store
+pub async fn load_shortcut_settings<R: Runtime>(app: &AppHandle<R>) -> Result<GlobalShortcutSettings> {
+    let store = match app.store(SHORTCUTS_STORE) {
+        Ok(store) => store,
+        Err(e) => {
+            warn!("Failed to access shortcuts store: {}, using defaults", e);
+            return Ok(GlobalShortcutSettings::default());
+        }
+    };
+
+    let settings = match store.get("settings") {
+        Some(value) => serde_json::from_value::<GlobalShortcutSettings>(value).unwrap_or_else(|e| {
+            warn!("Failed to deserialize shortcut settings: {}, using defaults", e);
+            GlobalShortcutSettings::default()
+        }),
+        None => GlobalShortcutSettings::default(),
+    };
+
+    Ok(settings)
+}
+
+/// Save shortcut settings to store
+pub async fn save_shortcut_settings<R: Runtime>(
+    app: &AppHandle<R>,
+    settings: &GlobalShortcutSettings,
+) -> Result<()> {
+    let store = app
+        .store(SHORTCUTS_STORE)
+        .map_err(|e| anyhow::anyhow!("Failed to access store: {}", e))?;
+
+    store.set("settings", serde_json::to_value(settings)?);
+    store
+        .save()
+        .map_err(|e| anyhow::anyhow!("Failed to save store to disk: {}", e))?;
+
+    info!("Successfully persisted global shortcut settings to disk");
+    Ok(())
+}
+
+/// Register the saved shortcuts at startup
+pub async fn initialize_shortcuts<R: Runtime>(app: &AppHandle<R>) {
+    let settings = match load_shortcut_settings(app).await {
+        Ok(settings) => settings,
+        Err(e) => {
+            error!("Failed to load global shortcut settings: {}", e);
+            return;
+        }
+    };
+
+    let report = register_shortcuts(app, &settings);
+    if let Some(conflict) = report.conflicts.first() {
+        show_feedback(
+            app,
+            format!(
+                "El atajo {} ({}) no está disponible",
+                conflict.accelerator,
+                conflict.action.display_name()
+            ),
+        )
+        .await;
+    }
+}
+
+/// Get the global shortcut settings
+#[tauri::command]
+pub async fn get_global_shortcuts<R: Runtime>(app: AppHandle<R>) -> Result<GlobalShortcutSettings, String> {
+    load_shortcut_settings(&app)
+        .await
+        .map_err(|e| format!("Failed to load global shortcuts: {}", e))
+}
+
+/// Check bindings for invalid or duplicate accelerators before saving
+#[tauri::command]
+pub async fn check_global_shortcut_conflicts(settings: GlobalShortcutSettings) -> Vec<ShortcutConflict> {
+    find_conflicts(&settings)
+}
+
+/// Save and apply the global shortcut settings
+/// Invalid or duplicate bindings are rejected; bindings taken by another app are reported
+#[tauri::command]
+pub async fn set_global_shortcuts<R: Runtime>(
+    app: AppHandle<R>,
+    settings: GlobalShortcutSettings,
+) -> Result<ShortcutRegistrationReport, String> {
+    if let Some(conflict) = find_conflicts(&settings).first() {
+        return Err(match conflict.reason {
+            ShortcutConflictReason::Duplicate => format!(
+                "Shortcut {} is assigned to more than one action",
+                conflict.accelerator
+            ),
+            _ => format!("Invalid shortcut: {}", conflict.accelerator),
+        });
+    }
+
+    save_shortcut_settings(&app, &settings)
+        .await
+        .map_err(|e| format!("Failed to save global shortcuts: {}", e))?;
+
+    Ok(register_shortcuts(&app, &settings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings(toggle: &str, pause: &str, stop: &str, bookmark: &str) -> GlobalShortcutSettings {
+        GlobalShortcutSettings {
+            enabled: true,
+            toggle_recording: Some(toggle.to_string()),
+            pause_resume: Some(pause.to_string()),
+            stop_recording: Some(stop.to_string()),
+            bookmark: Some(bookmark.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_default_bindings_have_no_conflicts() {
+        assert!(find_conflicts(&GlobalShortcutSettings::default()).is_empty());
+    }
+
+    #[test]
+    fn test_equivalent_accelerators_are_duplicates() {
+        let conflicts = find_conflicts(&settings("Ctrl+Shift+R", "Ctrl+Shift+P", "", "shift+ctrl+r"));
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].action, ShortcutAction::Bookmark);
+        assert_eq!(conflicts[0].reason, ShortcutConflictReason::Duplicate);
+        assert_eq!(conflicts[0].conflicting_action, Some(ShortcutAction::ToggleRecording));
+    }
+
+    #[test]
+    fn test_invalid_accelerator_is_reported() {
+        let conflicts = find_conflicts(&settings("Ctrl+Shift+R", "Ctrl+NotAKey", "  ", "Ctrl+Shift+B"));
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].action, ShortcutAction::PauseResume);
+        assert_eq!(conflicts[0].reason, ShortcutConflictReason::Invalid);
+    }
+}
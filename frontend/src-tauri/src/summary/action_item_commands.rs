@@ -0,0 +1,81 @@
+use crate::database::models::ActionItem;
+use crate::database::repositories::action_item::{
+    ActionItemFilter, ActionItemUpdate, ActionItemsRepository, ACTION_ITEM_STATUSES,
+};
+use crate::state::AppState;
+use chrono::NaiveDate;
+use log::{error as log_error, info as log_info};
+use tauri::{AppHandle, Runtime};
+
+/// Lists action items across all meetings
+///
+/// Pending items come first, then by due date. Without a filter every item is returned.
+#[tauri::command]
+pub async fn api_list_action_items<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    filter: Option<ActionItemFilter>,
+) -> Result<Vec<ActionItem>, String> {
+    let filter = filter.unwrap_or_default();
+    log_info!("api_list_action_items called with filter: {:?}", filter);
+
+    if let Some(statuses) = &filter.status {
+        for status in statuses {
+            validate_status(status)?;
+        }
+    }
+
+    ActionItemsRepository::list(state.db_manager.pool(), &filter)
+        .await
+        .map_err(|e| {
+            log_error!("Error listing action items: {}", e);
+            format!("Failed to list action items: {}", e)
+        })
+}
+
+/// Updates the description, owner, due date or status of an action item
+///
+/// Fields left out are kept; an empty owner or due date clears it.
+#[tauri::command]
+pub async fn api_update_action_item<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    id: String,
+    update: ActionItemUpdate,
+) -> Result<ActionItem, String> {
+    log_info!("api_update_action_item called for id: {}", id);
+
+    if let Some(status) = &update.status {
+        validate_status(status)?;
+    }
+    if let Some(description) = &update.description {
+        if description.trim().is_empty() {
+            return Err("Action item description cannot be empty".to_string());
+        }
+    }
+    if let Some(due_date) = update.due_date.as_deref().map(str::trim).filter(|d| !d.is_empty()) {
+        NaiveDate::parse_from_str(due_date, "%Y-%m-%d")
+            .map_err(|_| format!("Invalid due date '{}': expected YYYY-MM-DD", due_date))?;
+    }
+
+    match ActionItemsRepository::update(state.db_manager.pool(), &id, &update).await {
+        Ok(Some(item)) => Ok(item),
+        Ok(None) => Err(format!("Action item not found: {}", id)),
+        Err(e) => {
+            log_error!("Failed to update action item {}: {}", id, e);
+            Err(format!("Failed to update action item: {}", e))
+        }
+    }
+}
+
+fn validate_status(status: &str) -> Result<(), String> {
+    if ACTION_ITEM_STATUSES.contains(&status) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid action item status '{}'. Must be one of: {}",
+            status,
+            ACTION_ITEM_STATUSES.join(", ")
+        ))
+    }
+}
@@ -0,0 +1,371 @@
+use crate::api::MeetingTranscript;
+use crate::database::models::ActionItem;
+use crate::summary::llm_client::OutputSchema;
+use crate::summary::providers::{LlmProvider, LlmRequest, TokenCounter};
+use crate::summary::structured::extract_json_object;
+use chrono::NaiveDate;
+use reqwest::Client;
+use serde::Deserialize;
+use tracing::info;
+
+/// System prompt for action item extraction
+const EXTRACTION_SYSTEM_PROMPT: &str = r#"Eres un asistente que extrae tareas pendientes (action items) de transcripciones de reuniones.
+
+REGLAS:
+- Extrae solo compromisos explícitos: alguien se compromete o recibe el encargo de hacer algo concreto.
+- `description`: la tarea en una oración, empezando con un verbo.
+- `owner`: nombre del responsable tal como se menciona; si nadie es nombrado, el hablante que se compromete ("user" o "interlocutor"); "" si no está claro.
+- `due_date`: fecha límite en formato YYYY-MM-DD solo si se menciona (resuelve fechas relativas con la fecha de la reunión); "" si no hay.
+- `segment`: identificador del segmento donde se acuerda la tarea (por ejemplo "S12"); "" si no está claro.
+- No dupliques tareas ni inventes información. Si no hay tareas, devuelve una lista vacía.
+
+Responde ÚNICAMENTE con el JSON: {"action_items": [{"description": "...", "owner": "...", "due_date": "...", "segment": "..."}]}"#;
+
+/// GBNF equivalent of [`extraction_schema`] for the built-in model
+const EXTRACTION_GRAMMAR: &str = r#"root ::= "{" ws "\"action_items\"" ws ":" ws "[" ws ( item ( "," ws item )* )? "]" ws "}" ws
+item ::= "{" ws "\"description\"" ws ":" ws string "," ws "\"owner\"" ws ":" ws string "," ws "\"due_date\"" ws ":" ws string "," ws "\"segment\"" ws ":" ws string "}" ws
+string ::= "\"" ( [^"\\\x7F\x00-\x1F] | "\\" ( ["\\/bfnrt] | "u" [0-9a-fA-F] [0-9a-fA-F] [0-9a-fA-F] [0-9a-fA-F] ) )* "\"" ws
+ws ::= ( [ \t\n] ws )?
+"#;
+
+/// An action item found by the extraction pass, linked to its transcript segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtractedActionItem {
+    pub description: String,
+    pub owner: Option<String>,
+    pub due_date: Option<String>,
+    pub source_segment_id: Option<String>,
+    pub source_timestamp: Option<f64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ExtractionResponse {
+    #[serde(default)]
+    action_items: Vec<RawActionItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawActionItem {
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    owner: String,
+    #[serde(default)]
+    due_date: String,
+    #[serde(default)]
+    segment: String,
+}
+
+fn extraction_schema() -> OutputSchema {
+    let string = serde_json::json!({ "type": "string" });
+    OutputSchema {
+        name: "action_items".to_string(),
+        schema: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "action_items": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "description": string,
+                            "owner": string,
+                            "due_date": string,
+                            "segment": string,
+                        },
+                        "required": ["description", "owner", "due_date", "segment"],
+                        "additionalProperties": false,
+                    },
+                },
+            },
+            "required": ["action_items"],
+            "additionalProperties": false,
+        }),
+        grammar: EXTRACTION_GRAMMAR.to_string(),
+    }
+}
+
+/// Extracts action items from the transcript segments of a meeting
+///
+/// Segments are sent as `[S<n>] [HH:MM:SS] speaker: text` lines, in batches that fit the
+/// model's context, so every item can point back to the segment it came from.
+///
+/// # Arguments
+/// * `client` - Reqwest HTTP client
+/// * `provider` - Configured LLM provider and model
+/// * `segments` - Transcript segments of the meeting in recording order
+/// * `meeting_date` - Date of the meeting (YYYY-MM-DD), used to resolve relative due dates
+/// * `token_threshold` - Token budget for one extraction request
+/// * `token_counter` - Tokenizer of the provider's model
+///
+/// # Returns
+/// Deduplicated action items in transcript order, or an error if any request fails or any
+/// batch response can't be parsed (the caller then keeps the previous items)
+pub async fn extract_action_items(
+    client: &Client,
+    provider: &dyn LlmProvider,
+    segments: &[MeetingTranscript],
+    meeting_date: &str,
+    token_threshold: usize,
+    token_counter: &TokenCounter,
+) -> Result<Vec<ExtractedActionItem>, String> {
+    let lines: Vec<String> = segments
+        .iter()
+        .enumerate()
+        .map(|(i, segment)| {
+            let time = segment
+                .audio_start_time
+                .map(crate::utils::format_timestamp)
+                .unwrap_or_else(|| "--:--:--".to_string());
+            let speaker = segment.source_type.as_deref().unwrap_or("desconocido");
+            format!("[S{}] [{}] {}: {}", i + 1, time, speaker, segment.text.trim())
+        })
+        .collect();
+
+    // The completion is already reserved; the batch shares the rest with the prompt
+    let budget = token_threshold
+        .saturating_sub(token_counter.count(EXTRACTION_SYSTEM_PROMPT) + 100)
+        .max(500);
+    let batches = batch_lines(&lines, budget, token_counter);
+    info!(
+        "Extracting action items from {} segments in {} batch(es)",
+        segments.len(),
+        batches.len()
+    );
+
+    let output_schema = extraction_schema();
+    let mut items: Vec<ExtractedActionItem> = Vec::new();
+
+    for (i, batch) in batches.iter().enumerate() {
+        let user_prompt = format!(
+            "Fecha de la reunión: {}\n\n<transcripcion>\n{}\n</transcripcion>\n\nResponde ÚNICAMENTE con el JSON de tareas.",
+            meeting_date, batch
+        );
+
+        // Runs after the summary was saved: not cancellable, not streamed
+        let request =
+            LlmRequest::new(EXTRACTION_SYSTEM_PROMPT, &user_prompt).with_output_schema(&output_schema);
+        let response = provider.generate(client, &request).await?;
+
+        // A batch that can't be parsed fails the whole pass: saving a partial (or empty)
+        // list would replace the meeting's items and the statuses the user set on them
+        let batch_items = parse_extraction_response(&response, segments).map_err(|e| {
+            format!("Action item batch {}/{} could not be parsed: {}", i + 1, batches.len(), e)
+        })?;
+        for item in batch_items {
+            let key = item.description.to_lowercase();
+            if !items.iter().any(|existing| existing.description.to_lowercase() == key) {
+                items.push(item);
+            }
+        }
+    }
+
+    info!("Extracted {} action items", items.len());
+    Ok(items)
+}
+
+/// Formats pending items of earlier meetings for the summary prompt, one per line
+pub fn format_pending_items_for_prompt(items: &[ActionItem]) -> String {
+    items
+        .iter()
+        .map(|item| {
+            let mut line = format!("- {}", item.description);
+            if let Some(owner) = &item.owner {
+                line.push_str(&format!(" (responsable: {})", owner));
+            }
+            if let Some(due_date) = &item.due_date {
+                line.push_str(&format!(" (fecha límite: {})", due_date));
+            }
+            line.push_str(&format!(" [desde {}]", item.created_at.get(..10).unwrap_or(&item.created_at)));
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Joins lines into batches of at most `budget_tokens` (a longer single line gets its own batch)
+fn batch_lines(lines: &[String], budget_tokens: usize, token_counter: &TokenCounter) -> Vec<String> {
+    let mut batches = Vec::new();
+    let mut current = String::new();
+    let mut current_tokens = 0;
+
+    for line in lines {
+        let tokens = token_counter.count(line);
+        if !current.is_empty() && current_tokens + tokens > budget_tokens {
+            batches.push(std::mem::take(&mut current));
+            current_tokens = 0;
+        }
+        if !current.is_empty() {
+            current.push('\n');
+        }
+        current.push_str(line);
+        current_tokens += tokens;
+    }
+    if !current.is_empty() {
+        batches.push(current);
+    }
+
+    batches
+}
+
+/// Parses the model response and resolves `S<n>` references to transcript segments
+fn parse_extraction_response(
+    response: &str,
+    segments: &[MeetingTranscript],
+) -> Result<Vec<ExtractedActionItem>, String> {
+    let json = extract_json_object(response)
+        .ok_or_else(|| "Response does not contain a JSON object".to_string())?;
+    let parsed: ExtractionResponse =
+        serde_json::from_str(&json).map_err(|e| format!("Invalid action items JSON: {}", e))?;
+
+    let non_empty = |value: String| {
+        let value = value.trim().to_string();
+        (!value.is_empty()).then_some(value)
+    };
+
+    Ok(parsed
+        .action_items
+        .into_iter()
+        .filter_map(|raw| {
+            let description = non_empty(raw.description)?;
+            let segment = raw
+                .segment
+                .trim()
+                .trim_start_matches(['S', 's'])
+                .parse::<usize>()
+                .ok()
+                .and_then(|n| n.checked_sub(1))
+                .and_then(|index| segments.get(index));
+
+            Some(ExtractedActionItem {
+                description,
+                owner: non_empty(raw.owner),
+                // Only keep well-formed dates so they sort and filter correctly
+                due_date: non_empty(raw.due_date)
+                    .filter(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").is_ok()),
+                source_segment_id: segment.map(|s| s.id.clone()),
+                source_timestamp: segment.and_then(|s| s.audio_start_time),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::{ContextWindow, JsonMode, LlmError, ProviderCapabilities};
+    use async_trait::async_trait;
+
+    fn segment(id: &str, start: f64, text: &str) -> MeetingTranscript {
+        MeetingTranscript {
+            id: id.to_string(),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: None,
+            duration: None,
+            source_type: Some("user".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_parse_resolves_segments_and_dates() {
+        let segments = vec![
+            segment("transcript-a", 0.0, "Hola"),
+            segment("transcript-b", 42.5, "Yo envío el reporte el viernes"),
+        ];
+        let response = r#"```json
+{"action_items": [
+  {"description": "Enviar el reporte", "owner": "user", "due_date": "2026-02-20", "segment": "S2"},
+  {"description": "Revisar presupuesto", "owner": "", "due_date": "el viernes", "segment": "S9"},
+  {"description": "  ", "owner": "Ana", "due_date": "", "segment": ""}
+]}
+```"#;
+
+        let items = parse_extraction_response(response, &segments).unwrap();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].source_segment_id.as_deref(), Some("transcript-b"));
+        assert_eq!(items[0].source_timestamp, Some(42.5));
+        assert_eq!(items[0].due_date.as_deref(), Some("2026-02-20"));
+        assert_eq!(items[1].owner, None);
+        assert_eq!(items[1].due_date, None);
+        assert_eq!(items[1].source_segment_id, None);
+    }
+
+    /// Provider answering every request with the same text
+    struct FixedProvider(&'static str);
+
+    #[async_trait]
+    impl LlmProvider for FixedProvider {
+        fn provider_name(&self) -> &'static str {
+            "Fixed"
+        }
+
+        fn model_name(&self) -> &str {
+            "model"
+        }
+
+        fn capabilities(&self) -> ProviderCapabilities {
+            ProviderCapabilities {
+                streaming: false,
+                json_mode: JsonMode::Unconstrained,
+                local: false,
+                max_concurrency: 1,
+                max_output_tokens: None,
+            }
+        }
+
+        async fn context_window(&self, _client: &Client) -> ContextWindow {
+            ContextWindow { tokens: 8192, source: "test" }
+        }
+
+        async fn generate(&self, _client: &Client, _request: &LlmRequest<'_>) -> Result<String, LlmError> {
+            Ok(self.0.to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unparseable_response_is_an_error() {
+        let segments = vec![segment("transcript-a", 0.0, "Yo envío el reporte el viernes")];
+        let provider = FixedProvider("Lo siento, no encontré tareas en esta reunión.");
+
+        let result = extract_action_items(
+            &Client::new(),
+            &provider,
+            &segments,
+            "2026-02-16",
+            4000,
+            &TokenCounter::Heuristic,
+        )
+        .await;
+
+        // An empty Ok would make the caller delete the meeting's saved items
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_empty_list_is_not_an_error() {
+        let segments = vec![segment("transcript-a", 0.0, "Hola")];
+        let provider = FixedProvider(r#"{"action_items": []}"#);
+
+        let items = extract_action_items(
+            &Client::new(),
+            &provider,
+            &segments,
+            "2026-02-16",
+            4000,
+            &TokenCounter::Heuristic,
+        )
+        .await
+        .unwrap();
+        assert!(items.is_empty());
+    }
+
+    #[test]
+    fn test_batch_lines_respects_budget() {
+        let lines: Vec<String> = (0..6).map(|i| format!("[S{}] {}", i, "x".repeat(95))).collect();
+        let batches = batch_lines(&lines, 80, &TokenCounter::Heuristic);
+        assert_eq!(batches.len(), 3);
+        assert_eq!(batches[0].lines().count(), 2);
+    }
+}
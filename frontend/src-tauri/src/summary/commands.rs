@@ -1,8 +1,11 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, summary::SummaryProcessesRepository,
+    conversation_metrics::ConversationMetricsRepository, meeting::MeetingsRepository,
+    setting::SettingsRepository, summary::SummaryProcessesRepository,
     transcript_chunk::TranscriptChunksRepository,
 };
 use crate::state::AppState;
+use crate::summary::conversation_metrics::ConversationMetrics;
+use crate::summary::providers::gemini::{self, GeminiModel};
 use crate::summary::service::SummaryService;
 use log::{error as log_error, info as log_info, warn as log_warn};
 use serde::{Deserialize, Serialize};
@@ -197,7 +200,8 @@ pub async fn api_process_transcript<R: Runtime>(
 
     log_info!("✓ Summary process initialized for meeting_id: {}", &m_id);
 
-    // Save transcript chunks data (matching Python backend behavior)
+    // Save transcript chunks data (matching Python backend behavior). The values are only
+    // recorded: chunks are sized from the model's context window when summarizing
     let chunk_size = _chunk_size.unwrap_or(40000);
     let overlap = _overlap.unwrap_or(1000);
 
@@ -275,3 +279,70 @@ pub async fn api_cancel_summary<R: Runtime>(
         }))
     }
 }
+
+/// Gets the objective conversation metrics of a meeting
+///
+/// Metrics are stored when a summary is generated; meetings summarized before that are
+/// computed from their transcripts on first request. Returns None for meetings without
+/// transcripts.
+#[tauri::command]
+pub async fn api_get_conversation_metrics<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Option<ConversationMetrics>, String> {
+    log_info!("api_get_conversation_metrics called for meeting_id: {}", meeting_id);
+    let pool = state.db_manager.pool();
+
+    match ConversationMetricsRepository::get(pool, &meeting_id).await {
+        Ok(Some(metrics)) => return Ok(Some(metrics)),
+        Ok(None) => {}
+        Err(e) => log_warn!("Failed to load conversation metrics for {}: {}", meeting_id, e),
+    }
+
+    let meeting = MeetingsRepository::get_meeting(pool, &meeting_id)
+        .await
+        .map_err(|e| format!("Failed to load meeting: {}", e))?;
+    let Some(meeting) = meeting.filter(|m| !m.transcripts.is_empty()) else {
+        return Ok(None);
+    };
+
+    let language = SettingsRepository::get_transcript_config(pool)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|config| config.language);
+    let metrics = ConversationMetrics::compute(&meeting.transcripts, language.as_deref());
+    if let Err(e) = ConversationMetricsRepository::save(pool, &meeting_id, &metrics).await {
+        log_warn!("Failed to save conversation metrics for {}: {}", meeting_id, e);
+    }
+    Ok(Some(metrics))
+}
+
+/// Lists the Gemini models that support summary generation
+///
+/// Uses `api_key` when given (key typed but not saved yet), else the stored Gemini key.
+#[tauri::command]
+pub async fn api_get_gemini_models<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    api_key: Option<String>,
+) -> Result<Vec<GeminiModel>, String> {
+    log_info!("api_get_gemini_models called");
+
+    let api_key = match api_key.filter(|key| !key.trim().is_empty()) {
+        Some(key) => key,
+        None => SettingsRepository::get_api_key(state.db_manager.pool(), "gemini")
+            .await
+            .map_err(|e| format!("Failed to retrieve API key for gemini: {}", e))?
+            .filter(|key| !key.is_empty())
+            .ok_or_else(|| "API key not found for gemini".to_string())?,
+    };
+
+    let models = gemini::list_models(&reqwest::Client::new(), api_key.trim()).await.map_err(|e| {
+        log_error!("Failed to list Gemini models: {}", e);
+        e
+    })?;
+    log_info!("Found {} Gemini models", models.len());
+    Ok(models)
+}
@@ -1,7 +1,7 @@
 use crate::summary::communication_types::CommunicationFeedback;
-use crate::summary::llm_client::{generate_summary, LLMProvider};
+use crate::summary::conversation_metrics::ConversationMetrics;
+use crate::summary::providers::{LlmProvider, LlmRequest};
 use reqwest::Client;
-use std::path::PathBuf;
 use tracing::{error, info, warn};
 
 /// System prompt for communication evaluation
@@ -32,63 +32,41 @@ El formato de respuesta debe ser exactamente:
   }
 }
 
+Si se incluyen <metricas_objetivas>, fueron calculadas de los tiempos de la grabación: úsalas como base de tu evaluación (por ejemplo, la participación para engagement y las muletillas para clarity), cítalas cuando sean relevantes y no las contradigas.
+
 Sé específico y constructivo en tu análisis. Enfócate en comportamientos observables en la transcripción."#;
 
 /// Evaluates communication skills from a transcript
 ///
 /// # Arguments
 /// * `client` - Reqwest HTTP client
-/// * `provider` - LLM provider to use
-/// * `model_name` - Specific model name
-/// * `api_key` - API key for the provider
+/// * `provider` - Configured LLM provider and model
 /// * `transcript` - Full transcript text to analyze
-/// * `ollama_endpoint` - Optional custom Ollama endpoint
-/// * `custom_openai_endpoint` - Optional custom OpenAI endpoint
-/// * `max_tokens` - Optional max tokens for CustomOpenAI
-/// * `temperature` - Optional temperature for CustomOpenAI
-/// * `top_p` - Optional top_p for CustomOpenAI
-/// * `app_data_dir` - Optional app data directory for BuiltInAI
+/// * `metrics` - Objective metrics of the conversation, given to the model as grounding
 ///
 /// # Returns
 /// Communication feedback with scores and analysis
 pub async fn evaluate_communication(
     client: &Client,
-    provider: &LLMProvider,
-    model_name: &str,
-    api_key: &str,
+    provider: &dyn LlmProvider,
     transcript: &str,
-    ollama_endpoint: Option<&str>,
-    custom_openai_endpoint: Option<&str>,
-    max_tokens: Option<u32>,
-    temperature: Option<f32>,
-    top_p: Option<f32>,
-    app_data_dir: Option<&PathBuf>,
+    metrics: Option<&ConversationMetrics>,
 ) -> Result<CommunicationFeedback, String> {
     info!("Starting communication evaluation for transcript");
 
-    // Build user prompt with transcript
+    // Build user prompt with transcript and, when available, the measured metrics
+    let metrics_block = metrics
+        .map(|metrics| format!("<metricas_objetivas>\n{}</metricas_objetivas>\n\n", metrics.to_prompt()))
+        .unwrap_or_default();
     let user_prompt = format!(
-        "Analiza la siguiente transcripción de reunión y evalúa las habilidades de comunicación del usuario:\n\n<transcripcion>\n{}\n</transcripcion>\n\nResponde ÚNICAMENTE con el JSON de evaluación.",
-        transcript
+        "Analiza la siguiente transcripción de reunión y evalúa las habilidades de comunicación del usuario:\n\n{}<transcripcion>\n{}\n</transcripcion>\n\nResponde ÚNICAMENTE con el JSON de evaluación.",
+        metrics_block, transcript
     );
 
-    // Call LLM with evaluation prompt
-    let response = generate_summary(
-        client,
-        provider,
-        model_name,
-        api_key,
-        EVALUATION_SYSTEM_PROMPT,
-        &user_prompt,
-        ollama_endpoint,
-        custom_openai_endpoint,
-        max_tokens,
-        temperature,
-        top_p,
-        app_data_dir,
-        None, // No cancellation token for evaluation
-    )
-    .await?;
+    // Call LLM with evaluation prompt (not cancellable, not streamed, and parsed
+    // leniently by parse_evaluation_response instead of a schema)
+    let request = LlmRequest::new(EVALUATION_SYSTEM_PROMPT, &user_prompt);
+    let response = provider.generate(client, &request).await?;
 
     info!("Communication evaluation LLM response received");
 
@@ -0,0 +1,383 @@
+// Objective conversation metrics computed from the stored transcript segments
+//
+// Talk time, pace, turns and overlaps come from the recording-relative timestamps and the
+// segment source ("user" = microphone, "interlocutor" = system audio), so the numbers are
+// the same on every run and need no model. They are stored with the meeting and given to
+// the communication evaluator as grounding.
+
+use crate::api::MeetingTranscript;
+use serde::{Deserialize, Serialize};
+
+/// Overlaps shorter than this are treated as crosstalk between the mic and system
+/// captures rather than two people speaking at once
+const MIN_OVERLAP_SECONDS: f64 = 0.3;
+
+/// Filler words and phrases, written as they are normalized (lowercase, no punctuation)
+const SPANISH_FILLERS: &[&str] = &[
+    "eh", "ehh", "ehm", "em", "emm", "mm", "mmm", "o sea", "osea", "digamos",
+];
+const ENGLISH_FILLERS: &[&str] = &[
+    "uh", "uhh", "um", "umm", "uhm", "erm", "hmm", "you know", "i mean", "kind of", "sort of",
+    "basically",
+];
+
+/// Metrics of one speaker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpeakerMetrics {
+    /// "user", "interlocutor" or "unknown" (segments without a source)
+    pub speaker: String,
+    pub talk_time_seconds: f64,
+    /// Share of the total talk time (0-1)
+    pub talk_time_share: f64,
+    pub word_count: usize,
+    /// None when the speaker's segments have no timestamps
+    pub words_per_minute: Option<f64>,
+    /// Longest run of consecutive segments without the other speaker talking
+    pub longest_monologue_seconds: f64,
+    pub turns: usize,
+    /// Times the speaker started talking while the other speaker was still talking
+    pub interruptions: usize,
+    pub questions: usize,
+    pub filler_words: usize,
+    /// Filler words per 100 words
+    pub filler_rate: f64,
+}
+
+/// Metrics of a whole conversation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConversationMetrics {
+    /// From the start of the first segment to the end of the last one
+    pub duration_seconds: f64,
+    pub total_turns: usize,
+    /// Stretches where both speakers talk at the same time
+    pub overlap_count: usize,
+    pub overlap_seconds: f64,
+    pub speakers: Vec<SpeakerMetrics>,
+}
+
+struct Segment<'a> {
+    speaker: &'a str,
+    start: Option<f64>,
+    end: Option<f64>,
+    text: &'a str,
+}
+
+impl Segment<'_> {
+    fn span(&self) -> Option<(f64, f64)> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) if end >= start => Some((start, end)),
+            _ => None,
+        }
+    }
+}
+
+impl ConversationMetrics {
+    /// Computes the metrics of a meeting's transcript segments
+    ///
+    /// `language` selects the filler words (Spanish for "es…", English for "en…", both
+    /// otherwise).
+    pub fn compute(transcripts: &[MeetingTranscript], language: Option<&str>) -> Self {
+        let mut segments: Vec<Segment> = transcripts
+            .iter()
+            .filter(|t| !t.text.trim().is_empty())
+            .map(|t| Segment {
+                speaker: t.source_type.as_deref().unwrap_or("unknown"),
+                start: t.audio_start_time,
+                end: t
+                    .audio_end_time
+                    .or_else(|| Some(t.audio_start_time? + t.duration?)),
+                text: &t.text,
+            })
+            .collect();
+        // Untimed segments keep their stored order after the timed ones
+        segments.sort_by(|a, b| match (a.start, b.start) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => std::cmp::Ordering::Equal,
+        });
+
+        let fillers = fillers_for(language);
+        let mut speakers: Vec<SpeakerMetrics> = Vec::new();
+        let mut total_turns = 0;
+        let mut previous: Option<&str> = None;
+        let mut run_start: Option<f64> = None;
+
+        for segment in &segments {
+            let index = speaker_index(&mut speakers, segment.speaker);
+            let words = normalized_words(segment.text);
+            let metrics = &mut speakers[index];
+
+            metrics.word_count += words.len();
+            metrics.filler_words += count_fillers(&words, &fillers);
+            // Spanish questions may lose either mark in transcription
+            metrics.questions += segment
+                .text
+                .matches('?')
+                .count()
+                .max(segment.text.matches('¿').count());
+
+            if let Some((start, end)) = segment.span() {
+                metrics.talk_time_seconds += end - start;
+            }
+
+            if previous != Some(segment.speaker) {
+                metrics.turns += 1;
+                total_turns += 1;
+                run_start = segment.start;
+            }
+            if let (Some(run_start), Some((_, end))) = (run_start, segment.span()) {
+                metrics.longest_monologue_seconds =
+                    metrics.longest_monologue_seconds.max(end - run_start);
+            }
+            previous = Some(segment.speaker);
+        }
+
+        // Overlaps between timed segments of different speakers (sorted by start)
+        let timed: Vec<(&str, f64, f64)> = segments
+            .iter()
+            .filter_map(|s| s.span().map(|(start, end)| (s.speaker, start, end)))
+            .collect();
+        let mut overlap_count = 0;
+        let mut overlap_seconds = 0.0;
+        for (j, &(speaker, start, end)) in timed.iter().enumerate() {
+            let mut interrupted = false;
+            for &(other, other_start, other_end) in timed[..j].iter().rev() {
+                if other == speaker || other_end <= start {
+                    continue;
+                }
+                let overlap = other_end.min(end) - start;
+                if overlap < MIN_OVERLAP_SECONDS {
+                    continue;
+                }
+                overlap_count += 1;
+                overlap_seconds += overlap;
+                // Starting while the other speaker is mid-sentence is an interruption
+                if start > other_start && !interrupted {
+                    interrupted = true;
+                    let index = speaker_index(&mut speakers, speaker);
+                    speakers[index].interruptions += 1;
+                }
+            }
+        }
+
+        let total_talk: f64 = speakers.iter().map(|s| s.talk_time_seconds).sum();
+        for metrics in &mut speakers {
+            if total_talk > 0.0 {
+                metrics.talk_time_share = metrics.talk_time_seconds / total_talk;
+            }
+            if metrics.talk_time_seconds > 0.0 {
+                metrics.words_per_minute =
+                    Some(metrics.word_count as f64 * 60.0 / metrics.talk_time_seconds);
+            }
+            if metrics.word_count > 0 {
+                metrics.filler_rate = metrics.filler_words as f64 * 100.0 / metrics.word_count as f64;
+            }
+        }
+
+        let duration_seconds = match (
+            timed.iter().map(|t| t.1).reduce(f64::min),
+            timed.iter().map(|t| t.2).reduce(f64::max),
+        ) {
+            (Some(first), Some(last)) => last - first,
+            _ => 0.0,
+        };
+
+        Self {
+            duration_seconds,
+            total_turns,
+            overlap_count,
+            overlap_seconds,
+            speakers,
+        }
+    }
+
+    /// Metrics as text for the communication evaluator prompt
+    pub fn to_prompt(&self) -> String {
+        let mut prompt = format!(
+            "Duración: {} · {} turnos · {} solapamientos ({:.0} s hablando a la vez)\n",
+            format_duration(self.duration_seconds),
+            self.total_turns,
+            self.overlap_count,
+            self.overlap_seconds
+        );
+        for speaker in &self.speakers {
+            let pace = speaker
+                .words_per_minute
+                .map(|wpm| format!("{:.0} palabras/min", wpm))
+                .unwrap_or_else(|| "ritmo desconocido".to_string());
+            prompt.push_str(&format!(
+                "- {}: {:.0}% del tiempo de habla ({}), {} palabras, {}, monólogo más largo {}, {} turnos, {} interrupciones, {} preguntas, {} muletillas ({:.1} cada 100 palabras)\n",
+                speaker_label(&speaker.speaker),
+                speaker.talk_time_share * 100.0,
+                format_duration(speaker.talk_time_seconds),
+                speaker.word_count,
+                pace,
+                format_duration(speaker.longest_monologue_seconds),
+                speaker.turns,
+                speaker.interruptions,
+                speaker.questions,
+                speaker.filler_words,
+                speaker.filler_rate
+            ));
+        }
+        prompt
+    }
+}
+
+fn speaker_index(speakers: &mut Vec<SpeakerMetrics>, speaker: &str) -> usize {
+    if let Some(index) = speakers.iter().position(|s| s.speaker == speaker) {
+        return index;
+    }
+    speakers.push(SpeakerMetrics {
+        speaker: speaker.to_string(),
+        talk_time_seconds: 0.0,
+        talk_time_share: 0.0,
+        word_count: 0,
+        words_per_minute: None,
+        longest_monologue_seconds: 0.0,
+        turns: 0,
+        interruptions: 0,
+        questions: 0,
+        filler_words: 0,
+        filler_rate: 0.0,
+    });
+    speakers.len() - 1
+}
+
+fn speaker_label(speaker: &str) -> &str {
+    match speaker {
+        "user" => "Usuario (micrófono)",
+        "interlocutor" => "Interlocutor",
+        "unknown" => "Sin identificar",
+        other => other,
+    }
+}
+
+fn format_duration(seconds: f64) -> String {
+    let seconds = seconds.round() as u64;
+    if seconds < 60 {
+        format!("{} s", seconds)
+    } else {
+        format!("{} min {} s", seconds / 60, seconds % 60)
+    }
+}
+
+fn fillers_for(language: Option<&str>) -> Vec<Vec<&'static str>> {
+    let language = language.unwrap_or_default().to_lowercase();
+    let lists: Vec<&[&str]> = if language.starts_with("es") {
+        vec![SPANISH_FILLERS]
+    } else if language.starts_with("en") {
+        vec![ENGLISH_FILLERS]
+    } else {
+        vec![SPANISH_FILLERS, ENGLISH_FILLERS]
+    };
+    lists
+        .into_iter()
+        .flatten()
+        .map(|filler| filler.split(' ').collect())
+        .collect()
+}
+
+/// Lowercase words without punctuation
+fn normalized_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric() && c != '\'')
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+fn count_fillers(words: &[String], fillers: &[Vec<&str>]) -> usize {
+    fillers
+        .iter()
+        .map(|filler| {
+            words
+                .windows(filler.len())
+                .filter(|window| window.iter().zip(filler).all(|(word, f)| word == f))
+                .count()
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(speaker: &str, start: f64, end: f64, text: &str) -> MeetingTranscript {
+        MeetingTranscript {
+            id: format!("{}-{}", speaker, start),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(end),
+            duration: Some(end - start),
+            source_type: Some(speaker.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_talk_time_turns_and_monologues() {
+        let transcripts = vec![
+            segment("user", 0.0, 10.0, "Hola, eh, empecemos con el presupuesto."),
+            segment("user", 10.5, 30.0, "O sea, el costo subió bastante este mes."),
+            segment("interlocutor", 31.0, 41.0, "¿Cuánto subió exactamente?"),
+            segment("user", 42.0, 52.0, "Un veinte por ciento."),
+        ];
+        let metrics = ConversationMetrics::compute(&transcripts, Some("es"));
+
+        assert_eq!(metrics.total_turns, 3);
+        assert_eq!(metrics.duration_seconds, 52.0);
+
+        let user = &metrics.speakers[0];
+        assert_eq!(user.speaker, "user");
+        assert_eq!(user.turns, 2);
+        assert_eq!(user.talk_time_seconds, 39.5);
+        assert_eq!(user.longest_monologue_seconds, 30.0);
+        assert_eq!(user.word_count, 18);
+        assert_eq!(user.filler_words, 2);
+
+        let interlocutor = &metrics.speakers[1];
+        assert_eq!(interlocutor.questions, 1);
+        assert!((user.talk_time_share + interlocutor.talk_time_share - 1.0).abs() < 1e-9);
+        assert_eq!(interlocutor.words_per_minute, Some(18.0));
+    }
+
+    #[test]
+    fn test_interruptions_and_overlaps() {
+        let transcripts = vec![
+            segment("interlocutor", 0.0, 20.0, "Les cuento cómo va el proyecto y los plazos"),
+            // Talks over the interlocutor
+            segment("user", 12.0, 18.0, "Perdón, una pregunta"),
+            // Crosstalk below the threshold is ignored
+            segment("interlocutor", 17.9, 25.0, "Claro, dime"),
+        ];
+        let metrics = ConversationMetrics::compute(&transcripts, Some("es"));
+
+        assert_eq!(metrics.overlap_count, 1);
+        assert_eq!(metrics.overlap_seconds, 6.0);
+        assert_eq!(metrics.speakers[1].speaker, "user");
+        assert_eq!(metrics.speakers[1].interruptions, 1);
+        assert_eq!(metrics.speakers[0].interruptions, 0);
+    }
+
+    #[test]
+    fn test_fillers_by_language() {
+        let words = normalized_words("Um, you know, I mean... eh, it's fine");
+        assert_eq!(count_fillers(&words, &fillers_for(Some("en"))), 3);
+        assert_eq!(count_fillers(&words, &fillers_for(Some("es"))), 1);
+        assert_eq!(count_fillers(&words, &fillers_for(None)), 4);
+    }
+
+    #[test]
+    fn test_untimed_segments() {
+        let mut untimed = segment("user", 0.0, 0.0, "Texto sin tiempos");
+        untimed.audio_start_time = None;
+        untimed.audio_end_time = None;
+        untimed.duration = None;
+        let metrics = ConversationMetrics::compute(&[untimed], None);
+
+        assert_eq!(metrics.duration_seconds, 0.0);
+        assert_eq!(metrics.speakers[0].word_count, 3);
+        assert_eq!(metrics.speakers[0].words_per_minute, None);
+    }
+}
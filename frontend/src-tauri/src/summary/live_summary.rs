@@ -0,0 +1,529 @@
+// summary/live_summary.rs
+//
+// Rolling "so far" summary of a running recording. Every N minutes (or N new
+// transcript segments) the segments since the last update are sent with the
+// previous rolling summary to the configured provider, and the result is
+// emitted as `live-summary-update` and written to live_summary.json in the
+// meeting folder. After the recording, the post-meeting summary uses it as a
+// head start: only the transcript it does not cover has to be map-reduced.
+
+use log::{error, info, warn};
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, RwLock};
+use std::time::{Duration, Instant};
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::api::MeetingTranscript;
+use crate::audio::recording_lifecycle::{is_recording_active, RECORDING_MANAGER};
+use crate::audio::recording_saver::TranscriptSegment;
+use crate::notifications::commands::NotificationManagerState;
+use crate::state::AppState;
+use crate::summary::processor::{clean_llm_markdown_output, rough_token_count};
+use crate::summary::providers::{load_configured_provider, LlmRequest};
+use crate::utils::format_timestamp;
+
+/// How often the update conditions are checked
+const CHECK_INTERVAL_SECONDS: u64 = 15;
+/// Upper bound on the transcript sent in one update, so local models keep up;
+/// a larger backlog is caught up over several consecutive updates
+const MAX_DELTA_TOKENS: usize = 3000;
+const MIN_INTERVAL_MINUTES: u32 = 1;
+const MAX_INTERVAL_MINUTES: u32 = 60;
+const MIN_NEW_SEGMENTS: u32 = 5;
+const MAX_NEW_SEGMENTS: u32 = 500;
+
+/// File in the meeting folder holding the latest rolling summary
+const LIVE_SUMMARY_FILE: &str = "live_summary.json";
+
+/// System prompt for folding new transcript into the rolling summary
+const LIVE_SUMMARY_SYSTEM_PROMPT: &str = r#"Mantienes un resumen continuo de una reunión que todavía está en curso, para quien se une tarde o quiere repasar lo dicho.
+
+REGLAS:
+- Integra la transcripción nueva en el resumen previo y devuelve el resumen completo actualizado.
+- Usa Markdown con estas secciones: "## Temas tratados", "## Decisiones" y "## Pendientes". Omite las secciones sin contenido.
+- Conserva lo importante del resumen previo; no lo repitas palabra por palabra si puedes condensarlo.
+- Solo usa información presente en el resumen previo o en la transcripción; no inventes nombres, fechas ni cifras.
+- Responde en el idioma de la reunión y devuelve únicamente el resumen."#;
+
+fn default_interval_minutes() -> u32 {
+    5
+}
+
+fn default_min_new_segments() -> u32 {
+    40
+}
+
+/// When the rolling summary is refreshed. Off by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LiveSummarySettings {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Refresh after this many minutes when there is new transcript
+    #[serde(default = "default_interval_minutes")]
+    pub interval_minutes: u32,
+    /// Refresh earlier once this many new segments have arrived
+    #[serde(default = "default_min_new_segments")]
+    pub min_new_segments: u32,
+    /// Show a (low priority, DnD-aware) system notification on every refresh
+    #[serde(default)]
+    pub notify_on_update: bool,
+}
+
+impl Default for LiveSummarySettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_minutes: default_interval_minutes(),
+            min_new_segments: default_min_new_segments(),
+            notify_on_update: false,
+        }
+    }
+}
+
+impl LiveSummarySettings {
+    /// Clamp the interval and segment threshold into their allowed ranges
+    pub fn validated(self) -> Self {
+        Self {
+            enabled: self.enabled,
+            interval_minutes: self.interval_minutes.clamp(MIN_INTERVAL_MINUTES, MAX_INTERVAL_MINUTES),
+            min_new_segments: self.min_new_segments.clamp(MIN_NEW_SEGMENTS, MAX_NEW_SEGMENTS),
+            notify_on_update: self.notify_on_update,
+        }
+    }
+
+    /// Whether an update is due, `since_last_update` after the previous one
+    pub fn is_due(&self, since_last_update: Duration, new_segments: usize) -> bool {
+        new_segments > 0
+            && (since_last_update >= Duration::from_secs(self.interval_minutes as u64 * 60)
+                || new_segments >= self.min_new_segments as usize)
+    }
+}
+
+static LIVE_SUMMARY_SETTINGS: Lazy<RwLock<LiveSummarySettings>> =
+    Lazy::new(|| RwLock::new(LiveSummarySettings::default()));
+
+/// Settings used by the next recording
+pub fn current_live_summary_settings() -> LiveSummarySettings {
+    *LIVE_SUMMARY_SETTINGS.read().unwrap()
+}
+
+/// Replace the settings used by the next recording
+pub fn set_current_live_summary_settings(settings: LiveSummarySettings) {
+    info!("Live summary settings updated: {:?}", settings);
+    *LIVE_SUMMARY_SETTINGS.write().unwrap() = settings;
+}
+
+/// Rolling summary of a recording; payload of the `live-summary-update` event
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LiveSummary {
+    pub markdown: String,
+    /// Recording time (seconds) up to which the transcript is summarized
+    pub covered_until_seconds: f64,
+    pub segments_covered: usize,
+    pub updated_at: String, // RFC3339 wall-clock time
+}
+
+impl LiveSummary {
+    /// Head start for the post-meeting summary: this summary plus the transcript
+    /// recorded after it. None when the transcript has no timing to split on.
+    pub fn head_start(&self, transcripts: &[MeetingTranscript]) -> Option<HeadStart> {
+        if self.markdown.trim().is_empty()
+            || transcripts.iter().all(|t| t.audio_start_time.is_none())
+        {
+            return None;
+        }
+
+        let remaining_transcript = transcripts
+            .iter()
+            .filter(|t| t.audio_start_time.unwrap_or(0.0) >= self.covered_until_seconds)
+            .map(|t| format_line(t.audio_start_time, t.source_type.as_deref(), &t.text))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        Some(HeadStart {
+            summary_markdown: self.markdown.clone(),
+            remaining_transcript,
+        })
+    }
+}
+
+/// Rolling summary handed to the post-meeting summary (see [`LiveSummary::head_start`])
+#[derive(Debug, Clone, PartialEq)]
+pub struct HeadStart {
+    pub summary_markdown: String,
+    /// Transcript the rolling summary does not cover yet
+    pub remaining_transcript: String,
+}
+
+/// Rolling summary of the current recording (for frontend reload sync)
+static CURRENT_LIVE_SUMMARY: Lazy<Mutex<Option<LiveSummary>>> = Lazy::new(|| Mutex::new(None));
+
+/// Background task refreshing the rolling summary of the current recording
+static LIVE_SUMMARY_TASK: Mutex<Option<JoinHandle<()>>> = Mutex::new(None);
+
+/// Cancels the provider request in flight when the recording stops
+static LIVE_SUMMARY_CANCELLATION: Mutex<Option<CancellationToken>> = Mutex::new(None);
+
+/// One transcript line as the model sees it: `[HH:MM:SS] Speaker: text`
+fn format_line(start: Option<f64>, source_type: Option<&str>, text: &str) -> String {
+    let speaker = match source_type {
+        Some("user") => "Usuario",
+        Some("interlocutor") => "Interlocutor",
+        _ => "Desconocido",
+    };
+    match start {
+        Some(seconds) => format!("[{}] {}: {}", format_timestamp(seconds), speaker, text.trim()),
+        None => format!("{}: {}", speaker, text.trim()),
+    }
+}
+
+/// Segments that end after `covered_until`, in recording order
+fn segments_after(segments: Vec<TranscriptSegment>, covered_until: f64) -> Vec<TranscriptSegment> {
+    let mut pending: Vec<TranscriptSegment> = segments
+        .into_iter()
+        .filter(|s| s.audio_end_time > covered_until && !s.text.trim().is_empty())
+        .collect();
+    pending.sort_by(|a, b| a.audio_start_time.total_cmp(&b.audio_start_time));
+    pending
+}
+
+/// Oldest pending segments that fit in one update (always at least one)
+fn take_batch(pending: &[TranscriptSegment], max_tokens: usize) -> &[TranscriptSegment] {
+    let mut tokens = 0;
+    let end = pending
+        .iter()
+        .position(|segment| {
+            tokens += rough_token_count(&segment.text);
+            tokens > max_tokens
+        })
+        .unwrap_or(pending.len());
+    &pending[..end.max(1).min(pending.len())]
+}
+
+/// Transcript segments, meeting folder and paused state of the current recording
+fn recording_state() -> Option<(Vec<TranscriptSegment>, Option<PathBuf>, bool)> {
+    let manager_guard = RECORDING_MANAGER.lock().ok()?;
+    let manager = manager_guard.as_ref()?;
+    Some((
+        manager.get_transcript_segments(),
+        manager.get_meeting_folder(),
+        manager.is_paused(),
+    ))
+}
+
+/// Start the rolling summary for the recording that just started (when enabled)
+pub fn start_live_summary<R: Runtime>(app: &AppHandle<R>) {
+    stop_live_summary();
+    if let Ok(mut current) = CURRENT_LIVE_SUMMARY.lock() {
+        *current = None;
+    }
+
+    let settings = current_live_summary_settings();
+    if !settings.enabled {
+        info!("Live summary disabled");
+        return;
+    }
+    info!("📝 Live summary active: {:?}", settings);
+
+    let token = CancellationToken::new();
+    if let Ok(mut cancellation) = LIVE_SUMMARY_CANCELLATION.lock() {
+        *cancellation = Some(token.clone());
+    }
+
+    let app = app.clone();
+    let handle = tokio::spawn(async move {
+        run_live_summary_loop(app, settings, token).await;
+    });
+    if let Ok(mut task) = LIVE_SUMMARY_TASK.lock() {
+        *task = Some(handle);
+    }
+}
+
+/// Stop refreshing the rolling summary and cancel the update in flight.
+/// The last rolling summary stays in the meeting folder for the final summary.
+pub fn stop_live_summary() {
+    if let Ok(mut cancellation) = LIVE_SUMMARY_CANCELLATION.lock() {
+        if let Some(token) = cancellation.take() {
+            token.cancel();
+        }
+    }
+    if let Ok(mut task) = LIVE_SUMMARY_TASK.lock() {
+        if let Some(handle) = task.take() {
+            handle.abort();
+        }
+    }
+}
+
+async fn run_live_summary_loop<R: Runtime>(
+    app: AppHandle<R>,
+    settings: LiveSummarySettings,
+    token: CancellationToken,
+) {
+    let client = reqwest::Client::new();
+    let mut summary = LiveSummary::default();
+    let mut last_update = Instant::now();
+
+    loop {
+        tokio::select! {
+            _ = token.cancelled() => break,
+            _ = tokio::time::sleep(Duration::from_secs(CHECK_INTERVAL_SECONDS)) => {}
+        }
+        if !is_recording_active() {
+            break;
+        }
+
+        let Some((segments, meeting_folder, paused)) = recording_state() else {
+            continue;
+        };
+        if paused {
+            continue;
+        }
+
+        let pending = segments_after(segments, summary.covered_until_seconds);
+        if !settings.is_due(last_update.elapsed(), pending.len()) {
+            continue;
+        }
+        let batch = take_batch(&pending, MAX_DELTA_TOKENS);
+        let caught_up = batch.len() == pending.len();
+
+        match update_rolling_summary(&app, &client, &summary.markdown, batch, &token).await {
+            Ok(markdown) => {
+                summary = LiveSummary {
+                    markdown,
+                    covered_until_seconds: batch.last().map_or(summary.covered_until_seconds, |s| s.audio_end_time),
+                    segments_covered: summary.segments_covered + batch.len(),
+                    updated_at: chrono::Utc::now().to_rfc3339(),
+                };
+                publish(&app, &summary, meeting_folder.as_deref(), settings.notify_on_update).await;
+            }
+            Err(e) => {
+                if token.is_cancelled() {
+                    break;
+                }
+                warn!("Live summary update failed: {}", e);
+                let _ = app.emit("live-summary-error", serde_json::json!({ "error": e }));
+            }
+        }
+
+        // With a backlog left, the next check catches up right away
+        if caught_up {
+            last_update = Instant::now();
+        }
+    }
+    info!("📝 Live summary stopped after {} segments", summary.segments_covered);
+}
+
+/// Fold `batch` into `previous` with the configured summary provider
+async fn update_rolling_summary<R: Runtime>(
+    app: &AppHandle<R>,
+    client: &reqwest::Client,
+    previous: &str,
+    batch: &[TranscriptSegment],
+    token: &CancellationToken,
+) -> Result<String, String> {
+    let pool = app
+        .try_state::<AppState>()
+        .ok_or_else(|| "Database not initialized".to_string())?
+        .db_manager
+        .pool()
+        .clone();
+    let provider = load_configured_provider(&pool, app.path().app_data_dir().ok()).await?;
+
+    let delta = batch
+        .iter()
+        .map(|s| format_line(Some(s.audio_start_time), s.source_type.as_deref(), &s.text))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let previous = if previous.trim().is_empty() {
+        "(todavía no hay resumen)"
+    } else {
+        previous
+    };
+    let user_prompt = format!(
+        "<resumen_previo>\n{}\n</resumen_previo>\n\n<transcripcion_nueva>\n{}\n</transcripcion_nueva>",
+        previous, delta
+    );
+
+    let request = LlmRequest::new(LIVE_SUMMARY_SYSTEM_PROMPT, &user_prompt).with_cancellation(Some(token));
+    let raw = provider.generate(client, &request).await?;
+
+    let markdown = clean_llm_markdown_output(&raw);
+    if markdown.is_empty() {
+        return Err("The model returned an empty summary".to_string());
+    }
+    Ok(markdown)
+}
+
+/// Store, persist, emit and (optionally) notify a new rolling summary
+async fn publish<R: Runtime>(
+    app: &AppHandle<R>,
+    summary: &LiveSummary,
+    meeting_folder: Option<&Path>,
+    notify: bool,
+) {
+    info!(
+        "📝 Live summary updated: {} segments, up to {}",
+        summary.segments_covered,
+        format_timestamp(summary.covered_until_seconds)
+    );
+
+    if let Ok(mut current) = CURRENT_LIVE_SUMMARY.lock() {
+        *current = Some(summary.clone());
+    }
+    if let Some(folder) = meeting_folder {
+        if let Err(e) = write_live_summary_file(folder, summary) {
+            warn!("Failed to write {}: {}", LIVE_SUMMARY_FILE, e);
+        }
+    }
+    if let Err(e) = app.emit("live-summary-update", summary) {
+        error!("Failed to emit live-summary-update: {}", e);
+    }
+
+    if notify {
+        if let Some(manager_state) = app.try_state::<NotificationManagerState<R>>() {
+            let message = format!(
+                "Resumen en vivo actualizado (hasta {})",
+                format_timestamp(summary.covered_until_seconds)
+            );
+            if let Err(e) = crate::notifications::commands::show_live_summary_notification(
+                &manager_state,
+                message,
+            )
+            .await
+            {
+                warn!("Failed to show live summary notification: {}", e);
+            }
+        }
+    }
+}
+
+/// Write live_summary.json to a meeting folder (atomic write with temp file)
+pub fn write_live_summary_file(folder: &Path, summary: &LiveSummary) -> Result<(), String> {
+    let path = folder.join(LIVE_SUMMARY_FILE);
+    let temp_path = folder.join(format!(".{}.tmp", LIVE_SUMMARY_FILE));
+    let json = serde_json::to_string_pretty(summary)
+        .map_err(|e| format!("Failed to serialize live summary: {}", e))?;
+    std::fs::write(&temp_path, json).map_err(|e| format!("Failed to write live summary: {}", e))?;
+    std::fs::rename(&temp_path, &path).map_err(|e| format!("Failed to replace live summary: {}", e))
+}
+
+/// Read live_summary.json from a meeting folder (None when the recording had no live summary)
+pub fn read_live_summary_file(folder: &Path) -> Option<LiveSummary> {
+    let json = std::fs::read_to_string(folder.join(LIVE_SUMMARY_FILE)).ok()?;
+    match serde_json::from_str(&json) {
+        Ok(summary) => Some(summary),
+        Err(e) => {
+            warn!("Ignoring unreadable {} in {:?}: {}", LIVE_SUMMARY_FILE, folder, e);
+            None
+        }
+    }
+}
+
+/// Latest rolling summary of the current recording (for reload sync)
+#[tauri::command]
+pub async fn api_get_live_summary() -> Result<Option<LiveSummary>, String> {
+    CURRENT_LIVE_SUMMARY
+        .lock()
+        .map(|current| current.clone())
+        .map_err(|e| format!("Live summary lock poisoned: {}", e))
+}
+
+/// Stop the rolling summary of the current recording (the recording continues)
+#[tauri::command]
+pub async fn api_cancel_live_summary() -> Result<bool, String> {
+    let running = LIVE_SUMMARY_TASK
+        .lock()
+        .map_err(|e| format!("Live summary lock poisoned: {}", e))?
+        .is_some();
+    if running {
+        info!("📝 Live summary cancelled by the user");
+        stop_live_summary();
+    }
+    Ok(running)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segment(start: f64, end: f64, text: &str) -> TranscriptSegment {
+        TranscriptSegment {
+            id: format!("seg_{}", start),
+            text: text.to_string(),
+            audio_start_time: start,
+            audio_end_time: end,
+            duration: end - start,
+            display_time: String::new(),
+            confidence: 1.0,
+            sequence_id: start as u64,
+            source_type: Some("user".to_string()),
+        }
+    }
+
+    fn transcript(start: Option<f64>, text: &str) -> MeetingTranscript {
+        MeetingTranscript {
+            id: text.to_string(),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: start,
+            audio_end_time: None,
+            duration: None,
+            source_type: Some("interlocutor".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_update_due_on_interval_or_segment_count() {
+        let settings = LiveSummarySettings {
+            enabled: true,
+            interval_minutes: 5,
+            min_new_segments: 10,
+            notify_on_update: false,
+        };
+
+        assert!(!settings.is_due(Duration::from_secs(600), 0));
+        assert!(!settings.is_due(Duration::from_secs(60), 3));
+        assert!(settings.is_due(Duration::from_secs(300), 3));
+        assert!(settings.is_due(Duration::from_secs(60), 10));
+    }
+
+    #[test]
+    fn test_pending_segments_and_batch_limit() {
+        let segments = vec![
+            segment(20.0, 25.0, "tercero"),
+            segment(0.0, 5.0, "primero"),
+            segment(10.0, 15.0, "segundo"),
+        ];
+        let pending = segments_after(segments, 5.0);
+        let texts: Vec<&str> = pending.iter().map(|s| s.text.as_str()).collect();
+        assert_eq!(texts, vec!["segundo", "tercero"]);
+
+        // A single segment over the budget still makes progress
+        assert_eq!(take_batch(&pending, 1).len(), 1);
+        assert_eq!(take_batch(&pending, MAX_DELTA_TOKENS).len(), 2);
+    }
+
+    #[test]
+    fn test_head_start_keeps_uncovered_transcript() {
+        let summary = LiveSummary {
+            markdown: "## Temas tratados\n- Presupuesto".to_string(),
+            covered_until_seconds: 60.0,
+            segments_covered: 12,
+            updated_at: String::new(),
+        };
+        let transcripts = vec![
+            transcript(Some(30.0), "cubierto"),
+            transcript(Some(75.0), "nuevo"),
+        ];
+
+        let head_start = summary.head_start(&transcripts).unwrap();
+        assert_eq!(head_start.remaining_transcript, "[00:01:15] Interlocutor: nuevo");
+
+        // Without timings there is no way to know what the summary covers
+        assert_eq!(summary.head_start(&[transcript(None, "sin tiempo")]), None);
+    }
+}
@@ -1,11 +1,32 @@
-use reqwest::{header, Client};
+use reqwest::{Client, RequestBuilder, Response};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
-use tracing::info;
+
+use crate::summary::providers::{LlmError, LlmErrorKind};
+use crate::summary::streaming::{StreamDecoder, StreamEvent, StreamFormat};
 
 const REQUEST_TIMEOUT_DURATION: Duration = Duration::from_secs(300);
+/// Max wait between two streamed chunks (a streamed completion may run far longer overall)
+const STREAM_IDLE_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Callback receiving generated text incrementally while a completion streams
+pub type TokenCallback<'a> = &'a (dyn Fn(&str) + Send + Sync);
+
+/// Structure a completion must follow
+///
+/// OpenAI-compatible providers receive `schema` as a strict `response_format`, Ollama as
+/// `format` and BuiltInAI samples with `grammar` (GBNF). Claude has no equivalent, so its
+/// output is only validated afterwards.
+#[derive(Debug, Clone)]
+pub struct OutputSchema {
+    /// Schema name reported to OpenAI-compatible providers
+    pub name: String,
+    /// JSON Schema of the expected output
+    pub schema: serde_json::Value,
+    /// GBNF grammar accepting exactly the documents valid under `schema`
+    pub grammar: String,
+}
 
 // Generic structure for OpenAI-compatible API chat messages
 #[derive(Debug, Serialize)]
@@ -25,6 +46,17 @@ pub struct ChatRequest {
     pub temperature: Option<f32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+    /// OpenAI-compatible structured output (`json_schema` response format)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_format: Option<serde_json::Value>,
+    /// Ollama native structured output (JSON Schema)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<serde_json::Value>,
+    /// Ollama native model options (`num_ctx`, sampling)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<serde_json::Value>,
 }
 
 // Generic structure for OpenAI-compatible API chat responses
@@ -43,6 +75,12 @@ pub struct MessageContent {
     pub content: String,
 }
 
+// Ollama native /api/chat response (non-streaming)
+#[derive(Deserialize, Debug)]
+pub struct OllamaChatResponse {
+    pub message: MessageContent,
+}
+
 // Claude-specific request structure
 #[derive(Debug, Serialize)]
 pub struct ClaudeRequest {
@@ -50,6 +88,12 @@ pub struct ClaudeRequest {
     pub max_tokens: u32,
     pub system: String,
     pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
 }
 
 // Claude-specific response structure
@@ -63,284 +107,150 @@ pub struct ClaudeChatContent {
     pub text: String,
 }
 
-/// LLM Provider enumeration for multi-provider support
-#[derive(Debug, Clone, PartialEq)]
-pub enum LLMProvider {
-    OpenAI,
-    Claude,
-    Groq,
-    Ollama,
-    OpenRouter,
-    BuiltInAI,
-    CustomOpenAI,
-}
-
-impl LLMProvider {
-    /// Parse provider from string (case-insensitive)
-    pub fn from_str(s: &str) -> Result<Self, String> {
-        match s.to_lowercase().as_str() {
-            "openai" => Ok(Self::OpenAI),
-            "claude" => Ok(Self::Claude),
-            "groq" => Ok(Self::Groq),
-            "ollama" => Ok(Self::Ollama),
-            "openrouter" => Ok(Self::OpenRouter),
-            "builtin-ai" | "local-llama" | "localllama" => Ok(Self::BuiltInAI),
-            "custom-openai" => Ok(Self::CustomOpenAI),
-            _ => Err(format!("Unsupported LLM provider: {}", s)),
-        }
-    }
-}
-
-/// Generates a summary using the specified LLM provider
+/// Sends a provider request, racing it against cancellation
 ///
-/// # Arguments
-/// * `client` - Reqwest HTTP client (reused for performance)
-/// * `provider` - The LLM provider to use
-/// * `model_name` - The specific model to use (e.g., "gpt-4", "claude-3-opus")
-/// * `api_key` - API key for the provider (not needed for Ollama)
-/// * `system_prompt` - System instructions for the LLM
-/// * `user_prompt` - User query/content to process
-/// * `ollama_endpoint` - Optional custom Ollama endpoint (defaults to localhost:11434)
-/// * `custom_openai_endpoint` - Optional custom OpenAI-compatible endpoint
-/// * `max_tokens` - Optional max tokens (for CustomOpenAI provider)
-/// * `temperature` - Optional temperature (for CustomOpenAI provider)
-/// * `top_p` - Optional top_p (for CustomOpenAI provider)
-/// * `app_data_dir` - Optional app data directory (for BuiltInAI provider)
-/// * `cancellation_token` - Optional token to cancel the request
-///
-/// # Returns
-/// The generated summary text or an error message
-pub async fn generate_summary(
-    client: &Client,
-    provider: &LLMProvider,
-    model_name: &str,
-    api_key: &str,
-    system_prompt: &str,
-    user_prompt: &str,
-    ollama_endpoint: Option<&str>,
-    custom_openai_endpoint: Option<&str>,
-    max_tokens: Option<u32>,
-    temperature: Option<f32>,
-    top_p: Option<f32>,
-    app_data_dir: Option<&PathBuf>,
+/// Non-streamed requests time out after [`REQUEST_TIMEOUT_DURATION`]; streamed ones are
+/// bounded by [`STREAM_IDLE_TIMEOUT`] between chunks instead (see [`read_stream`]).
+/// Responses with an error status are turned into a classified [`LlmError`].
+pub async fn send_request(
+    request: RequestBuilder,
+    streaming: bool,
     cancellation_token: Option<&CancellationToken>,
-) -> Result<String, String> {
-    // Check if cancelled before starting
-    if let Some(token) = cancellation_token {
-        if token.is_cancelled() {
-            return Err("Summary generation was cancelled".to_string());
-        }
-    }
-
-    // Handle BuiltInAI provider separately (uses local sidecar, no HTTP API)
-    if provider == &LLMProvider::BuiltInAI {
-        let app_data_dir = app_data_dir
-            .ok_or_else(|| "app_data_dir is required for BuiltInAI provider".to_string())?;
-
-        return crate::summary::summary_engine::generate_with_builtin(
-            app_data_dir,
-            model_name,
-            system_prompt,
-            user_prompt,
-            cancellation_token,
-        )
-        .await
-        .map_err(|e| e.to_string());
-    }
-
-    let (api_url, mut headers) = match provider {
-        LLMProvider::OpenAI => (
-            "https://api.openai.com/v1/chat/completions".to_string(),
-            header::HeaderMap::new(),
-        ),
-        LLMProvider::Groq => (
-            "https://api.groq.com/openai/v1/chat/completions".to_string(),
-            header::HeaderMap::new(),
-        ),
-        LLMProvider::OpenRouter => (
-            "https://openrouter.ai/api/v1/chat/completions".to_string(),
-            header::HeaderMap::new(),
-        ),
-        LLMProvider::Ollama => {
-            let host = ollama_endpoint
-                .map(|s| s.to_string())
-                .unwrap_or_else(|| "http://localhost:11434".to_string());
-            (
-                format!("{}/v1/chat/completions", host),
-                header::HeaderMap::new(),
-            )
-        }
-        LLMProvider::CustomOpenAI => {
-            let endpoint = custom_openai_endpoint
-                .ok_or_else(|| "Custom OpenAI endpoint not configured".to_string())?;
-            (
-                format!("{}/chat/completions", endpoint.trim_end_matches('/')),
-                header::HeaderMap::new(),
-            )
-        }
-        LLMProvider::Claude => {
-            let mut header_map = header::HeaderMap::new();
-            header_map.insert(
-                "x-api-key",
-                api_key
-                    .parse()
-                    .map_err(|_| "Invalid API key format".to_string())?,
-            );
-            header_map.insert(
-                "anthropic-version",
-                "2023-06-01"
-                    .parse()
-                    .map_err(|_| "Invalid anthropic version".to_string())?,
-            );
-            ("https://api.anthropic.com/v1/messages".to_string(), header_map)
-        }
-        LLMProvider::BuiltInAI => {
-            // This case is handled earlier with early returns
-            unreachable!("BuiltInAI is handled before this match statement")
-        }
-    };
-
-    // Add authorization header for non-Claude providers
-    if provider != &LLMProvider::Claude {
-        headers.insert(
-            header::AUTHORIZATION,
-            format!("Bearer {}", api_key)
-                .parse()
-                .map_err(|_| "Invalid authorization header".to_string())?,
-        );
+) -> Result<Response, LlmError> {
+    if cancellation_token.is_some_and(|token| token.is_cancelled()) {
+        return Err(LlmError::cancelled());
     }
-    headers.insert(
-        header::CONTENT_TYPE,
-        "application/json"
-            .parse()
-            .map_err(|_| "Invalid content type".to_string())?,
-    );
-
-    // Build request body based on provider
-    let request_body = if provider != &LLMProvider::Claude {
-        // For CustomOpenAI, apply optional parameters if provided
-        let (max_tokens_val, temperature_val, top_p_val) = if provider == &LLMProvider::CustomOpenAI {
-            (max_tokens, temperature, top_p)
-        } else {
-            (None, None, None)
-        };
 
-        serde_json::json!(ChatRequest {
-            model: model_name.to_string(),
-            messages: vec![
-                ChatMessage {
-                    role: "system".to_string(),
-                    content: system_prompt.to_string(),
-                },
-                ChatMessage {
-                    role: "user".to_string(),
-                    content: user_prompt.to_string(),
-                }
-            ],
-            max_tokens: max_tokens_val,
-            temperature: temperature_val,
-            top_p: top_p_val,
-        })
+    let request = if streaming {
+        request
     } else {
-        serde_json::json!(ClaudeRequest {
-            system: system_prompt.to_string(),
-            model: model_name.to_string(),
-            max_tokens: 2048,
-            messages: vec![ChatMessage {
-                role: "user".to_string(),
-                content: user_prompt.to_string(),
-            }]
-        })
+        request.timeout(REQUEST_TIMEOUT_DURATION)
     };
+    let request_future = request.send();
 
-    info!("🐞 LLM Request to {}: model={}", provider_name(provider), model_name);
-
-    // Send request with timeout and cancellation support
-    let request_future = client
-        .post(api_url)
-        .headers(headers)
-        .json(&request_body)
-        .timeout(REQUEST_TIMEOUT_DURATION)
-        .send();
-
-    // Use tokio::select to race between cancellation and request completion
-    let response = if let Some(token) = cancellation_token {
-        tokio::select! {
-            result = request_future => {
-                result.map_err(|e| {
-                    if e.is_timeout() {
-                        format!("LLM request timed out after 60 seconds")
-                    } else {
-                        format!("Failed to send request to LLM: {}", e)
-                    }
-                })?
-            }
+    let result = match cancellation_token {
+        Some(token) => tokio::select! {
+            result = request_future => result,
             _ = token.cancelled() => {
-                return Err("Summary generation was cancelled".to_string());
+                return Err(LlmError::cancelled());
             }
-        }
-    } else {
-        request_future.await.map_err(|e| {
-            if e.is_timeout() {
-                format!("LLM request timed out after 60 seconds")
-            } else {
-                format!("Failed to send request to LLM: {}", e)
-            }
-        })?
+        },
+        None => request_future.await,
     };
+    let response = result.map_err(|e| {
+        let message = if e.is_timeout() {
+            format!(
+                "LLM request timed out after {} seconds",
+                REQUEST_TIMEOUT_DURATION.as_secs()
+            )
+        } else {
+            format!("Failed to send request to LLM: {}", e)
+        };
+        LlmError::new(LlmErrorKind::Transient, message)
+    })?;
 
-    if !response.status().is_success() {
+    let status = response.status();
+    if !status.is_success() {
+        let headers = response.headers().clone();
         let error_body = response
             .text()
             .await
             .unwrap_or_else(|_| "Unknown error".to_string());
-        return Err(format!("LLM API request failed: {}", error_body));
+        return Err(LlmError::from_response(status, &headers, &error_body));
     }
+    Ok(response)
+}
 
-    // Parse response based on provider
-    if provider == &LLMProvider::Claude {
-        let chat_response = response
-            .json::<ClaudeChatResponse>()
-            .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+/// Read a streamed completion, passing each text delta to `on_token`
+///
+/// Failures are classified here: cancellation, dropped or stalled connections (transient)
+/// and errors reported inside the stream (see [`LlmError::from_stream_error`]).
+pub async fn read_stream(
+    mut response: reqwest::Response,
+    format: StreamFormat,
+    on_token: TokenCallback<'_>,
+    cancellation_token: Option<&CancellationToken>,
+) -> Result<String, LlmError> {
+    let mut decoder = StreamDecoder::new(format);
+    let mut content = String::new();
 
-        info!("🐞 LLM Response received from Claude");
+    loop {
+        let next_chunk = tokio::time::timeout(STREAM_IDLE_TIMEOUT, response.chunk());
+        let chunk = match cancellation_token {
+            Some(token) => tokio::select! {
+                result = next_chunk => result,
+                _ = token.cancelled() => {
+                    return Err(LlmError::cancelled());
+                }
+            },
+            None => next_chunk.await,
+        };
 
-        let content = chat_response
-            .content
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .text
-            .trim();
-        Ok(content.to_string())
-    } else {
-        let chat_response = response
-            .json::<ChatResponse>()
-            .await
-            .map_err(|e| format!("Failed to parse LLM response: {}", e))?;
+        let events = match chunk {
+            Ok(Ok(Some(bytes))) => decoder.push(&bytes),
+            Ok(Ok(None)) => decoder.finish(),
+            Ok(Err(e)) => {
+                return Err(LlmError::new(
+                    LlmErrorKind::Transient,
+                    format!("Failed to read LLM stream: {}", e),
+                ))
+            }
+            Err(_) => {
+                return Err(LlmError::new(
+                    LlmErrorKind::Transient,
+                    format!("LLM stream stalled for {} seconds", STREAM_IDLE_TIMEOUT.as_secs()),
+                ))
+            }
+        };
+        let body_ended = matches!(chunk, Ok(Ok(None)));
 
-        info!("🐞 LLM Response received from {}", provider_name(provider));
+        for event in events {
+            match event {
+                StreamEvent::Delta(text) => {
+                    content.push_str(&text);
+                    on_token(&text);
+                }
+                StreamEvent::Done => return Ok(content),
+                StreamEvent::Error(message) => return Err(LlmError::from_stream_error(&message)),
+            }
+        }
 
-        let content = chat_response
-            .choices
-            .get(0)
-            .ok_or("No content in LLM response")?
-            .message
-            .content
-            .trim();
-        Ok(content.to_string())
+        if body_ended {
+            // Some servers close the connection without an explicit end marker
+            return if content.is_empty() {
+                Err(LlmError::new(LlmErrorKind::Transient, "No content in LLM response"))
+            } else {
+                Ok(content)
+            };
+        }
     }
 }
 
-/// Helper function to get provider name for logging
-fn provider_name(provider: &LLMProvider) -> &str {
-    match provider {
-        LLMProvider::OpenAI => "OpenAI",
-        LLMProvider::Claude => "Claude",
-        LLMProvider::Groq => "Groq",
-        LLMProvider::Ollama => "Ollama",
-        LLMProvider::BuiltInAI => "Built-in AI",
-        LLMProvider::OpenRouter => "OpenRouter",
-        LLMProvider::CustomOpenAI => "Custom OpenAI",
+/// Context window reported by an OpenAI-compatible `GET {base}/models` endpoint
+///
+/// Servers expose it under different names: `context_length` (OpenRouter, LM Studio),
+/// `context_window` (Groq), `max_model_len` (vLLM) or `meta.n_ctx_train` (llama.cpp).
+pub async fn fetch_context_window(
+    client: &Client,
+    models_url: &str,
+    api_key: Option<&str>,
+    model_name: &str,
+) -> Option<usize> {
+    let mut request = client.get(models_url).timeout(Duration::from_secs(10));
+    if let Some(key) = api_key.filter(|k| !k.is_empty()) {
+        request = request.bearer_auth(key);
     }
+
+    let body: serde_json::Value = request.send().await.ok()?.error_for_status().ok()?.json().await.ok()?;
+    let model = body
+        .get("data")?
+        .as_array()?
+        .iter()
+        .find(|m| m.get("id").and_then(|id| id.as_str()) == Some(model_name))?;
+
+    ["/context_length", "/context_window", "/max_model_len", "/max_context_length", "/meta/n_ctx_train"]
+        .iter()
+        .find_map(|pointer| model.pointer(pointer).and_then(|v| v.as_u64()))
+        .map(|tokens| tokens as usize)
+        .filter(|tokens| *tokens > 0)
 }
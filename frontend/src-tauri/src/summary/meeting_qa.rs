@@ -0,0 +1,506 @@
+use crate::api::MeetingTranscript;
+use crate::database::models::{Setting, TranscriptEmbedding};
+use crate::database::repositories::{
+    meeting::MeetingsRepository,
+    transcript_embedding::{decode_embedding, TranscriptEmbeddingsRepository},
+};
+use crate::summary::processor::clean_llm_markdown_output;
+use crate::summary::providers::{resolve_provider_id, LlmProvider, LlmRequest};
+use crate::summary::summary_engine::{embed_with_builtin, models as builtin_models};
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use tracing::info;
+
+/// Speaker turns longer than this are split into several chunks
+const MAX_TURN_CHARS: usize = 1200;
+
+/// Number of texts sent in one embedding request
+const EMBED_BATCH_SIZE: usize = 16;
+
+/// Segments retrieved for a question when the caller does not choose
+pub const DEFAULT_TOP_K: usize = 6;
+
+/// Upper bound on retrieved segments, so the answer prompt stays small for local models
+pub const MAX_TOP_K: usize = 20;
+
+/// System prompt for answering questions over retrieved transcript segments
+const QA_SYSTEM_PROMPT: &str = r#"Eres un asistente que responde preguntas sobre reuniones pasadas usando únicamente fragmentos de sus transcripciones.
+
+REGLAS:
+- Responde en el idioma de la pregunta, de forma breve y directa.
+- Usa solo la información de los fragmentos. Si no alcanza para responder, dilo claramente.
+- Cita cada afirmación con el número del fragmento entre corchetes, por ejemplo [2] o [1][3].
+- No inventes nombres, fechas ni cifras."#;
+
+/// Consecutive transcript segments of one speaker, the unit that gets embedded
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpeakerTurn {
+    pub speaker: Option<String>,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+}
+
+/// Answer to a question over all meetings
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingAnswer {
+    pub answer: String,
+    /// Retrieved segments, numbered as referenced in the answer (`[1]`, `[2]`, ...)
+    pub citations: Vec<MeetingCitation>,
+}
+
+/// A transcript segment the answer was grounded on
+#[derive(Debug, Clone, Serialize)]
+pub struct MeetingCitation {
+    pub index: usize,
+    pub meeting_id: String,
+    pub meeting_title: String,
+    pub speaker: Option<String>,
+    pub text: String,
+    pub audio_start_time: Option<f64>,
+    pub audio_end_time: Option<f64>,
+    /// Cosine similarity with the question
+    pub score: f32,
+    /// Whether the answer references this segment
+    pub cited: bool,
+}
+
+/// Where transcript and question vectors come from
+///
+/// Embeddings are always computed locally: through Ollama when it is the configured
+/// provider, otherwise through a downloaded built-in model.
+#[derive(Debug, Clone)]
+pub enum EmbeddingBackend {
+    BuiltIn {
+        app_data_dir: PathBuf,
+        model_name: String,
+    },
+    Ollama {
+        endpoint: Option<String>,
+        model_name: String,
+    },
+}
+
+impl EmbeddingBackend {
+    /// Picks the embedding backend for the configured summary provider
+    pub fn resolve(setting: Option<&Setting>, app_data_dir: Option<PathBuf>) -> Result<Self, String> {
+        let provider = setting.and_then(|s| resolve_provider_id(&s.provider));
+
+        if let (Some("ollama"), Some(setting)) = (provider, setting) {
+            return Ok(Self::Ollama {
+                endpoint: setting.ollama_endpoint.clone(),
+                model_name: setting.model.clone(),
+            });
+        }
+
+        let app_data_dir =
+            app_data_dir.ok_or_else(|| "app_data_dir is required for built-in embeddings".to_string())?;
+        let is_downloaded = |name: &str| {
+            builtin_models::get_model_path(&app_data_dir, name)
+                .map(|path| path.exists())
+                .unwrap_or(false)
+        };
+
+        // Prefer the configured built-in model, then any downloaded one
+        let configured = setting
+            .filter(|_| provider == Some("builtin-ai"))
+            .map(|s| s.model.clone())
+            .filter(|name| is_downloaded(name));
+        let model_name = configured
+            .or_else(|| {
+                builtin_models::get_available_models()
+                    .into_iter()
+                    .map(|model| model.name)
+                    .find(|name| is_downloaded(name))
+            })
+            .ok_or_else(|| {
+                "Asking your meetings needs a local model for embeddings: download a built-in AI model or select Ollama".to_string()
+            })?;
+
+        Ok(Self::BuiltIn {
+            app_data_dir,
+            model_name,
+        })
+    }
+
+    /// Identifier stored with every vector; vectors of different models are never compared
+    pub fn model_id(&self) -> String {
+        match self {
+            Self::BuiltIn { model_name, .. } => format!("builtin-ai:{}", model_name),
+            Self::Ollama { model_name, .. } => format!("ollama:{}", model_name),
+        }
+    }
+
+    /// Embeds texts into L2-normalized vectors (one per text, same order)
+    pub async fn embed(&self, client: &Client, texts: &[String]) -> Result<Vec<Vec<f32>>, String> {
+        match self {
+            Self::BuiltIn {
+                app_data_dir,
+                model_name,
+            } => embed_with_builtin(app_data_dir, model_name, texts)
+                .await
+                .map_err(|e| e.to_string()),
+            Self::Ollama {
+                endpoint,
+                model_name,
+            } => embed_with_ollama(client, endpoint.as_deref(), model_name, texts).await,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaEmbedResponse {
+    embeddings: Vec<Vec<f32>>,
+}
+
+async fn embed_with_ollama(
+    client: &Client,
+    endpoint: Option<&str>,
+    model_name: &str,
+    texts: &[String],
+) -> Result<Vec<Vec<f32>>, String> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let host = endpoint.unwrap_or("http://localhost:11434");
+    let response = client
+        .post(format!("{}/api/embed", host.trim_end_matches('/')))
+        .json(&serde_json::json!({ "model": model_name, "input": texts }))
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach Ollama for embeddings: {}", e))?;
+
+    if !response.status().is_success() {
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+        return Err(format!("Ollama embeddings failed ({}): {}", status, body));
+    }
+
+    let parsed: OllamaEmbedResponse = response
+        .json()
+        .await
+        .map_err(|e| format!("Invalid Ollama embeddings response: {}", e))?;
+
+    if parsed.embeddings.len() != texts.len() {
+        return Err(format!(
+            "Expected {} embeddings, Ollama returned {}",
+            texts.len(),
+            parsed.embeddings.len()
+        ));
+    }
+
+    Ok(parsed.embeddings.into_iter().map(normalize).collect())
+}
+
+fn normalize(mut vector: Vec<f32>) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        vector.iter_mut().for_each(|v| *v /= norm);
+    }
+    vector
+}
+
+/// Groups transcript segments (in recording order) into speaker turns
+pub fn chunk_by_speaker_turn(segments: &[MeetingTranscript]) -> Vec<SpeakerTurn> {
+    let mut turns: Vec<SpeakerTurn> = Vec::new();
+
+    for segment in segments {
+        let text = segment.text.trim();
+        if text.is_empty() {
+            continue;
+        }
+
+        match turns.last_mut() {
+            Some(turn)
+                if turn.speaker == segment.source_type
+                    && turn.text.len() + text.len() < MAX_TURN_CHARS =>
+            {
+                turn.text.push(' ');
+                turn.text.push_str(text);
+                turn.audio_end_time = segment.audio_end_time.or(turn.audio_end_time);
+            }
+            _ => turns.push(SpeakerTurn {
+                speaker: segment.source_type.clone(),
+                text: text.to_string(),
+                audio_start_time: segment.audio_start_time,
+                audio_end_time: segment.audio_end_time,
+            }),
+        }
+    }
+
+    turns
+}
+
+/// SHA-256 of the speaker turns a meeting is indexed from, to notice edited transcripts
+pub fn transcript_hash(turns: &[SpeakerTurn]) -> String {
+    let mut hasher = Sha256::new();
+    for turn in turns {
+        hasher.update(turn.speaker.as_deref().unwrap_or_default());
+        hasher.update([0]);
+        hasher.update(&turn.text);
+        hasher.update([0]);
+        hasher.update(turn.audio_start_time.unwrap_or(-1.0).to_le_bytes());
+        hasher.update(turn.audio_end_time.unwrap_or(-1.0).to_le_bytes());
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// Embeds every meeting that has no vectors for the backend's model yet, or whose
+/// transcript changed since it was indexed
+///
+/// `on_progress(indexed, total)` is called after each meeting.
+///
+/// # Returns
+/// Number of meetings indexed
+pub async fn index_pending_meetings(
+    pool: &SqlitePool,
+    client: &Client,
+    backend: &EmbeddingBackend,
+    on_progress: impl Fn(usize, usize),
+) -> Result<usize, String> {
+    let model_id = backend.model_id();
+    let candidates = TranscriptEmbeddingsRepository::get_index_candidates(pool, &model_id)
+        .await
+        .map_err(|e| format!("Failed to list meetings to index: {}", e))?;
+
+    let mut pending = Vec::new();
+    for (meeting_id, indexed_hash) in candidates {
+        let mut segments = match MeetingsRepository::get_meeting(pool, &meeting_id).await {
+            Ok(Some(meeting)) => meeting.transcripts,
+            Ok(None) => continue,
+            Err(e) => return Err(format!("Failed to load meeting {}: {}", meeting_id, e)),
+        };
+        segments.sort_by(|a, b| {
+            a.audio_start_time
+                .unwrap_or(0.0)
+                .total_cmp(&b.audio_start_time.unwrap_or(0.0))
+        });
+
+        let turns = chunk_by_speaker_turn(&segments);
+        let hash = transcript_hash(&turns);
+        if indexed_hash.as_deref() != Some(hash.as_str()) {
+            pending.push((meeting_id, hash, turns));
+        }
+    }
+
+    if pending.is_empty() {
+        return Ok(0);
+    }
+    info!("Indexing {} meetings with {}", pending.len(), model_id);
+
+    let total = pending.len();
+    for (i, (meeting_id, hash, turns)) in pending.into_iter().enumerate() {
+        let mut embeddings = Vec::with_capacity(turns.len());
+        for batch in turns.chunks(EMBED_BATCH_SIZE) {
+            let texts: Vec<String> = batch.iter().map(|turn| turn.text.clone()).collect();
+            embeddings.extend(backend.embed(client, &texts).await?);
+        }
+
+        let chunks: Vec<(SpeakerTurn, Vec<f32>)> = turns.into_iter().zip(embeddings).collect();
+        TranscriptEmbeddingsRepository::replace_meeting_embeddings(pool, &meeting_id, &model_id, &hash, &chunks)
+            .await
+            .map_err(|e| format!("Failed to save embeddings for meeting {}: {}", meeting_id, e))?;
+
+        on_progress(i + 1, total);
+    }
+
+    Ok(total)
+}
+
+/// Returns the `top_k` chunks most similar to the (normalized) query vector, best first
+pub fn rank_chunks(
+    query: &[f32],
+    chunks: Vec<TranscriptEmbedding>,
+    top_k: usize,
+) -> Vec<(f32, TranscriptEmbedding)> {
+    let mut scored: Vec<(f32, TranscriptEmbedding)> = chunks
+        .into_iter()
+        .filter_map(|chunk| {
+            let vector = decode_embedding(&chunk.embedding);
+            (vector.len() == query.len()).then(|| {
+                let score = vector.iter().zip(query).map(|(a, b)| a * b).sum::<f32>();
+                (score, chunk)
+            })
+        })
+        .collect();
+
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0));
+    scored.truncate(top_k);
+    scored
+}
+
+/// Answers a question from the transcript segments most similar to it
+///
+/// Meetings must already be indexed with the backend's model (see [`index_pending_meetings`]).
+pub async fn ask_meetings(
+    pool: &SqlitePool,
+    client: &Client,
+    backend: &EmbeddingBackend,
+    answer_provider: &dyn LlmProvider,
+    question: &str,
+    top_k: usize,
+) -> Result<MeetingAnswer, String> {
+    let query = backend
+        .embed(client, &[question.to_string()])
+        .await?
+        .pop()
+        .ok_or_else(|| "No embedding returned for the question".to_string())?;
+
+    let chunks = TranscriptEmbeddingsRepository::list_for_model(pool, &backend.model_id())
+        .await
+        .map_err(|e| format!("Failed to load transcript embeddings: {}", e))?;
+    let ranked = rank_chunks(&query, chunks, top_k);
+
+    if ranked.is_empty() {
+        return Ok(MeetingAnswer {
+            answer: "No hay transcripciones indexadas para responder esta pregunta.".to_string(),
+            citations: Vec::new(),
+        });
+    }
+    info!("Answering question from {} retrieved segments", ranked.len());
+
+    let context = ranked
+        .iter()
+        .enumerate()
+        .map(|(i, (_, chunk))| {
+            let time = chunk
+                .audio_start_time
+                .map(crate::utils::format_timestamp)
+                .unwrap_or_else(|| "--:--:--".to_string());
+            format!(
+                "[{}] Reunión \"{}\" · {} · {}\n{}",
+                i + 1,
+                chunk.meeting_title,
+                time,
+                chunk.speaker.as_deref().unwrap_or("desconocido"),
+                chunk.text
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    let user_prompt = format!(
+        "<fragmentos>\n{}\n</fragmentos>\n\nPregunta: {}",
+        context,
+        question.trim()
+    );
+
+    let request = LlmRequest::new(QA_SYSTEM_PROMPT, &user_prompt);
+    let raw_answer = answer_provider.generate(client, &request).await?;
+    let answer = clean_llm_markdown_output(&raw_answer);
+
+    let citations = ranked
+        .into_iter()
+        .enumerate()
+        .map(|(i, (score, chunk))| MeetingCitation {
+            index: i + 1,
+            cited: answer.contains(&format!("[{}]", i + 1)),
+            meeting_id: chunk.meeting_id,
+            meeting_title: chunk.meeting_title,
+            speaker: chunk.speaker,
+            text: chunk.text,
+            audio_start_time: chunk.audio_start_time,
+            audio_end_time: chunk.audio_end_time,
+            score,
+        })
+        .collect();
+
+    Ok(MeetingAnswer { answer, citations })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::repositories::transcript_embedding::encode_embedding;
+
+    fn segment(speaker: &str, start: f64, text: &str) -> MeetingTranscript {
+        MeetingTranscript {
+            id: format!("transcript-{}", start),
+            text: text.to_string(),
+            timestamp: String::new(),
+            audio_start_time: Some(start),
+            audio_end_time: Some(start + 5.0),
+            duration: Some(5.0),
+            source_type: Some(speaker.to_string()),
+        }
+    }
+
+    fn chunk(meeting_id: &str, embedding: &[f32]) -> TranscriptEmbedding {
+        TranscriptEmbedding {
+            meeting_id: meeting_id.to_string(),
+            meeting_title: "Weekly".to_string(),
+            speaker: None,
+            text: String::new(),
+            audio_start_time: None,
+            audio_end_time: None,
+            embedding: encode_embedding(embedding),
+        }
+    }
+
+    #[test]
+    fn test_chunk_by_speaker_turn_merges_consecutive_segments() {
+        let segments = vec![
+            segment("user", 0.0, "Hola"),
+            segment("user", 5.0, "¿qué precio proponen?"),
+            segment("interlocutor", 10.0, "Unos 500 euros"),
+            segment("interlocutor", 15.0, "  "),
+            segment("user", 20.0, "Perfecto"),
+        ];
+
+        let turns = chunk_by_speaker_turn(&segments);
+        assert_eq!(turns.len(), 3);
+        assert_eq!(turns[0].text, "Hola ¿qué precio proponen?");
+        assert_eq!(turns[0].audio_start_time, Some(0.0));
+        assert_eq!(turns[0].audio_end_time, Some(10.0));
+        assert_eq!(turns[1].speaker.as_deref(), Some("interlocutor"));
+    }
+
+    #[test]
+    fn test_chunk_by_speaker_turn_splits_long_turns() {
+        let long = "x".repeat(MAX_TURN_CHARS / 2 + 1);
+        let segments: Vec<_> = (0..3).map(|i| segment("user", i as f64 * 5.0, &long)).collect();
+
+        let turns = chunk_by_speaker_turn(&segments);
+        assert_eq!(turns.len(), 3);
+        assert_eq!(turns[2].audio_start_time, Some(10.0));
+    }
+
+    #[test]
+    fn test_transcript_hash_changes_with_edited_text() {
+        let segments = vec![
+            segment("user", 0.0, "Hola"),
+            segment("interlocutor", 5.0, "Unos 500 euros"),
+        ];
+        let hash = transcript_hash(&chunk_by_speaker_turn(&segments));
+        assert_eq!(hash, transcript_hash(&chunk_by_speaker_turn(&segments)));
+
+        let edited = vec![
+            segment("user", 0.0, "Hola"),
+            segment("interlocutor", 5.0, "Unos 600 euros"),
+        ];
+        assert_ne!(hash, transcript_hash(&chunk_by_speaker_turn(&edited)));
+    }
+
+    #[test]
+    fn test_rank_chunks_orders_by_similarity() {
+        let chunks = vec![
+            chunk("meeting-a", &[1.0, 0.0]),
+            chunk("meeting-b", &[0.6, 0.8]),
+            chunk("meeting-c", &[0.0, 1.0]),
+            chunk("meeting-d", &[1.0, 0.0, 0.0]),
+        ];
+
+        let ranked = rank_chunks(&[0.0, 1.0], chunks, 2);
+        assert_eq!(ranked.len(), 2);
+        assert_eq!(ranked[0].1.meeting_id, "meeting-c");
+        assert_eq!(ranked[1].1.meeting_id, "meeting-b");
+        assert!((ranked[1].0 - 0.8).abs() < 1e-6);
+    }
+}
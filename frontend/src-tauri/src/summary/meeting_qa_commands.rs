@@ -0,0 +1,94 @@
+use crate::database::repositories::setting::SettingsRepository;
+use crate::state::AppState;
+use crate::summary::meeting_qa::{
+    ask_meetings, index_pending_meetings, EmbeddingBackend, MeetingAnswer, DEFAULT_TOP_K,
+    MAX_TOP_K,
+};
+use crate::summary::providers::load_provider_chain;
+use log::{error as log_error, info as log_info};
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Emitter, Manager, Runtime};
+
+/// Payload of the `meeting-index-progress` event
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MeetingIndexProgress {
+    pub indexed: usize,
+    pub total: usize,
+}
+
+/// Answers a question over all meeting transcripts, citing the segments it used
+///
+/// Meetings that are not indexed yet, or whose transcript changed, are embedded first
+/// (progress is emitted as `meeting-index-progress`). With Ollama or BuiltInAI everything runs locally.
+#[tauri::command]
+pub async fn api_ask_meetings<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    question: String,
+    top_k: Option<usize>,
+) -> Result<MeetingAnswer, String> {
+    log_info!("api_ask_meetings called");
+
+    if question.trim().is_empty() {
+        return Err("Question cannot be empty".to_string());
+    }
+
+    let pool = state.db_manager.pool();
+    let setting = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load model config: {}", e))?
+        .ok_or_else(|| "No summary model configured".to_string())?;
+    let app_data_dir = app.path().app_data_dir().ok();
+
+    let backend = EmbeddingBackend::resolve(Some(&setting), app_data_dir.clone())?;
+    let answer_provider =
+        load_provider_chain(pool, &setting.provider, &setting.model, app_data_dir).await?;
+    let client = reqwest::Client::new();
+
+    index_with_progress(&app, pool, &client, &backend).await?;
+
+    let top_k = top_k.unwrap_or(DEFAULT_TOP_K).clamp(1, MAX_TOP_K);
+    ask_meetings(pool, &client, &backend, answer_provider.as_ref(), &question, top_k)
+        .await
+        .map_err(|e| {
+            log_error!("Failed to answer question over meetings: {}", e);
+            e
+        })
+}
+
+/// Embeds every meeting that is not indexed for the current embedding model, or whose
+/// transcript changed since it was indexed
+///
+/// # Returns
+/// Number of meetings indexed
+#[tauri::command]
+pub async fn api_index_meetings_for_qa<R: Runtime>(
+    app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+) -> Result<usize, String> {
+    log_info!("api_index_meetings_for_qa called");
+
+    let pool = state.db_manager.pool();
+    let setting = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load model config: {}", e))?;
+    let backend = EmbeddingBackend::resolve(setting.as_ref(), app.path().app_data_dir().ok())?;
+
+    index_with_progress(&app, pool, &reqwest::Client::new(), &backend).await
+}
+
+async fn index_with_progress<R: Runtime>(
+    app: &AppHandle<R>,
+    pool: &SqlitePool,
+    client: &reqwest::Client,
+    backend: &EmbeddingBackend,
+) -> Result<usize, String> {
+    index_pending_meetings(pool, client, backend, |indexed, total| {
+        let _ = app.emit("meeting-index-progress", MeetingIndexProgress { indexed, total });
+    })
+    .await
+    .map_err(|e| {
+        log_error!("Failed to index meetings for Q&A: {}", e);
+        e
+    })
+}
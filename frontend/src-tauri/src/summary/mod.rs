@@ -1,10 +1,16 @@
 /// Summary module - handles all meeting summary generation functionality
 ///
 /// This module contains:
-/// - LLM client for communicating with various AI providers (OpenAI, Claude, Groq, Ollama, OpenRouter, CustomOpenAI)
+/// - LLM client for communicating with various AI providers (OpenAI, Claude, Gemini, Groq, Ollama, OpenRouter, CustomOpenAI)
+/// - Pluggable LLM providers (trait + registry) with their capabilities and context windows
 /// - Processor for chunking transcripts and generating summaries
 /// - Service layer for orchestrating summary generation
 /// - Templates for structured meeting summary generation
+/// - Action item extraction and tracking across meetings
+/// - Question answering over all transcripts (local embeddings + retrieval)
+/// - Rolling live summary during recording, reused as a head start for the final summary
+/// - Version history of generated and edited summaries (diff and restore)
+/// - Objective conversation metrics (talk time, pace, turns, interruptions) without an LLM
 /// - Tauri commands for frontend integration
 
 use serde::{Deserialize, Serialize};
@@ -30,31 +36,79 @@ pub struct CustomOpenAIConfig {
     pub top_p: Option<f32>,
 }
 
+/// One provider of the summary fallback chain
+/// Stored as a JSON array in the database, tried in order after the configured provider
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FallbackProviderConfig {
+    /// Provider id (e.g., "ollama", "groq")
+    pub provider: String,
+    /// Model of that provider (empty for custom endpoints means the configured model)
+    pub model: String,
+}
+
+pub mod action_item_commands;
+pub mod action_items;
 pub mod commands;
 pub mod communication_evaluator;
 pub mod communication_types;
+pub mod conversation_metrics;
+pub mod live_summary;
 pub mod llm_client;
+pub mod meeting_qa;
+pub mod meeting_qa_commands;
 pub mod processor;
+pub mod providers;
 pub mod service;
+pub mod streaming;
+pub mod structured;
 pub mod summary_engine;
 pub mod template_commands;
 pub mod templates;
+pub mod version_commands;
+pub mod versions;
 
 // Re-export Tauri commands (with their generated __cmd__ variants)
 pub use commands::{
-    __cmd__api_cancel_summary, __cmd__api_get_summary, __cmd__api_process_transcript,
-    __cmd__api_save_meeting_summary, api_cancel_summary, api_get_summary,
+    __cmd__api_cancel_summary, __cmd__api_get_conversation_metrics, __cmd__api_get_gemini_models,
+    __cmd__api_get_summary, __cmd__api_process_transcript, __cmd__api_save_meeting_summary,
+    api_cancel_summary, api_get_conversation_metrics, api_get_gemini_models, api_get_summary,
     api_process_transcript, api_save_meeting_summary,
 };
 
+// Re-export action item commands
+pub use action_item_commands::{
+    __cmd__api_list_action_items, __cmd__api_update_action_item, api_list_action_items,
+    api_update_action_item,
+};
+
+// Re-export live summary commands
+pub use live_summary::{
+    __cmd__api_cancel_live_summary, __cmd__api_get_live_summary, api_cancel_live_summary,
+    api_get_live_summary,
+};
+
+// Re-export meeting Q&A commands
+pub use meeting_qa_commands::{
+    __cmd__api_ask_meetings, __cmd__api_index_meetings_for_qa, api_ask_meetings,
+    api_index_meetings_for_qa,
+};
+
 // Re-export template commands
 pub use template_commands::{
-    __cmd__api_get_template_details, __cmd__api_list_templates, __cmd__api_validate_template,
-    api_get_template_details, api_list_templates, api_validate_template,
+    __cmd__api_export_template, __cmd__api_get_template_details, __cmd__api_import_template,
+    __cmd__api_list_templates, __cmd__api_validate_template, api_export_template,
+    api_get_template_details, api_import_template, api_list_templates, api_validate_template,
+};
+
+// Re-export summary version commands
+pub use version_commands::{
+    __cmd__api_diff_summary_versions, __cmd__api_list_summary_versions,
+    __cmd__api_restore_summary_version, api_diff_summary_versions, api_list_summary_versions,
+    api_restore_summary_version,
 };
 
 // Re-export commonly used items
-pub use llm_client::LLMProvider;
+pub use providers::{LlmProvider, LlmRequest};
 pub use processor::{
     chunk_text, clean_llm_markdown_output, extract_meeting_name_from_markdown,
     generate_meeting_summary, rough_token_count,
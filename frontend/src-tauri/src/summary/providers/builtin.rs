@@ -0,0 +1,110 @@
+// summary/providers/builtin.rs
+//
+// Built-in AI provider: local inference through the llama-helper sidecar.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use std::path::PathBuf;
+use tracing::warn;
+
+use super::error::{LlmError, LlmErrorKind};
+use super::provider::{ContextWindow, JsonMode, LlmProvider, LlmRequest, ProviderCapabilities};
+use super::tokenizer::TokenCounter;
+use crate::summary::summary_engine::{count_tokens_with_builtin, generate_with_builtin, models};
+
+/// Context window assumed for models missing from the registry
+const FALLBACK_CONTEXT_WINDOW: usize = 2048;
+
+pub struct BuiltInProvider {
+    app_data_dir: PathBuf,
+    model_name: String,
+}
+
+impl BuiltInProvider {
+    pub fn new(app_data_dir: PathBuf, model_name: String) -> Self {
+        Self {
+            app_data_dir,
+            model_name,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for BuiltInProvider {
+    fn provider_name(&self) -> &'static str {
+        "Built-in AI"
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            json_mode: JsonMode::Grammar,
+            local: true,
+            // The sidecar serves one request at a time
+            max_concurrency: 1,
+            // The completion shares the model's context with the prompt
+            max_output_tokens: Some(models::DEFAULT_MAX_TOKENS as u32),
+        }
+    }
+
+    async fn context_window(&self, _client: &Client) -> ContextWindow {
+        match models::get_model_by_name(&self.model_name) {
+            Some(model) => ContextWindow {
+                tokens: model.context_size as usize,
+                source: "model registry",
+            },
+            None => {
+                warn!("Unknown model: {}, using default context", self.model_name);
+                ContextWindow {
+                    tokens: FALLBACK_CONTEXT_WINDOW,
+                    source: "fallback",
+                }
+            }
+        }
+    }
+
+    /// Calibrated with the model's own tokenizer (the sidecar loads the model once
+    /// for counting and generation)
+    async fn token_counter(&self, _client: &Client, sample: &str) -> TokenCounter {
+        if sample.is_empty() {
+            return TokenCounter::Heuristic;
+        }
+        match count_tokens_with_builtin(&self.app_data_dir, &self.model_name, &[sample.to_string()]).await {
+            Ok(counts) => TokenCounter::measured(sample, counts[0]),
+            Err(e) => {
+                warn!("Failed to count tokens with {}: {}", self.model_name, e);
+                TokenCounter::Heuristic
+            }
+        }
+    }
+
+    async fn generate(&self, _client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+        if request.is_cancelled() {
+            return Err(LlmError::cancelled());
+        }
+
+        generate_with_builtin(
+            &self.app_data_dir,
+            &self.model_name,
+            request.system_prompt,
+            request.user_prompt,
+            request.cancellation_token,
+            request.on_token,
+            request.output_schema.map(|schema| schema.grammar.as_str()),
+            request.sampling,
+        )
+        .await
+        .map_err(|e| {
+            // The sidecar reports cancellation as a plain error
+            if request.is_cancelled() {
+                LlmError::cancelled()
+            } else {
+                LlmError::new(LlmErrorKind::Other, e.to_string())
+            }
+        })
+    }
+}
@@ -0,0 +1,110 @@
+// summary/providers/claude.rs
+//
+// Anthropic Messages API provider.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use tracing::info;
+
+use super::error::{LlmError, LlmErrorKind};
+use super::provider::{ContextWindow, JsonMode, LlmProvider, LlmRequest, ProviderCapabilities};
+use crate::summary::llm_client::{
+    read_stream, send_request, ChatMessage, ClaudeChatResponse, ClaudeRequest,
+};
+use crate::summary::streaming::StreamFormat;
+
+const MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Completion limit when the request does not set one (the API requires it)
+const DEFAULT_MAX_TOKENS: u32 = 2048;
+
+pub struct ClaudeProvider {
+    api_key: String,
+    model_name: String,
+}
+
+impl ClaudeProvider {
+    pub fn new(api_key: String, model_name: String) -> Self {
+        Self { api_key, model_name }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for ClaudeProvider {
+    fn provider_name(&self) -> &'static str {
+        "Claude"
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            // No structured output: the result is only validated afterwards
+            json_mode: JsonMode::Unconstrained,
+            local: false,
+            max_concurrency: 4,
+            max_output_tokens: None,
+        }
+    }
+
+    async fn context_window(&self, _client: &Client) -> ContextWindow {
+        ContextWindow {
+            tokens: 200_000,
+            source: "published limit",
+        }
+    }
+
+    async fn generate(&self, client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+        let streaming = request.on_token.is_some();
+        let body = ClaudeRequest {
+            system: request.system_prompt.to_string(),
+            model: self.model_name.clone(),
+            max_tokens: request.sampling.max_tokens.unwrap_or(DEFAULT_MAX_TOKENS),
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: request.user_prompt.to_string(),
+            }],
+            temperature: request.sampling.temperature,
+            top_p: request.sampling.top_p,
+            stream: streaming.then_some(true),
+        };
+
+        info!("🐞 LLM Request to Claude: model={}", self.model_name);
+
+        let http_request = client
+            .post(MESSAGES_URL)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .json(&body);
+        let response = send_request(http_request, streaming, request.cancellation_token).await?;
+
+        if let Some(on_token) = request.on_token {
+            let content =
+                read_stream(response, StreamFormat::ClaudeSse, on_token, request.cancellation_token)
+                    .await?;
+            info!("🐞 LLM streamed response completed from Claude");
+            return Ok(content.trim().to_string());
+        }
+
+        let chat_response = response
+            .json::<ClaudeChatResponse>()
+            .await
+            .map_err(|e| {
+                LlmError::new(LlmErrorKind::Transient, format!("Failed to parse LLM response: {}", e))
+            })?;
+
+        info!("🐞 LLM Response received from Claude");
+
+        let content = chat_response
+            .content
+            .first()
+            .ok_or_else(|| LlmError::new(LlmErrorKind::Transient, "No content in LLM response"))?
+            .text
+            .trim();
+        Ok(content.to_string())
+    }
+}
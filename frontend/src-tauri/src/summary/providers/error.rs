@@ -0,0 +1,226 @@
+// summary/providers/error.rs
+//
+// Classified provider errors. The class decides what happens next: transient
+// failures and rate limits are retried on the same provider, everything else
+// (except cancellation) moves on to the next provider of the fallback chain.
+
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
+
+/// Why a provider request failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LlmErrorKind {
+    /// Invalid, missing or unauthorized API key
+    Auth,
+    /// Out of credits or billing quota (retrying does not help)
+    Quota,
+    /// Too many requests; retried after the reported delay
+    RateLimited,
+    /// The prompt does not fit the model's context window
+    ContextOverflow,
+    /// The provider's safety filters blocked the prompt or the response
+    Blocked,
+    /// Network failures, timeouts, overloaded or failing servers
+    Transient,
+    /// The request was rejected for another reason (bad model name, bad parameters)
+    InvalidRequest,
+    /// The user cancelled the summary
+    Cancelled,
+    /// Unclassified failure (e.g. the built-in sidecar); retried like transient errors
+    Other,
+}
+
+impl LlmErrorKind {
+    /// Whether the same provider may succeed on a later attempt
+    pub fn is_retryable(self) -> bool {
+        matches!(self, Self::RateLimited | Self::Transient | Self::Other)
+    }
+
+    /// Identifier stored in the summary metadata
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Auth => "auth",
+            Self::Quota => "quota",
+            Self::RateLimited => "rate_limited",
+            Self::ContextOverflow => "context_overflow",
+            Self::Blocked => "blocked",
+            Self::Transient => "transient",
+            Self::InvalidRequest => "invalid_request",
+            Self::Cancelled => "cancelled",
+            Self::Other => "other",
+        }
+    }
+}
+
+/// A failed provider request
+#[derive(Debug, Clone, PartialEq)]
+pub struct LlmError {
+    pub kind: LlmErrorKind,
+    pub message: String,
+    /// Delay requested by the provider (`Retry-After` header or Gemini `retryDelay`)
+    pub retry_after: Option<Duration>,
+}
+
+impl LlmError {
+    pub fn new(kind: LlmErrorKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            retry_after: None,
+        }
+    }
+
+    pub fn cancelled() -> Self {
+        Self::new(LlmErrorKind::Cancelled, "Summary generation was cancelled")
+    }
+
+    /// Classifies an error response from its status, headers and body
+    pub fn from_response(status: StatusCode, headers: &HeaderMap, body: &str) -> Self {
+        let retry_after = retry_after_header(headers).or_else(|| retry_delay_in_body(body));
+        let lower = body.to_lowercase();
+
+        let kind = match status.as_u16() {
+            401 | 403 => LlmErrorKind::Auth,
+            // OpenAI reports exhausted credits as 429 `insufficient_quota`
+            429 if lower.contains("insufficient_quota")
+                || (retry_after.is_none() && lower.contains("billing")) =>
+            {
+                LlmErrorKind::Quota
+            }
+            429 => LlmErrorKind::RateLimited,
+            402 => LlmErrorKind::Quota,
+            400 | 413 | 422 if is_context_overflow(&lower) => LlmErrorKind::ContextOverflow,
+            408 | 425 | 500 | 502 | 503 | 504 | 529 => LlmErrorKind::Transient,
+            _ if status.is_server_error() => LlmErrorKind::Transient,
+            _ => LlmErrorKind::InvalidRequest,
+        };
+
+        Self {
+            kind,
+            message: format!("LLM API request failed ({}): {}", status.as_u16(), body),
+            retry_after,
+        }
+    }
+}
+
+impl LlmError {
+    /// Classifies an error the server reported inside a stream that had started normally
+    pub fn from_stream_error(message: &str) -> Self {
+        let kind = if is_context_overflow(&message.to_lowercase()) {
+            LlmErrorKind::ContextOverflow
+        } else {
+            LlmErrorKind::Other
+        };
+        Self::new(kind, format!("LLM API stream failed: {}", message))
+    }
+}
+
+impl fmt::Display for LlmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for LlmError {}
+
+/// Callers outside the providers keep working with plain messages
+impl From<LlmError> for String {
+    fn from(error: LlmError) -> Self {
+        error.message
+    }
+}
+
+fn is_context_overflow(lower: &str) -> bool {
+    [
+        "context_length_exceeded",
+        "maximum context length",
+        "context window",
+        "prompt is too long",
+        "too many tokens",
+        "input token count",
+        "reduce the length",
+    ]
+    .iter()
+    .any(|marker| lower.contains(marker))
+}
+
+/// `Retry-After` in seconds or as an HTTP date
+fn retry_after_header(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+    if let Ok(seconds) = value.parse::<f64>() {
+        return (seconds >= 0.0).then(|| Duration::from_secs_f64(seconds));
+    }
+    let date = chrono::DateTime::parse_from_rfc2822(value).ok()?;
+    let wait = date.timestamp_millis() - chrono::Utc::now().timestamp_millis();
+    Some(Duration::from_millis(wait.max(0) as u64))
+}
+
+/// Gemini reports the delay in the body: `error.details[].retryDelay` ("27s")
+fn retry_delay_in_body(body: &str) -> Option<Duration> {
+    let value: Value = serde_json::from_str(body).ok()?;
+    value
+        .pointer("/error/details")?
+        .as_array()?
+        .iter()
+        .filter_map(|detail| detail.get("retryDelay").and_then(Value::as_str))
+        .find_map(|delay| delay.trim_end_matches('s').parse::<f64>().ok())
+        .map(Duration::from_secs_f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_classifies_status_codes() {
+        let headers = HeaderMap::new();
+        let classify = |status: u16, body: &str| {
+            LlmError::from_response(StatusCode::from_u16(status).unwrap(), &headers, body).kind
+        };
+
+        assert_eq!(classify(401, "invalid api key"), LlmErrorKind::Auth);
+        assert_eq!(classify(429, r#"{"error":{"message":"Rate limit reached"}}"#), LlmErrorKind::RateLimited);
+        assert_eq!(
+            classify(429, r#"{"error":{"code":"insufficient_quota","message":"You exceeded your current quota, please check your plan and billing details."}}"#),
+            LlmErrorKind::Quota
+        );
+        assert_eq!(
+            classify(400, r#"{"error":{"code":"context_length_exceeded"}}"#),
+            LlmErrorKind::ContextOverflow
+        );
+        assert_eq!(classify(400, r#"{"error":{"message":"model not found"}}"#), LlmErrorKind::InvalidRequest);
+        assert_eq!(classify(503, "Service Unavailable"), LlmErrorKind::Transient);
+        assert_eq!(classify(529, r#"{"type":"overloaded_error"}"#), LlmErrorKind::Transient);
+    }
+
+    #[test]
+    fn test_retry_after_header_and_gemini_retry_delay() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+        let error = LlmError::from_response(StatusCode::TOO_MANY_REQUESTS, &headers, "");
+        assert_eq!(error.retry_after, Some(Duration::from_secs(7)));
+
+        // Gemini's per-minute limit mentions billing but reports a delay
+        let body = r#"{"error":{"code":429,"message":"You exceeded your current quota, please check your plan and billing details.","status":"RESOURCE_EXHAUSTED","details":[{"@type":"type.googleapis.com/google.rpc.RetryInfo","retryDelay":"27s"}]}}"#;
+        let error = LlmError::from_response(StatusCode::TOO_MANY_REQUESTS, &HeaderMap::new(), body);
+        assert_eq!(error.kind, LlmErrorKind::RateLimited);
+        assert_eq!(error.retry_after, Some(Duration::from_secs(27)));
+    }
+
+    #[test]
+    fn test_classifies_stream_errors() {
+        // The server's wording never turns an error into a user cancellation
+        let error = LlmError::from_stream_error("request cancelled by upstream");
+        assert_eq!(error.kind, LlmErrorKind::Other);
+        assert_eq!(String::from(error), "LLM API stream failed: request cancelled by upstream");
+
+        assert_eq!(
+            LlmError::from_stream_error("This model's maximum context length is 8192 tokens").kind,
+            LlmErrorKind::ContextOverflow
+        );
+    }
+}
@@ -0,0 +1,509 @@
+// summary/providers/fallback.rs
+//
+// Provider chain: retries transient failures and rate limits with exponential
+// backoff (honouring `Retry-After`), then falls back to the next configured
+// provider (for example Groq → local Ollama). Records which provider served
+// each call so the summary metadata can tell what actually produced it.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use super::error::{LlmError, LlmErrorKind};
+use super::provider::{ContextWindow, LlmProvider, LlmRequest, ProviderCapabilities};
+use super::registry::load_provider;
+use super::tokenizer::TokenCounter;
+use crate::database::repositories::setting::SettingsRepository;
+
+/// Retry behaviour of each provider in the chain
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Attempts per provider before falling back to the next one
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled on each following attempt
+    pub base_delay: Duration,
+    /// Longest wait accepted; a longer `Retry-After` falls back immediately
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_secs(2),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Wait before attempt `attempt + 1`, or None when the provider asks for too long
+    fn delay(&self, attempt: u32, error: &LlmError) -> Option<Duration> {
+        match error.retry_after {
+            Some(wait) if wait > self.max_delay => None,
+            Some(wait) => Some(wait),
+            None => Some((self.base_delay * 2u32.pow(attempt - 1)).min(self.max_delay)),
+        }
+    }
+}
+
+/// Calls served and failed by one provider of the chain
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ProviderUsage {
+    pub provider: String,
+    pub model: String,
+    /// Successful completions
+    pub calls: usize,
+    /// Requests that failed after all retries
+    pub failures: usize,
+    /// Class of the last error (see [`LlmErrorKind::as_str`])
+    pub last_error: Option<&'static str>,
+}
+
+/// Which provider produced a summary; stored in `summary_processes.metadata`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ProviderReport {
+    /// Provider and model of the last successful completion
+    pub provider: Option<String>,
+    pub model: Option<String>,
+    /// A provider other than the configured one served at least one call
+    pub fallback_used: bool,
+    pub providers: Vec<ProviderUsage>,
+}
+
+/// Chain of providers tried in order; itself an [`LlmProvider`]
+pub struct ProviderChain {
+    providers: Vec<Arc<dyn LlmProvider>>,
+    policy: RetryPolicy,
+    usage: Mutex<Vec<ProviderUsage>>,
+    /// Index of the provider that served the last successful call
+    last_success: Mutex<Option<usize>>,
+}
+
+impl ProviderChain {
+    /// Chain starting with `primary`, falling back to `fallbacks` in order
+    pub fn new(primary: Arc<dyn LlmProvider>, fallbacks: Vec<Arc<dyn LlmProvider>>) -> Self {
+        let providers: Vec<Arc<dyn LlmProvider>> =
+            std::iter::once(primary).chain(fallbacks).collect();
+        let usage = providers
+            .iter()
+            .map(|provider| ProviderUsage {
+                provider: provider.provider_name().to_string(),
+                model: provider.model_name().to_string(),
+                calls: 0,
+                failures: 0,
+                last_error: None,
+            })
+            .collect();
+
+        Self {
+            providers,
+            policy: RetryPolicy::default(),
+            usage: Mutex::new(usage),
+            last_success: Mutex::new(None),
+        }
+    }
+
+    pub fn with_policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    fn primary(&self) -> &dyn LlmProvider {
+        self.providers[0].as_ref()
+    }
+
+    /// Providers used so far, for the summary metadata
+    pub fn report(&self) -> ProviderReport {
+        let usage = self.usage.lock().map(|usage| usage.clone()).unwrap_or_default();
+        let last_success = self.last_success.lock().ok().and_then(|index| *index);
+
+        ProviderReport {
+            provider: last_success.map(|index| usage[index].provider.clone()),
+            model: last_success.map(|index| usage[index].model.clone()),
+            fallback_used: usage.iter().skip(1).any(|u| u.calls > 0),
+            providers: usage,
+        }
+    }
+
+    fn record(&self, index: usize, result: &Result<String, LlmError>) {
+        if let Ok(mut usage) = self.usage.lock() {
+            match result {
+                Ok(_) => usage[index].calls += 1,
+                Err(error) => {
+                    usage[index].failures += 1;
+                    usage[index].last_error = Some(error.kind.as_str());
+                }
+            }
+        }
+        if result.is_ok() {
+            if let Ok(mut last_success) = self.last_success.lock() {
+                *last_success = Some(index);
+            }
+        }
+    }
+
+    /// Tries one provider, retrying retryable errors. Nothing is retried once text was
+    /// streamed to the caller, since it cannot be taken back.
+    async fn generate_with_retry(
+        &self,
+        provider: &dyn LlmProvider,
+        client: &Client,
+        request: &LlmRequest<'_>,
+        streamed: &AtomicBool,
+    ) -> Result<String, LlmError> {
+        let mut attempt = 1;
+        loop {
+            let error = match provider.generate(client, request).await {
+                Ok(text) if !text.trim().is_empty() => return Ok(text),
+                Ok(_) => LlmError::new(LlmErrorKind::Transient, "Empty response from the model"),
+                Err(error) => error,
+            };
+
+            if !error.kind.is_retryable()
+                || attempt >= self.policy.max_attempts
+                || streamed.load(Ordering::Relaxed)
+            {
+                return Err(error);
+            }
+            let Some(delay) = self.policy.delay(attempt, &error) else {
+                warn!(
+                    "{} asked to wait longer than {}s: {}",
+                    provider.provider_name(),
+                    self.policy.max_delay.as_secs(),
+                    error
+                );
+                return Err(error);
+            };
+
+            warn!(
+                "{} request failed ({}, attempt {}/{}): {}. Retrying in {:.1}s",
+                provider.provider_name(),
+                error.kind.as_str(),
+                attempt,
+                self.policy.max_attempts,
+                error,
+                delay.as_secs_f64()
+            );
+            match request.cancellation_token {
+                Some(token) => tokio::select! {
+                    _ = tokio::time::sleep(delay) => {}
+                    _ = token.cancelled() => return Err(LlmError::cancelled()),
+                },
+                None => tokio::time::sleep(delay).await,
+            }
+            attempt += 1;
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for ProviderChain {
+    fn provider_name(&self) -> &'static str {
+        self.primary().provider_name()
+    }
+
+    fn model_name(&self) -> &str {
+        self.primary().model_name()
+    }
+
+    /// The primary's capabilities, limited so any fallback can serve the same requests
+    fn capabilities(&self) -> ProviderCapabilities {
+        let primary = self.primary().capabilities();
+        ProviderCapabilities {
+            local: self.providers.iter().all(|p| p.capabilities().local),
+            max_concurrency: self
+                .providers
+                .iter()
+                .map(|p| p.capabilities().max_concurrency)
+                .min()
+                .unwrap_or(primary.max_concurrency),
+            ..primary
+        }
+    }
+
+    /// The smallest window of the chain, so chunks fit whichever provider serves them
+    async fn context_window(&self, client: &Client) -> ContextWindow {
+        let mut smallest = self.primary().context_window(client).await;
+        for provider in self.providers.iter().skip(1) {
+            let window = provider.context_window(client).await;
+            if window.tokens < smallest.tokens {
+                info!(
+                    "Fallback {} limits the context window to {} tokens",
+                    provider.provider_name(),
+                    window.tokens
+                );
+                smallest = window;
+            }
+        }
+        smallest
+    }
+
+    /// The counter reporting the most tokens for `sample`, so sizes hold for every provider
+    async fn token_counter(&self, client: &Client, sample: &str) -> TokenCounter {
+        let mut largest = self.primary().token_counter(client, sample).await;
+        let mut largest_count = largest.count(sample);
+        for provider in self.providers.iter().skip(1) {
+            let counter = provider.token_counter(client, sample).await;
+            let count = counter.count(sample);
+            if count > largest_count {
+                largest = counter;
+                largest_count = count;
+            }
+        }
+        largest
+    }
+
+    async fn generate(&self, client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+        // Track whether any text reached the caller, which rules out retrying
+        let streamed = AtomicBool::new(false);
+        let streamed_flag = &streamed;
+        let on_token = request.on_token.map(|on_token| {
+            move |text: &str| {
+                streamed_flag.store(true, Ordering::Relaxed);
+                on_token(text);
+            }
+        });
+        let request = LlmRequest {
+            system_prompt: request.system_prompt,
+            user_prompt: request.user_prompt,
+            sampling: request.sampling,
+            output_schema: request.output_schema,
+            cancellation_token: request.cancellation_token,
+            on_token: on_token.as_ref().map(|f| f as &(dyn Fn(&str) + Send + Sync)),
+        };
+
+        let mut last_error = None;
+        for (index, provider) in self.providers.iter().enumerate() {
+            if let Some(error) = &last_error {
+                warn!(
+                    "Falling back to {} ({}) after: {}",
+                    provider.provider_name(),
+                    provider.model_name(),
+                    error
+                );
+            }
+
+            let result = self
+                .generate_with_retry(provider.as_ref(), client, &request, &streamed)
+                .await;
+            self.record(index, &result);
+            match result {
+                Ok(text) => return Ok(text),
+                Err(error) if error.kind == LlmErrorKind::Cancelled => return Err(error),
+                Err(error) if streamed.load(Ordering::Relaxed) => return Err(error),
+                Err(error) => last_error = Some(error),
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| LlmError::new(LlmErrorKind::Other, "No provider configured")))
+    }
+}
+
+/// Builds the configured provider followed by the fallback chain from the settings
+///
+/// Fallbacks that cannot be built (missing key, unknown id) are skipped with a warning.
+pub async fn load_provider_chain(
+    pool: &SqlitePool,
+    provider: &str,
+    model_name: &str,
+    app_data_dir: Option<PathBuf>,
+) -> Result<Arc<ProviderChain>, String> {
+    let primary = load_provider(pool, provider, model_name, app_data_dir.clone()).await?;
+
+    let configured = SettingsRepository::get_summary_fallback_chain(pool)
+        .await
+        .unwrap_or_else(|e| {
+            warn!("Failed to load the summary fallback chain: {}", e);
+            Vec::new()
+        });
+    let mut fallbacks = Vec::new();
+    for fallback in configured {
+        if fallback.provider == provider && fallback.model == model_name {
+            continue;
+        }
+        match load_provider(pool, &fallback.provider, &fallback.model, app_data_dir.clone()).await {
+            Ok(fallback_provider) => fallbacks.push(fallback_provider),
+            Err(e) => warn!("Skipping fallback {} ({}): {}", fallback.provider, fallback.model, e),
+        }
+    }
+    if !fallbacks.is_empty() {
+        info!(
+            "Summary provider {} ({}) with fallbacks: {}",
+            primary.provider_name(),
+            primary.model_name(),
+            fallbacks
+                .iter()
+                .map(|p| format!("{} ({})", p.provider_name(), p.model_name()))
+                .collect::<Vec<_>>()
+                .join(" → ")
+        );
+    }
+
+    Ok(Arc::new(ProviderChain::new(primary, fallbacks)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::providers::JsonMode;
+    use std::collections::VecDeque;
+
+    /// Provider answering with scripted results
+    struct ScriptedProvider {
+        name: &'static str,
+        results: Mutex<VecDeque<Result<String, LlmError>>>,
+    }
+
+    impl ScriptedProvider {
+        fn new(name: &'static str, results: Vec<Result<String, LlmError>>) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                results: Mutex::new(results.into()),
+            })
+        }
+
+        fn remaining(&self) -> usize {
+            self.results.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait]
+    impl LlmProvider for ScriptedProvider {
+        fn provider_name(&self) -> &'static str {
+            self.name
+        }
+
+        fn model_name(&self) -> &str {
+            "model"
+        }
+
+        fn capabilities(&self) -> ProviderCapabilities {
+            ProviderCapabilities {
+                streaming: true,
+                json_mode: JsonMode::Unconstrained,
+                local: false,
+                max_concurrency: 4,
+                max_output_tokens: None,
+            }
+        }
+
+        async fn context_window(&self, _client: &Client) -> ContextWindow {
+            ContextWindow { tokens: 8192, source: "test" }
+        }
+
+        async fn generate(&self, _client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+            let result = self.results.lock().unwrap().pop_front().expect("unexpected call");
+            if let (Ok(text), Some(on_token)) = (&result, request.on_token) {
+                on_token(text);
+            }
+            result
+        }
+    }
+
+    fn fast_policy() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(50),
+        }
+    }
+
+    fn rate_limited(retry_after: Option<Duration>) -> LlmError {
+        LlmError {
+            retry_after,
+            ..LlmError::new(LlmErrorKind::RateLimited, "429 Too Many Requests")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_rate_limits_on_the_same_provider() {
+        let primary = ScriptedProvider::new(
+            "Groq",
+            vec![
+                Err(rate_limited(Some(Duration::from_millis(5)))),
+                Err(LlmError::new(LlmErrorKind::Transient, "503")),
+                Ok("resumen".to_string()),
+            ],
+        );
+        let chain = ProviderChain::new(primary.clone(), Vec::new()).with_policy(fast_policy());
+
+        let text = chain.generate(&Client::new(), &LlmRequest::new("s", "u")).await.unwrap();
+        assert_eq!(text, "resumen");
+        assert_eq!(primary.remaining(), 0);
+
+        let report = chain.report();
+        assert_eq!(report.provider.as_deref(), Some("Groq"));
+        assert!(!report.fallback_used);
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_without_retrying_auth_errors() {
+        let primary = ScriptedProvider::new(
+            "Groq",
+            vec![Err(LlmError::new(LlmErrorKind::Auth, "401 invalid key"))],
+        );
+        let fallback = ScriptedProvider::new("Ollama", vec![Ok("resumen local".to_string())]);
+        let chain = ProviderChain::new(primary.clone(), vec![fallback.clone() as Arc<dyn LlmProvider>])
+            .with_policy(fast_policy());
+
+        let text = chain.generate(&Client::new(), &LlmRequest::new("s", "u")).await.unwrap();
+        assert_eq!(text, "resumen local");
+
+        let report = chain.report();
+        assert_eq!(report.provider.as_deref(), Some("Ollama"));
+        assert!(report.fallback_used);
+        assert_eq!(report.providers[0].failures, 1);
+        assert_eq!(report.providers[0].last_error, Some("auth"));
+        assert_eq!(report.providers[1].calls, 1);
+    }
+
+    #[tokio::test]
+    async fn test_long_retry_after_falls_back_immediately() {
+        let primary = ScriptedProvider::new(
+            "OpenAI",
+            vec![Err(rate_limited(Some(Duration::from_secs(3600))))],
+        );
+        let fallback = ScriptedProvider::new("Ollama", vec![Ok("ok".to_string())]);
+        let chain = ProviderChain::new(primary.clone(), vec![fallback as Arc<dyn LlmProvider>])
+            .with_policy(fast_policy());
+
+        assert_eq!(chain.generate(&Client::new(), &LlmRequest::new("s", "u")).await.unwrap(), "ok");
+        assert_eq!(primary.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_is_not_retried_or_fallen_back() {
+        let primary = ScriptedProvider::new("Groq", vec![Err(LlmError::cancelled())]);
+        let fallback = ScriptedProvider::new("Ollama", vec![Ok("ok".to_string())]);
+        let chain = ProviderChain::new(primary, vec![fallback.clone() as Arc<dyn LlmProvider>])
+            .with_policy(fast_policy());
+
+        let error = chain.generate(&Client::new(), &LlmRequest::new("s", "u")).await.unwrap_err();
+        assert_eq!(error.kind, LlmErrorKind::Cancelled);
+        assert_eq!(fallback.remaining(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_after_max_attempts() {
+        let primary = ScriptedProvider::new(
+            "Groq",
+            vec![
+                Err(LlmError::new(LlmErrorKind::Transient, "502")),
+                Err(LlmError::new(LlmErrorKind::Transient, "502")),
+                Err(LlmError::new(LlmErrorKind::Transient, "503")),
+            ],
+        );
+        let chain = ProviderChain::new(primary.clone(), Vec::new()).with_policy(fast_policy());
+
+        let error = chain.generate(&Client::new(), &LlmRequest::new("s", "u")).await.unwrap_err();
+        assert_eq!(error.message, "503");
+        assert_eq!(chain.report().provider, None);
+    }
+}
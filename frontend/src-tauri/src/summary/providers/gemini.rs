@@ -0,0 +1,509 @@
+// summary/providers/gemini.rs
+//
+// Google Gemini provider (Generative Language API, `generateContent`).
+// Gemini models have context windows of up to a few million tokens, so most
+// transcripts are summarized in a single pass instead of being map-reduced.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::{info, warn};
+
+use super::error::{LlmError, LlmErrorKind};
+use super::provider::{ContextWindow, JsonMode, LlmProvider, LlmRequest, ProviderCapabilities};
+use crate::summary::llm_client::{read_stream, send_request};
+use crate::summary::streaming::StreamFormat;
+
+const DEFAULT_BASE_URL: &str = "https://generativelanguage.googleapis.com/v1beta";
+
+/// Context window assumed for models missing from the model listing
+const FALLBACK_CONTEXT_WINDOW: usize = 32_768;
+
+/// Finish reasons for which Gemini withholds the generated text
+const BLOCKING_FINISH_REASONS: &[&str] = &[
+    "SAFETY",
+    "RECITATION",
+    "BLOCKLIST",
+    "PROHIBITED_CONTENT",
+    "SPII",
+    "IMAGE_SAFETY",
+];
+
+pub struct GeminiProvider {
+    /// API base URL, without the trailing `/models`
+    base_url: String,
+    api_key: String,
+    /// Model id without the `models/` prefix (e.g., "gemini-2.5-flash")
+    model_name: String,
+}
+
+impl GeminiProvider {
+    pub fn new(api_key: String, model_name: String) -> Self {
+        Self::with_base_url(DEFAULT_BASE_URL, api_key, model_name)
+    }
+
+    /// Provider talking to another API base URL (proxies, tests)
+    pub fn with_base_url(base_url: &str, api_key: String, model_name: String) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key,
+            model_name: model_name.trim_start_matches("models/").to_string(),
+        }
+    }
+}
+
+/// A Gemini model that supports `generateContent`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GeminiModel {
+    /// Model id to store in the settings (e.g., "gemini-2.5-flash")
+    pub id: String,
+    pub name: String,
+    pub input_token_limit: Option<u32>,
+    pub output_token_limit: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ApiModel {
+    /// Resource name: `models/{id}`
+    name: String,
+    display_name: Option<String>,
+    input_token_limit: Option<u32>,
+    output_token_limit: Option<u32>,
+    #[serde(default)]
+    supported_generation_methods: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListModelsResponse {
+    #[serde(default)]
+    models: Vec<ApiModel>,
+    next_page_token: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct Part<'a> {
+    text: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct Content<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    role: Option<&'static str>,
+    parts: Vec<Part<'a>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerationConfig<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_output_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_mime_type: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    response_json_schema: Option<&'a Value>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerateContentRequest<'a> {
+    system_instruction: Content<'a>,
+    contents: Vec<Content<'a>>,
+    generation_config: GenerationConfig<'a>,
+}
+
+#[async_trait]
+impl LlmProvider for GeminiProvider {
+    fn provider_name(&self) -> &'static str {
+        "Gemini"
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            json_mode: JsonMode::Schema,
+            local: false,
+            max_concurrency: 4,
+            max_output_tokens: None,
+        }
+    }
+
+    /// Asks the model endpoint for its input limit, else uses the published limit
+    async fn context_window(&self, client: &Client) -> ContextWindow {
+        let url = format!("{}/models/{}", self.base_url, self.model_name);
+        let reported = match client.get(&url).header("x-goog-api-key", &self.api_key).send().await {
+            Ok(response) if response.status().is_success() => response
+                .json::<ApiModel>()
+                .await
+                .ok()
+                .and_then(|model| model.input_token_limit),
+            Ok(response) => {
+                warn!("Gemini model lookup for {} failed: {}", self.model_name, response.status());
+                None
+            }
+            Err(e) => {
+                warn!("Gemini model lookup for {} failed: {}", self.model_name, e);
+                None
+            }
+        };
+
+        match (reported, published_context_window(&self.model_name)) {
+            (Some(tokens), _) => ContextWindow { tokens: tokens as usize, source: "reported by server" },
+            (None, Some(tokens)) => ContextWindow { tokens, source: "published limit" },
+            (None, None) => ContextWindow { tokens: FALLBACK_CONTEXT_WINDOW, source: "fallback" },
+        }
+    }
+
+    async fn generate(&self, client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+        let streaming = request.on_token.is_some();
+        let body = GenerateContentRequest {
+            system_instruction: Content {
+                role: None,
+                parts: vec![Part { text: request.system_prompt }],
+            },
+            contents: vec![Content {
+                role: Some("user"),
+                parts: vec![Part { text: request.user_prompt }],
+            }],
+            generation_config: GenerationConfig {
+                max_output_tokens: request.sampling.max_tokens,
+                temperature: request.sampling.temperature,
+                top_p: request.sampling.top_p,
+                response_mime_type: request.output_schema.map(|_| "application/json"),
+                response_json_schema: request.output_schema.map(|output| &output.schema),
+            },
+        };
+
+        let url = if streaming {
+            format!("{}/models/{}:streamGenerateContent?alt=sse", self.base_url, self.model_name)
+        } else {
+            format!("{}/models/{}:generateContent", self.base_url, self.model_name)
+        };
+
+        info!("🐞 LLM Request to Gemini: model={}", self.model_name);
+
+        let http_request = client
+            .post(&url)
+            .header("x-goog-api-key", &self.api_key)
+            .json(&body);
+        let response = send_request(http_request, streaming, request.cancellation_token).await?;
+
+        if let Some(on_token) = request.on_token {
+            let content =
+                read_stream(response, StreamFormat::GeminiSse, on_token, request.cancellation_token)
+                    .await?;
+            info!("🐞 LLM streamed response completed from Gemini");
+            return Ok(content.trim().to_string());
+        }
+
+        let response = response
+            .json::<Value>()
+            .await
+            .map_err(|e| {
+                LlmError::new(LlmErrorKind::Transient, format!("Failed to parse LLM response: {}", e))
+            })?;
+
+        info!("🐞 LLM Response received from Gemini");
+
+        if let Some(message) = block_message(&response) {
+            return Err(LlmError::new(LlmErrorKind::Blocked, message));
+        }
+        if response.pointer("/candidates/0/finishReason").and_then(Value::as_str) == Some("MAX_TOKENS") {
+            warn!("Gemini response was truncated at the output token limit");
+        }
+
+        let content = response_text(&response);
+        if content.trim().is_empty() {
+            return Err(LlmError::new(LlmErrorKind::Transient, "No content in LLM response"));
+        }
+        Ok(content.trim().to_string())
+    }
+}
+
+/// Lists the Gemini models that support `generateContent`
+pub async fn list_models(client: &Client, api_key: &str) -> Result<Vec<GeminiModel>, String> {
+    list_models_at(client, DEFAULT_BASE_URL, api_key).await
+}
+
+async fn list_models_at(client: &Client, base_url: &str, api_key: &str) -> Result<Vec<GeminiModel>, String> {
+    let mut models = Vec::new();
+    let mut page_token: Option<String> = None;
+
+    loop {
+        let mut request = client
+            .get(format!("{}/models", base_url.trim_end_matches('/')))
+            .header("x-goog-api-key", api_key)
+            .query(&[("pageSize", "1000")]);
+        if let Some(token) = &page_token {
+            request = request.query(&[("pageToken", token.as_str())]);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| format!("Failed to make HTTP request: {}", e))?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(format!("HTTP request failed with status: {} {}", status, api_error_message(&body)));
+        }
+
+        let page: ListModelsResponse = response
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse JSON response: {}", e))?;
+
+        models.extend(
+            page.models
+                .into_iter()
+                .filter(|m| m.supported_generation_methods.iter().any(|method| method == "generateContent"))
+                .map(|m| {
+                    let id = m.name.trim_start_matches("models/").to_string();
+                    GeminiModel {
+                        name: m.display_name.unwrap_or_else(|| id.clone()),
+                        id,
+                        input_token_limit: m.input_token_limit,
+                        output_token_limit: m.output_token_limit,
+                    }
+                }),
+        );
+
+        match page.next_page_token.filter(|token| !token.is_empty()) {
+            Some(token) => page_token = Some(token),
+            None => break,
+        }
+    }
+
+    Ok(models)
+}
+
+/// Text of the first candidate (thought summaries of thinking models are skipped)
+pub(crate) fn response_text(response: &Value) -> String {
+    response
+        .pointer("/candidates/0/content/parts")
+        .and_then(Value::as_array)
+        .map(|parts| {
+            parts
+                .iter()
+                .filter(|part| part.get("thought").and_then(Value::as_bool) != Some(true))
+                .filter_map(|part| part.get("text").and_then(Value::as_str))
+                .collect::<String>()
+        })
+        .unwrap_or_default()
+}
+
+/// Error message when Gemini blocked the prompt or withheld the response
+pub(crate) fn block_message(response: &Value) -> Option<String> {
+    if let Some(reason) = response.pointer("/promptFeedback/blockReason").and_then(Value::as_str) {
+        return Some(format!(
+            "Gemini blocked the request ({}): the transcript was flagged by its safety filters",
+            reason
+        ));
+    }
+
+    let candidate = response.pointer("/candidates/0")?;
+    let reason = candidate.get("finishReason").and_then(Value::as_str)?;
+    if !BLOCKING_FINISH_REASONS.contains(&reason) {
+        return None;
+    }
+
+    let categories: Vec<&str> = candidate
+        .get("safetyRatings")
+        .and_then(Value::as_array)
+        .map(|ratings| {
+            ratings
+                .iter()
+                .filter(|rating| rating.get("blocked").and_then(Value::as_bool) == Some(true))
+                .filter_map(|rating| rating.get("category").and_then(Value::as_str))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(if categories.is_empty() {
+        format!("Gemini withheld the response ({})", reason)
+    } else {
+        format!("Gemini withheld the response ({}: {})", reason, categories.join(", "))
+    })
+}
+
+/// `error.message` of a Gemini error body, or the body itself
+fn api_error_message(body: &str) -> String {
+    serde_json::from_str::<Value>(body)
+        .ok()
+        .and_then(|value| value.pointer("/error/message").and_then(Value::as_str).map(str::to_string))
+        .unwrap_or_else(|| body.to_string())
+}
+
+/// Published input limits of Gemini models
+fn published_context_window(model_name: &str) -> Option<usize> {
+    let model = model_name.to_lowercase();
+    if model.starts_with("gemini-1.5-pro") {
+        Some(2_097_152)
+    } else if model.starts_with("gemini-1.0") || model == "gemini-pro" {
+        Some(30_720)
+    } else if model.starts_with("gemini-") {
+        // gemini-1.5-flash, 2.0, 2.5 and later
+        Some(1_048_576)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc;
+
+    /// Serves `body` with `status` to the first connection; yields the raw request
+    fn mock_server(status: &str, body: &str) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}/v1beta", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            body.len(),
+            body
+        );
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::new();
+            let mut buffer = [0u8; 4096];
+            loop {
+                let read = stream.read(&mut buffer).unwrap();
+                if read == 0 {
+                    break;
+                }
+                request.extend_from_slice(&buffer[..read]);
+                let text = String::from_utf8_lossy(&request).to_string();
+                if let Some(header_end) = text.find("\r\n\r\n") {
+                    let content_length = text[..header_end]
+                        .lines()
+                        .find_map(|line| {
+                            let (name, value) = line.split_once(':')?;
+                            name.eq_ignore_ascii_case("content-length")
+                                .then(|| value.trim().parse::<usize>().ok())?
+                        })
+                        .unwrap_or(0);
+                    if request.len() >= header_end + 4 + content_length {
+                        break;
+                    }
+                }
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            sender.send(String::from_utf8_lossy(&request).to_string()).unwrap();
+        });
+
+        (base_url, receiver)
+    }
+
+    #[tokio::test]
+    async fn test_generate_content_request_and_response() {
+        let (base_url, requests) = mock_server(
+            "200 OK",
+            r#"{"candidates":[{"content":{"parts":[{"text":"pensando...","thought":true},{"text":"# Resumen"},{"text":" de la reunión"}],"role":"model"},"finishReason":"STOP"}]}"#,
+        );
+        let provider = GeminiProvider::with_base_url(&base_url, "test-key".to_string(), "models/gemini-2.5-flash".to_string());
+
+        let request = LlmRequest::new("Eres un asistente", "Transcripción");
+        let content = provider.generate(&Client::new(), &request).await.unwrap();
+        assert_eq!(content, "# Resumen de la reunión");
+
+        let raw_request = requests.recv().unwrap();
+        assert!(raw_request.starts_with("POST /v1beta/models/gemini-2.5-flash:generateContent "));
+        assert!(raw_request.to_lowercase().contains("x-goog-api-key: test-key"));
+        assert!(raw_request.contains(r#""systemInstruction":{"parts":[{"text":"Eres un asistente"}]}"#));
+        assert!(raw_request.contains(r#""contents":[{"role":"user","parts":[{"text":"Transcripción"}]}]"#));
+    }
+
+    #[tokio::test]
+    async fn test_safety_blocks_are_reported() {
+        let (base_url, _requests) = mock_server(
+            "200 OK",
+            r#"{"promptFeedback":{"blockReason":"SAFETY","safetyRatings":[]}}"#,
+        );
+        let provider = GeminiProvider::with_base_url(&base_url, "key".to_string(), "gemini-2.0-flash".to_string());
+        let error = provider
+            .generate(&Client::new(), &LlmRequest::new("system", "user"))
+            .await
+            .unwrap_err();
+        assert_eq!(error.kind, LlmErrorKind::Blocked);
+        assert!(error.message.contains("blocked the request (SAFETY)"), "{}", error);
+
+        let withheld = serde_json::json!({
+            "candidates": [{
+                "finishReason": "SAFETY",
+                "safetyRatings": [
+                    {"category": "HARM_CATEGORY_HARASSMENT", "probability": "HIGH", "blocked": true},
+                    {"category": "HARM_CATEGORY_HATE_SPEECH", "probability": "NEGLIGIBLE"}
+                ]
+            }]
+        });
+        assert_eq!(
+            block_message(&withheld).as_deref(),
+            Some("Gemini withheld the response (SAFETY: HARM_CATEGORY_HARASSMENT)")
+        );
+        assert_eq!(block_message(&serde_json::json!({"candidates": [{"finishReason": "STOP"}]})), None);
+    }
+
+    #[tokio::test]
+    async fn test_api_errors_are_reported() {
+        let (base_url, _requests) = mock_server(
+            "400 Bad Request",
+            r#"{"error":{"code":400,"message":"API key not valid. Please pass a valid API key.","status":"INVALID_ARGUMENT"}}"#,
+        );
+        let error = list_models_at(&Client::new(), &base_url, "bad-key").await.unwrap_err();
+        assert!(error.contains("API key not valid"), "{}", error);
+    }
+
+    #[tokio::test]
+    async fn test_context_window_from_model_endpoint() {
+        let (base_url, requests) = mock_server(
+            "200 OK",
+            r#"{"name":"models/gemini-2.5-pro","displayName":"Gemini 2.5 Pro","inputTokenLimit":1048576,"outputTokenLimit":65536,"supportedGenerationMethods":["generateContent","countTokens"]}"#,
+        );
+        let provider = GeminiProvider::with_base_url(&base_url, "key".to_string(), "gemini-2.5-pro".to_string());
+
+        let window = provider.context_window(&Client::new()).await;
+        assert_eq!(window.tokens, 1_048_576);
+        assert_eq!(window.source, "reported by server");
+        assert!(requests.recv().unwrap().starts_with("GET /v1beta/models/gemini-2.5-pro "));
+    }
+
+    #[tokio::test]
+    async fn test_list_models_keeps_generate_content_models() {
+        let (base_url, _requests) = mock_server(
+            "200 OK",
+            r#"{"models":[
+                {"name":"models/gemini-2.5-flash","displayName":"Gemini 2.5 Flash","inputTokenLimit":1048576,"outputTokenLimit":65536,"supportedGenerationMethods":["generateContent","countTokens"]},
+                {"name":"models/text-embedding-004","displayName":"Text Embedding 004","inputTokenLimit":2048,"supportedGenerationMethods":["embedContent"]}
+            ]}"#,
+        );
+
+        let models = list_models_at(&Client::new(), &base_url, "key").await.unwrap();
+        assert_eq!(models.len(), 1);
+        assert_eq!(models[0].id, "gemini-2.5-flash");
+        assert_eq!(models[0].name, "Gemini 2.5 Flash");
+        assert_eq!(models[0].input_token_limit, Some(1_048_576));
+    }
+
+    #[test]
+    fn test_published_context_windows() {
+        assert_eq!(published_context_window("gemini-1.5-pro-002"), Some(2_097_152));
+        assert_eq!(published_context_window("gemini-2.5-flash"), Some(1_048_576));
+        assert_eq!(published_context_window("gemma-3-27b-it"), None);
+    }
+}
@@ -0,0 +1,25 @@
+// Summary LLM providers
+// Every provider implements LlmProvider and is registered in registry.rs;
+// fallback.rs wraps them with retries and the configured fallback chain
+
+pub mod builtin;
+pub mod claude;
+pub mod error;
+pub mod fallback;
+pub mod gemini;
+pub mod ollama;
+pub mod openai_compatible;
+pub mod provider;
+pub mod registry;
+pub mod tokenizer;
+
+// Re-export commonly used types
+pub use error::{LlmError, LlmErrorKind};
+pub use fallback::{load_provider_chain, ProviderChain, ProviderReport, RetryPolicy};
+pub use provider::{
+    ContextWindow, JsonMode, LlmProvider, LlmRequest, ProviderCapabilities, SamplingParams,
+};
+pub use registry::{
+    create_provider, load_configured_provider, load_provider, resolve_provider_id, ProviderConfig,
+};
+pub use tokenizer::TokenCounter;
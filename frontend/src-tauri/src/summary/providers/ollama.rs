@@ -0,0 +1,147 @@
+// summary/providers/ollama.rs
+//
+// Ollama provider, through the native /api/chat (NDJSON stream, JSON Schema
+// `format`). Only the native API accepts `num_ctx`: without it Ollama runs every
+// model with its small default context and silently truncates long prompts.
+
+use async_trait::async_trait;
+use reqwest::Client;
+use serde_json::{Map, Value};
+use tracing::{info, warn};
+
+use super::error::{LlmError, LlmErrorKind};
+use super::provider::{ContextWindow, JsonMode, LlmProvider, LlmRequest, ProviderCapabilities};
+use crate::ollama::ollama::METADATA_CACHE;
+use crate::summary::llm_client::{
+    read_stream, send_request, ChatMessage, ChatRequest, OllamaChatResponse,
+};
+use crate::summary::streaming::StreamFormat;
+
+pub const DEFAULT_OLLAMA_ENDPOINT: &str = "http://localhost:11434";
+
+/// Context window assumed when Ollama does not report the model's
+const FALLBACK_CONTEXT_WINDOW: usize = 4096;
+
+/// Largest context requested from Ollama: it allocates the KV cache for the whole
+/// `num_ctx`, so long-context models (128k+) are run with at most this window
+const MAX_CONTEXT_WINDOW: usize = 32_768;
+
+pub struct OllamaProvider {
+    endpoint: String,
+    model_name: String,
+}
+
+impl OllamaProvider {
+    pub fn new(endpoint: Option<String>, model_name: String) -> Self {
+        Self {
+            endpoint: endpoint
+                .filter(|e| !e.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_OLLAMA_ENDPOINT.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            model_name,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OllamaProvider {
+    fn provider_name(&self) -> &'static str {
+        "Ollama"
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            json_mode: JsonMode::Schema,
+            local: true,
+            // A local Ollama serves only a few requests at a time
+            max_concurrency: 2,
+            max_output_tokens: None,
+        }
+    }
+
+    async fn context_window(&self, _client: &Client) -> ContextWindow {
+        match METADATA_CACHE.get_or_fetch(&self.model_name, Some(&self.endpoint)).await {
+            Ok(metadata) => ContextWindow {
+                tokens: metadata.context_size.min(MAX_CONTEXT_WINDOW),
+                source: "reported by Ollama",
+            },
+            Err(e) => {
+                warn!("Failed to fetch context for {}: {}", self.model_name, e);
+                ContextWindow {
+                    tokens: FALLBACK_CONTEXT_WINDOW,
+                    source: "fallback",
+                }
+            }
+        }
+    }
+
+    async fn generate(&self, client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+        let streaming = request.on_token.is_some();
+
+        // Run the model with the window the summary was planned for
+        let mut options = Map::new();
+        let context_window = self.context_window(client).await;
+        options.insert("num_ctx".to_string(), Value::from(context_window.tokens));
+        if let Some(max_tokens) = request.sampling.max_tokens {
+            options.insert("num_predict".to_string(), Value::from(max_tokens));
+        }
+        if let Some(temperature) = request.sampling.temperature {
+            options.insert("temperature".to_string(), Value::from(temperature));
+        }
+        if let Some(top_p) = request.sampling.top_p {
+            options.insert("top_p".to_string(), Value::from(top_p));
+        }
+
+        let body = ChatRequest {
+            model: self.model_name.clone(),
+            messages: vec![
+                ChatMessage {
+                    role: "system".to_string(),
+                    content: request.system_prompt.to_string(),
+                },
+                ChatMessage {
+                    role: "user".to_string(),
+                    content: request.user_prompt.to_string(),
+                },
+            ],
+            max_tokens: None,
+            temperature: None,
+            top_p: None,
+            // The native API streams unless told otherwise
+            stream: Some(streaming),
+            response_format: None,
+            format: request.output_schema.map(|output| output.schema.clone()),
+            options: Some(Value::Object(options)),
+        };
+
+        info!("🐞 LLM Request to Ollama: model={}", self.model_name);
+
+        let http_request = client.post(format!("{}/api/chat", self.endpoint)).json(&body);
+        let response = send_request(http_request, streaming, request.cancellation_token).await?;
+
+        if let Some(on_token) = request.on_token {
+            let content =
+                read_stream(response, StreamFormat::OllamaNdjson, on_token, request.cancellation_token)
+                    .await?;
+            info!("🐞 LLM streamed response completed from Ollama");
+            return Ok(content.trim().to_string());
+        }
+
+        let chat_response = response
+            .json::<OllamaChatResponse>()
+            .await
+            .map_err(|e| {
+                LlmError::new(LlmErrorKind::Transient, format!("Failed to parse LLM response: {}", e))
+            })?;
+
+        info!("🐞 LLM Response received from Ollama");
+
+        Ok(chat_response.message.content.trim().to_string())
+    }
+}
@@ -0,0 +1,351 @@
+// summary/providers/openai_compatible.rs
+//
+// Providers speaking the OpenAI chat completions API: OpenAI, Groq, OpenRouter
+// and any custom OpenAI-compatible server (LM Studio, vLLM, llama.cpp, ...).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use tracing::{info, warn};
+
+use super::error::{LlmError, LlmErrorKind};
+use super::provider::{
+    ContextWindow, JsonMode, LlmProvider, LlmRequest, ProviderCapabilities, SamplingParams,
+};
+use super::tokenizer::TokenCounter;
+use crate::summary::llm_client::{
+    fetch_context_window, read_stream, send_request, ChatMessage, ChatRequest, ChatResponse,
+};
+use crate::summary::streaming::StreamFormat;
+use crate::summary::CustomOpenAIConfig;
+
+/// Context window assumed for OpenAI-compatible servers that do not report one
+const DEFAULT_CONTEXT_WINDOW: usize = 8192;
+
+pub struct OpenAiCompatibleProvider {
+    name: &'static str,
+    /// API base URL, without the trailing `/chat/completions`
+    base_url: String,
+    api_key: String,
+    model_name: String,
+    /// Sampling defaults from the provider configuration
+    sampling: SamplingParams,
+    /// `GET {base_url}/models` reports context windows
+    lists_context_windows: bool,
+    /// Published context window of a model, when known
+    published_context_window: fn(&str) -> Option<usize>,
+    /// Whether a model accepts a strict `json_schema` response format
+    supports_json_schema: fn(&str) -> bool,
+}
+
+impl OpenAiCompatibleProvider {
+    pub fn openai(api_key: String, model_name: String) -> Self {
+        Self {
+            name: "OpenAI",
+            base_url: "https://api.openai.com/v1".to_string(),
+            api_key,
+            model_name,
+            sampling: SamplingParams::default(),
+            lists_context_windows: false,
+            published_context_window: openai_context_window,
+            supports_json_schema: openai_supports_json_schema,
+        }
+    }
+
+    pub fn groq(api_key: String, model_name: String) -> Self {
+        Self {
+            name: "Groq",
+            base_url: "https://api.groq.com/openai/v1".to_string(),
+            api_key,
+            model_name,
+            sampling: SamplingParams::default(),
+            lists_context_windows: true,
+            published_context_window: groq_context_window,
+            supports_json_schema: groq_supports_json_schema,
+        }
+    }
+
+    pub fn openrouter(api_key: String, model_name: String) -> Self {
+        Self {
+            name: "OpenRouter",
+            base_url: "https://openrouter.ai/api/v1".to_string(),
+            api_key,
+            model_name,
+            sampling: SamplingParams::default(),
+            lists_context_windows: true,
+            published_context_window: |_| None,
+            // Support depends on the routed model; the response is validated instead
+            supports_json_schema: |_| false,
+        }
+    }
+
+    /// Custom endpoint; an empty `model_name` uses the configured model
+    pub fn custom(config: &CustomOpenAIConfig, model_name: String) -> Self {
+        Self {
+            name: "Custom OpenAI",
+            base_url: config.endpoint.trim_end_matches('/').to_string(),
+            api_key: config.api_key.clone().unwrap_or_default(),
+            model_name: if model_name.is_empty() { config.model.clone() } else { model_name },
+            sampling: SamplingParams {
+                max_tokens: config.max_tokens.map(|t| t as u32),
+                temperature: config.temperature,
+                top_p: config.top_p,
+            },
+            lists_context_windows: true,
+            published_context_window: |_| None,
+            // Servers differ (LM Studio, vLLM, llama.cpp, ...); the response is validated instead
+            supports_json_schema: |_| false,
+        }
+    }
+}
+
+#[async_trait]
+impl LlmProvider for OpenAiCompatibleProvider {
+    fn provider_name(&self) -> &'static str {
+        self.name
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_name
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            streaming: true,
+            json_mode: if (self.supports_json_schema)(&self.model_name) {
+                JsonMode::Schema
+            } else {
+                JsonMode::Unconstrained
+            },
+            local: false,
+            max_concurrency: 4,
+            max_output_tokens: self.sampling.max_tokens,
+        }
+    }
+
+    /// Asks the `/models` endpoint when it reports the window, else uses the published limit
+    async fn context_window(&self, client: &Client) -> ContextWindow {
+        let reported = if self.lists_context_windows {
+            let models_url = format!("{}/models", self.base_url);
+            fetch_context_window(client, &models_url, Some(&self.api_key), &self.model_name).await
+        } else {
+            None
+        };
+
+        match (reported, (self.published_context_window)(&self.model_name)) {
+            (Some(tokens), _) => ContextWindow { tokens, source: "reported by server" },
+            (None, Some(tokens)) => ContextWindow { tokens, source: "published limit" },
+            (None, None) => ContextWindow { tokens: DEFAULT_CONTEXT_WINDOW, source: "fallback" },
+        }
+    }
+
+    async fn token_counter(&self, _client: &Client, _sample: &str) -> TokenCounter {
+        TokenCounter::for_openai_model(&self.model_name)
+    }
+
+    async fn generate(&self, client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError> {
+        let url = format!("{}/chat/completions", self.base_url);
+        let sampling = request.sampling.or(self.sampling);
+        let json_schema =
+            request.output_schema.is_some() && (self.supports_json_schema)(&self.model_name);
+
+        let send = |json_schema| {
+            chat_completions(
+                client,
+                self.name,
+                &url,
+                Some(&self.api_key),
+                &self.model_name,
+                request,
+                sampling,
+                json_schema,
+            )
+        };
+        match send(json_schema).await {
+            // The server rejects the response format: ask once more without it (the
+            // prompt still asks for the JSON and the caller validates it)
+            Err(error) if json_schema && error.kind == LlmErrorKind::InvalidRequest => {
+                warn!(
+                    "{} rejected the JSON schema response format for {}; retrying without it: {}",
+                    self.name, self.model_name, error
+                );
+                send(false).await
+            }
+            result => result,
+        }
+    }
+}
+
+/// Sends a chat completions request and returns the (optionally streamed) content
+///
+/// The output schema is sent as a strict `json_schema` response format only when
+/// `json_schema` is set.
+#[allow(clippy::too_many_arguments)]
+async fn chat_completions(
+    client: &Client,
+    name: &str,
+    url: &str,
+    api_key: Option<&str>,
+    model_name: &str,
+    request: &LlmRequest<'_>,
+    sampling: SamplingParams,
+    json_schema: bool,
+) -> Result<String, LlmError> {
+    let streaming = request.on_token.is_some();
+    let body = ChatRequest {
+        model: model_name.to_string(),
+        messages: vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: request.system_prompt.to_string(),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: request.user_prompt.to_string(),
+            },
+        ],
+        max_tokens: sampling.max_tokens,
+        temperature: sampling.temperature,
+        top_p: sampling.top_p,
+        stream: streaming.then_some(true),
+        response_format: request.output_schema.filter(|_| json_schema).map(|output| {
+            serde_json::json!({
+                "type": "json_schema",
+                "json_schema": {
+                    "name": output.name,
+                    "strict": true,
+                    "schema": output.schema,
+                },
+            })
+        }),
+        format: None,
+        options: None,
+    };
+
+    info!("🐞 LLM Request to {}: model={}", name, model_name);
+
+    let mut http_request = client.post(url).json(&body);
+    if let Some(key) = api_key {
+        http_request = http_request.bearer_auth(key);
+    }
+    let response = send_request(http_request, streaming, request.cancellation_token).await?;
+
+    if let Some(on_token) = request.on_token {
+        let content =
+            read_stream(response, StreamFormat::OpenAiSse, on_token, request.cancellation_token).await?;
+        info!("🐞 LLM streamed response completed from {}", name);
+        return Ok(content.trim().to_string());
+    }
+
+    let chat_response = response
+        .json::<ChatResponse>()
+        .await
+        .map_err(|e| {
+            LlmError::new(LlmErrorKind::Transient, format!("Failed to parse LLM response: {}", e))
+        })?;
+
+    info!("🐞 LLM Response received from {}", name);
+
+    let content = chat_response
+        .choices
+        .first()
+        .ok_or_else(|| LlmError::new(LlmErrorKind::Transient, "No content in LLM response"))?
+        .message
+        .content
+        .trim();
+    Ok(content.to_string())
+}
+
+/// Published context windows of OpenAI models
+fn openai_context_window(model_name: &str) -> Option<usize> {
+    let model = model_name.to_lowercase();
+    Some(if model.starts_with("gpt-4.1") {
+        1_000_000
+    } else if model.starts_with("gpt-5") {
+        400_000
+    } else if model.starts_with("gpt-3.5") {
+        16_385
+    } else if model == "gpt-4" || model.starts_with("gpt-4-0") {
+        8_192
+    } else {
+        // gpt-4o, gpt-4-turbo and the o-series
+        128_000
+    })
+}
+
+/// Published context windows of models hosted by Groq
+fn groq_context_window(model_name: &str) -> Option<usize> {
+    Some(if model_name.to_lowercase().contains("gemma") { 8_192 } else { 128_000 })
+}
+
+/// OpenAI models with Structured Outputs
+fn openai_supports_json_schema(model_name: &str) -> bool {
+    let model = model_name.to_lowercase();
+    // Snapshots released before Structured Outputs
+    if ["gpt-4o-2024-05-13", "o1-mini", "o1-preview"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+    {
+        return false;
+    }
+    ["gpt-4o", "gpt-4.1", "gpt-5", "o1", "o3", "o4"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+}
+
+/// Models hosted by Groq with Structured Outputs (the others reject `json_schema`)
+fn groq_supports_json_schema(model_name: &str) -> bool {
+    let model = model_name.to_lowercase();
+    ["openai/gpt-oss", "moonshotai/kimi-k2", "meta-llama/llama-4"]
+        .iter()
+        .any(|prefix| model.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_custom_provider_uses_configured_model_and_sampling() {
+        let config = CustomOpenAIConfig {
+            endpoint: "http://localhost:8000/v1/".to_string(),
+            api_key: None,
+            model: "llama-3-70b".to_string(),
+            max_tokens: Some(1024),
+            temperature: Some(0.2),
+            top_p: None,
+        };
+        let provider = OpenAiCompatibleProvider::custom(&config, String::new());
+
+        assert_eq!(provider.model_name(), "llama-3-70b");
+        assert_eq!(provider.base_url, "http://localhost:8000/v1");
+        assert_eq!(provider.capabilities().max_output_tokens, Some(1024));
+        assert_eq!(provider.capabilities().json_mode, JsonMode::Unconstrained);
+
+        // Request overrides win over the configured defaults
+        let sampling = SamplingParams { temperature: Some(0.7), ..Default::default() }.or(provider.sampling);
+        assert_eq!(sampling.temperature, Some(0.7));
+        assert_eq!(sampling.max_tokens, Some(1024));
+    }
+
+    #[test]
+    fn test_published_context_windows() {
+        assert_eq!(openai_context_window("gpt-4o-mini"), Some(128_000));
+        assert_eq!(openai_context_window("gpt-3.5-turbo"), Some(16_385));
+        assert_eq!(groq_context_window("gemma2-9b-it"), Some(8_192));
+    }
+
+    #[test]
+    fn test_json_schema_only_for_supporting_models() {
+        let openai = |model: &str| OpenAiCompatibleProvider::openai(String::new(), model.to_string());
+        assert_eq!(openai("gpt-4o-mini").capabilities().json_mode, JsonMode::Schema);
+        assert_eq!(openai("gpt-4o-2024-05-13").capabilities().json_mode, JsonMode::Unconstrained);
+        assert_eq!(openai("gpt-3.5-turbo").capabilities().json_mode, JsonMode::Unconstrained);
+
+        let groq = |model: &str| OpenAiCompatibleProvider::groq(String::new(), model.to_string());
+        assert_eq!(groq("openai/gpt-oss-120b").capabilities().json_mode, JsonMode::Schema);
+        assert_eq!(groq("llama-3.3-70b-versatile").capabilities().json_mode, JsonMode::Unconstrained);
+
+        let openrouter = OpenAiCompatibleProvider::openrouter(String::new(), "openai/gpt-4o".to_string());
+        assert_eq!(openrouter.capabilities().json_mode, JsonMode::Unconstrained);
+    }
+}
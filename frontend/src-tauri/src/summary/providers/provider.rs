@@ -0,0 +1,145 @@
+// summary/providers/provider.rs
+//
+// Defines the LlmProvider trait and the request/capability types shared by
+// every summary provider (hosted APIs, Ollama, the built-in sidecar).
+
+use async_trait::async_trait;
+use reqwest::Client;
+use tokio_util::sync::CancellationToken;
+
+use super::error::LlmError;
+use super::tokenizer::TokenCounter;
+use crate::summary::llm_client::{OutputSchema, TokenCallback};
+
+/// Sampling parameters of a completion; unset values use the provider's defaults
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SamplingParams {
+    pub max_tokens: Option<u32>,
+    pub temperature: Option<f32>,
+    pub top_p: Option<f32>,
+}
+
+impl SamplingParams {
+    /// Fill unset values from `defaults`
+    pub fn or(self, defaults: SamplingParams) -> Self {
+        Self {
+            max_tokens: self.max_tokens.or(defaults.max_tokens),
+            temperature: self.temperature.or(defaults.temperature),
+            top_p: self.top_p.or(defaults.top_p),
+        }
+    }
+}
+
+/// How a provider enforces an [`OutputSchema`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JsonMode {
+    /// No constrained output; the result is only validated afterwards
+    Unconstrained,
+    /// The JSON Schema is sent with the request
+    Schema,
+    /// Sampling is constrained by the GBNF grammar
+    Grammar,
+}
+
+/// What a provider supports, used to plan a summary (chunking, parallelism, streaming)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderCapabilities {
+    /// Completions can be streamed to a [`TokenCallback`]
+    pub streaming: bool,
+    pub json_mode: JsonMode,
+    /// Inference runs on this machine (no transcript leaves it)
+    pub local: bool,
+    /// Parallel requests the provider handles well
+    pub max_concurrency: usize,
+    /// Configured completion limit, reserved from the context window
+    pub max_output_tokens: Option<u32>,
+}
+
+/// Context window of the configured model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContextWindow {
+    pub tokens: usize,
+    /// Where the size comes from ("reported by server", "published limit", "fallback", ...)
+    pub source: &'static str,
+}
+
+/// One completion request
+pub struct LlmRequest<'a> {
+    pub system_prompt: &'a str,
+    pub user_prompt: &'a str,
+    /// Overrides of the provider's sampling defaults
+    pub sampling: SamplingParams,
+    /// Structure the completion must follow
+    pub output_schema: Option<&'a OutputSchema>,
+    pub cancellation_token: Option<&'a CancellationToken>,
+    /// When set the completion is streamed (providers without streaming call it once)
+    pub on_token: Option<TokenCallback<'a>>,
+}
+
+impl<'a> LlmRequest<'a> {
+    pub fn new(system_prompt: &'a str, user_prompt: &'a str) -> Self {
+        Self {
+            system_prompt,
+            user_prompt,
+            sampling: SamplingParams::default(),
+            output_schema: None,
+            cancellation_token: None,
+            on_token: None,
+        }
+    }
+
+    pub fn with_sampling(mut self, sampling: SamplingParams) -> Self {
+        self.sampling = sampling;
+        self
+    }
+
+    pub fn with_output_schema(mut self, output_schema: &'a OutputSchema) -> Self {
+        self.output_schema = Some(output_schema);
+        self
+    }
+
+    pub fn with_cancellation(mut self, cancellation_token: Option<&'a CancellationToken>) -> Self {
+        self.cancellation_token = cancellation_token;
+        self
+    }
+
+    pub fn with_streaming(mut self, on_token: Option<TokenCallback<'a>>) -> Self {
+        self.on_token = on_token;
+        self
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancellation_token.is_some_and(|token| token.is_cancelled())
+    }
+}
+
+/// Trait for summary providers (OpenAI-compatible APIs, Claude, Ollama, built-in AI)
+///
+/// Providers are created through the registry (see `registry.rs`), so adding one only
+/// means implementing this trait and registering it there.
+#[async_trait]
+pub trait LlmProvider: Send + Sync {
+    /// Provider name (for logging/debugging)
+    fn provider_name(&self) -> &'static str;
+
+    /// Model the completions are requested from
+    fn model_name(&self) -> &str;
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    /// Context window of the model (falls back to a safe default when unknown)
+    async fn context_window(&self, client: &Client) -> ContextWindow;
+
+    /// Token counter for this provider's model; `sample` is the text about to be sized
+    /// (providers measuring with the model's own tokenizer calibrate on it)
+    async fn token_counter(&self, _client: &Client, _sample: &str) -> TokenCounter {
+        TokenCounter::Heuristic
+    }
+
+    /// Generate a completion
+    ///
+    /// # Returns
+    /// The generated text (trimmed) or a classified error; a cancelled request
+    /// fails with [`LlmErrorKind::Cancelled`](super::error::LlmErrorKind::Cancelled)
+    async fn generate(&self, client: &Client, request: &LlmRequest<'_>) -> Result<String, LlmError>;
+}
@@ -0,0 +1,217 @@
+// summary/providers/registry.rs
+//
+// Registry of summary providers: maps the provider ids stored in the settings
+// to their settings requirements and constructors.
+
+use sqlx::SqlitePool;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::builtin::BuiltInProvider;
+use super::claude::ClaudeProvider;
+use super::fallback::load_provider_chain;
+use super::gemini::GeminiProvider;
+use super::ollama::OllamaProvider;
+use super::openai_compatible::OpenAiCompatibleProvider;
+use super::provider::LlmProvider;
+use crate::database::repositories::setting::SettingsRepository;
+use crate::summary::CustomOpenAIConfig;
+
+/// Settings a provider is built from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProviderSettings {
+    /// API key stored for the provider id
+    ApiKey,
+    /// Endpoint of the model config (optional)
+    OllamaEndpoint,
+    /// Custom OpenAI-compatible endpoint configuration
+    CustomOpenAI,
+    /// Models downloaded to the app data directory
+    AppDataDir,
+}
+
+/// Everything a provider constructor may need, as loaded from the settings
+#[derive(Debug, Clone, Default)]
+pub struct ProviderConfig {
+    pub model_name: String,
+    pub api_key: String,
+    pub ollama_endpoint: Option<String>,
+    pub custom_openai: Option<CustomOpenAIConfig>,
+    pub app_data_dir: Option<PathBuf>,
+}
+
+struct ProviderEntry {
+    /// Id stored in the settings
+    id: &'static str,
+    /// Older ids still accepted
+    aliases: &'static [&'static str],
+    settings: ProviderSettings,
+    build: fn(ProviderConfig) -> Result<Arc<dyn LlmProvider>, String>,
+}
+
+const PROVIDERS: &[ProviderEntry] = &[
+    ProviderEntry {
+        id: "openai",
+        aliases: &[],
+        settings: ProviderSettings::ApiKey,
+        build: |config| Ok(Arc::new(OpenAiCompatibleProvider::openai(config.api_key, config.model_name))),
+    },
+    ProviderEntry {
+        id: "claude",
+        aliases: &[],
+        settings: ProviderSettings::ApiKey,
+        build: |config| Ok(Arc::new(ClaudeProvider::new(config.api_key, config.model_name))),
+    },
+    ProviderEntry {
+        id: "gemini",
+        aliases: &["google"],
+        settings: ProviderSettings::ApiKey,
+        build: |config| Ok(Arc::new(GeminiProvider::new(config.api_key, config.model_name))),
+    },
+    ProviderEntry {
+        id: "groq",
+        aliases: &[],
+        settings: ProviderSettings::ApiKey,
+        build: |config| Ok(Arc::new(OpenAiCompatibleProvider::groq(config.api_key, config.model_name))),
+    },
+    ProviderEntry {
+        id: "openrouter",
+        aliases: &[],
+        settings: ProviderSettings::ApiKey,
+        build: |config| Ok(Arc::new(OpenAiCompatibleProvider::openrouter(config.api_key, config.model_name))),
+    },
+    ProviderEntry {
+        id: "ollama",
+        aliases: &[],
+        settings: ProviderSettings::OllamaEndpoint,
+        build: |config| Ok(Arc::new(OllamaProvider::new(config.ollama_endpoint, config.model_name))),
+    },
+    ProviderEntry {
+        id: "custom-openai",
+        aliases: &[],
+        settings: ProviderSettings::CustomOpenAI,
+        build: |config| {
+            let custom = config
+                .custom_openai
+                .ok_or_else(|| "Custom OpenAI provider selected but no configuration found".to_string())?;
+            Ok(Arc::new(OpenAiCompatibleProvider::custom(&custom, config.model_name)))
+        },
+    },
+    ProviderEntry {
+        id: "builtin-ai",
+        aliases: &["local-llama", "localllama"],
+        settings: ProviderSettings::AppDataDir,
+        build: |config| {
+            let app_data_dir = config
+                .app_data_dir
+                .ok_or_else(|| "app_data_dir is required for BuiltInAI provider".to_string())?;
+            Ok(Arc::new(BuiltInProvider::new(app_data_dir, config.model_name)))
+        },
+    },
+];
+
+fn find_entry(provider: &str) -> Result<&'static ProviderEntry, String> {
+    let provider = provider.to_lowercase();
+    PROVIDERS
+        .iter()
+        .find(|entry| entry.id == provider || entry.aliases.contains(&provider.as_str()))
+        .ok_or_else(|| format!("Unsupported LLM provider: {}", provider))
+}
+
+/// Canonical id of a provider name (case-insensitive, aliases resolved)
+pub fn resolve_provider_id(provider: &str) -> Option<&'static str> {
+    find_entry(provider).ok().map(|entry| entry.id)
+}
+
+/// Builds a provider from an explicit configuration
+pub fn create_provider(provider: &str, config: ProviderConfig) -> Result<Arc<dyn LlmProvider>, String> {
+    (find_entry(provider)?.build)(config)
+}
+
+/// Builds a provider, loading its credentials and endpoints from the settings
+///
+/// # Arguments
+/// * `provider` - Provider id (e.g., "ollama", "openai")
+/// * `model_name` - Model to use; empty for custom endpoints means the configured model
+/// * `app_data_dir` - App data directory (built-in AI models)
+pub async fn load_provider(
+    pool: &SqlitePool,
+    provider: &str,
+    model_name: &str,
+    app_data_dir: Option<PathBuf>,
+) -> Result<Arc<dyn LlmProvider>, String> {
+    let entry = find_entry(provider)?;
+    let mut config = ProviderConfig {
+        model_name: model_name.to_string(),
+        app_data_dir,
+        ..Default::default()
+    };
+
+    match entry.settings {
+        ProviderSettings::ApiKey => {
+            config.api_key = SettingsRepository::get_api_key(pool, entry.id)
+                .await
+                .map_err(|e| format!("Failed to retrieve API key for {}: {}", entry.id, e))?
+                .filter(|key| !key.is_empty())
+                .ok_or_else(|| format!("API key not found for {}", entry.id))?;
+        }
+        ProviderSettings::OllamaEndpoint => {
+            config.ollama_endpoint = SettingsRepository::get_model_config(pool)
+                .await
+                .map_err(|e| format!("Failed to retrieve Ollama endpoint: {}", e))?
+                .and_then(|setting| setting.ollama_endpoint);
+        }
+        ProviderSettings::CustomOpenAI => {
+            config.custom_openai = SettingsRepository::get_custom_openai_config(pool)
+                .await
+                .map_err(|e| format!("Failed to retrieve custom OpenAI config: {}", e))?;
+        }
+        ProviderSettings::AppDataDir => {}
+    }
+
+    (entry.build)(config)
+}
+
+/// Builds the summary provider and model selected in the settings, wrapped in the
+/// provider chain (retries and configured fallbacks)
+pub async fn load_configured_provider(
+    pool: &SqlitePool,
+    app_data_dir: Option<PathBuf>,
+) -> Result<Arc<dyn LlmProvider>, String> {
+    let setting = SettingsRepository::get_model_config(pool)
+        .await
+        .map_err(|e| format!("Failed to load model config: {}", e))?
+        .ok_or_else(|| "No summary model configured".to_string())?;
+    let chain = load_provider_chain(pool, &setting.provider, &setting.model, app_data_dir).await?;
+    Ok(chain)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_provider_id() {
+        assert_eq!(resolve_provider_id("OpenAI"), Some("openai"));
+        assert_eq!(resolve_provider_id("local-llama"), Some("builtin-ai"));
+        assert_eq!(resolve_provider_id("Gemini"), Some("gemini"));
+        assert_eq!(resolve_provider_id("gpt"), None);
+    }
+
+    #[test]
+    fn test_create_provider_checks_required_config() {
+        let provider = create_provider(
+            "ollama",
+            ProviderConfig {
+                model_name: "llama3.2:latest".to_string(),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(provider.provider_name(), "Ollama");
+        assert!(provider.capabilities().local);
+
+        assert!(create_provider("custom-openai", ProviderConfig::default()).is_err());
+        assert!(create_provider("builtin-ai", ProviderConfig::default()).is_err());
+    }
+}
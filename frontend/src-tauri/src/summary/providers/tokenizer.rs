@@ -0,0 +1,161 @@
+// summary/providers/tokenizer.rs
+//
+// Token counting used to size summary requests. OpenAI-style models are counted
+// with their tiktoken encoding, the built-in model with its own tokenizer (through
+// the sidecar); everything else falls back to the character heuristic.
+
+use once_cell::sync::Lazy;
+use tiktoken_rs::CoreBPE;
+use tracing::warn;
+
+use crate::summary::processor::rough_token_count;
+
+static O200K_BASE: Lazy<Option<CoreBPE>> = Lazy::new(|| load_encoding("o200k_base", tiktoken_rs::o200k_base));
+static CL100K_BASE: Lazy<Option<CoreBPE>> = Lazy::new(|| load_encoding("cl100k_base", tiktoken_rs::cl100k_base));
+
+/// Margin applied when a model is counted with an encoding that is not its own
+const APPROXIMATE_BPE_MARGIN: f64 = 1.1;
+
+fn load_encoding(name: &str, load: fn() -> anyhow::Result<CoreBPE>) -> Option<CoreBPE> {
+    load()
+        .map_err(|e| warn!("Failed to load the {} encoding: {}", name, e))
+        .ok()
+}
+
+/// How the tokens of a provider's model are counted
+#[derive(Clone, Copy)]
+pub enum TokenCounter {
+    /// Character heuristic (`rough_token_count`)
+    Heuristic,
+    /// tiktoken encoding; `exact` is false when it only approximates the model's tokenizer
+    Bpe {
+        encoding: &'static CoreBPE,
+        name: &'static str,
+        exact: bool,
+    },
+    /// Characters per token measured on the text with the model's own tokenizer
+    Measured { chars_per_token: f64 },
+}
+
+impl TokenCounter {
+    /// tiktoken encoding for a model served through an OpenAI-style API
+    ///
+    /// OpenAI models get their own encoding; other models (Llama, Mistral, Qwen on Groq,
+    /// OpenRouter or custom servers) are approximated with cl100k plus a margin.
+    pub fn for_openai_model(model: &str) -> Self {
+        let model = model.rsplit('/').next().unwrap_or(model).to_lowercase();
+        let (encoding, name, exact) = if ["gpt-4o", "gpt-4.1", "gpt-4.5", "gpt-5", "gpt-oss", "chatgpt", "o1", "o3", "o4"]
+            .iter()
+            .any(|prefix| model.starts_with(prefix))
+        {
+            (&O200K_BASE, "o200k_base", true)
+        } else if model.starts_with("gpt-4") || model.starts_with("gpt-3.5") {
+            (&CL100K_BASE, "cl100k_base", true)
+        } else {
+            (&CL100K_BASE, "cl100k_base", false)
+        };
+
+        match encoding.as_ref() {
+            Some(encoding) => Self::Bpe {
+                encoding,
+                name,
+                exact,
+            },
+            None => Self::Heuristic,
+        }
+    }
+
+    /// Counter calibrated from the model tokenizer's count of `text`
+    pub fn measured(text: &str, tokens: usize) -> Self {
+        let chars = text.chars().count();
+        if chars == 0 || tokens == 0 {
+            return Self::Heuristic;
+        }
+        Self::Measured {
+            chars_per_token: chars as f64 / tokens as f64,
+        }
+    }
+
+    pub fn count(&self, text: &str) -> usize {
+        match self {
+            Self::Heuristic => rough_token_count(text),
+            Self::Bpe { encoding, exact, .. } => {
+                let tokens = encoding.encode_ordinary(text).len();
+                if *exact {
+                    tokens
+                } else {
+                    (tokens as f64 * APPROXIMATE_BPE_MARGIN).ceil() as usize
+                }
+            }
+            Self::Measured { chars_per_token } => {
+                (text.chars().count() as f64 / chars_per_token).ceil() as usize
+            }
+        }
+    }
+
+    /// Average characters per token of `text`, used to cut it into token-sized chunks
+    pub fn chars_per_token(&self, text: &str) -> f64 {
+        match self {
+            Self::Measured { chars_per_token } => *chars_per_token,
+            _ => {
+                let tokens = self.count(text);
+                if tokens == 0 {
+                    1.0 / 0.35
+                } else {
+                    text.chars().count() as f64 / tokens as f64
+                }
+            }
+        }
+    }
+
+    /// Where the counts come from (for logging)
+    pub fn description(&self) -> String {
+        match self {
+            Self::Heuristic => "character heuristic".to_string(),
+            Self::Bpe { name, exact: true, .. } => format!("tiktoken {}", name),
+            Self::Bpe { name, exact: false, .. } => format!("tiktoken {} (approximate)", name),
+            Self::Measured { chars_per_token } => {
+                format!("model tokenizer ({:.2} chars/token)", chars_per_token)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_openai_models_use_their_encoding() {
+        assert!(matches!(
+            TokenCounter::for_openai_model("gpt-4o-mini"),
+            TokenCounter::Bpe { name: "o200k_base", exact: true, .. }
+        ));
+        assert!(matches!(
+            TokenCounter::for_openai_model("openai/gpt-4-turbo"),
+            TokenCounter::Bpe { name: "cl100k_base", exact: true, .. }
+        ));
+        assert!(matches!(
+            TokenCounter::for_openai_model("llama-3.3-70b-versatile"),
+            TokenCounter::Bpe { exact: false, .. }
+        ));
+    }
+
+    #[test]
+    fn test_bpe_counts_and_margin() {
+        let text = "hello world";
+        assert_eq!(TokenCounter::for_openai_model("gpt-4o").count(text), 2);
+        assert_eq!(TokenCounter::for_openai_model("mistral-large").count(text), 3);
+        assert_eq!(TokenCounter::for_openai_model("gpt-4o").count(""), 0);
+    }
+
+    #[test]
+    fn test_measured_counter() {
+        let counter = TokenCounter::measured(&"x".repeat(400), 100);
+        assert_eq!(counter.count(&"y".repeat(40)), 10);
+        assert_eq!(counter.chars_per_token("anything"), 4.0);
+
+        // Nothing to calibrate from
+        assert!(matches!(TokenCounter::measured("", 0), TokenCounter::Heuristic));
+    }
+}
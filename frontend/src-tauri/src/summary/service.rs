@@ -1,29 +1,44 @@
 use crate::database::repositories::{
-    meeting::MeetingsRepository, setting::SettingsRepository, summary::SummaryProcessesRepository,
+    action_item::ActionItemsRepository, bookmark::BookmarksRepository,
+    conversation_metrics::ConversationMetricsRepository, meeting::MeetingsRepository,
+    setting::SettingsRepository, summary::SummaryProcessesRepository,
+    summary_version::SummaryVersionDetails,
 };
+use crate::summary::action_items::extract_action_items;
 use crate::summary::communication_evaluator::evaluate_communication;
 use crate::summary::communication_types::CommunicationFeedback;
-use crate::summary::llm_client::LLMProvider;
-use crate::summary::processor::{extract_meeting_name_from_markdown, generate_meeting_summary};
-use crate::ollama::metadata::ModelMetadataCache;
+use crate::summary::conversation_metrics::ConversationMetrics;
+use crate::summary::live_summary::read_live_summary_file;
+use crate::summary::processor::{generate_meeting_summary, input_token_budget};
+use crate::summary::providers::{load_provider_chain, LlmProvider};
+use crate::summary::structured::StructuredSummary;
+use crate::summary::templates::{self, TemplateContext};
+use crate::summary::versions::prompt_hash;
 use sqlx::SqlitePool;
 use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tokio_util::sync::CancellationToken;
 use tracing::{error, info, warn};
 use once_cell::sync::Lazy;
 
-// Global cache for model metadata (5 minute TTL)
-static METADATA_CACHE: Lazy<ModelMetadataCache> = Lazy::new(|| {
-    ModelMetadataCache::new(Duration::from_secs(300))
-});
-
 // Global registry for cancellation tokens (thread-safe)
 static CANCELLATION_REGISTRY: Lazy<Arc<Mutex<HashMap<String, CancellationToken>>>> =
     Lazy::new(|| Arc::new(Mutex::new(HashMap::new())));
 
+// Minimum interval between two `summary-progress` events while the report streams
+const PROGRESS_EMIT_INTERVAL: Duration = Duration::from_millis(150);
+
+/// Payload of the `summary-progress` event (partial markdown of the final report)
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SummaryProgress {
+    pub meeting_id: String,
+    pub partial_markdown: String,
+    pub done: bool,
+}
+
 /// Summary service - handles all summary generation logic
 pub struct SummaryService;
 
@@ -93,162 +108,247 @@ impl SummaryService {
         // Register cancellation token for this meeting
         let cancellation_token = Self::register_cancellation_token(&meeting_id);
 
-        // Parse provider
-        let provider = match LLMProvider::from_str(&model_provider) {
-            Ok(p) => p,
+        // Get app data directory for BuiltInAI provider
+        let app_data_dir = _app.path().app_data_dir().ok();
+
+        let template = match templates::get_template(&template_id) {
+            Ok(template) => template,
             Err(e) => {
-                Self::update_process_failed(&pool, &meeting_id, &e).await;
+                let error_msg = format!("Failed to load template '{}': {}", template_id, e);
+                Self::update_process_failed(&pool, &meeting_id, &error_msg).await;
                 return;
             }
         };
 
-        // Validate and setup api_key, Flexible for Ollama, BuiltInAI, and CustomOpenAI
-        let api_key = if provider == LLMProvider::Ollama || provider == LLMProvider::BuiltInAI || provider == LLMProvider::CustomOpenAI {
-            // These providers don't require API keys from the standard database column
-            String::new()
-        } else {
-            match SettingsRepository::get_api_key(&pool, &model_provider).await {
-                Ok(Some(key)) if !key.is_empty() => key,
-                Ok(None) | Ok(Some(_)) => {
-                    let err_msg = format!("API key not found for {}", &model_provider);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                    return;
+        // A template may prefer its own provider; it is used when configured, otherwise
+        // the selected one is
+        let preferred = template
+            .llm
+            .as_ref()
+            .and_then(|llm| llm.provider.as_ref().zip(llm.model.as_ref()));
+        let preferred_provider = match preferred {
+            Some((provider, model)) => {
+                match load_provider_chain(&pool, provider, model, app_data_dir.clone()).await {
+                    Ok(chain) => {
+                        info!(
+                            "Using {}/{} preferred by template '{}'",
+                            provider, model, template_id
+                        );
+                        Some(chain)
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Provider {} preferred by template '{}' is not available ({}), using {}",
+                            provider, template_id, e, model_provider
+                        );
+                        None
+                    }
                 }
+            }
+            None => None,
+        };
+
+        // Build the provider with its credentials and endpoints from the settings; the chain
+        // retries transient failures and falls back to the configured fallback providers
+        let provider = match preferred_provider {
+            Some(provider) => provider,
+            None => match load_provider_chain(&pool, &model_provider, &model_name, app_data_dir).await {
+                Ok(provider) => provider,
                 Err(e) => {
-                    let err_msg = format!("Failed to retrieve API key for {}: {}", &model_provider, e);
-                    Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
+                    Self::update_process_failed(&pool, &meeting_id, &e).await;
                     return;
                 }
+            },
+        };
+        let model_name = provider.model_name().to_string();
+
+        let client = reqwest::Client::new();
+
+        // Requests are sized from the model's real context window minus its completion
+        // budget, counted with the model's tokenizer where one is available
+        let context_window = provider.context_window(&client).await;
+        let max_output_tokens = template
+            .sampling()
+            .max_tokens
+            .or(provider.capabilities().max_output_tokens);
+        let token_threshold = input_token_budget(context_window.tokens, max_output_tokens);
+        let token_counter = provider.token_counter(&client, &text).await;
+        info!(
+            "✓ Using context window for {} ({}): {} tokens (prompt budget: {}, counted with {})",
+            model_name,
+            context_window.source,
+            context_window.tokens,
+            token_threshold,
+            token_counter.description()
+        );
+
+        // Bookmarks flagged during the recording get extra weight in the summary
+        let bookmarks = match BookmarksRepository::get_bookmarks(&pool, &meeting_id).await {
+            Ok(bookmarks) => bookmarks,
+            Err(e) => {
+                warn!("Failed to load bookmarks for meeting {}: {}", meeting_id, e);
+                Vec::new()
             }
         };
 
-        // Get Ollama endpoint if provider is Ollama
-        let ollama_endpoint = if provider == LLMProvider::Ollama {
-            match SettingsRepository::get_model_config(&pool).await {
-                Ok(Some(config)) => config.ollama_endpoint,
-                Ok(None) => None,
-                Err(e) => {
-                    info!("Failed to retrieve Ollama endpoint: {}, using default", e);
-                    None
-                }
+        // Open action items of earlier meetings with the same title are carried into the
+        // prompt. The title is taken before the summary renames the meeting (and kept from
+        // the first extraction when regenerating), so recurring meetings keep matching.
+        let mut meeting = match MeetingsRepository::get_meeting(&pool, &meeting_id).await {
+            Ok(meeting) => meeting,
+            Err(e) => {
+                warn!("Failed to load meeting {} for action items: {}", meeting_id, e);
+                None
             }
-        } else {
-            None
         };
+        if let Some(meeting) = meeting.as_mut() {
+            meeting.transcripts.sort_by(|a, b| {
+                a.audio_start_time
+                    .unwrap_or(0.0)
+                    .total_cmp(&b.audio_start_time.unwrap_or(0.0))
+            });
+        }
 
-        // Get CustomOpenAI config if provider is CustomOpenAI
-        let (custom_openai_endpoint, custom_openai_api_key, custom_openai_max_tokens, custom_openai_temperature, custom_openai_top_p) =
-            if provider == LLMProvider::CustomOpenAI {
-                match SettingsRepository::get_custom_openai_config(&pool).await {
-                    Ok(Some(config)) => {
-                        info!("✓ Using custom OpenAI endpoint: {}", config.endpoint);
-                        (
-                            Some(config.endpoint),
-                            config.api_key,
-                            config.max_tokens.map(|t| t as u32),
-                            config.temperature,
-                            config.top_p,
-                        )
-                    }
-                    Ok(None) => {
-                        let err_msg = "Custom OpenAI provider selected but no configuration found";
-                        Self::update_process_failed(&pool, &meeting_id, err_msg).await;
-                        return;
-                    }
-                    Err(e) => {
-                        let err_msg = format!("Failed to retrieve custom OpenAI config: {}", e);
-                        Self::update_process_failed(&pool, &meeting_id, &err_msg).await;
-                        return;
-                    }
-                }
-            } else {
-                (None, None, None, None, None)
-            };
+        // The rolling summary kept during the recording (if any) spares re-summarizing
+        // the part of a long transcript it already covers
+        let folder_path = match MeetingsRepository::get_meeting_metadata(&pool, &meeting_id).await {
+            Ok(metadata) => metadata.and_then(|m| m.folder_path),
+            Err(e) => {
+                warn!("Failed to load meeting folder for {}: {}", meeting_id, e);
+                None
+            }
+        };
+        let head_start = match (folder_path.as_deref(), meeting.as_ref()) {
+            (Some(folder), Some(meeting)) => read_live_summary_file(Path::new(folder))
+                .and_then(|live_summary| live_summary.head_start(&meeting.transcripts)),
+            _ => None,
+        };
+        if head_start.is_some() {
+            info!("Found a live summary for meeting {} to use as a head start", meeting_id);
+        }
 
-        // For CustomOpenAI, use its API key (if any) instead of the empty string
-        let final_api_key = if provider == LLMProvider::CustomOpenAI {
-            custom_openai_api_key.unwrap_or_default()
-        } else {
-            api_key
+        let series_title = match ActionItemsRepository::get_series_title(&pool, &meeting_id).await {
+            Ok(Some(title)) => Some(title),
+            _ => meeting.as_ref().map(|m| m.title.clone()),
+        };
+        let pending_action_items = match &series_title {
+            Some(title) => ActionItemsRepository::get_pending_for_title(&pool, title, &meeting_id)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("Failed to load pending action items for '{}': {}", title, e);
+                    Vec::new()
+                }),
+            None => Vec::new(),
         };
+        if !pending_action_items.is_empty() {
+            info!(
+                "Carrying {} open action items from earlier '{}' meetings",
+                pending_action_items.len(),
+                series_title.as_deref().unwrap_or_default()
+            );
+        }
 
-        // Dynamically fetch context size based on provider and model
-        let token_threshold = if provider == LLMProvider::Ollama {
-            match METADATA_CACHE.get_or_fetch(&model_name, ollama_endpoint.as_deref()).await {
-                Ok(metadata) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = metadata.context_size.saturating_sub(300);
-                    info!(
-                        "✓ Using dynamic context for {}: {} tokens (chunk size: {})",
-                        model_name, metadata.context_size, optimal
-                    );
-                    optimal
-                }
-                Err(e) => {
-                    warn!(
-                        "Failed to fetch context for {}: {}. Using default 4000",
-                        model_name, e
-                    );
-                    4000  // Fallback to safe default
-                }
-            }
-        } else if provider == LLMProvider::BuiltInAI {
-            // Get model's context size from registry
-            use crate::summary::summary_engine::models;
-            let model = models::get_model_by_name(&model_name)
-                .ok_or_else(|| format!("Unknown model: {}", model_name));
-
-            match model {
-                Ok(model_def) => {
-                    // Reserve 300 tokens for prompt overhead
-                    let optimal = model_def.context_size.saturating_sub(300) as usize;
-                    info!(
-                        "✓ Using BuiltInAI context size: {} tokens (chunk size: {})",
-                        model_def.context_size, optimal
-                    );
-                    optimal
-                }
-                Err(e) => {
-                    warn!("{}, using default 2048", e);
-                    1748  // 2048 - 300 for overhead
-                }
+        // Fill the template in for this meeting: variables from the meeting data, conditional
+        // sections only when the transcript mentions them
+        let language = match SettingsRepository::get_transcript_config(&pool).await {
+            Ok(config) => config.and_then(|c| c.language),
+            Err(e) => {
+                warn!("Failed to load transcription language: {}", e);
+                None
             }
-        } else {
-            // Cloud providers (OpenAI, Claude, Groq, CustomOpenAI) handle large contexts automatically
-            100000  // Effectively unlimited for single-pass processing
         };
+        let template_context = match meeting.as_ref() {
+            Some(meeting) => TemplateContext::for_meeting(meeting, language.as_deref()),
+            None => TemplateContext {
+                language: language.clone().unwrap_or_else(|| "es".to_string()),
+                ..TemplateContext::default()
+            },
+        };
+        let template = template.render(&template_context, &text);
+        info!(
+            "Rendered template '{}' with {} sections",
+            template_id,
+            template.sections.len()
+        );
 
-        // Get app data directory for BuiltInAI provider
-        let app_data_dir = _app.path().app_data_dir().ok();
+        // Objective conversation metrics (no model needed), stored with the meeting and
+        // used to ground the communication evaluation. They take the configured language
+        // as-is (not the template's "es" default) so unset languages count both filler lists.
+        let conversation_metrics = meeting
+            .as_ref()
+            .map(|meeting| ConversationMetrics::compute(&meeting.transcripts, language.as_deref()));
+        if let Some(metrics) = &conversation_metrics {
+            if let Err(e) = ConversationMetricsRepository::save(&pool, &meeting_id, metrics).await {
+                warn!("Failed to save conversation metrics for {}: {}", meeting_id, e);
+            }
+        }
+
+        // Stream the final report to the UI as `summary-progress` events (throttled).
+        // The report arrives as JSON, so each event renders whatever sections are readable.
+        let progress: Mutex<(String, Option<Instant>)> = Mutex::new((String::new(), None));
+        let on_token = |text: &str| {
+            let Ok(mut progress) = progress.lock() else {
+                return;
+            };
+            progress.0.push_str(text);
+            if progress.1.is_some_and(|last| last.elapsed() < PROGRESS_EMIT_INTERVAL) {
+                return;
+            }
+            let Some(partial) = StructuredSummary::from_partial(&template, &progress.0) else {
+                return;
+            };
+            progress.1 = Some(Instant::now());
+            Self::emit_progress(&_app, &meeting_id, &partial.to_markdown(), false);
+        };
 
         // Generate summary
-        let client = reqwest::Client::new();
         let result = generate_meeting_summary(
             &client,
-            &provider,
-            &model_name,
-            &final_api_key,
+            provider.as_ref(),
             &text,
             &custom_prompt,
-            &template_id,
+            &bookmarks,
+            &pending_action_items,
+            head_start.as_ref(),
+            &template,
             token_threshold,
-            ollama_endpoint.as_deref(),
-            custom_openai_endpoint.as_deref(),
-            custom_openai_max_tokens,
-            custom_openai_temperature,
-            custom_openai_top_p,
-            app_data_dir.as_ref(),
+            &token_counter,
             Some(&cancellation_token),
+            Some(&on_token),
         )
         .await;
 
+        // Record which provider actually produced the summary (or failed to)
+        let provider_report = provider.report();
+        if provider_report.fallback_used {
+            warn!(
+                "Summary for meeting {} was produced by fallback {:?} ({:?})",
+                meeting_id, provider_report.provider, provider_report.model
+            );
+        }
+        match serde_json::to_value(&provider_report) {
+            Ok(report) => {
+                if let Err(e) =
+                    SummaryProcessesRepository::set_process_metadata(&pool, &meeting_id, "provider", &report).await
+                {
+                    warn!("Failed to save provider metadata for {}: {}", meeting_id, e);
+                }
+            }
+            Err(e) => warn!("Failed to serialize provider report: {}", e),
+        }
+
+        if let Ok((summary, _)) = &result {
+            Self::emit_progress(&_app, &meeting_id, &summary.to_markdown(), true);
+        }
+
         let duration = start_time.elapsed().as_secs_f64();
 
         // Clean up cancellation token regardless of outcome
         Self::cleanup_cancellation_token(&meeting_id);
 
         match result {
-            Ok((mut final_markdown, num_chunks)) => {
+            Ok((summary, num_chunks)) => {
+                let final_markdown = summary.body_markdown();
                 if num_chunks == 0 && final_markdown.is_empty() {
                     Self::update_process_failed(
                         &pool,
@@ -265,35 +365,16 @@ impl SummaryService {
                 );
                 info!("final markdown is {}", &final_markdown);
 
-                // Extract and update meeting name if present
-                if let Some(name) = extract_meeting_name_from_markdown(&final_markdown) {
-                    if !name.is_empty() {
-                        info!(
-                            "Updating meeting name to '{}' for meeting_id: {}",
-                            name, meeting_id
-                        );
-                        if let Err(e) =
-                            MeetingsRepository::update_meeting_title(&pool, &meeting_id, &name).await
-                        {
-                            error!("Failed to update meeting name for {}: {}", meeting_id, e);
-                        }
-
-                        // Strip the title line from markdown
-                        info!("Stripping title from final_markdown");
-                        if let Some(hash_pos) = final_markdown.find('#') {
-                            // Find end of first line after '#'
-                            let body_start =
-                                if let Some(line_end) = final_markdown[hash_pos..].find('\n') {
-                                    hash_pos + line_end
-                                } else {
-                                    final_markdown.len() // No newline, whole string is title
-                                };
-
-                            final_markdown = final_markdown[body_start..].trim_start().to_string();
-                        } else {
-                            // No '#' found, clear the string
-                            final_markdown.clear();
-                        }
+                // The generated title becomes the meeting name (the body is saved without it)
+                if !summary.title.is_empty() {
+                    info!(
+                        "Updating meeting name to '{}' for meeting_id: {}",
+                        summary.title, meeting_id
+                    );
+                    if let Err(e) =
+                        MeetingsRepository::update_meeting_title(&pool, &meeting_id, &summary.title).await
+                    {
+                        error!("Failed to update meeting name for {}: {}", meeting_id, e);
                     }
                 }
 
@@ -301,16 +382,9 @@ impl SummaryService {
                 info!("Starting communication evaluation for meeting_id: {}", meeting_id);
                 let communication_feedback: Option<CommunicationFeedback> = match evaluate_communication(
                     &client,
-                    &provider,
-                    &model_name,
-                    &final_api_key,
+                    provider.as_ref(),
                     &text,
-                    ollama_endpoint.as_deref(),
-                    custom_openai_endpoint.as_deref(),
-                    custom_openai_max_tokens,
-                    custom_openai_temperature,
-                    custom_openai_top_p,
-                    app_data_dir.as_ref(),
+                    conversation_metrics.as_ref(),
                 )
                 .await
                 {
@@ -330,25 +404,35 @@ impl SummaryService {
                     }
                 };
 
-                // Create result JSON with markdown and optional communication feedback
+                // Create result JSON with the validated summary, its rendered markdown and
+                // optional communication feedback
                 let result_json = if let Some(ref feedback) = communication_feedback {
                     serde_json::json!({
                         "markdown": final_markdown,
+                        "structured": summary,
                         "communication_feedback": feedback,
                     })
                 } else {
                     serde_json::json!({
                         "markdown": final_markdown,
+                        "structured": summary,
                     })
                 };
 
-                // Update database with completed status
+                // Update database with completed status (and record it in the version history)
+                let version = SummaryVersionDetails {
+                    provider: provider_report.provider.clone(),
+                    model: provider_report.model.clone(),
+                    template_id: Some(template_id.clone()),
+                    prompt_hash: Some(prompt_hash(&template, &custom_prompt)),
+                };
                 if let Err(e) = SummaryProcessesRepository::update_process_completed(
                     &pool,
                     &meeting_id,
                     result_json,
                     num_chunks,
                     duration,
+                    &version,
                 )
                 .await
                 {
@@ -362,6 +446,51 @@ impl SummaryService {
                         meeting_id
                     );
                 }
+
+                // Dedicated extraction pass for action items (runs after the summary is
+                // available; if it fails the previous items are kept)
+                if let (Some(meeting), Some(series_title)) = (&meeting, &series_title) {
+                    let meeting_date = meeting.created_at.get(..10).unwrap_or_default();
+                    match extract_action_items(
+                        &client,
+                        provider.as_ref(),
+                        &meeting.transcripts,
+                        meeting_date,
+                        token_threshold,
+                        &token_counter,
+                    )
+                    .await
+                    {
+                        Ok(items) => {
+                            match ActionItemsRepository::replace_meeting_items(
+                                &pool,
+                                &meeting_id,
+                                series_title,
+                                &items,
+                            )
+                            .await
+                            {
+                                Ok(()) => {
+                                    let payload = serde_json::json!({
+                                        "meeting_id": meeting_id,
+                                        "count": items.len(),
+                                    });
+                                    if let Err(e) = _app.emit("action-items-updated", payload) {
+                                        warn!("Failed to emit action-items-updated: {}", e);
+                                    }
+                                }
+                                Err(e) => error!(
+                                    "Failed to save action items for {}: {}",
+                                    meeting_id, e
+                                ),
+                            }
+                        }
+                        Err(e) => warn!(
+                            "Action item extraction failed for meeting_id: {}. Error: {}",
+                            meeting_id, e
+                        ),
+                    }
+                }
             }
             Err(e) => {
                 // Check if error is due to cancellation
@@ -377,6 +506,18 @@ impl SummaryService {
         }
     }
 
+    /// Emits the streamed report so far as a `summary-progress` event
+    fn emit_progress<R: tauri::Runtime>(app: &AppHandle<R>, meeting_id: &str, markdown: &str, done: bool) {
+        let progress = SummaryProgress {
+            meeting_id: meeting_id.to_string(),
+            partial_markdown: markdown.to_string(),
+            done,
+        };
+        if let Err(e) = app.emit("summary-progress", &progress) {
+            warn!("Failed to emit summary-progress for {}: {}", meeting_id, e);
+        }
+    }
+
     /// Updates the summary process status to failed with error message
     ///
     /// # Arguments
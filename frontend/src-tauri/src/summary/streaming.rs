@@ -0,0 +1,233 @@
+// Incremental parsers for streamed LLM responses
+//
+// OpenAI-compatible providers (OpenAI, Groq, OpenRouter, CustomOpenAI), Claude and Gemini
+// stream Server-Sent Events; Ollama's native /api/chat streams newline-delimited JSON. Both are
+// line based, so a small line buffer handles chunks that split a line in two.
+
+use serde_json::Value;
+
+use crate::summary::providers::gemini;
+
+/// Text extracted from one streamed event
+#[derive(Debug, Clone, PartialEq)]
+pub enum StreamEvent {
+    /// Incremental text to append to the output
+    Delta(String),
+    /// The provider signalled the end of the completion
+    Done,
+    /// The provider reported an error mid-stream
+    Error(String),
+}
+
+/// Stream wire format of a provider
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StreamFormat {
+    /// `data: {...}` lines with OpenAI chat-completion chunks, ended by `data: [DONE]`
+    OpenAiSse,
+    /// `data: {...}` lines with Anthropic message events, ended by `message_stop`
+    ClaudeSse,
+    /// One JSON object per line with `message.content`, ended by `"done": true`
+    OllamaNdjson,
+    /// `data: {...}` lines with Gemini `GenerateContentResponse` chunks, ended by the body
+    GeminiSse,
+}
+
+/// Buffers raw bytes and yields parsed events line by line
+pub struct StreamDecoder {
+    format: StreamFormat,
+    buffer: Vec<u8>,
+}
+
+impl StreamDecoder {
+    pub fn new(format: StreamFormat) -> Self {
+        Self {
+            format,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Feed a network chunk and return the events of every complete line in it
+    pub fn push(&mut self, chunk: &[u8]) -> Vec<StreamEvent> {
+        self.buffer.extend_from_slice(chunk);
+
+        let mut events = Vec::new();
+        while let Some(newline) = self.buffer.iter().position(|b| *b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=newline).collect();
+            let line = String::from_utf8_lossy(&line);
+            if let Some(event) = self.parse_line(line.trim()) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    /// Parse whatever is left once the body has ended (a last line without newline)
+    pub fn finish(&mut self) -> Vec<StreamEvent> {
+        let rest = std::mem::take(&mut self.buffer);
+        let line = String::from_utf8_lossy(&rest);
+        self.parse_line(line.trim()).into_iter().collect()
+    }
+
+    fn parse_line(&self, line: &str) -> Option<StreamEvent> {
+        if line.is_empty() {
+            return None;
+        }
+
+        match self.format {
+            StreamFormat::OpenAiSse => parse_openai_data(sse_data(line)?),
+            StreamFormat::ClaudeSse => parse_claude_data(sse_data(line)?),
+            StreamFormat::OllamaNdjson => parse_ollama_line(line),
+            StreamFormat::GeminiSse => parse_gemini_data(sse_data(line)?),
+        }
+    }
+}
+
+/// Payload of an SSE `data:` line (comments, `event:` and `id:` lines are skipped)
+fn sse_data(line: &str) -> Option<&str> {
+    line.strip_prefix("data:").map(str::trim)
+}
+
+fn parse_openai_data(data: &str) -> Option<StreamEvent> {
+    if data == "[DONE]" {
+        return Some(StreamEvent::Done);
+    }
+
+    let value: Value = serde_json::from_str(data).ok()?;
+    if let Some(error) = value.get("error") {
+        return Some(StreamEvent::Error(error_message(error)));
+    }
+
+    value
+        .pointer("/choices/0/delta/content")
+        .and_then(Value::as_str)
+        .filter(|text| !text.is_empty())
+        .map(|text| StreamEvent::Delta(text.to_string()))
+}
+
+fn parse_claude_data(data: &str) -> Option<StreamEvent> {
+    let value: Value = serde_json::from_str(data).ok()?;
+
+    match value.get("type").and_then(Value::as_str)? {
+        "content_block_delta" => value
+            .pointer("/delta/text")
+            .and_then(Value::as_str)
+            .map(|text| StreamEvent::Delta(text.to_string())),
+        "message_stop" => Some(StreamEvent::Done),
+        "error" => Some(StreamEvent::Error(
+            value.get("error").map(error_message).unwrap_or_default(),
+        )),
+        _ => None,
+    }
+}
+
+fn parse_ollama_line(line: &str) -> Option<StreamEvent> {
+    let value: Value = serde_json::from_str(line).ok()?;
+
+    if let Some(error) = value.get("error") {
+        return Some(StreamEvent::Error(error_message(error)));
+    }
+    if value.get("done").and_then(Value::as_bool) == Some(true) {
+        return Some(StreamEvent::Done);
+    }
+
+    value
+        .pointer("/message/content")
+        .and_then(Value::as_str)
+        .filter(|text| !text.is_empty())
+        .map(|text| StreamEvent::Delta(text.to_string()))
+}
+
+fn parse_gemini_data(data: &str) -> Option<StreamEvent> {
+    let value: Value = serde_json::from_str(data).ok()?;
+
+    if let Some(error) = value.get("error") {
+        return Some(StreamEvent::Error(error_message(error)));
+    }
+    if let Some(message) = gemini::block_message(&value) {
+        return Some(StreamEvent::Error(message));
+    }
+
+    Some(gemini::response_text(&value))
+        .filter(|text| !text.is_empty())
+        .map(StreamEvent::Delta)
+}
+
+fn error_message(error: &Value) -> String {
+    error
+        .get("message")
+        .and_then(Value::as_str)
+        .or_else(|| error.as_str())
+        .map(str::to_string)
+        .unwrap_or_else(|| error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_openai_sse_split_across_chunks() {
+        let mut decoder = StreamDecoder::new(StreamFormat::OpenAiSse);
+        let mut events = decoder.push(b"data: {\"choices\":[{\"delta\":{\"content\":\"Hola\"}}]}\n\ndata: {\"choi");
+        events.extend(decoder.push(b"ces\":[{\"delta\":{\"content\":\" mundo\"}}]}\n\n: keep-alive\ndata: [DONE]\n"));
+
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::Delta("Hola".to_string()),
+                StreamEvent::Delta(" mundo".to_string()),
+                StreamEvent::Done,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_claude_sse_events() {
+        let mut decoder = StreamDecoder::new(StreamFormat::ClaudeSse);
+        let events = decoder.push(
+            b"event: content_block_delta\n\
+              data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"# Resumen\"}}\n\n\
+              event: message_stop\n\
+              data: {\"type\":\"message_stop\"}\n\n",
+        );
+
+        assert_eq!(
+            events,
+            vec![StreamEvent::Delta("# Resumen".to_string()), StreamEvent::Done]
+        );
+    }
+
+    #[test]
+    fn test_ollama_ndjson_without_trailing_newline() {
+        let mut decoder = StreamDecoder::new(StreamFormat::OllamaNdjson);
+        let mut events = decoder.push(b"{\"message\":{\"role\":\"assistant\",\"content\":\"Hi\"},\"done\":false}\n");
+        events.extend(decoder.push(b"{\"message\":{\"content\":\"\"},\"done\":true}"));
+        events.extend(decoder.finish());
+
+        assert_eq!(events, vec![StreamEvent::Delta("Hi".to_string()), StreamEvent::Done]);
+    }
+
+    #[test]
+    fn test_gemini_sse_chunks_and_safety_block() {
+        let mut decoder = StreamDecoder::new(StreamFormat::GeminiSse);
+        let events = decoder.push(
+            b"data: {\"candidates\":[{\"content\":{\"parts\":[{\"text\":\"# Resumen\"}],\"role\":\"model\"}}]}\r\n\r\n\
+              data: {\"candidates\":[{\"finishReason\":\"SAFETY\",\"safetyRatings\":[]}]}\r\n\r\n",
+        );
+
+        assert_eq!(
+            events,
+            vec![
+                StreamEvent::Delta("# Resumen".to_string()),
+                StreamEvent::Error("Gemini withheld the response (SAFETY)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mid_stream_error() {
+        let mut decoder = StreamDecoder::new(StreamFormat::OpenAiSse);
+        let events = decoder.push(b"data: {\"error\":{\"message\":\"rate limited\"}}\n");
+        assert_eq!(events, vec![StreamEvent::Error("rate limited".to_string())]);
+    }
+}
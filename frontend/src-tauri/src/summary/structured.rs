@@ -0,0 +1,423 @@
+// Typed meeting summaries produced from a template's JSON Schema
+//
+// The final report is requested as JSON (see `templates::schema`), validated against the
+// template into a `StructuredSummary` and stored as-is; the markdown shown in the editor is
+// rendered from it.
+
+use crate::summary::processor::{clean_llm_markdown_output, extract_meeting_name_from_markdown};
+use crate::summary::templates::{SectionShape, Template, TITLE_KEY};
+use crate::summary::versions::markdown_sections;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Written in place of sections the model left empty
+const EMPTY_SECTION_TEXT: &str = "Sin información en esta sección.";
+
+/// Title of the text a markdown response has before its first section
+const UNTITLED_SECTION_TITLE: &str = "Resumen";
+
+/// A validated summary following its template
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuredSummary {
+    pub title: String,
+    pub sections: Vec<StructuredSection>,
+}
+
+/// One template section with its generated content
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StructuredSection {
+    /// Property name in the template schema
+    pub key: String,
+    /// Section title from the template
+    pub title: String,
+    pub content: SectionContent,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SectionContent {
+    Text { text: String },
+    List { items: Vec<String> },
+    Table { columns: Vec<String>, rows: Vec<Vec<String>> },
+}
+
+impl StructuredSummary {
+    /// Validates a model response against the template
+    ///
+    /// `raw` may contain thinking tags, code fences or text around the JSON object.
+    pub fn parse(template: &Template, raw: &str) -> Result<Self, String> {
+        let json = extract_json_object(raw)
+            .ok_or_else(|| "Structured summary is not a JSON object".to_string())?;
+        let value: Value = serde_json::from_str(&json)
+            .map_err(|e| format!("Structured summary is not valid JSON: {}", e))?;
+        Self::from_value(template, &value, true)
+    }
+
+    /// Best-effort summary of a response that is still streaming
+    ///
+    /// Open strings, arrays and objects are closed and missing sections skipped, so the
+    /// preview grows section by section. Returns None until the JSON can be read at all.
+    pub fn from_partial(template: &Template, raw: &str) -> Option<Self> {
+        let start = raw.find('{')?;
+        let closed = close_partial_json(&raw[start..])?;
+        let value: Value = serde_json::from_str(&closed).ok()?;
+        Self::from_value(template, &value, false).ok()
+    }
+
+    /// Keeps a response that is not the requested JSON as markdown
+    ///
+    /// Fallback for models that ignore the schema: the first `# ` heading becomes the title
+    /// and the rest is split at its section titles into text sections, keyed like the
+    /// template section with the same title. Returns None when there is no content.
+    pub fn from_markdown(template: &Template, raw: &str) -> Option<Self> {
+        let markdown = clean_llm_markdown_output(raw);
+        let title = extract_meeting_name_from_markdown(&markdown).unwrap_or_default();
+        let body = markdown
+            .lines()
+            .filter(|line| !(line.starts_with("# ") && line[2..].trim() == title))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let keys = template.section_keys();
+        let sections: Vec<StructuredSection> = markdown_sections(&body)
+            .into_iter()
+            .map(|section| {
+                let title = if section.title.is_empty() {
+                    UNTITLED_SECTION_TITLE.to_string()
+                } else {
+                    section.title
+                };
+                let key = template
+                    .sections
+                    .iter()
+                    .zip(&keys)
+                    .find(|(template_section, _)| {
+                        template_section.title.to_lowercase() == title.to_lowercase()
+                    })
+                    .map(|(_, key)| key.clone())
+                    .unwrap_or_default();
+                StructuredSection {
+                    key,
+                    title,
+                    content: SectionContent::Text { text: section.content },
+                }
+            })
+            .collect();
+
+        (!sections.is_empty()).then_some(Self { title, sections })
+    }
+
+    fn from_value(template: &Template, value: &Value, strict: bool) -> Result<Self, String> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| "Structured summary must be a JSON object".to_string())?;
+
+        let title = match object.get(TITLE_KEY).and_then(Value::as_str) {
+            Some(title) => title.trim().to_string(),
+            None if strict => return Err(format!("Structured summary is missing '{}'", TITLE_KEY)),
+            None => String::new(),
+        };
+
+        let mut sections = Vec::new();
+        for (key, section) in template.section_keys().into_iter().zip(&template.sections) {
+            let Some(field) = object.get(&key) else {
+                if strict {
+                    return Err(format!("Structured summary is missing section '{}'", key));
+                }
+                continue;
+            };
+
+            let content = match section.shape() {
+                SectionShape::Text => field
+                    .as_str()
+                    .map(|text| SectionContent::Text { text: text.trim().to_string() }),
+                SectionShape::List => string_array(field).map(|items| SectionContent::List { items }),
+                SectionShape::Table(columns) => field.as_array().and_then(|rows| {
+                    let rows = rows
+                        .iter()
+                        .map(|row| {
+                            let row = row.as_object()?;
+                            Some(
+                                columns
+                                    .iter()
+                                    .map(|c| {
+                                        row.get(&c.key)
+                                            .and_then(Value::as_str)
+                                            .unwrap_or_default()
+                                            .trim()
+                                            .to_string()
+                                    })
+                                    .collect(),
+                            )
+                        })
+                        .collect::<Option<Vec<Vec<String>>>>()?;
+                    Some(SectionContent::Table {
+                        columns: columns.iter().map(|c| c.title.clone()).collect(),
+                        rows,
+                    })
+                }),
+            };
+
+            match content {
+                Some(content) => sections.push(StructuredSection {
+                    key,
+                    title: section.title.clone(),
+                    content,
+                }),
+                None if strict => {
+                    return Err(format!(
+                        "Section '{}' does not match its template format '{}'",
+                        key, section.format
+                    ))
+                }
+                None => {}
+            }
+        }
+
+        Ok(Self { title, sections })
+    }
+
+    /// Markdown of the sections only (the title is stored as the meeting name)
+    pub fn body_markdown(&self) -> String {
+        let mut markdown = String::new();
+
+        for section in &self.sections {
+            markdown.push_str(&format!("**{}**\n\n", section.title));
+            match &section.content {
+                SectionContent::Text { text } if !text.is_empty() => {
+                    markdown.push_str(text);
+                    markdown.push('\n');
+                }
+                SectionContent::List { items } if !items.is_empty() => {
+                    for item in items {
+                        markdown.push_str(&format!("- {}\n", item.replace('\n', " ")));
+                    }
+                }
+                SectionContent::Table { columns, rows } if !rows.is_empty() => {
+                    markdown.push_str(&table_row(columns));
+                    markdown.push_str(&format!("|{}\n", " --- |".repeat(columns.len())));
+                    for row in rows {
+                        markdown.push_str(&table_row(row));
+                    }
+                }
+                _ => {
+                    markdown.push_str(EMPTY_SECTION_TEXT);
+                    markdown.push('\n');
+                }
+            }
+            markdown.push('\n');
+        }
+
+        markdown.trim_end().to_string()
+    }
+
+    /// Full markdown report with the title as top-level heading
+    pub fn to_markdown(&self) -> String {
+        if self.title.is_empty() {
+            self.body_markdown()
+        } else {
+            format!("# {}\n\n{}", self.title, self.body_markdown())
+        }
+    }
+}
+
+fn string_array(value: &Value) -> Option<Vec<String>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|item| item.as_str().map(|s| s.trim().to_string()))
+        .collect()
+}
+
+fn table_row(cells: &[String]) -> String {
+    let cells: Vec<String> = cells
+        .iter()
+        .map(|cell| cell.replace('|', "\\|").replace('\n', " "))
+        .collect();
+    format!("| {} |\n", cells.join(" | "))
+}
+
+/// The outermost JSON object in a model response (thinking tags and fences are skipped)
+pub(crate) fn extract_json_object(raw: &str) -> Option<String> {
+    let cleaned = clean_llm_markdown_output(raw);
+    let open = cleaned.find('{')?;
+    let close = cleaned.rfind('}')?;
+    (open < close).then(|| cleaned[open..=close].to_string())
+}
+
+/// Closes a truncated JSON document so it parses
+///
+/// Cuts back to the last complete value (or closes a value string in progress) and
+/// appends the missing `]`/`}`. Keys without a value are dropped.
+fn close_partial_json(partial: &str) -> Option<String> {
+    let mut stack: Vec<char> = Vec::new();
+    let mut in_string = false;
+    let mut escaped = false;
+    let mut string_is_value = false;
+    let mut expect_key = false;
+    let mut string_start = 0;
+    // Byte offset up to which the document is complete, with the containers open there
+    let mut checkpoint: Option<(usize, Vec<char>)> = None;
+
+    for (i, c) in partial.char_indices() {
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+                if string_is_value {
+                    checkpoint = Some((i + 1, stack.clone()));
+                }
+            }
+            continue;
+        }
+
+        match c {
+            '"' => {
+                in_string = true;
+                string_start = i;
+                string_is_value = !(stack.last() == Some(&'{') && expect_key);
+            }
+            '{' | '[' => {
+                stack.push(c);
+                expect_key = c == '{';
+                checkpoint = Some((i + 1, stack.clone()));
+            }
+            '}' | ']' => {
+                stack.pop();
+                checkpoint = Some((i + 1, stack.clone()));
+            }
+            ':' => expect_key = false,
+            ',' => expect_key = stack.last() == Some(&'{'),
+            _ => {}
+        }
+    }
+
+    let (mut document, open) = if in_string && string_is_value {
+        // Keep the text streamed so far, minus a dangling escape sequence
+        let mut text = &partial[string_start..];
+        if let Some(backslash) = text.rfind('\\') {
+            if text.len() - backslash < 6 {
+                text = &text[..backslash];
+            }
+        }
+        (format!("{}{}\"", &partial[..string_start], text), stack)
+    } else {
+        let (end, open) = checkpoint?;
+        (partial[..end].to_string(), open)
+    };
+
+    // A value that ends right after a key (`"key": "...`) is fine; a trailing comma is not
+    let trimmed_len = document.trim_end().trim_end_matches(',').len();
+    document.truncate(trimmed_len);
+
+    for container in open.iter().rev() {
+        document.push(if *container == '{' { '}' } else { ']' });
+    }
+    Some(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::templates::TemplateSection;
+
+    fn template() -> Template {
+        let section = |title: &str, format: &str, item_format: Option<&str>| TemplateSection {
+            title: title.to_string(),
+            instruction: "Test".to_string(),
+            format: format.to_string(),
+            item_format: item_format.map(str::to_string),
+            example_item_format: None,
+            condition: None,
+        };
+        Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            extends: None,
+            llm: None,
+            sections: vec![
+                section("Resumen", "paragraph", None),
+                section("Decisiones", "list", None),
+                section("Tareas", "list", Some("| **Responsable** | **Tarea** |\n| --- | --- |")),
+            ],
+        }
+    }
+
+    const RESPONSE: &str = r#"{"title":"Planificación Q3","resumen":"Se revisó el roadmap.","decisiones":["Lanzar en julio"],"tareas":[{"responsable":"Ana","tarea":"Preparar demo | v2"}]}"#;
+
+    #[test]
+    fn test_markdown_response_is_kept() {
+        let raw = "```markdown\n# Planificación Q3\n\n**Resumen**\n\nSe revisó el roadmap.\n\n## Otros temas\n- Presupuesto\n```";
+        let summary = StructuredSummary::from_markdown(&template(), raw).unwrap();
+
+        assert_eq!(summary.title, "Planificación Q3");
+        assert_eq!(summary.sections[0].key, "resumen");
+        assert_eq!(summary.sections[1].key, "");
+        assert_eq!(
+            summary.body_markdown(),
+            "**Resumen**\n\nSe revisó el roadmap.\n\n**Otros temas**\n\n- Presupuesto"
+        );
+        assert!(StructuredSummary::from_markdown(&template(), "  \n").is_none());
+    }
+
+    #[test]
+    fn test_parse_and_render() {
+        let raw = format!("<think>plan</think>\n```json\n{}\n```", RESPONSE);
+        let summary = StructuredSummary::parse(&template(), &raw).unwrap();
+
+        assert_eq!(summary.title, "Planificación Q3");
+        assert_eq!(
+            summary.body_markdown(),
+            "**Resumen**\n\nSe revisó el roadmap.\n\n\
+             **Decisiones**\n\n- Lanzar en julio\n\n\
+             **Tareas**\n\n| Responsable | Tarea |\n| --- | --- |\n| Ana | Preparar demo \\| v2 |"
+        );
+        assert!(summary.to_markdown().starts_with("# Planificación Q3\n\n**Resumen**"));
+    }
+
+    #[test]
+    fn test_parse_rejects_wrong_shape() {
+        let missing = r#"{"title":"T","resumen":"R","decisiones":[]}"#;
+        assert!(StructuredSummary::parse(&template(), missing).is_err());
+
+        let wrong_type = r#"{"title":"T","resumen":"R","decisiones":"no es lista","tareas":[]}"#;
+        assert!(StructuredSummary::parse(&template(), wrong_type).is_err());
+    }
+
+    #[test]
+    fn test_empty_sections_render_placeholder() {
+        let raw = r#"{"title":"T","resumen":"","decisiones":[],"tareas":[]}"#;
+        let markdown = StructuredSummary::parse(&template(), raw).unwrap().body_markdown();
+        assert_eq!(markdown.matches(EMPTY_SECTION_TEXT).count(), 3);
+    }
+
+    #[test]
+    fn test_partial_json_preview() {
+        let partial = r#"{"title":"Planificación","resumen":"Se revisó el road"#;
+        let summary = StructuredSummary::from_partial(&template(), partial).unwrap();
+        assert_eq!(summary.title, "Planificación");
+        assert_eq!(summary.sections.len(), 1);
+        assert_eq!(summary.sections[0].content, SectionContent::Text { text: "Se revisó el road".to_string() });
+
+        // A key still being written is dropped
+        let partial = r#"{"title":"T","resumen":"R","decisiones":["A","B"],"tar"#;
+        let summary = StructuredSummary::from_partial(&template(), partial).unwrap();
+        assert_eq!(summary.sections.len(), 2);
+
+        // Every prefix of a complete response is readable or skipped, never a panic
+        for end in (0..RESPONSE.len()).filter(|i| RESPONSE.is_char_boundary(*i)) {
+            let _ = StructuredSummary::from_partial(&template(), &RESPONSE[..end]);
+        }
+    }
+
+    #[test]
+    fn test_close_partial_json() {
+        assert_eq!(close_partial_json(r#"{"a":["x","y"#).unwrap(), r#"{"a":["x","y"]}"#);
+        assert_eq!(close_partial_json(r#"{"a":"x","#).unwrap(), r#"{"a":"x"}"#);
+        assert_eq!(close_partial_json(r#"{"a":"x\"#).unwrap(), r#"{"a":"x"}"#);
+        assert_eq!(close_partial_json(r#"{"a":"#).unwrap(), r#"{}"#);
+    }
+}
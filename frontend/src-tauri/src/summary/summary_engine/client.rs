@@ -15,6 +15,8 @@ use tokio_util::sync::CancellationToken;
 
 use super::models;
 use super::sidecar::SidecarManager;
+use crate::summary::llm_client::TokenCallback;
+use crate::summary::providers::SamplingParams;
 
 // ============================================================================
 // Request/Response Types
@@ -33,13 +35,31 @@ enum Request {
         top_k: Option<i32>,
         top_p: Option<f32>,
         stop_tokens: Option<Vec<String>>,
+        // Ask the helper for incremental `token` messages
+        stream: bool,
+        // GBNF grammar the output must follow
+        #[serde(skip_serializing_if = "Option::is_none")]
+        grammar: Option<String>,
+    },
+    Embed {
+        texts: Vec<String>,
+        context_size: Option<u32>,
+        model_path: Option<String>,
+    },
+    Tokenize {
+        texts: Vec<String>,
+        context_size: Option<u32>,
+        model_path: Option<String>,
     },
 }
 
 #[derive(Debug, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum Response {
+    Token { text: String },
     Response { text: String, error: Option<String> },
+    Embeddings { embeddings: Vec<Vec<f32>>, error: Option<String> },
+    TokenCounts { counts: Vec<usize>, error: Option<String> },
     Error { message: String },
 }
 
@@ -123,6 +143,9 @@ fn get_cached_model_path(app_data_dir: &PathBuf, model_name: &str) -> Result<Pat
 /// * `system_prompt` - System instructions for the model
 /// * `user_prompt` - User message/task
 /// * `cancellation_token` - Optional token for cancellation
+/// * `on_token` - Optional callback receiving text as it is generated
+/// * `grammar` - Optional GBNF grammar constraining the output (grammar-constrained sampling)
+/// * `sampling` - Overrides of the model's sampling defaults
 ///
 /// # Returns
 /// Generated text
@@ -132,6 +155,9 @@ pub async fn generate_with_builtin(
     system_prompt: &str,
     user_prompt: &str,
     cancellation_token: Option<&CancellationToken>,
+    on_token: Option<TokenCallback<'_>>,
+    grammar: Option<&str>,
+    sampling: SamplingParams,
 ) -> Result<String> {
     // Check cancellation at start
     if let Some(token) = cancellation_token {
@@ -177,13 +203,19 @@ pub async fn generate_with_builtin(
     // Prepare generation request with model-specific sampling parameters
     let request = Request::Generate {
         prompt: formatted_prompt,
-        max_tokens: Some(models::DEFAULT_MAX_TOKENS),
+        max_tokens: Some(
+            sampling
+                .max_tokens
+                .map_or(models::DEFAULT_MAX_TOKENS, |t| t as i32),
+        ),
         context_size: Some(model_def.context_size),
         model_path: Some(model_path.to_string_lossy().to_string()),
-        temperature: Some(model_def.sampling.temperature),
+        temperature: Some(sampling.temperature.unwrap_or(model_def.sampling.temperature)),
         top_k: Some(model_def.sampling.top_k),
-        top_p: Some(model_def.sampling.top_p),
+        top_p: Some(sampling.top_p.unwrap_or(model_def.sampling.top_p)),
         stop_tokens: Some(model_def.sampling.stop_tokens.clone()),
+        stream: on_token.is_some(),
+        grammar: grammar.map(str::to_string),
     };
 
     let request_json = serde_json::to_string(&request)?;
@@ -193,10 +225,17 @@ pub async fn generate_with_builtin(
 
     log::info!("Sending generation request to sidecar");
 
+    let send = async {
+        match on_token {
+            Some(callback) => manager.send_request_streaming(request_json, timeout, callback).await,
+            None => manager.send_request(request_json, timeout).await,
+        }
+    };
+
     // Race between send_request and cancellation token
     let response_json = if let Some(token) = cancellation_token {
         tokio::select! {
-            result = manager.send_request(request_json, timeout) => {
+            result = send => {
                 result?
             }
             _ = token.cancelled() => {
@@ -209,7 +248,7 @@ pub async fn generate_with_builtin(
             }
         }
     } else {
-        manager.send_request(request_json, timeout).await?
+        send.await?
     };
 
     // Check cancellation before parsing response
@@ -232,6 +271,159 @@ pub async fn generate_with_builtin(
                 Ok(text)
             }
         }
+        Response::Token { .. } => Err(anyhow!("Unexpected token message as final response")),
+        Response::Embeddings { .. } => Err(anyhow!("Unexpected embeddings message as generation response")),
+        Response::TokenCounts { .. } => Err(anyhow!("Unexpected token counts message as generation response")),
+        Response::Error { message } => Err(anyhow!("Sidecar error: {}", message)),
+    }
+}
+
+/// Embed texts using built-in AI
+///
+/// The helper returns one mean-pooled, L2-normalized vector per text, so cosine
+/// similarity between two results is their dot product.
+///
+/// # Arguments
+/// * `app_data_dir` - Application data directory (for model resolution)
+/// * `model_name` - Model name (e.g., "gemma3:1b")
+/// * `texts` - Texts to embed
+///
+/// # Returns
+/// One embedding per input text, in the same order
+pub async fn embed_with_builtin(
+    app_data_dir: &PathBuf,
+    model_name: &str,
+    texts: &[String],
+) -> Result<Vec<Vec<f32>>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let model_def = models::get_model_by_name(model_name)
+        .ok_or_else(|| anyhow!("Unknown model: {}", model_name))?;
+    let model_path = get_cached_model_path(app_data_dir, model_name)?;
+
+    let manager = {
+        let mut global_manager = SIDECAR_MANAGER.lock().await;
+        if global_manager.is_none() {
+            log::info!("Initializing sidecar manager");
+            let new_manager = SidecarManager::new(app_data_dir.clone())?;
+            *global_manager = Some(Arc::new(new_manager));
+        }
+        global_manager.clone().unwrap()
+    };
+
+    manager.ensure_running(model_path.clone()).await?;
+
+    // Same context size as generation so the helper keeps the loaded model
+    let request = Request::Embed {
+        texts: texts.to_vec(),
+        context_size: Some(model_def.context_size),
+        model_path: Some(model_path.to_string_lossy().to_string()),
+    };
+
+    let request_json = serde_json::to_string(&request)?;
+    let timeout = Duration::from_secs(models::GENERATION_TIMEOUT_SECS);
+
+    log::info!("Sending embedding request for {} texts to sidecar", texts.len());
+    let response_json = manager.send_request(request_json, timeout).await?;
+
+    let response: Response = serde_json::from_str(&response_json)
+        .with_context(|| format!("Failed to parse embeddings response: {}", response_json))?;
+
+    match response {
+        Response::Embeddings { embeddings, error } => {
+            if let Some(err_msg) = error {
+                Err(anyhow!("Embedding failed: {}", err_msg))
+            } else if embeddings.len() != texts.len() {
+                Err(anyhow!(
+                    "Expected {} embeddings, sidecar returned {}",
+                    texts.len(),
+                    embeddings.len()
+                ))
+            } else {
+                Ok(embeddings)
+            }
+        }
+        Response::Response { error, .. } => Err(anyhow!(
+            "Unexpected generation response to embedding request: {}",
+            error.unwrap_or_default()
+        )),
+        Response::Token { .. } => Err(anyhow!("Unexpected token message as embeddings response")),
+        Response::TokenCounts { .. } => Err(anyhow!("Unexpected token counts message as embeddings response")),
+        Response::Error { message } => Err(anyhow!("Sidecar error: {}", message)),
+    }
+}
+
+/// Count tokens with the built-in model's own tokenizer
+///
+/// # Arguments
+/// * `app_data_dir` - Application data directory (for model resolution)
+/// * `model_name` - Model name (e.g., "gemma3:1b")
+/// * `texts` - Texts to count
+///
+/// # Returns
+/// One token count per input text, in the same order (without the BOS token)
+pub async fn count_tokens_with_builtin(
+    app_data_dir: &PathBuf,
+    model_name: &str,
+    texts: &[String],
+) -> Result<Vec<usize>> {
+    if texts.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let model_def = models::get_model_by_name(model_name)
+        .ok_or_else(|| anyhow!("Unknown model: {}", model_name))?;
+    let model_path = get_cached_model_path(app_data_dir, model_name)?;
+
+    let manager = {
+        let mut global_manager = SIDECAR_MANAGER.lock().await;
+        if global_manager.is_none() {
+            log::info!("Initializing sidecar manager");
+            let new_manager = SidecarManager::new(app_data_dir.clone())?;
+            *global_manager = Some(Arc::new(new_manager));
+        }
+        global_manager.clone().unwrap()
+    };
+
+    manager.ensure_running(model_path.clone()).await?;
+
+    // Same context size as generation so the helper keeps the loaded model
+    let request = Request::Tokenize {
+        texts: texts.to_vec(),
+        context_size: Some(model_def.context_size),
+        model_path: Some(model_path.to_string_lossy().to_string()),
+    };
+
+    let request_json = serde_json::to_string(&request)?;
+    let timeout = Duration::from_secs(models::GENERATION_TIMEOUT_SECS);
+
+    let response_json = manager.send_request(request_json, timeout).await?;
+
+    let response: Response = serde_json::from_str(&response_json)
+        .with_context(|| format!("Failed to parse token counts response: {}", response_json))?;
+
+    match response {
+        Response::TokenCounts { counts, error } => {
+            if let Some(err_msg) = error {
+                Err(anyhow!("Tokenization failed: {}", err_msg))
+            } else if counts.len() != texts.len() {
+                Err(anyhow!(
+                    "Expected {} token counts, sidecar returned {}",
+                    texts.len(),
+                    counts.len()
+                ))
+            } else {
+                Ok(counts)
+            }
+        }
+        Response::Response { error, .. } => Err(anyhow!(
+            "Unexpected generation response to tokenize request: {}",
+            error.unwrap_or_default()
+        )),
+        Response::Token { .. } => Err(anyhow!("Unexpected token message as token counts response")),
+        Response::Embeddings { .. } => Err(anyhow!("Unexpected embeddings message as token counts response")),
         Response::Error { message } => Err(anyhow!("Sidecar error: {}", message)),
     }
 }
@@ -304,6 +496,8 @@ mod tests {
             top_k: Some(64),
             top_p: Some(0.95),
             stop_tokens: Some(vec!["<end_of_turn>".to_string()]),
+            stream: true,
+            grammar: None,
         };
 
         let json = serde_json::to_string(&request).unwrap();
@@ -311,6 +505,60 @@ mod tests {
         assert!(json.contains("\"prompt\":\"test prompt\""));
         assert!(json.contains("\"max_tokens\":512"));
         assert!(json.contains("\"temperature\":1.0"));
+        assert!(json.contains("\"stream\":true"));
+        assert!(!json.contains("grammar"));
+    }
+
+    #[test]
+    fn test_embed_request_serialization() {
+        let request = Request::Embed {
+            texts: vec!["hola".to_string()],
+            context_size: Some(2048),
+            model_path: None,
+        };
+
+        let json = serde_json::to_string(&request).unwrap();
+        assert!(json.contains("\"type\":\"embed\""));
+        assert!(json.contains("\"texts\":[\"hola\"]"));
+    }
+
+    #[test]
+    fn test_embeddings_deserialization() {
+        let json = r#"{"type":"embeddings","embeddings":[[0.6,0.8]],"error":null}"#;
+        let response: Response = serde_json::from_str(json).unwrap();
+
+        match response {
+            Response::Embeddings { embeddings, error } => {
+                assert_eq!(embeddings, vec![vec![0.6, 0.8]]);
+                assert!(error.is_none());
+            }
+            _ => panic!("Wrong response type"),
+        }
+    }
+
+    #[test]
+    fn test_token_counts_deserialization() {
+        let json = r#"{"type":"token_counts","counts":[12,0],"error":null}"#;
+        let response: Response = serde_json::from_str(json).unwrap();
+
+        match response {
+            Response::TokenCounts { counts, error } => {
+                assert_eq!(counts, vec![12, 0]);
+                assert!(error.is_none());
+            }
+            _ => panic!("Wrong response type"),
+        }
+    }
+
+    #[test]
+    fn test_token_deserialization() {
+        let json = r#"{"type":"token","text":"Resu"}"#;
+        let response: Response = serde_json::from_str(json).unwrap();
+
+        match response {
+            Response::Token { text } => assert_eq!(text, "Resu"),
+            _ => panic!("Wrong response type"),
+        }
     }
 
     #[test]
@@ -355,23 +355,7 @@ impl SidecarManager {
         // Track active request
         let _guard = RequestGuard::new(self.active_request_count.clone());
 
-        // Write request to stdin
-        {
-            let mut stdin_lock = self.stdin_writer.lock().await;
-            let stdin = stdin_lock
-                .as_mut()
-                .ok_or_else(|| anyhow!("Sidecar not running"))?;
-
-            stdin
-                .write_all(request_json.as_bytes())
-                .await
-                .context("Failed to write request to stdin")?;
-            stdin
-                .write_all(b"\n")
-                .await
-                .context("Failed to write newline")?;
-            stdin.flush().await.context("Failed to flush stdin")?;
-        }
+        self.write_request(&request_json).await?;
 
         // Read response from stdout with timeout
         match tokio::time::timeout(timeout, self.read_response()).await {
@@ -391,6 +375,89 @@ impl SidecarManager {
         }
     }
 
+    /// Send a streaming request: `token` lines are passed to `on_token` until the final response
+    /// The stdout reader stays locked for the whole generation so pings can't interleave
+    pub async fn send_request_streaming<F>(
+        &self,
+        request_json: String,
+        timeout: Duration,
+        mut on_token: F,
+    ) -> Result<String>
+    where
+        F: FnMut(&str) + Send,
+    {
+        let _guard = RequestGuard::new(self.active_request_count.clone());
+
+        self.write_request(&request_json).await?;
+
+        let read_all = async {
+            let mut stdout_lock = self.stdout_reader.lock().await;
+            let reader = stdout_lock
+                .as_mut()
+                .ok_or_else(|| anyhow!("Sidecar not running"))?;
+
+            loop {
+                let mut line = String::new();
+                reader
+                    .read_line(&mut line)
+                    .await
+                    .context("Failed to read response from stdout")?;
+
+                if line.is_empty() {
+                    return Err(anyhow!("Sidecar closed stdout (process may have crashed)"));
+                }
+
+                let line = line.trim();
+                let message: serde_json::Value = match serde_json::from_str(line) {
+                    Ok(message) => message,
+                    Err(_) => continue,
+                };
+
+                if message.get("type").and_then(|t| t.as_str()) == Some("token") {
+                    if let Some(text) = message.get("text").and_then(|t| t.as_str()) {
+                        on_token(text);
+                    }
+                } else {
+                    return Ok(line.to_string());
+                }
+            }
+        };
+
+        match tokio::time::timeout(timeout, read_all).await {
+            Ok(Ok(response)) => {
+                self.update_activity().await;
+                Ok(response)
+            }
+            Ok(Err(e)) => Err(e),
+            Err(_) => {
+                log::error!("Streaming request timeout after {:?}, shutting down sidecar", timeout);
+                if let Err(shutdown_err) = self.shutdown().await {
+                    log::error!("Failed to shutdown sidecar after timeout: {}", shutdown_err);
+                }
+                Err(anyhow!("Request timed out after {:?}", timeout))
+            }
+        }
+    }
+
+    /// Write one JSON request line to the sidecar's stdin
+    async fn write_request(&self, request_json: &str) -> Result<()> {
+        let mut stdin_lock = self.stdin_writer.lock().await;
+        let stdin = stdin_lock
+            .as_mut()
+            .ok_or_else(|| anyhow!("Sidecar not running"))?;
+
+        stdin
+            .write_all(request_json.as_bytes())
+            .await
+            .context("Failed to write request to stdin")?;
+        stdin
+            .write_all(b"\n")
+            .await
+            .context("Failed to write newline")?;
+        stdin.flush().await.context("Failed to flush stdin")?;
+        Ok(())
+    }
+
     /// Read a single line response from stdout
     async fn read_response(&self) -> Result<String> {
         let mut stdout_lock = self.stdout_reader.lock().await;
@@ -1,6 +1,8 @@
 use crate::summary::templates;
 use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
 use tauri::Runtime;
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
 use tracing::{info, warn};
 
 /// Template metadata for UI display
@@ -123,6 +125,102 @@ pub async fn api_validate_template<R: Runtime>(
     }
 }
 
+/// Imports a template from a JSON file chosen by the user
+///
+/// The template is validated (including the template it extends) and saved to the
+/// custom templates directory under the file name. If a custom template already uses that
+/// id, or importing would override a built-in one, the user is asked before replacing it.
+///
+/// # Returns
+/// Some(TemplateInfo) of the imported template, or None if the user cancelled
+#[tauri::command]
+pub async fn api_import_template<R: Runtime>(
+    app: tauri::AppHandle<R>,
+) -> Result<Option<TemplateInfo>, String> {
+    info!("api_import_template called");
+
+    let Some(path) = app
+        .dialog()
+        .file()
+        .add_filter("Plantillas", &["json"])
+        .blocking_pick_file()
+    else {
+        info!("User cancelled template import");
+        return Ok(None);
+    };
+
+    let path = PathBuf::from(path.to_string());
+    let template_json = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    let template_id = path.file_stem().and_then(|stem| stem.to_str());
+
+    let parsed = templates::validate_and_parse_template(&template_json)?;
+    let id = templates::import_template_id(&parsed, template_id);
+    let overwrite = templates::template_exists(&id);
+    if overwrite {
+        let confirmed = app
+            .dialog()
+            .message(format!(
+                "Ya existe una plantilla con el identificador \"{}\". ¿Quieres reemplazarla por la importada?",
+                id
+            ))
+            .title("Importar plantilla")
+            .kind(MessageDialogKind::Warning)
+            .buttons(MessageDialogButtons::OkCancelCustom(
+                "Reemplazar".to_string(),
+                "Cancelar".to_string(),
+            ))
+            .blocking_show();
+        if !confirmed {
+            info!("User kept the existing template '{}'", id);
+            return Ok(None);
+        }
+    }
+
+    let (id, template) = templates::import_template(&template_json, template_id, overwrite)?;
+    info!("Imported template '{}' as '{}'", template.name, id);
+
+    Ok(Some(TemplateInfo {
+        id,
+        name: template.name,
+        description: template.description,
+    }))
+}
+
+/// Exports a template to a JSON file chosen by the user
+///
+/// The exported template is self-contained: the template it extends is already merged in.
+///
+/// # Returns
+/// Some(path) of the written file, or None if the user cancelled
+#[tauri::command]
+pub async fn api_export_template<R: Runtime>(
+    app: tauri::AppHandle<R>,
+    template_id: String,
+) -> Result<Option<String>, String> {
+    info!("api_export_template called for template_id: {}", template_id);
+
+    let template_json = templates::export_template(&template_id)?;
+
+    let Some(path) = app
+        .dialog()
+        .file()
+        .add_filter("Plantillas", &["json"])
+        .set_file_name(format!("{}.json", template_id))
+        .blocking_save_file()
+    else {
+        info!("User cancelled template export");
+        return Ok(None);
+    };
+
+    let path = path.to_string();
+    std::fs::write(&path, template_json)
+        .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+    info!("Exported template '{}' to {}", template_id, path);
+
+    Ok(Some(path))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,4 +1,5 @@
 use super::defaults;
+use super::schema::slugify;
 use super::types::Template;
 use std::path::PathBuf;
 use tracing::{debug, info, warn};
@@ -79,6 +80,50 @@ fn load_custom_template(template_id: &str) -> Option<String> {
     }
 }
 
+/// Where a template's JSON comes from, in lookup order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum TemplateSource {
+    Custom,
+    Bundled,
+    BuiltIn,
+}
+
+/// Longest `extends` chain accepted
+const MAX_EXTENDS_DEPTH: usize = 5;
+
+/// Load the JSON of a template from the first source that has it
+///
+/// # Arguments
+/// * `template_id` - Template identifier (without .json extension)
+/// * `below` - Only look at sources after this one (a custom template extending the
+///   built-in template it overrides)
+fn load_template_source(
+    template_id: &str,
+    below: Option<TemplateSource>,
+) -> Option<(TemplateSource, String)> {
+    let after = |source: TemplateSource| below.map_or(true, |below| source > below);
+
+    if after(TemplateSource::Custom) {
+        if let Some(content) = load_custom_template(template_id) {
+            debug!("Using custom template for '{}'", template_id);
+            return Some((TemplateSource::Custom, content));
+        }
+    }
+    if after(TemplateSource::Bundled) {
+        if let Some(content) = load_bundled_template(template_id) {
+            debug!("Using bundled template for '{}'", template_id);
+            return Some((TemplateSource::Bundled, content));
+        }
+    }
+    if after(TemplateSource::BuiltIn) {
+        if let Some(content) = defaults::get_builtin_template(template_id) {
+            debug!("Using built-in template for '{}'", template_id);
+            return Some((TemplateSource::BuiltIn, content.to_string()));
+        }
+    }
+    None
+}
+
 /// Load and parse a template by identifier
 ///
 /// This function implements a fallback strategy:
@@ -87,25 +132,18 @@ fn load_custom_template(template_id: &str) -> Option<String> {
 /// 3. Fall back to built-in embedded templates
 /// 4. Return error if not found in any location
 ///
+/// Templates with `extends` are resolved against their parent (a template may extend
+/// the lower-priority template it overrides, e.g. a custom `standard_meeting`).
+///
 /// # Arguments
 /// * `template_id` - Template identifier (e.g., "daily_standup", "standard_meeting")
 ///
 /// # Returns
-/// Parsed and validated Template struct
+/// Parsed and validated Template struct (inheritance resolved, variables not yet filled in)
 pub fn get_template(template_id: &str) -> Result<Template, String> {
     info!("Loading template: {}", template_id);
 
-    // Try custom template first, then bundled, then built-in
-    let json_content = if let Some(custom_content) = load_custom_template(template_id) {
-        debug!("Using custom template for '{}'", template_id);
-        custom_content
-    } else if let Some(bundled_content) = load_bundled_template(template_id) {
-        debug!("Using bundled template for '{}'", template_id);
-        bundled_content
-    } else if let Some(builtin_content) = defaults::get_builtin_template(template_id) {
-        debug!("Using built-in template for '{}'", template_id);
-        builtin_content.to_string()
-    } else {
+    let Some((source, json_content)) = load_template_source(template_id, None) else {
         return Err(format!(
             "Template '{}' not found. Available templates: {}",
             template_id,
@@ -113,8 +151,8 @@ pub fn get_template(template_id: &str) -> Result<Template, String> {
         ));
     };
 
-    // Parse and validate
-    validate_and_parse_template(&json_content)
+    // Parse, resolve inheritance and validate
+    parse_template(&json_content, &mut vec![(template_id.to_string(), source)])
 }
 
 /// Validate and parse template JSON
@@ -123,16 +161,131 @@ pub fn get_template(template_id: &str) -> Result<Template, String> {
 /// * `json_content` - Raw JSON string
 ///
 /// # Returns
-/// Parsed and validated Template struct
+/// Parsed and validated Template struct (inheritance resolved)
 pub fn validate_and_parse_template(json_content: &str) -> Result<Template, String> {
+    parse_template(json_content, &mut Vec::new())
+}
+
+/// Parses a template and resolves its `extends` chain
+///
+/// `chain` holds the templates being resolved (id and source), to detect cycles.
+fn parse_template(
+    json_content: &str,
+    chain: &mut Vec<(String, TemplateSource)>,
+) -> Result<Template, String> {
     let template: Template = serde_json::from_str(json_content)
         .map_err(|e| format!("Failed to parse template JSON: {}", e))?;
 
+    let template = match template.extends.clone() {
+        Some(parent_id) => {
+            if chain.len() > MAX_EXTENDS_DEPTH {
+                return Err(format!(
+                    "Template inheritance is deeper than {} levels",
+                    MAX_EXTENDS_DEPTH
+                ));
+            }
+
+            // Extending itself means extending the template it overrides
+            let below = chain
+                .last()
+                .filter(|(id, _)| *id == parent_id)
+                .map(|(_, source)| *source);
+            let (source, parent_json) = load_template_source(&parent_id, below)
+                .ok_or_else(|| format!("Template extends unknown template '{}'", parent_id))?;
+            if chain.contains(&(parent_id.clone(), source)) {
+                let path: Vec<&str> = chain.iter().map(|(id, _)| id.as_str()).collect();
+                return Err(format!(
+                    "Template inheritance cycle: {} -> {}",
+                    path.join(" -> "),
+                    parent_id
+                ));
+            }
+
+            chain.push((parent_id.clone(), source));
+            let parent = parse_template(&parent_json, chain)
+                .map_err(|e| format!("Invalid parent template '{}': {}", parent_id, e))?;
+            chain.pop();
+
+            template.inherit(parent)
+        }
+        None => template,
+    };
+
     template.validate()?;
 
     Ok(template)
 }
 
+/// Identifier an imported template is saved under: `template_id`, or its name when missing
+pub fn import_template_id(template: &Template, template_id: Option<&str>) -> String {
+    slugify(template_id.unwrap_or(&template.name))
+}
+
+/// Whether a custom, bundled or built-in template already uses `template_id`
+pub fn template_exists(template_id: &str) -> bool {
+    load_template_source(template_id, None).is_some()
+}
+
+/// Saves a template into the custom templates directory
+///
+/// # Arguments
+/// * `json_content` - Template JSON (validated before saving; may use `extends`)
+/// * `template_id` - Identifier to save it under; derived from the name when missing
+/// * `overwrite` - Replace a custom template with the same identifier, or override the
+///   bundled or built-in one; without it such a collision is an error
+///
+/// # Returns
+/// The identifier and the resolved template
+pub fn import_template(
+    json_content: &str,
+    template_id: Option<&str>,
+    overwrite: bool,
+) -> Result<(String, Template), String> {
+    let template = validate_and_parse_template(json_content)?;
+
+    let id = import_template_id(&template, template_id);
+    if id.is_empty() {
+        return Err("Template identifier cannot be empty".to_string());
+    }
+
+    if !overwrite {
+        match load_template_source(&id, None) {
+            Some((TemplateSource::Custom, _)) => {
+                return Err(format!("A custom template '{}' already exists", id));
+            }
+            Some(_) => {
+                return Err(format!(
+                    "Template '{}' would override the built-in template with the same id",
+                    id
+                ));
+            }
+            None => {}
+        }
+    }
+
+    let custom_dir = get_custom_templates_dir()
+        .ok_or_else(|| "Custom templates directory is not available".to_string())?;
+    std::fs::create_dir_all(&custom_dir)
+        .map_err(|e| format!("Failed to create custom templates directory: {}", e))?;
+
+    // Saved as written (keeps `extends`), only reformatted
+    let value: serde_json::Value = serde_json::from_str(json_content)
+        .map_err(|e| format!("Failed to parse template JSON: {}", e))?;
+    let pretty = serde_json::to_string_pretty(&value)
+        .map_err(|e| format!("Failed to serialize template: {}", e))?;
+    let path = custom_dir.join(format!("{}.json", id));
+    std::fs::write(&path, pretty).map_err(|e| format!("Failed to save template: {}", e))?;
+
+    info!("Imported template '{}' to {:?}", id, path);
+    Ok((id, template))
+}
+
+/// Template JSON ready to share: inheritance resolved, variables and conditions kept
+pub fn export_template(template_id: &str) -> Result<String, String> {
+    let template = get_template(template_id)?;
+    serde_json::to_string_pretty(&template).map_err(|e| format!("Failed to serialize template: {}", e))
+}
+
 /// List all available template identifiers
 ///
 /// Returns a combined list of:
@@ -244,6 +397,45 @@ mod tests {
         assert!(ids.contains(&"standard_meeting".to_string()));
     }
 
+    #[test]
+    fn test_extends_builtin_template() {
+        let json = r#"{
+            "extends": "standard_meeting",
+            "name": "Reunión de proyecto",
+            "llm": {"temperature": 0.2},
+            "sections": [
+                {"title": "Resumen", "instruction": "Resume en dos frases", "format": "paragraph"},
+                {"title": "Riesgos", "instruction": "Lista los riesgos", "format": "list"}
+            ]
+        }"#;
+        let template = validate_and_parse_template(json).unwrap();
+        let parent = get_template("standard_meeting").unwrap();
+
+        assert_eq!(template.name, "Reunión de proyecto");
+        assert_eq!(template.description, parent.description);
+        assert!(template.extends.is_none());
+        assert_eq!(template.llm.unwrap().temperature, Some(0.2));
+        // Same-title sections are replaced in place, new ones appended
+        assert_eq!(template.sections.len(), parent.sections.len() + 1);
+        assert_eq!(template.sections[0].instruction, "Resume en dos frases");
+        assert_eq!(template.sections.last().unwrap().title, "Riesgos");
+    }
+
+    #[test]
+    fn test_extends_unknown_template() {
+        let json = r#"{"extends": "nonexistent_template", "name": "X", "description": "X"}"#;
+        let error = validate_and_parse_template(json).unwrap_err();
+        assert!(error.contains("nonexistent_template"));
+    }
+
+    #[test]
+    fn test_import_rejects_builtin_id_without_overwrite() {
+        let json = r#"{"name": "Standard Meeting", "description": "X", "sections": []}"#;
+        assert!(template_exists("standard_meeting"));
+        let error = import_template(json, Some("standard_meeting"), false).unwrap_err();
+        assert!(error.contains("standard_meeting"));
+    }
+
     #[test]
     fn test_validate_invalid_json() {
         let result = validate_and_parse_template("invalid json");
@@ -9,17 +9,21 @@
 //! - **Built-in templates**: JSON files in `frontend/src-tauri/templates/` embedded at compile time
 //! - **Custom templates**: JSON files in platform-specific app data directory
 //! - **Fallback strategy**: Custom templates override built-in templates with the same ID
+//! - **Inheritance**: `extends` builds on another template (sections replaced by title or appended)
+//! - **Rendering**: `{{variable}}` placeholders are filled in from the meeting and conditional
+//!   sections are dropped when the transcript does not mention their keywords
 //!
 //! # Usage
 //!
 //! ```rust
 //! use crate::summary::templates;
 //!
-//! // Load a specific template
-//! let template = templates::get_template("daily_standup")?;
+//! // Load a specific template and render it for a meeting
+//! let template = templates::get_template("daily_standup")?.render(&context, &transcript);
 //!
-//! // Generate markdown structure
-//! let markdown = template.to_markdown_structure();
+//! // Compile the JSON Schema (and GBNF grammar) the summary must follow
+//! let schema = template.to_json_schema();
+//! let grammar = template.to_gbnf();
 //!
 //! // Generate LLM instructions
 //! let instructions = template.to_section_instructions();
@@ -39,14 +43,18 @@
 
 mod defaults;
 mod loader;
+mod render;
+mod schema;
 mod types;
 
 // Re-export public API
 pub use loader::{
-    get_template, list_template_ids, list_templates, set_bundled_templates_dir,
-    validate_and_parse_template,
+    export_template, get_template, import_template, import_template_id, list_template_ids,
+    list_templates, set_bundled_templates_dir, template_exists, validate_and_parse_template,
 };
-pub use types::{Template, TemplateSection};
+pub use render::{TemplateContext, TEMPLATE_VARIABLES};
+pub use schema::{SectionShape, TableColumn, TITLE_KEY};
+pub use types::{SectionCondition, Template, TemplateModelSettings, TemplateSection};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,214 @@
+//! Renders a template for one meeting
+//!
+//! `{{variable}}` placeholders are filled in from the meeting data and sections whose
+//! `condition` does not match the transcript are dropped, so the schema, the grammar and
+//! the instructions only cover what the meeting actually talked about.
+
+use super::schema::slugify;
+use crate::api::MeetingDetails;
+use super::types::{SectionCondition, Template};
+
+/// Variables available in template texts
+pub const TEMPLATE_VARIABLES: &[&str] = &["meeting_title", "meeting_date", "participants", "language"];
+
+/// Meeting data the template variables are filled in from
+#[derive(Debug, Clone, Default)]
+pub struct TemplateContext {
+    pub meeting_title: String,
+    /// Date of the meeting (YYYY-MM-DD)
+    pub meeting_date: String,
+    /// Speakers of the transcript, in order of appearance
+    pub participants: Vec<String>,
+    /// Language of the meeting (transcription language, e.g. "es")
+    pub language: String,
+}
+
+impl TemplateContext {
+    /// Context of a stored meeting; `language` is the transcription language ("es" if unset)
+    pub fn for_meeting(meeting: &MeetingDetails, language: Option<&str>) -> Self {
+        let mut participants: Vec<String> = Vec::new();
+        for source in meeting.transcripts.iter().filter_map(|t| t.source_type.as_deref()) {
+            let speaker = match source {
+                "user" => "Usuario",
+                "interlocutor" => "Interlocutor",
+                other => other,
+            };
+            if !participants.iter().any(|p| p == speaker) {
+                participants.push(speaker.to_string());
+            }
+        }
+
+        Self {
+            meeting_title: meeting.title.clone(),
+            meeting_date: meeting.created_at.get(..10).unwrap_or(&meeting.created_at).to_string(),
+            participants,
+            language: language
+                .filter(|language| !language.is_empty())
+                .unwrap_or("es")
+                .to_string(),
+        }
+    }
+
+    fn value(&self, name: &str) -> Option<String> {
+        match name {
+            "meeting_title" => Some(self.meeting_title.clone()),
+            "meeting_date" => Some(self.meeting_date.clone()),
+            "participants" => Some(self.participants.join(", ")),
+            "language" => Some(self.language.clone()),
+            _ => None,
+        }
+    }
+
+    /// Replaces every known `{{variable}}` in `text` (unknown ones are kept as written)
+    pub fn fill(&self, text: &str) -> String {
+        let mut filled = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start + 2..].find("}}") else {
+                break;
+            };
+            let name = rest[start + 2..start + 2 + end].trim();
+            filled.push_str(&rest[..start]);
+            match self.value(name) {
+                Some(value) => filled.push_str(&value),
+                None => filled.push_str(&rest[start..start + 2 + end + 2]),
+            }
+            rest = &rest[start + 2 + end + 2..];
+        }
+        filled.push_str(rest);
+        filled
+    }
+}
+
+/// Names of the `{{variable}}` placeholders used in `text`
+pub fn find_variables(text: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        names.push(rest[start + 2..start + 2 + end].trim());
+        rest = &rest[start + 2 + end + 2..];
+    }
+    names
+}
+
+impl SectionCondition {
+    /// Whether the transcript mentions the keywords often enough
+    ///
+    /// `transcript_slug` is the slugified transcript wrapped in `_` (see [`Template::render`]),
+    /// so keywords only match whole words regardless of case and accents.
+    fn matches(&self, transcript_slug: &str) -> bool {
+        let mentions: usize = self
+            .keywords
+            .iter()
+            .map(|keyword| slugify(keyword))
+            .filter(|keyword| !keyword.is_empty())
+            .map(|keyword| {
+                // Counted by hand so consecutive mentions sharing a `_` all count
+                let needle = format!("_{}", keyword);
+                transcript_slug
+                    .match_indices(&needle)
+                    .filter(|(i, _)| transcript_slug[i + needle.len()..].starts_with('_'))
+                    .count()
+            })
+            .sum();
+        mentions >= self.min_mentions
+    }
+}
+
+impl Template {
+    /// The template for one meeting: variables filled in and conditional sections
+    /// kept only when the transcript matches
+    pub fn render(&self, context: &TemplateContext, transcript: &str) -> Template {
+        let transcript_slug = format!("_{}_", slugify(transcript));
+
+        let sections = self
+            .sections
+            .iter()
+            .filter(|section| {
+                section
+                    .condition
+                    .as_ref()
+                    .map_or(true, |condition| condition.matches(&transcript_slug))
+            })
+            .map(|section| {
+                let mut section = section.clone();
+                section.title = context.fill(&section.title);
+                section.instruction = context.fill(&section.instruction);
+                section.condition = None;
+                section
+            })
+            .collect();
+
+        Template {
+            name: context.fill(&self.name),
+            description: context.fill(&self.description),
+            extends: None,
+            llm: self.llm.clone(),
+            sections,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::summary::templates::validate_and_parse_template;
+
+    const TEMPLATE: &str = r#"{
+        "name": "Llamada de ventas",
+        "description": "Reunión con {{participants}}",
+        "sections": [
+            {"title": "Resumen", "instruction": "Resume la reunión '{{meeting_title}}' del {{meeting_date}} en {{language}}", "format": "paragraph"},
+            {"title": "Precios", "instruction": "Lista los precios discutidos", "format": "list",
+             "condition": {"keywords": ["precio", "cotización"], "min_mentions": 2}}
+        ]
+    }"#;
+
+    fn context() -> TemplateContext {
+        TemplateContext {
+            meeting_title: "Demo Acme".to_string(),
+            meeting_date: "2026-03-02".to_string(),
+            participants: vec!["Usuario".to_string(), "Interlocutor".to_string()],
+            language: "es".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_fills_variables() {
+        let template = validate_and_parse_template(TEMPLATE).unwrap();
+        let rendered = template.render(&context(), "");
+
+        assert_eq!(rendered.description, "Reunión con Usuario, Interlocutor");
+        assert_eq!(
+            rendered.sections[0].instruction,
+            "Resume la reunión 'Demo Acme' del 2026-03-02 en es"
+        );
+        // Unknown placeholders are left as written
+        assert_eq!(context().fill("{{otro}} {{ language }}"), "{{otro}} es");
+    }
+
+    #[test]
+    fn test_conditional_sections() {
+        let template = validate_and_parse_template(TEMPLATE).unwrap();
+
+        // Whole words only, case and accents ignored
+        let rendered = template.render(&context(), "El PRECIO final y la cotizacion llegan mañana");
+        assert_eq!(rendered.sections.len(), 2);
+        assert!(rendered.sections.iter().all(|s| s.condition.is_none()));
+
+        let rendered = template.render(&context(), "Aprecio la cotización");
+        assert_eq!(rendered.sections.len(), 1);
+        assert_eq!(rendered.section_keys(), vec!["resumen"]);
+    }
+
+    #[test]
+    fn test_rejects_unknown_variables() {
+        let json = r#"{"name": "X", "description": "Para {{cliente}}", "sections": [
+            {"title": "Resumen", "instruction": "Resume", "format": "paragraph"}]}"#;
+        let error = validate_and_parse_template(json).unwrap_err();
+        assert!(error.contains("cliente"), "{}", error);
+    }
+}
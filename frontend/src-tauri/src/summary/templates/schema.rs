@@ -0,0 +1,323 @@
+//! Compiles a template into the structure the LLM must produce
+//!
+//! Every template maps to a JSON object with a `title` plus one property per section:
+//! `paragraph`/`string` sections are strings, `list` sections are arrays of strings, and
+//! `list` sections whose `item_format` is a markdown table are arrays of row objects (one
+//! string property per column). The same structure is emitted as a JSON Schema (OpenAI
+//! `response_format`, Ollama `format`) and as a GBNF grammar (BuiltInAI sampling).
+
+use super::types::{Template, TemplateSection};
+use crate::summary::llm_client::OutputSchema;
+use serde_json::{json, Map, Value};
+
+/// Property holding the generated meeting title
+pub const TITLE_KEY: &str = "title";
+
+/// How a section is represented in the structured output
+#[derive(Debug, Clone, PartialEq)]
+pub enum SectionShape {
+    /// A single string (`paragraph` and `string` formats)
+    Text,
+    /// An array of strings (`list` format)
+    List,
+    /// An array of row objects (`list` format with a markdown table `item_format`)
+    Table(Vec<TableColumn>),
+}
+
+/// Column of a table section
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableColumn {
+    /// Property name inside each row object
+    pub key: String,
+    /// Header as written in the template (without markdown emphasis)
+    pub title: String,
+}
+
+impl TemplateSection {
+    /// Structured representation of this section
+    pub fn shape(&self) -> SectionShape {
+        if self.format != "list" {
+            return SectionShape::Text;
+        }
+
+        let header = self
+            .item_format
+            .as_ref()
+            .or(self.example_item_format.as_ref())
+            .and_then(|format| format.lines().next())
+            .map(str::trim)
+            .filter(|line| line.starts_with('|'));
+
+        let Some(header) = header else {
+            return SectionShape::List;
+        };
+
+        let titles: Vec<String> = header
+            .split('|')
+            .map(|cell| cell.trim().trim_matches('*').trim().to_string())
+            .filter(|cell| !cell.is_empty())
+            .collect();
+        if titles.is_empty() {
+            return SectionShape::List;
+        }
+
+        let keys = unique_keys(titles.iter().map(String::as_str), &[]);
+        SectionShape::Table(
+            keys.into_iter()
+                .zip(titles)
+                .map(|(key, title)| TableColumn { key, title })
+                .collect(),
+        )
+    }
+}
+
+impl Template {
+    /// Property name of each section, in template order
+    ///
+    /// Derived from the section titles (lowercase ASCII, `_` separated) and made unique.
+    pub fn section_keys(&self) -> Vec<String> {
+        unique_keys(self.sections.iter().map(|s| s.title.as_str()), &[TITLE_KEY])
+    }
+
+    /// JSON Schema of the structured summary (strict: every property required, no extras)
+    pub fn to_json_schema(&self) -> Value {
+        let mut properties = Map::new();
+        properties.insert(
+            TITLE_KEY.to_string(),
+            json!({
+                "type": "string",
+                "description": "Título conciso y descriptivo de la reunión",
+            }),
+        );
+
+        for (key, section) in self.section_keys().into_iter().zip(&self.sections) {
+            let schema = match section.shape() {
+                SectionShape::Text => json!({
+                    "type": "string",
+                    "description": section.instruction,
+                }),
+                SectionShape::List => json!({
+                    "type": "array",
+                    "description": section.instruction,
+                    "items": { "type": "string" },
+                }),
+                SectionShape::Table(columns) => {
+                    let column_properties: Map<String, Value> = columns
+                        .iter()
+                        .map(|c| (c.key.clone(), json!({ "type": "string", "description": c.title })))
+                        .collect();
+                    let column_keys: Vec<&str> = columns.iter().map(|c| c.key.as_str()).collect();
+                    json!({
+                        "type": "array",
+                        "description": section.instruction,
+                        "items": {
+                            "type": "object",
+                            "properties": column_properties,
+                            "required": column_keys,
+                            "additionalProperties": false,
+                        },
+                    })
+                }
+            };
+            properties.insert(key, schema);
+        }
+
+        let required: Vec<String> = std::iter::once(TITLE_KEY.to_string())
+            .chain(self.section_keys())
+            .collect();
+
+        json!({
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        })
+    }
+
+    /// GBNF grammar accepting exactly the JSON documents described by [`Self::to_json_schema`]
+    ///
+    /// Properties are emitted in template order so the model writes the title first.
+    pub fn to_gbnf(&self) -> String {
+        let mut rules = Vec::new();
+        let mut root = format!("root ::= \"{{\" ws {}", gbnf_key(TITLE_KEY));
+        root.push_str(" string");
+
+        for (i, (key, section)) in self.section_keys().iter().zip(&self.sections).enumerate() {
+            let value_rule = match section.shape() {
+                SectionShape::Text => "string".to_string(),
+                SectionShape::List => "list".to_string(),
+                SectionShape::Table(columns) => {
+                    let row = columns
+                        .iter()
+                        .map(|c| format!("{} string", gbnf_key(&c.key)))
+                        .collect::<Vec<_>>()
+                        .join(" \",\" ws ");
+                    rules.push(format!("section-{}-row ::= \"{{\" ws {} \"}}\" ws", i, row));
+                    rules.push(format!(
+                        "section-{0} ::= \"[\" ws ( section-{0}-row ( \",\" ws section-{0}-row )* )? \"]\" ws",
+                        i
+                    ));
+                    format!("section-{}", i)
+                }
+            };
+            root.push_str(&format!(" \",\" ws {} {}", gbnf_key(key), value_rule));
+        }
+        root.push_str(" \"}\" ws");
+
+        let mut grammar = root;
+        for rule in rules {
+            grammar.push('\n');
+            grammar.push_str(&rule);
+        }
+        grammar.push_str(
+            "\nlist ::= \"[\" ws ( string ( \",\" ws string )* )? \"]\" ws\
+             \nstring ::= \"\\\"\" ( [^\"\\\\\\x7F\\x00-\\x1F] | \"\\\\\" ( [\"\\\\/bfnrt] | \"u\" [0-9a-fA-F] [0-9a-fA-F] [0-9a-fA-F] [0-9a-fA-F] ) )* \"\\\"\" ws\
+             \nws ::= ( [ \\t\\n] ws )?\n",
+        );
+        grammar
+    }
+
+    /// Schema and grammar passed to the LLM client for the final report
+    pub fn to_output_schema(&self) -> OutputSchema {
+        OutputSchema {
+            name: "meeting_summary".to_string(),
+            schema: self.to_json_schema(),
+            grammar: self.to_gbnf(),
+        }
+    }
+}
+
+/// `"key" ws ":" ws` in GBNF (keys are ASCII identifiers, so no escaping is needed)
+fn gbnf_key(key: &str) -> String {
+    format!("\"\\\"{}\\\"\" ws \":\" ws", key)
+}
+
+/// Turns titles into unique snake_case ASCII keys, avoiding `reserved`
+fn unique_keys<'a>(titles: impl Iterator<Item = &'a str>, reserved: &[&str]) -> Vec<String> {
+    let mut used: Vec<String> = reserved.iter().map(|k| k.to_string()).collect();
+    let mut keys = Vec::new();
+
+    for (i, title) in titles.enumerate() {
+        let base = slugify(title);
+        let base = if base.is_empty() { format!("section_{}", i + 1) } else { base };
+
+        let mut key = base.clone();
+        let mut suffix = 2;
+        while used.contains(&key) {
+            key = format!("{}_{}", base, suffix);
+            suffix += 1;
+        }
+        used.push(key.clone());
+        keys.push(key);
+    }
+
+    keys
+}
+
+/// Lowercase ASCII slug; Spanish accents are folded, anything else becomes `_`
+pub(super) fn slugify(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.to_lowercase().chars() {
+        let c = match c {
+            'á' | 'à' | 'ä' | 'â' => 'a',
+            'é' | 'è' | 'ë' | 'ê' => 'e',
+            'í' | 'ì' | 'ï' | 'î' => 'i',
+            'ó' | 'ò' | 'ö' | 'ô' => 'o',
+            'ú' | 'ù' | 'ü' | 'û' => 'u',
+            'ñ' => 'n',
+            'ç' => 'c',
+            c => c,
+        };
+        if c.is_ascii_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('_') {
+            slug.push('_');
+        }
+    }
+    slug.trim_end_matches('_').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section(title: &str, format: &str, item_format: Option<&str>) -> TemplateSection {
+        TemplateSection {
+            title: title.to_string(),
+            instruction: format!("Instrucción para {}", title),
+            format: format.to_string(),
+            item_format: item_format.map(str::to_string),
+            example_item_format: None,
+            condition: None,
+        }
+    }
+
+    fn template() -> Template {
+        Template {
+            name: "Test".to_string(),
+            description: "Test".to_string(),
+            extends: None,
+            llm: None,
+            sections: vec![
+                section("Resumen", "paragraph", None),
+                section("Decisiones Clave", "list", None),
+                section(
+                    "Tareas Pendientes",
+                    "list",
+                    Some("| **Responsable** | Tarea | Fecha |\n| --- | --- | --- |"),
+                ),
+                section("Resumen", "string", None),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_section_keys_are_unique_ascii() {
+        assert_eq!(
+            template().section_keys(),
+            vec!["resumen", "decisiones_clave", "tareas_pendientes", "resumen_2"]
+        );
+        assert_eq!(slugify("Próximos Pasos / Acción"), "proximos_pasos_accion");
+        assert_eq!(unique_keys(["Title"].into_iter(), &[TITLE_KEY]), vec!["title_2"]);
+    }
+
+    #[test]
+    fn test_table_columns_from_item_format() {
+        let shape = template().sections[2].shape();
+        let SectionShape::Table(columns) = shape else {
+            panic!("expected a table section");
+        };
+        let keys: Vec<&str> = columns.iter().map(|c| c.key.as_str()).collect();
+        assert_eq!(keys, vec!["responsable", "tarea", "fecha"]);
+        assert_eq!(columns[0].title, "Responsable");
+    }
+
+    #[test]
+    fn test_json_schema_is_strict() {
+        let schema = template().to_json_schema();
+
+        assert_eq!(schema["additionalProperties"], false);
+        assert_eq!(schema["required"].as_array().unwrap().len(), 5);
+        assert_eq!(schema["properties"]["resumen"]["type"], "string");
+        assert_eq!(schema["properties"]["decisiones_clave"]["items"]["type"], "string");
+        assert_eq!(
+            schema["properties"]["tareas_pendientes"]["items"]["required"],
+            json!(["responsable", "tarea", "fecha"])
+        );
+    }
+
+    #[test]
+    fn test_gbnf_follows_template_order() {
+        let grammar = template().to_gbnf();
+        let root = grammar.lines().next().unwrap();
+
+        assert!(root.starts_with("root ::= \"{\" ws \"\\\"title\\\"\" ws \":\" ws string"));
+        let resumen = root.find("\\\"resumen\\\"").unwrap();
+        let tareas = root.find("\\\"tareas_pendientes\\\"").unwrap();
+        assert!(resumen < tareas);
+        assert!(root.contains("\\\"tareas_pendientes\\\"\" ws \":\" ws section-2"));
+        assert!(grammar.contains("section-2-row ::= \"{\" ws \"\\\"responsable\\\"\""));
+        assert!(grammar.contains("\nstring ::= "));
+        assert!(grammar.contains("\nws ::= "));
+    }
+}
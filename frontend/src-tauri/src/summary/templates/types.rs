@@ -1,5 +1,43 @@
+use super::render::{find_variables, TEMPLATE_VARIABLES};
+use super::schema::SectionShape;
+use crate::summary::providers::SamplingParams;
 use serde::{Deserialize, Serialize};
 
+/// Includes a section only when the transcript talks about it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SectionCondition {
+    /// Words or phrases to look for (case- and accent-insensitive, whole words)
+    pub keywords: Vec<String>,
+
+    /// Mentions (of any keyword) needed to include the section
+    #[serde(default = "default_min_mentions")]
+    pub min_mentions: usize,
+}
+
+fn default_min_mentions() -> usize {
+    1
+}
+
+/// LLM parameters of a template; unset values keep the user's settings
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TemplateModelSettings {
+    /// Sampling temperature of the final report
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+
+    /// Completion limit of the final report
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// Provider used instead of the selected one when it is configured (needs `model`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<String>,
+
+    /// Model of the preferred provider
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+}
+
 /// Represents a single section in a meeting template
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TemplateSection {
@@ -19,22 +57,90 @@ pub struct TemplateSection {
     /// Alternative formatting hint
     #[serde(skip_serializing_if = "Option::is_none")]
     pub example_item_format: Option<String>,
+
+    /// Section is only included when the transcript matches
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub condition: Option<SectionCondition>,
 }
 
 /// Represents a complete meeting template
+///
+/// Text fields may use `{{variable}}` placeholders (see [`TEMPLATE_VARIABLES`]), filled in
+/// from the meeting when the template is rendered.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Template {
     /// Template display name
+    #[serde(default)]
     pub name: String,
 
     /// Brief description of the template's purpose
+    #[serde(default)]
     pub description: String,
 
+    /// Identifier of the template this one builds on (its sections and settings are inherited)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub extends: Option<String>,
+
+    /// LLM parameters for summaries made with this template
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llm: Option<TemplateModelSettings>,
+
     /// List of sections in the template
+    #[serde(default)]
     pub sections: Vec<TemplateSection>,
 }
 
 impl Template {
+    /// Applies this template over `parent` (the template it extends)
+    ///
+    /// Empty fields are inherited, LLM settings are merged field by field, and sections
+    /// replace the parent's section with the same title or are appended after them.
+    pub fn inherit(self, parent: Template) -> Template {
+        let llm = match (self.llm, parent.llm) {
+            (Some(child), Some(parent)) => {
+                // Provider and model go together
+                let (provider, model) = if child.provider.is_some() {
+                    (child.provider, child.model)
+                } else {
+                    (parent.provider, parent.model)
+                };
+                Some(TemplateModelSettings {
+                    temperature: child.temperature.or(parent.temperature),
+                    max_tokens: child.max_tokens.or(parent.max_tokens),
+                    provider,
+                    model,
+                })
+            }
+            (child, parent) => child.or(parent),
+        };
+
+        let mut sections = parent.sections;
+        for section in self.sections {
+            match sections.iter_mut().find(|s| s.title == section.title) {
+                Some(inherited) => *inherited = section,
+                None => sections.push(section),
+            }
+        }
+
+        Template {
+            name: if self.name.is_empty() { parent.name } else { self.name },
+            description: if self.description.is_empty() { parent.description } else { self.description },
+            extends: None,
+            llm,
+            sections,
+        }
+    }
+
+    /// Sampling overrides of the final report (unset values keep the provider's defaults)
+    pub fn sampling(&self) -> SamplingParams {
+        let llm = self.llm.clone().unwrap_or_default();
+        SamplingParams {
+            max_tokens: llm.max_tokens,
+            temperature: llm.temperature,
+            top_p: None,
+        }
+    }
+
     /// Validates the template structure
     pub fn validate(&self) -> Result<(), String> {
         if self.name.is_empty() {
@@ -65,43 +171,82 @@ impl Template {
                     section.title, other
                 )),
             }
+
+            if let Some(condition) = &section.condition {
+                if condition.keywords.iter().all(|k| k.trim().is_empty()) {
+                    return Err(format!("Section '{}' has a condition without keywords", section.title));
+                }
+                if condition.min_mentions == 0 {
+                    return Err(format!("Section '{}' condition needs min_mentions of at least 1", section.title));
+                }
+            }
         }
 
-        Ok(())
-    }
+        if self.sections.iter().all(|section| section.condition.is_some()) {
+            return Err("Template must have at least one section without a condition".to_string());
+        }
 
-    /// Generates a clean markdown template structure
-    pub fn to_markdown_structure(&self) -> String {
-        let mut markdown = String::from("# <Add Title here>\n\n");
+        // Placeholders must name a known variable
+        let texts = [&self.name, &self.description]
+            .into_iter()
+            .chain(self.sections.iter().flat_map(|s| [&s.title, &s.instruction]));
+        for text in texts {
+            if let Some(unknown) = find_variables(text)
+                .into_iter()
+                .find(|name| !TEMPLATE_VARIABLES.contains(name))
+            {
+                return Err(format!(
+                    "Unknown variable '{{{{{}}}}}'. Available variables: {}",
+                    unknown,
+                    TEMPLATE_VARIABLES.join(", ")
+                ));
+            }
+        }
 
-        for section in &self.sections {
-            markdown.push_str(&format!("**{}**\n\n", section.title));
+        if let Some(llm) = &self.llm {
+            if llm.temperature.is_some_and(|t| !(0.0..=2.0).contains(&t)) {
+                return Err("Template temperature must be between 0 and 2".to_string());
+            }
+            if llm.max_tokens == Some(0) {
+                return Err("Template max_tokens must be greater than 0".to_string());
+            }
+            if llm.provider.is_some() != llm.model.is_some() {
+                return Err("Template provider and model must be set together".to_string());
+            }
         }
 
-        markdown
+        Ok(())
     }
 
-    /// Generates section-specific instructions for the LLM
+    /// Generates field-specific instructions for the LLM (one per JSON property)
     pub fn to_section_instructions(&self) -> String {
-        let mut instructions = String::from(
-            "- **Para el título principal (`# [Título Generado]`):** Analiza toda la transcripción y crea un título conciso y descriptivo para la reunión.\n"
+        let mut instructions = format!(
+            "- **Para el campo `{}`:** Analiza toda la transcripción y crea un título conciso y descriptivo para la reunión.\n",
+            super::schema::TITLE_KEY
         );
 
-        for section in &self.sections {
+        for (key, section) in self.section_keys().iter().zip(&self.sections) {
             instructions.push_str(&format!(
-                "- **Para la sección '{}':** {}.\n",
-                section.title, section.instruction
+                "- **Para el campo `{}` (sección '{}'):** {}.\n",
+                key, section.title, section.instruction
             ));
 
-            // Add item format instructions if present
-            let item_format = section.item_format.as_ref()
-                .or(section.example_item_format.as_ref());
-
-            if let Some(format) = item_format {
-                instructions.push_str(&format!(
-                    "  - Los elementos de esta sección deben seguir el formato: `{}`.\n",
-                    format
-                ));
+            match section.shape() {
+                SectionShape::Text => {}
+                SectionShape::List => {
+                    instructions.push_str("  - Cada elemento de la lista es un texto breve e independiente.\n");
+                }
+                SectionShape::Table(columns) => {
+                    let columns = columns
+                        .iter()
+                        .map(|c| format!("`{}` ({})", c.key, c.title))
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    instructions.push_str(&format!(
+                        "  - Cada elemento es una fila con los campos: {}.\n",
+                        columns
+                    ));
+                }
             }
         }
 
@@ -118,6 +263,8 @@ mod tests {
         let template = Template {
             name: "Test Template".to_string(),
             description: "A test template".to_string(),
+            extends: None,
+            llm: None,
             sections: vec![
                 TemplateSection {
                     title: "Summary".to_string(),
@@ -125,6 +272,7 @@ mod tests {
                     format: "paragraph".to_string(),
                     item_format: None,
                     example_item_format: None,
+                    condition: None,
                 },
             ],
         };
@@ -137,6 +285,8 @@ mod tests {
         let template = Template {
             name: "".to_string(),
             description: "A test template".to_string(),
+            extends: None,
+            llm: None,
             sections: vec![],
         };
 
@@ -148,6 +298,8 @@ mod tests {
         let template = Template {
             name: "Test".to_string(),
             description: "Test".to_string(),
+            extends: None,
+            llm: None,
             sections: vec![
                 TemplateSection {
                     title: "Test".to_string(),
@@ -155,6 +307,7 @@ mod tests {
                     format: "invalid".to_string(),
                     item_format: None,
                     example_item_format: None,
+                    condition: None,
                 },
             ],
         };
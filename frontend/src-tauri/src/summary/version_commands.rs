@@ -0,0 +1,104 @@
+use crate::database::models::SummaryVersion;
+use crate::database::repositories::summary::SummaryProcessesRepository;
+use crate::database::repositories::summary_version::SummaryVersionsRepository;
+use crate::state::AppState;
+use crate::summary::versions::{diff_summaries, SectionDiff};
+use log::{error as log_error, info as log_info};
+use serde_json::Value;
+use sqlx::SqlitePool;
+use tauri::{AppHandle, Runtime};
+
+/// Lists every version of a meeting's summary, newest first
+#[tauri::command]
+pub async fn api_list_summary_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+) -> Result<Vec<SummaryVersion>, String> {
+    log_info!("api_list_summary_versions called for meeting_id: {}", meeting_id);
+
+    SummaryVersionsRepository::list(state.db_manager.pool(), &meeting_id)
+        .await
+        .map_err(|e| {
+            log_error!("Error listing summary versions for {}: {}", meeting_id, e);
+            format!("Failed to list summary versions: {}", e)
+        })
+}
+
+/// Compares two versions of a meeting's summary section by section
+#[tauri::command]
+pub async fn api_diff_summary_versions<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    from_version: i64,
+    to_version: i64,
+) -> Result<Vec<SectionDiff>, String> {
+    log_info!(
+        "api_diff_summary_versions called for meeting_id: {} ({} -> {})",
+        meeting_id,
+        from_version,
+        to_version
+    );
+    let pool = state.db_manager.pool();
+
+    let old = load_version_result(pool, &meeting_id, from_version).await?;
+    let new = load_version_result(pool, &meeting_id, to_version).await?;
+    Ok(diff_summaries(&old, &new))
+}
+
+/// Makes an earlier version the current summary
+///
+/// The restored summary is added as a new version. Returns that version.
+#[tauri::command]
+pub async fn api_restore_summary_version<R: Runtime>(
+    _app: AppHandle<R>,
+    state: tauri::State<'_, AppState>,
+    meeting_id: String,
+    version: i64,
+) -> Result<SummaryVersion, String> {
+    log_info!(
+        "api_restore_summary_version called for meeting_id: {} (version {})",
+        meeting_id,
+        version
+    );
+    let pool = state.db_manager.pool();
+
+    // A running generation would overwrite the restored summary when it finishes
+    if let Ok(Some(process)) = SummaryProcessesRepository::get_summary_data(pool, &meeting_id).await {
+        if matches!(process.status.to_lowercase().as_str(), "pending" | "processing") {
+            return Err("Cannot restore a version while a summary is being generated".to_string());
+        }
+    }
+
+    match SummaryVersionsRepository::restore(pool, &meeting_id, version).await {
+        Ok(Some(restored)) => {
+            log_info!(
+                "Restored version {} of meeting {} as version {}",
+                version,
+                meeting_id,
+                restored.version
+            );
+            Ok(restored)
+        }
+        Ok(None) => Err(format!("Summary version {} not found", version)),
+        Err(e) => {
+            log_error!("Failed to restore version {} of {}: {}", version, meeting_id, e);
+            Err(format!("Failed to restore summary version: {}", e))
+        }
+    }
+}
+
+async fn load_version_result(
+    pool: &SqlitePool,
+    meeting_id: &str,
+    version: i64,
+) -> Result<Value, String> {
+    let stored = SummaryVersionsRepository::get(pool, meeting_id, version)
+        .await
+        .map_err(|e| format!("Failed to load summary version {}: {}", version, e))?
+        .ok_or_else(|| format!("Summary version {} not found", version))?;
+
+    serde_json::from_str(&stored.result)
+        .map_err(|e| format!("Summary version {} is not valid JSON: {}", version, e))
+}
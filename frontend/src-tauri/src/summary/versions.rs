@@ -0,0 +1,285 @@
+// Summary version history: prompt hashes and section-by-section diffs
+//
+// Versions are stored in `summary_versions` (see `SummaryVersionsRepository`). Generated
+// and edited summaries share the stored `markdown`, so diffs split it into sections at its
+// section titles (bold lines or headings) and compare them by title.
+
+use crate::summary::structured::StructuredSummary;
+use crate::summary::templates::Template;
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// SHA-256 of what the summary was asked for: the rendered template and the custom prompt
+pub fn prompt_hash(template: &Template, custom_prompt: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(serde_json::to_string(template).unwrap_or_default());
+    hasher.update([0]);
+    hasher.update(custom_prompt.trim());
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
+
+/// One section of a stored summary
+#[derive(Debug, Clone, PartialEq)]
+pub struct VersionSection {
+    pub title: String,
+    pub content: String,
+}
+
+/// Sections of a stored summary result (`summary_processes.result` format)
+pub fn summary_sections(result: &Value) -> Vec<VersionSection> {
+    if let Some(markdown) = result.get("markdown").and_then(Value::as_str) {
+        return markdown_sections(markdown);
+    }
+    if let Some(structured) = result
+        .get("structured")
+        .and_then(|structured| serde_json::from_value::<StructuredSummary>(structured.clone()).ok())
+    {
+        return markdown_sections(&structured.body_markdown());
+    }
+
+    // Older formats: the whole result as a single section
+    vec![VersionSection {
+        title: "Resumen".to_string(),
+        content: serde_json::to_string_pretty(result).unwrap_or_default(),
+    }]
+}
+
+/// Title of a line that starts a section: a heading or a line that is entirely bold
+fn section_title(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if line.starts_with('#') {
+        let title = line.trim_start_matches('#').trim();
+        return (!title.is_empty()).then_some(title);
+    }
+    let title = line.strip_prefix("**")?.strip_suffix("**")?.trim();
+    (!title.is_empty() && !title.contains("**")).then_some(title)
+}
+
+/// Splits markdown at its section titles (text before the first one gets an empty title)
+pub(crate) fn markdown_sections(markdown: &str) -> Vec<VersionSection> {
+    let mut sections: Vec<VersionSection> = Vec::new();
+    let mut intro = Vec::new();
+
+    for line in markdown.lines() {
+        if let Some(title) = section_title(line) {
+            sections.push(VersionSection {
+                title: title.to_string(),
+                content: String::new(),
+            });
+            continue;
+        }
+        match sections.last_mut() {
+            Some(section) => {
+                section.content.push_str(line);
+                section.content.push('\n');
+            }
+            None => intro.push(line),
+        }
+    }
+
+    for section in &mut sections {
+        section.content = section.content.trim().to_string();
+    }
+    let intro = intro.join("\n").trim().to_string();
+    if !intro.is_empty() {
+        sections.insert(
+            0,
+            VersionSection {
+                title: String::new(),
+                content: intro,
+            },
+        );
+    }
+    sections
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SectionChange {
+    Added,
+    Removed,
+    Changed,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LineChange {
+    Added,
+    Removed,
+    Unchanged,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DiffLine {
+    pub change: LineChange,
+    pub text: String,
+}
+
+/// How one section differs between two versions
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SectionDiff {
+    /// Section title (empty for text before the first section)
+    pub title: String,
+    pub change: SectionChange,
+    pub old_content: Option<String>,
+    pub new_content: Option<String>,
+    /// Line diff of the content (empty when the section is unchanged)
+    pub lines: Vec<DiffLine>,
+}
+
+/// Compares two summaries section by section
+///
+/// Sections are matched by title (repeated titles in order of appearance) and listed in
+/// the order of the new summary, followed by the sections it no longer has.
+pub fn diff_summaries(old: &Value, new: &Value) -> Vec<SectionDiff> {
+    let mut old_sections: Vec<Option<VersionSection>> =
+        summary_sections(old).into_iter().map(Some).collect();
+    let mut diffs = Vec::new();
+
+    for new_section in summary_sections(new) {
+        let matched = old_sections
+            .iter_mut()
+            .find(|old| old.as_ref().is_some_and(|old| old.title == new_section.title))
+            .and_then(Option::take);
+
+        let diff = match matched {
+            Some(old_section) if old_section.content == new_section.content => SectionDiff {
+                title: new_section.title,
+                change: SectionChange::Unchanged,
+                old_content: Some(old_section.content),
+                new_content: Some(new_section.content),
+                lines: Vec::new(),
+            },
+            Some(old_section) => SectionDiff {
+                lines: diff_lines(&old_section.content, &new_section.content),
+                title: new_section.title,
+                change: SectionChange::Changed,
+                old_content: Some(old_section.content),
+                new_content: Some(new_section.content),
+            },
+            None => SectionDiff {
+                lines: diff_lines("", &new_section.content),
+                title: new_section.title,
+                change: SectionChange::Added,
+                old_content: None,
+                new_content: Some(new_section.content),
+            },
+        };
+        diffs.push(diff);
+    }
+
+    diffs.extend(old_sections.into_iter().flatten().map(|old_section| SectionDiff {
+        lines: diff_lines(&old_section.content, ""),
+        title: old_section.title,
+        change: SectionChange::Removed,
+        old_content: Some(old_section.content),
+        new_content: None,
+    }));
+    diffs
+}
+
+/// Line diff from the longest common subsequence of lines
+fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j]: common lines of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let line = |change, text: &str| DiffLine {
+        change,
+        text: text.to_string(),
+    };
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(line(LineChange::Unchanged, old[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(line(LineChange::Removed, old[i]));
+            i += 1;
+        } else {
+            lines.push(line(LineChange::Added, new[j]));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|text| line(LineChange::Removed, text)));
+    lines.extend(new[j..].iter().map(|text| line(LineChange::Added, text)));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_sections_from_markdown() {
+        let result = json!({
+            "markdown": "**Resumen**\n\nSe revisó el plan.\n\n## Decisiones Clave\n- Lanzar en marzo\n- Contratar QA",
+        });
+        let sections = summary_sections(&result);
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(sections[0].title, "Resumen");
+        assert_eq!(sections[0].content, "Se revisó el plan.");
+        assert_eq!(sections[1].title, "Decisiones Clave");
+        assert_eq!(sections[1].content, "- Lanzar en marzo\n- Contratar QA");
+
+        // Bold text inside a line does not start a section
+        assert!(section_title("**Ana** y **Luis** revisan").is_none());
+    }
+
+    #[test]
+    fn test_diff_by_section() {
+        let old = json!({"markdown": "**Resumen**\nPlan A\n\n**Riesgos**\n- Retraso\n\n**Notas**\nNada"});
+        let new = json!({"markdown": "**Resumen**\nPlan A\n\n**Riesgos**\n- Retraso\n- Costos\n\n**Próximos Pasos**\n- Llamar"});
+        let diff = diff_summaries(&old, &new);
+
+        let changes: Vec<(&str, SectionChange)> =
+            diff.iter().map(|d| (d.title.as_str(), d.change)).collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("Resumen", SectionChange::Unchanged),
+                ("Riesgos", SectionChange::Changed),
+                ("Próximos Pasos", SectionChange::Added),
+                ("Notas", SectionChange::Removed),
+            ]
+        );
+        assert_eq!(
+            diff[1].lines,
+            vec![
+                DiffLine { change: LineChange::Unchanged, text: "- Retraso".to_string() },
+                DiffLine { change: LineChange::Added, text: "- Costos".to_string() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_prompt_hash_is_stable() {
+        let template = crate::summary::templates::get_template("standard_meeting").unwrap();
+        let hash = prompt_hash(&template, "Enfócate en ventas");
+
+        assert_eq!(hash.len(), 64);
+        assert_eq!(hash, prompt_hash(&template, " Enfócate en ventas\n"));
+        assert_ne!(hash, prompt_hash(&template, ""));
+    }
+}
@@ -19,7 +19,7 @@ pub enum RecordingState {
 pub fn create_tray<R: Runtime>(app: &AppHandle<R>) -> tauri::Result<()> {
     // Start with default menu, will update with actual state after initialization
     // Pass can_record=true initially, will be updated by update_tray_menu immediately
-    let menu = build_menu(app, RecordingState::Stopped, true)?;
+    let menu = build_menu(app, RecordingState::Stopped, true, false)?;
 
     TrayIconBuilder::with_id("main-tray")
         .menu(&menu)
@@ -40,6 +40,10 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         "pause_recording" => pause_recording_handler(app),
         "resume_recording" => resume_recording_handler(app),
         "stop_recording" => stop_recording_handler(app),
+        "continue_after_auto_rule" => continue_after_auto_rule_handler(app),
+        "bookmark_important" => bookmark_handler(app, "important"),
+        "bookmark_action_item" => bookmark_handler(app, "action item"),
+        "bookmark_follow_up" => bookmark_handler(app, "follow up"),
         "open_window" => focus_main_window(app),
         "settings" => {
             focus_main_window(app);
@@ -52,7 +56,7 @@ fn handle_menu_event<R: Runtime>(app: &AppHandle<R>, item_id: &str) {
         _ => {}
     }
 }
-fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+pub(crate) fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     focus_main_window(app);
     let app_clone = app.clone();
     tauri::async_runtime::spawn(async move {
@@ -114,7 +118,7 @@ fn toggle_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
-fn pause_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+pub(crate) fn pause_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     // Immediately show pausing state
     set_tray_state(app, RecordingState::Pausing);
 
@@ -131,7 +135,7 @@ fn pause_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
-fn resume_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+pub(crate) fn resume_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     // Immediately show resuming state
     set_tray_state(app, RecordingState::Resuming);
 
@@ -149,7 +153,31 @@ fn resume_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     });
 }
 
-fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
+fn continue_after_auto_rule_handler<R: Runtime>(app: &AppHandle<R>) {
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match crate::audio::auto_rules::continue_recording_after_auto_rule(app_clone.clone()).await {
+            Ok(Some(rule)) => log::info!("Tray: Auto rule {:?} cancelled from tray", rule),
+            Ok(None) => {
+                log::info!("Tray: No auto rule pending");
+                update_tray_menu(&app_clone);
+            }
+            Err(e) => log::error!("Tray: Failed to cancel auto rule: {}", e),
+        }
+    });
+}
+
+pub(crate) fn bookmark_handler<R: Runtime>(app: &AppHandle<R>, label: &'static str) {
+    let app_clone = app.clone();
+    tauri::async_runtime::spawn(async move {
+        match crate::audio::recording_commands::add_recording_bookmark(app_clone, label.to_string(), None).await {
+            Ok(bookmark) => log::info!("Bookmark '{}' added from tray at {:.1}s", label, bookmark.timestamp),
+            Err(e) => log::error!("Failed to add bookmark from tray: {}", e),
+        }
+    });
+}
+
+pub(crate) fn stop_recording_handler<R: Runtime>(app: &AppHandle<R>) {
     // Immediately show stopping state
     set_tray_state(app, RecordingState::Stopping);
 
@@ -222,7 +250,7 @@ pub fn update_tray_menu<R: Runtime>(app: &AppHandle<R>) {
 pub fn set_tray_state<R: Runtime>(app: &AppHandle<R>, state: RecordingState) {
     log::info!("Tray: Setting intermediate state: {:?}", state);
     // During recording state transitions, we assume recording is allowed (we're already recording)
-    if let Ok(menu) = build_menu(app, state, true) {
+    if let Ok(menu) = build_menu(app, state, true, false) {
         if let Some(tray) = app.tray_by_id("main-tray") {
             let result = tray.set_menu(Some(menu));
             log::info!("Tray: Intermediate state menu update result: {:?}", result);
@@ -301,7 +329,9 @@ pub async fn update_tray_menu_async<R: Runtime>(app: &AppHandle<R>) {
     let can_record = check_can_record(app).await;
     log::info!("Tray: can_record: {}", can_record);
 
-    if let Ok(menu) = build_menu(app, recording_state, can_record) {
+    let auto_rule_pending = crate::audio::auto_rules::pending_auto_rule().is_some();
+
+    if let Ok(menu) = build_menu(app, recording_state, can_record, auto_rule_pending) {
         if let Some(tray) = app.tray_by_id("main-tray") {
             let result = tray.set_menu(Some(menu));
             log::info!("Tray: Menu update result: {:?}", result);
@@ -317,6 +347,7 @@ fn build_menu<R: Runtime>(
     app: &AppHandle<R>,
     state: RecordingState,
     can_record: bool, // True if recording is allowed (onboarding complete OR transcription model ready)
+    auto_rule_pending: bool, // True while an auto-stop / auto-pause rule waits out its grace period
 ) -> tauri::Result<tauri::menu::Menu<R>> {
     let mut builder = MenuBuilder::new(app);
 
@@ -341,9 +372,18 @@ fn build_menu<R: Runtime>(
                 );
             }
             RecordingState::Recording => {
+                if auto_rule_pending {
+                    builder = builder.item(
+                        &MenuItemBuilder::with_id("continue_after_auto_rule", "⏲ Keep Recording").build(app)?,
+                    );
+                }
                 builder = builder
                     .item(&MenuItemBuilder::with_id("pause_recording", "⏸ Pause Recording").build(app)?)
-                    .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?);
+                    .item(&MenuItemBuilder::with_id("stop_recording", "⏹ Stop Recording").build(app)?)
+                    .item(&PredefinedMenuItem::separator(app)?)
+                    .item(&MenuItemBuilder::with_id("bookmark_important", "🔖 Mark as Important").build(app)?)
+                    .item(&MenuItemBuilder::with_id("bookmark_action_item", "🔖 Mark Action Item").build(app)?)
+                    .item(&MenuItemBuilder::with_id("bookmark_follow_up", "🔖 Mark Follow Up").build(app)?);
             }
             RecordingState::Pausing => {
                 builder = builder
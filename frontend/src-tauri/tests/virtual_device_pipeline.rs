@@ -0,0 +1,256 @@
+// End-to-end pipeline tests driven by file-backed virtual devices.
+//
+// Audio files are streamed through AudioStreamManager -> AudioCapture ->
+// AudioPipelineManager (VAD, echo suppression, stereo mixing) -> transcription
+// worker, with a scripted TranscriptionProvider in place of a real model.
+
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use app_lib::audio::device_detection::InputDeviceKind;
+use app_lib::audio::devices::{AudioDevice, DeviceType};
+use app_lib::audio::native_encoder::write_wav;
+use app_lib::audio::transcription::{
+    start_transcription_task_with_engine, TranscriptResult, TranscriptUpdate, TranscriptionEngine,
+    TranscriptionError, TranscriptionProvider,
+};
+use app_lib::audio::vad::{VadEngineKind, VadSettings};
+use app_lib::audio::{AudioChunk, AudioPipelineManager, AudioStreamManager, RecordingState};
+use async_trait::async_trait;
+use tauri::Listener;
+use tempfile::TempDir;
+use tokio::sync::mpsc;
+
+const SAMPLE_RATE: u32 = 48000;
+const PLAYBACK_SPEED: f32 = 4.0;
+
+/// Returns a fixed text per call and remembers how much audio it was given
+#[derive(Default)]
+struct ScriptedProvider {
+    calls: Mutex<Vec<usize>>,
+}
+
+#[async_trait]
+impl TranscriptionProvider for ScriptedProvider {
+    async fn transcribe(
+        &self,
+        audio: Vec<f32>,
+        _language: Option<String>,
+    ) -> Result<TranscriptResult, TranscriptionError> {
+        let mut calls = self.calls.lock().unwrap();
+        calls.push(audio.len());
+        Ok(TranscriptResult {
+            text: format!("segment {}", calls.len()),
+            confidence: Some(0.9),
+            is_partial: false,
+        })
+    }
+
+    async fn is_model_loaded(&self) -> bool {
+        true
+    }
+
+    async fn get_current_model(&self) -> Option<String> {
+        Some("scripted".to_string())
+    }
+
+    fn provider_name(&self) -> &'static str {
+        "Scripted (test)"
+    }
+}
+
+struct SessionOutput {
+    updates: Vec<TranscriptUpdate>,
+    /// Interleaved stereo recording (L = mic, R = system)
+    recording: Vec<f32>,
+    provider_calls: usize,
+}
+
+fn write_signal(dir: &TempDir, name: &str, seconds: f32, signal: impl Fn(f32) -> f32) -> PathBuf {
+    let frames = (seconds * SAMPLE_RATE as f32) as usize;
+    let samples: Vec<f32> = (0..frames).map(|i| signal(i as f32 / SAMPLE_RATE as f32)).collect();
+    let path = dir.path().join(name);
+    write_wav(&path, &samples, SAMPLE_RATE, 1).unwrap();
+    path
+}
+
+/// Voiced, speech-like sound: a 140 Hz harmonic series with a 4 Hz syllable envelope
+fn voiced(t: f32) -> f32 {
+    let envelope = 0.6 + 0.4 * (2.0 * std::f32::consts::PI * 4.0 * t).sin();
+    let voice: f32 = (1..=8)
+        .map(|harmonic| (2.0 * std::f32::consts::PI * 140.0 * harmonic as f32 * t).sin() / harmonic as f32)
+        .sum();
+    0.1 * envelope * voice
+}
+
+fn channel_rms(stereo: &[f32], channel: usize) -> f32 {
+    let values: Vec<f32> = stereo.chunks_exact(2).map(|f| f[channel]).collect();
+    if values.is_empty() {
+        return 0.0;
+    }
+    (values.iter().map(|v| v * v).sum::<f32>() / values.len() as f32).sqrt()
+}
+
+/// Play `mic` and `system` through the full pipeline and collect its outputs
+///
+/// VAD settings are passed to the pipeline rather than set globally, so tests running
+/// in parallel do not affect each other.
+async fn run_virtual_session(mic: &Path, system: &Path, vad_settings: VadSettings) -> SessionOutput {
+    let state = RecordingState::new();
+    state.start_recording().unwrap();
+
+    let app = tauri::test::mock_app();
+    let updates = Arc::new(Mutex::new(Vec::<TranscriptUpdate>::new()));
+    let updates_sink = updates.clone();
+    app.listen_any("transcript-update", move |event| {
+        if let Ok(update) = serde_json::from_str::<TranscriptUpdate>(event.payload()) {
+            updates_sink.lock().unwrap().push(update);
+        }
+    });
+
+    let (transcription_sender, transcription_receiver) = mpsc::unbounded_channel::<AudioChunk>();
+    let (recording_sender, mut recording_receiver) = mpsc::unbounded_channel::<AudioChunk>();
+
+    let provider = Arc::new(ScriptedProvider::default());
+    let worker = start_transcription_task_with_engine(
+        app.handle().clone(),
+        transcription_receiver,
+        TranscriptionEngine::Provider(provider.clone()),
+    );
+
+    let mut pipeline = AudioPipelineManager::new().with_vad_settings(vad_settings);
+    pipeline
+        .start(
+            state.clone(),
+            transcription_sender,
+            0,
+            SAMPLE_RATE,
+            Some(recording_sender),
+            "virtual mic".to_string(),
+            InputDeviceKind::Wired,
+            "virtual system".to_string(),
+            InputDeviceKind::Wired,
+        )
+        .unwrap();
+
+    let mut streams = AudioStreamManager::new(state.clone());
+    streams
+        .start_streams(
+            Some(Arc::new(AudioDevice::virtual_file(mic, DeviceType::Input, PLAYBACK_SPEED))),
+            Some(Arc::new(AudioDevice::virtual_file(system, DeviceType::Output, PLAYBACK_SPEED))),
+            None,
+        )
+        .await
+        .unwrap();
+
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while !streams.all_streams_finished() {
+        assert!(Instant::now() < deadline, "virtual devices did not finish in time");
+        tokio::time::sleep(Duration::from_millis(20)).await;
+    }
+
+    streams.stop_streams().unwrap();
+    pipeline.force_flush_and_stop().await.unwrap();
+    state.stop_recording();
+    tokio::time::timeout(Duration::from_secs(30), worker)
+        .await
+        .expect("transcription worker did not finish")
+        .unwrap();
+
+    let mut recording = Vec::new();
+    while let Ok(chunk) = recording_receiver.try_recv() {
+        recording.extend(chunk.data);
+    }
+
+    let provider_calls = provider.calls.lock().unwrap().len();
+    let updates = updates.lock().unwrap().clone();
+    SessionOutput { updates, recording, provider_calls }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn silence_is_recorded_but_never_transcribed() {
+    let dir = TempDir::new().unwrap();
+    let mic = write_signal(&dir, "mic.wav", 3.0, |_| 0.0);
+    let system = write_signal(&dir, "system.wav", 3.0, |_| 0.0);
+
+    let output = run_virtual_session(&mic, &system, VadSettings::default()).await;
+
+    assert!(output.updates.is_empty(), "silence produced transcripts: {:?}", output.updates);
+    assert_eq!(output.provider_calls, 0);
+
+    // The stereo recording covers (almost) the whole file
+    let frames = output.recording.len() / 2;
+    let expected = 3 * SAMPLE_RATE as usize;
+    assert!(frames as f64 >= expected as f64 * 0.9, "recorded {} of {} frames", frames, expected);
+    assert!(frames <= expected + SAMPLE_RATE as usize / 10);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn mic_and_system_are_mixed_into_separate_channels() {
+    let dir = TempDir::new().unwrap();
+    let mic = write_signal(&dir, "mic.wav", 2.0, |t| 0.3 * (2.0 * std::f32::consts::PI * 440.0 * t).sin());
+    let system = write_signal(&dir, "system.wav", 2.0, |_| 0.0);
+
+    let output = run_virtual_session(&mic, &system, VadSettings::default()).await;
+
+    let left = channel_rms(&output.recording, 0);
+    let right = channel_rms(&output.recording, 1);
+    assert!(left > 0.01, "mic tone missing from the left channel (rms {})", left);
+    assert!(right < left / 10.0, "mic tone leaked into the right channel ({} vs {})", right, left);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn speech_fixture_produces_timed_user_transcripts() {
+    // Two utterances separated by silence, at known positions (seconds)
+    const UTTERANCES: [(f32, f32); 2] = [(1.0, 2.2), (4.5, 5.5)];
+    const DURATION: f32 = 7.0;
+
+    let dir = TempDir::new().unwrap();
+    let mic = write_signal(&dir, "speech.wav", DURATION, |t| {
+        if UTTERANCES.iter().any(|&(start, end)| t >= start && t < end) {
+            voiced(t)
+        } else {
+            0.0
+        }
+    });
+    let system = write_signal(&dir, "system.wav", DURATION, |_| 0.0);
+
+    // The energy engine segments synthetic speech deterministically
+    let vad_settings = VadSettings { engine: VadEngineKind::Energy, ..Default::default() };
+    let output = run_virtual_session(&mic, &system, vad_settings).await;
+
+    assert_eq!(
+        output.updates.len(),
+        UTTERANCES.len(),
+        "one transcript per utterance: {:?}",
+        output.updates
+    );
+    assert_eq!(output.provider_calls, UTTERANCES.len());
+
+    let mut updates = output.updates;
+    updates.sort_by(|a, b| a.audio_start_time.total_cmp(&b.audio_start_time));
+
+    let mut texts: Vec<&str> = updates.iter().map(|update| update.text.as_str()).collect();
+    texts.sort();
+    assert_eq!(texts, vec!["segment 1", "segment 2"]);
+
+    for (update, &(start, end)) in updates.iter().zip(&UTTERANCES) {
+        assert_eq!(update.source_type.as_deref(), Some("user"));
+        assert!(!update.is_partial);
+        // Segments include up to 150 ms of audio before the speech and 400 ms after it
+        let (start, end) = (start as f64, end as f64);
+        assert!(
+            update.audio_start_time >= start - 0.3 && update.audio_start_time <= start + 0.1,
+            "segment should start near {}s: {:?}",
+            start,
+            update
+        );
+        assert!(
+            update.audio_end_time >= end && update.audio_end_time <= end + 0.6,
+            "segment should end near {}s: {:?}",
+            end,
+            update
+        );
+    }
+}
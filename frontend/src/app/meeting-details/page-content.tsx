@@ -197,12 +197,15 @@ export default function PageContent({
           onSaveSummary={meetingData.handleSaveSummary}
           onSummaryChange={meetingData.handleSummaryChange}
           onDirtyChange={meetingData.setIsSummaryDirty}
+          onSummaryRestored={meetingData.setAiSummary}
           summaryError={summaryGeneration.summaryError}
           onRegenerateSummary={summaryGeneration.handleRegenerateSummary}
           getSummaryStatusMessage={summaryGeneration.getSummaryStatusMessage}
           availableTemplates={templates.availableTemplates}
           selectedTemplate={templates.selectedTemplate}
           onTemplateSelect={templates.handleTemplateSelection}
+          onImportTemplate={templates.handleImportTemplate}
+          onExportTemplate={templates.handleExportTemplate}
           isModelConfigLoading={false}
           onOpenModelSettings={handleRegisterModalOpen}
         />
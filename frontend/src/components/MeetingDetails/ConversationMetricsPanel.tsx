@@ -0,0 +1,108 @@
+'use client';
+
+import { useEffect, useState } from 'react';
+import { invoke } from '@tauri-apps/api/core';
+import { BarChart3 } from 'lucide-react';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
+import { Progress } from '@/components/ui/progress';
+import { ConversationMetrics } from '@/types/communication';
+
+interface ConversationMetricsPanelProps {
+  meetingId: string;
+  /** Metrics are reloaded when the summary status changes (they are recomputed with it) */
+  summaryStatus: string;
+}
+
+const SPEAKER_LABELS: Record<string, string> = {
+  user: 'Tú',
+  interlocutor: 'Interlocutor',
+  unknown: 'Sin identificar',
+};
+
+function formatDuration(seconds: number): string {
+  const rounded = Math.round(seconds);
+  if (rounded < 60) return `${rounded} s`;
+  return `${Math.floor(rounded / 60)} min ${rounded % 60} s`;
+}
+
+function Metric({ label, value }: { label: string; value: string }) {
+  return (
+    <div>
+      <div className="text-xs text-muted-foreground">{label}</div>
+      <div className="text-sm font-medium text-foreground">{value}</div>
+    </div>
+  );
+}
+
+export function ConversationMetricsPanel({ meetingId, summaryStatus }: ConversationMetricsPanelProps) {
+  const [metrics, setMetrics] = useState<ConversationMetrics | null>(null);
+
+  useEffect(() => {
+    let cancelled = false;
+    invoke<ConversationMetrics | null>('api_get_conversation_metrics', { meetingId })
+      .then((result) => {
+        if (!cancelled) setMetrics(result);
+      })
+      .catch((error) => {
+        console.error('Failed to load conversation metrics:', error);
+      });
+    return () => {
+      cancelled = true;
+    };
+  }, [meetingId, summaryStatus]);
+
+  if (!metrics || metrics.speakers.length === 0) {
+    return null;
+  }
+
+  return (
+    <Card className="mb-6">
+      <CardHeader className="pb-3">
+        <CardTitle className="flex items-center gap-2 text-lg">
+          <BarChart3 className="h-5 w-5 text-primary" />
+          Metricas de la Conversacion
+        </CardTitle>
+      </CardHeader>
+      <CardContent className="space-y-4">
+        <div className="grid gap-3 grid-cols-3">
+          <Metric label="Duracion" value={formatDuration(metrics.duration_seconds)} />
+          <Metric label="Turnos" value={String(metrics.total_turns)} />
+          <Metric
+            label="Hablando a la vez"
+            value={`${metrics.overlap_count} (${formatDuration(metrics.overlap_seconds)})`}
+          />
+        </div>
+
+        {metrics.speakers.map((speaker) => (
+          <div key={speaker.speaker} className="p-3 bg-secondary rounded-lg space-y-3">
+            <div>
+              <div className="flex justify-between text-sm mb-1">
+                <span className="font-medium text-foreground">
+                  {SPEAKER_LABELS[speaker.speaker] ?? speaker.speaker}
+                </span>
+                <span className="text-muted-foreground">
+                  {Math.round(speaker.talk_time_share * 100)}% del tiempo ({formatDuration(speaker.talk_time_seconds)})
+                </span>
+              </div>
+              <Progress value={speaker.talk_time_share * 100} className="h-2" />
+            </div>
+            <div className="grid gap-3 grid-cols-3">
+              <Metric
+                label="Palabras por minuto"
+                value={speaker.words_per_minute !== null ? Math.round(speaker.words_per_minute).toString() : '—'}
+              />
+              <Metric label="Monologo mas largo" value={formatDuration(speaker.longest_monologue_seconds)} />
+              <Metric label="Turnos" value={String(speaker.turns)} />
+              <Metric label="Interrupciones" value={String(speaker.interruptions)} />
+              <Metric label="Preguntas" value={String(speaker.questions)} />
+              <Metric
+                label="Muletillas"
+                value={`${speaker.filler_words} (${speaker.filler_rate.toFixed(1)} / 100 palabras)`}
+              />
+            </div>
+          </div>
+        ))}
+      </CardContent>
+    </Card>
+  );
+}
@@ -15,9 +15,10 @@ import {
   DropdownMenu,
   DropdownMenuContent,
   DropdownMenuItem,
+  DropdownMenuSeparator,
   DropdownMenuTrigger,
 } from '@/components/ui/dropdown-menu';
-import { Sparkles, Settings, Loader2, FileText, Check, Square } from 'lucide-react';
+import { Sparkles, Settings, Loader2, FileText, Check, Square, Upload, Download } from 'lucide-react';
 import Analytics from '@/lib/analytics';
 import { invoke } from '@tauri-apps/api/core';
 import { toast } from 'sonner';
@@ -36,6 +37,8 @@ interface SummaryGeneratorButtonGroupProps {
   availableTemplates: Array<{ id: string, name: string, description: string }>;
   selectedTemplate: string;
   onTemplateSelect: (templateId: string, templateName: string) => void;
+  onImportTemplate?: () => void;
+  onExportTemplate?: () => void;
   hasTranscripts?: boolean;
   isModelConfigLoading?: boolean;
   onOpenModelSettings?: (openFn: () => void) => void;
@@ -52,6 +55,8 @@ export function SummaryGeneratorButtonGroup({
   availableTemplates,
   selectedTemplate,
   onTemplateSelect,
+  onImportTemplate,
+  onExportTemplate,
   hasTranscripts = true,
   isModelConfigLoading = false,
   onOpenModelSettings
@@ -346,6 +351,19 @@ export function SummaryGeneratorButtonGroup({
               </DropdownMenuItem>
             ))}
 
+            {(onImportTemplate || onExportTemplate) && <DropdownMenuSeparator />}
+            {onImportTemplate && (
+              <DropdownMenuItem onClick={onImportTemplate} className="flex items-center gap-2">
+                <Upload className="h-4 w-4" />
+                <span>Importar plantilla…</span>
+              </DropdownMenuItem>
+            )}
+            {onExportTemplate && (
+              <DropdownMenuItem onClick={onExportTemplate} className="flex items-center gap-2">
+                <Download className="h-4 w-4" />
+                <span>Exportar plantilla</span>
+              </DropdownMenuItem>
+            )}
           </DropdownMenuContent>
         </DropdownMenu>
       )}
@@ -6,9 +6,11 @@ import { EditableTitle } from '@/components/shared/EditableTitle';
 import { BlockNoteSummaryView, BlockNoteSummaryViewRef } from '@/components/AISummary/BlockNoteSummaryView';
 import { EmptyStateSummary } from '@/components/shared/EmptyStateSummary';
 import { CommunicationFeedbackPanel } from './CommunicationFeedbackPanel';
+import { ConversationMetricsPanel } from './ConversationMetricsPanel';
 import type { ModelConfig } from '@/types/models';
 import { SummaryGeneratorButtonGroup } from './SummaryGeneratorButtonGroup';
 import { SummaryUpdaterButtonGroup } from './SummaryUpdaterButtonGroup';
+import { SummaryVersionHistory } from './SummaryVersionHistory';
 import Analytics from '@/lib/analytics';
 import { RefObject } from 'react';
 
@@ -43,12 +45,15 @@ interface SummaryPanelProps {
   onSaveSummary: (summary: Summary | { markdown?: string; summary_json?: any[] }) => Promise<void>;
   onSummaryChange: (summary: Summary) => void;
   onDirtyChange: (isDirty: boolean) => void;
+  onSummaryRestored: (summary: Summary | null) => void;
   summaryError: string | null;
   onRegenerateSummary: () => Promise<void>;
   getSummaryStatusMessage: (status: 'idle' | 'processing' | 'summarizing' | 'regenerating' | 'completed' | 'error') => string;
   availableTemplates: Array<{ id: string, name: string, description: string }>;
   selectedTemplate: string;
   onTemplateSelect: (templateId: string, templateName: string) => void;
+  onImportTemplate?: () => void;
+  onExportTemplate?: () => void;
   isModelConfigLoading?: boolean;
   onOpenModelSettings?: (openFn: () => void) => void;
 }
@@ -80,12 +85,15 @@ export function SummaryPanel({
   onSaveSummary,
   onSummaryChange,
   onDirtyChange,
+  onSummaryRestored,
   summaryError,
   onRegenerateSummary,
   getSummaryStatusMessage,
   availableTemplates,
   selectedTemplate,
   onTemplateSelect,
+  onImportTemplate,
+  onExportTemplate,
   isModelConfigLoading = false,
   onOpenModelSettings
 }: SummaryPanelProps) {
@@ -119,6 +127,8 @@ export function SummaryPanel({
                 availableTemplates={availableTemplates}
                 selectedTemplate={selectedTemplate}
                 onTemplateSelect={onTemplateSelect}
+                onImportTemplate={onImportTemplate}
+                onExportTemplate={onExportTemplate}
                 hasTranscripts={transcripts.length > 0}
                 isModelConfigLoading={isModelConfigLoading}
                 onOpenModelSettings={onOpenModelSettings}
@@ -140,6 +150,14 @@ export function SummaryPanel({
                 hasSummary={!!aiSummary}
               />
             </div>
+
+            {/* Version history of the summary */}
+            <div className="flex-shrink-0">
+              <SummaryVersionHistory
+                meetingId={meeting.id}
+                setAiSummary={onSummaryRestored}
+              />
+            </div>
           </div>
         )}
       </div>
@@ -159,6 +177,8 @@ export function SummaryPanel({
               availableTemplates={availableTemplates}
               selectedTemplate={selectedTemplate}
               onTemplateSelect={onTemplateSelect}
+              onImportTemplate={onImportTemplate}
+              onExportTemplate={onExportTemplate}
               hasTranscripts={transcripts.length > 0}
               isModelConfigLoading={isModelConfigLoading}
               onOpenModelSettings={onOpenModelSettings}
@@ -187,6 +207,8 @@ export function SummaryPanel({
               availableTemplates={availableTemplates}
               selectedTemplate={selectedTemplate}
               onTemplateSelect={onTemplateSelect}
+              onImportTemplate={onImportTemplate}
+              onExportTemplate={onExportTemplate}
               hasTranscripts={transcripts.length > 0}
               isModelConfigLoading={isModelConfigLoading}
               onOpenModelSettings={onOpenModelSettings}
@@ -251,6 +273,8 @@ export function SummaryPanel({
             {communicationFeedback && (
               <CommunicationFeedbackPanel feedback={communicationFeedback} />
             )}
+            {/* Objective metrics computed from the transcript timestamps */}
+            <ConversationMetricsPanel meetingId={meeting.id} summaryStatus={summaryStatus} />
             <BlockNoteSummaryView
               ref={summaryRef}
               summaryData={aiSummary}
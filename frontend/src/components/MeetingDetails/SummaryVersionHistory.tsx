@@ -0,0 +1,204 @@
+"use client";
+
+import { useState, useEffect } from 'react';
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogHeader,
+  DialogTitle,
+} from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { History, Loader2, RotateCcw } from 'lucide-react';
+import { Summary } from '@/types';
+import Analytics from '@/lib/analytics';
+import { useSummaryVersions, SectionDiff, SummaryVersion } from '@/hooks/meeting-details/useSummaryVersions';
+
+interface SummaryVersionHistoryProps {
+  meetingId: string;
+  setAiSummary: (summary: Summary | null) => void;
+}
+
+const SOURCE_LABELS: Record<SummaryVersion['source'], string> = {
+  generated: 'Generado',
+  edited: 'Editado',
+  restored: 'Restaurado',
+};
+
+const CHANGE_LABELS: Record<SectionDiff['change'], string> = {
+  added: 'Nueva',
+  removed: 'Eliminada',
+  changed: 'Modificada',
+  unchanged: 'Sin cambios',
+};
+
+function describeVersion(version: SummaryVersion): string {
+  const parts = [SOURCE_LABELS[version.source] ?? version.source];
+  if (version.restored_from !== null) {
+    parts.push(`de la versión ${version.restored_from}`);
+  }
+  if (version.provider && version.model) {
+    parts.push(`· ${version.provider}/${version.model}`);
+  }
+  return parts.join(' ');
+}
+
+export function SummaryVersionHistory({ meetingId, setAiSummary }: SummaryVersionHistoryProps) {
+  const [open, setOpen] = useState(false);
+  const [selected, setSelected] = useState<number | null>(null);
+  const [diff, setDiff] = useState<SectionDiff[] | null>(null);
+  const [isDiffLoading, setIsDiffLoading] = useState(false);
+  const { versions, isLoading, isRestoring, loadVersions, diffVersions, restoreVersion } =
+    useSummaryVersions({ meetingId, setAiSummary });
+
+  const current = versions[0];
+
+  useEffect(() => {
+    if (open) {
+      setSelected(null);
+      setDiff(null);
+      loadVersions();
+    }
+  }, [open, loadVersions]);
+
+  // Compare the selected version with the current summary
+  useEffect(() => {
+    if (selected === null || !current || selected === current.version) {
+      setDiff(null);
+      return;
+    }
+    let cancelled = false;
+    setIsDiffLoading(true);
+    diffVersions(selected, current.version).then((result) => {
+      if (!cancelled) {
+        setDiff(result);
+        setIsDiffLoading(false);
+      }
+    });
+    return () => {
+      cancelled = true;
+    };
+  }, [selected, current, diffVersions]);
+
+  return (
+    <Dialog open={open} onOpenChange={setOpen}>
+      <Button
+        variant="outline"
+        size="sm"
+        title="Historial de versiones"
+        onClick={() => {
+          Analytics.trackButtonClick('summary_version_history', 'meeting_details');
+          setOpen(true);
+        }}
+      >
+        <History />
+        <span className="hidden lg:inline">Historial</span>
+      </Button>
+
+      <DialogContent className="max-w-4xl">
+        <DialogHeader>
+          <DialogTitle>Historial de versiones</DialogTitle>
+          <DialogDescription>
+            Cada resumen generado o editado se guarda. Elige una versión para compararla con el resumen actual.
+          </DialogDescription>
+        </DialogHeader>
+
+        {isLoading ? (
+          <div className="flex justify-center py-8">
+            <Loader2 className="h-6 w-6 animate-spin text-muted-foreground" />
+          </div>
+        ) : versions.length === 0 ? (
+          <p className="py-8 text-center text-sm text-muted-foreground">
+            Este resumen todavía no tiene versiones guardadas.
+          </p>
+        ) : (
+          <div className="flex gap-4 h-[60vh]">
+            <ul className="w-64 flex-shrink-0 overflow-y-auto space-y-1">
+              {versions.map((version) => (
+                <li key={version.id}>
+                  <button
+                    onClick={() => setSelected(version.version)}
+                    className={`w-full text-left rounded-md px-3 py-2 text-sm hover:bg-accent ${
+                      selected === version.version ? 'bg-accent' : ''
+                    }`}
+                  >
+                    <div className="font-medium">
+                      Versión {version.version}
+                      {version.version === current?.version && (
+                        <span className="ml-2 text-xs text-[#16bb7b]">Actual</span>
+                      )}
+                    </div>
+                    <div className="text-xs text-muted-foreground">
+                      {new Date(version.created_at).toLocaleString()}
+                    </div>
+                    <div className="text-xs text-muted-foreground truncate">
+                      {describeVersion(version)}
+                    </div>
+                  </button>
+                </li>
+              ))}
+            </ul>
+
+            <div className="flex-1 min-w-0 overflow-y-auto border-l border-border pl-4">
+              {selected === null ? (
+                <p className="text-sm text-muted-foreground">Selecciona una versión.</p>
+              ) : selected === current?.version ? (
+                <p className="text-sm text-muted-foreground">Esta es la versión actual del resumen.</p>
+              ) : isDiffLoading || !diff ? (
+                <Loader2 className="h-5 w-5 animate-spin text-muted-foreground" />
+              ) : (
+                <div className="space-y-4">
+                  <div className="flex items-center justify-between">
+                    <p className="text-sm text-muted-foreground">
+                      Cambios de la versión {selected} a la actual
+                    </p>
+                    <Button
+                      size="sm"
+                      disabled={isRestoring}
+                      onClick={async () => {
+                        if (await restoreVersion(selected)) {
+                          setOpen(false);
+                        }
+                      }}
+                    >
+                      {isRestoring ? <Loader2 className="animate-spin" /> : <RotateCcw />}
+                      Restaurar versión {selected}
+                    </Button>
+                  </div>
+
+                  {diff.map((section, index) => (
+                    <div key={`${section.title}-${index}`} className="rounded-md border border-border p-3">
+                      <div className="flex items-center justify-between mb-2">
+                        <span className="font-medium text-sm">{section.title || 'Introducción'}</span>
+                        <span className="text-xs text-muted-foreground">{CHANGE_LABELS[section.change]}</span>
+                      </div>
+                      {section.change === 'unchanged' ? null : (
+                        <pre className="text-xs whitespace-pre-wrap font-sans">
+                          {section.lines.map((line, lineIndex) => (
+                            <div
+                              key={lineIndex}
+                              className={
+                                line.change === 'added'
+                                  ? 'bg-green-100 text-green-900'
+                                  : line.change === 'removed'
+                                    ? 'bg-red-100 text-red-900 line-through'
+                                    : ''
+                              }
+                            >
+                              {line.change === 'added' ? '+ ' : line.change === 'removed' ? '- ' : '  '}
+                              {line.text}
+                            </div>
+                          ))}
+                        </pre>
+                      )}
+                    </div>
+                  ))}
+                </div>
+              )}
+            </div>
+          </div>
+        )}
+      </DialogContent>
+    </Dialog>
+  );
+}
@@ -19,12 +19,19 @@ export interface CurrentMeeting {
   title: string;
 }
 
-// Search result type for transcript search
+// Search result type for transcript search (BM25-ranked, best match first)
 interface TranscriptSearchResult {
   id: string;
   title: string;
   matchContext: string;
   timestamp: string;
+  snippet: string; // matchContext with matched terms wrapped in <mark></mark>
+  sourceType: 'transcript' | 'summary' | 'notes';
+  sourceId: string;
+  speaker?: string | null;
+  audioStartTime?: number | null;
+  meetingDate: string;
+  rank: number;
 };
 
 interface SidebarContextType {
@@ -562,6 +562,16 @@ const Sidebar: React.FC = () => {
     );
   };
 
+  // Render a search snippet, highlighting the terms the backend wrapped in <mark></mark>
+  const renderSnippet = (snippet: string) =>
+    snippet.split(/<\/?mark>/).map((part, index) =>
+      index % 2 === 1 ? (
+        <mark key={index} className="bg-primary/20 text-foreground rounded-sm">{part}</mark>
+      ) : (
+        <React.Fragment key={index}>{part}</React.Fragment>
+      )
+    );
+
   // Find matching transcript snippet for a meeting item
   const findMatchingSnippet = (itemId: string) => {
     if (!searchQuery.trim() || !searchResults.length) return null;
@@ -662,7 +672,7 @@ const Sidebar: React.FC = () => {
               {/* Show transcript match snippet if available */}
               {hasTranscriptMatch && (
                 <div className="mt-1 ml-8 text-xs text-muted-foreground bg-muted/50 p-1.5 rounded border border-border line-clamp-2">
-                  <span className="font-medium text-primary">Match:</span> {matchingResult.matchContext}
+                  <span className="font-medium text-primary">Match:</span> {renderSnippet(matchingResult.snippet)}
                 </div>
               )}
             </div>
@@ -0,0 +1,80 @@
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from '@/components/ui/select';
+import type { ModelConfig } from '@/types/models';
+
+// Sentinel for "no fallback" (Select items cannot use an empty value)
+const NO_FALLBACK = 'none';
+
+interface FallbackProviderSettingsProps {
+  primaryProvider: ModelConfig['provider'];
+  provider: string;
+  setProvider: (value: string) => void;
+  model: string;
+  setModel: (value: string) => void;
+}
+
+export function FallbackProviderSettings({
+  primaryProvider,
+  provider,
+  setProvider,
+  model,
+  setModel,
+}: FallbackProviderSettingsProps) {
+  return (
+    <div className="space-y-3 border-t pt-4">
+      <div>
+        <Label>Proveedor de respaldo (opcional)</Label>
+        <p className="text-xs text-muted-foreground mt-1">
+          Si el proveedor principal falla tras varios reintentos (límite de uso, caída del servicio,
+          clave inválida), el resumen se genera con este proveedor.
+        </p>
+      </div>
+
+      <div className="flex space-x-2">
+        <Select
+          value={provider || NO_FALLBACK}
+          onValueChange={(value) => {
+            setProvider(value === NO_FALLBACK ? '' : value);
+            setModel('');
+          }}
+        >
+          <SelectTrigger className="w-56">
+            <SelectValue placeholder="Sin respaldo" />
+          </SelectTrigger>
+          <SelectContent>
+            <SelectItem value={NO_FALLBACK}>Sin respaldo</SelectItem>
+            {primaryProvider !== 'ollama' && <SelectItem value="ollama">Ollama (local)</SelectItem>}
+            {primaryProvider !== 'builtin-ai' && <SelectItem value="builtin-ai">IA Integrada (local)</SelectItem>}
+            {primaryProvider !== 'claude' && <SelectItem value="claude">Claude</SelectItem>}
+            {primaryProvider !== 'gemini' && <SelectItem value="gemini">Google Gemini</SelectItem>}
+            {primaryProvider !== 'groq' && <SelectItem value="groq">Groq</SelectItem>}
+            {primaryProvider !== 'openai' && <SelectItem value="openai">OpenAI</SelectItem>}
+            {primaryProvider !== 'openrouter' && <SelectItem value="openrouter">OpenRouter</SelectItem>}
+          </SelectContent>
+        </Select>
+
+        {provider && (
+          <Input
+            value={model}
+            onChange={(e) => setModel(e.target.value)}
+            placeholder="Modelo (p. ej. llama3.2:latest)"
+            className="flex-1"
+          />
+        )}
+      </div>
+
+      {provider && provider !== 'ollama' && provider !== 'builtin-ai' && (
+        <p className="text-xs text-muted-foreground">
+          Usa la clave API guardada para este proveedor.
+        </p>
+      )}
+    </div>
+  );
+}
@@ -20,8 +20,9 @@ import { cn, isOllamaNotInstalledError } from '@/lib/utils';
 import { toast } from 'sonner';
 import { OllamaSettings } from '@/components/models/ModelSettings/OllamaSettings';
 import { CustomOpenAISettings } from '@/components/models/ModelSettings/CustomOpenAISettings';
+import { FallbackProviderSettings } from '@/components/models/ModelSettings/FallbackProviderSettings';
 import type { ModelConfig } from '@/types/models';
-import type { OllamaModel } from '@/types/models';
+import type { FallbackProviderConfig, OllamaModel } from '@/types/models';
 
 export type { ModelConfig };
 
@@ -33,6 +34,16 @@ interface OpenRouterModel {
   completion_price?: string;
 }
 
+interface GeminiModel {
+  id: string;
+  name: string;
+  input_token_limit?: number;
+  output_token_limit?: number;
+}
+
+// Shown until the model list of the Gemini API has been loaded
+const DEFAULT_GEMINI_MODELS = ['gemini-2.5-flash', 'gemini-2.5-pro', 'gemini-2.5-flash-lite'];
+
 interface ModelSettingsModalProps {
   modelConfig: ModelConfig;
   setModelConfig: (config: ModelConfig | ((prev: ModelConfig) => ModelConfig)) => void;
@@ -61,6 +72,10 @@ export function ModelSettingsModal({
   const [openRouterModels, setOpenRouterModels] = useState<OpenRouterModel[]>([]);
   const [openRouterError, setOpenRouterError] = useState<string>('');
   const [isLoadingOpenRouter, setIsLoadingOpenRouter] = useState<boolean>(false);
+  const [geminiModels, setGeminiModels] = useState<GeminiModel[]>([]);
+  const [isLoadingGemini, setIsLoadingGemini] = useState<boolean>(false);
+  const [fallbackProvider, setFallbackProvider] = useState<string>('');
+  const [fallbackModel, setFallbackModel] = useState<string>('');
   const [ollamaEndpoint, setOllamaEndpoint] = useState<string>(modelConfig.ollamaEndpoint || '');
   const [isLoadingOllama, setIsLoadingOllama] = useState<boolean>(false);
   const [lastFetchedEndpoint, setLastFetchedEndpoint] = useState<string>(modelConfig.ollamaEndpoint || '');
@@ -150,6 +165,7 @@ export function ModelSettingsModal({
   const modelOptions: Record<string, string[]> = {
     ollama: models.map((model) => model.name),
     claude: ['claude-sonnet-4-5-20250929', 'claude-haiku-4-5-20251001', 'claude-opus-4-5-20251101'],
+    gemini: geminiModels.length > 0 ? geminiModels.map((m) => m.id) : DEFAULT_GEMINI_MODELS,
     groq: ['llama-3.3-70b-versatile'],
     openai: [
       'gpt-5',
@@ -183,6 +199,7 @@ export function ModelSettingsModal({
 
   const requiresApiKey =
     modelConfig.provider === 'claude' ||
+    modelConfig.provider === 'gemini' ||
     modelConfig.provider === 'groq' ||
     modelConfig.provider === 'openai' ||
     modelConfig.provider === 'openrouter';
@@ -200,7 +217,20 @@ export function ModelSettingsModal({
   const isDoneDisabled =
     (requiresApiKey && (!apiKey || (typeof apiKey === 'string' && !apiKey.trim()))) ||
     (modelConfig.provider === 'ollama' && ollamaEndpointChanged) ||
-    isCustomOpenAIInvalid;
+    isCustomOpenAIInvalid ||
+    (!!fallbackProvider && !fallbackModel.trim());
+
+  // Load the configured fallback provider (the UI edits the first entry of the chain)
+  useEffect(() => {
+    invoke<FallbackProviderConfig[]>('api_get_summary_fallback_chain')
+      .then((chain) => {
+        if (chain.length > 0) {
+          setFallbackProvider(chain[0].provider);
+          setFallbackModel(chain[0].model);
+        }
+      })
+      .catch((err) => console.error('Failed to load summary fallback chain:', err));
+  }, []);
 
   useEffect(() => {
     const fetchModelConfig = async () => {
@@ -235,6 +265,10 @@ export function ModelSettingsModal({
           }
           hasLoadedInitialConfig.current = true; // Mark that initial config is loaded
 
+          if (data.provider === 'gemini') {
+            loadGeminiModels(data.apiKey);
+          }
+
           // Fetch Custom OpenAI config if that's the active provider
           if (data.provider === 'custom-openai') {
             try {
@@ -440,6 +474,23 @@ export function ModelSettingsModal({
     }
   };
 
+  const loadGeminiModels = async (key?: string | null) => {
+    if (geminiModels.length > 0) return; // Already loaded
+
+    try {
+      setIsLoadingGemini(true);
+      const data = await invoke<GeminiModel[]>('api_get_gemini_models', {
+        apiKey: key?.trim() || null,
+      });
+      setGeminiModels(data);
+    } catch (err) {
+      // Without a valid key the default model list stays available
+      console.error('Error loading Gemini models:', err);
+    } finally {
+      setIsLoadingGemini(false);
+    }
+  };
+
   const loadBuiltinAiModels = async () => {
     if (builtinAiModels.length > 0) return; // Already loaded
 
@@ -480,6 +531,17 @@ export function ModelSettingsModal({
       }
     }
 
+    try {
+      const chain: FallbackProviderConfig[] = fallbackProvider
+        ? [{ provider: fallbackProvider, model: fallbackModel.trim() }]
+        : [];
+      await invoke('api_save_summary_fallback_chain', { chain });
+    } catch (err) {
+      console.error('Failed to save summary fallback chain:', err);
+      toast.error('Error al guardar el proveedor de respaldo');
+      return;
+    }
+
     const updatedConfig = {
       ...modelConfig,
       apiKey: typeof apiKey === 'string' ? apiKey.trim() || null : null,
@@ -671,6 +733,11 @@ export function ModelSettingsModal({
                   loadOpenRouterModels();
                 }
 
+                // Load Gemini models (with the stored key) when selected
+                if (provider === 'gemini') {
+                  loadGeminiModels();
+                }
+
                 // Load Built-in AI models when selected
                 if (provider === 'builtin-ai') {
                   loadBuiltinAiModels();
@@ -700,6 +767,7 @@ export function ModelSettingsModal({
                 <SelectItem value="builtin-ai">IA Integrada (Sin conexión, sin API)</SelectItem>
                 <SelectItem value="claude">Claude</SelectItem>
                 <SelectItem value="custom-openai">Servidor Personalizado (OpenAI)</SelectItem>
+                <SelectItem value="gemini">Google Gemini</SelectItem>
                 <SelectItem value="groq">Groq</SelectItem>
                 <SelectItem value="ollama">Ollama</SelectItem>
                 <SelectItem value="openai">OpenAI</SelectItem>
@@ -718,7 +786,8 @@ export function ModelSettingsModal({
                   <SelectValue placeholder="Seleccionar modelo" />
                 </SelectTrigger>
                 <SelectContent className="max-h-48 overflow-y-auto">
-                  {modelConfig.provider === 'openrouter' && isLoadingOpenRouter ? (
+                  {(modelConfig.provider === 'openrouter' && isLoadingOpenRouter) ||
+                  (modelConfig.provider === 'gemini' && isLoadingGemini) ? (
                     <SelectItem value="loading" disabled>
                       Cargando modelos...
                     </SelectItem>
@@ -844,6 +913,14 @@ export function ModelSettingsModal({
             />
           </div>
         )}
+
+        <FallbackProviderSettings
+          primaryProvider={modelConfig.provider}
+          provider={fallbackProvider}
+          setProvider={setFallbackProvider}
+          model={fallbackModel}
+          setModel={setFallbackModel}
+        />
       </div>
 
       {/* Auto-generate summaries toggle */}
@@ -1,8 +1,10 @@
-import { useState, useCallback } from 'react';
+import { useState, useCallback, useEffect } from 'react';
 import { Transcript, Summary } from '@/types';
+import type { SummaryProgress } from '@/types/summary';
 import type { ModelConfig } from '@/types/models';
 import { CurrentMeeting, useSidebar } from '@/components/Sidebar/SidebarProvider';
 import { invoke as invokeTauri } from '@tauri-apps/api/core';
+import { listen } from '@tauri-apps/api/event';
 import { toast } from 'sonner';
 import Analytics from '@/lib/analytics';
 import { isOllamaNotInstalledError } from '@/lib/utils';
@@ -36,9 +38,43 @@ export function useSummaryGeneration({
   const [summaryStatus, setSummaryStatus] = useState<SummaryStatus>('idle');
   const [summaryError, setSummaryError] = useState<string | null>(null);
   const [originalTranscript, setOriginalTranscript] = useState<string>('');
+  // Partial markdown of the report while the LLM streams it (null when not streaming)
+  const [streamingSummary, setStreamingSummary] = useState<string | null>(null);
 
   const { startSummaryPolling, stopSummaryPolling } = useSidebar();
 
+  // Live preview of the summary as it is generated
+  useEffect(() => {
+    let unlistenFn: (() => void) | undefined;
+
+    const setupProgressListener = async () => {
+      try {
+        unlistenFn = await listen<SummaryProgress>('summary-progress', (event) => {
+          if (event.payload.meeting_id === meeting.id) {
+            setStreamingSummary(event.payload.partial_markdown);
+          }
+        });
+      } catch (error) {
+        console.error('Failed to setup summary-progress listener:', error);
+      }
+    };
+
+    setupProgressListener();
+
+    return () => {
+      if (unlistenFn) {
+        unlistenFn();
+      }
+    };
+  }, [meeting.id]);
+
+  // Drop the preview once the summary is saved, fails or is stopped
+  useEffect(() => {
+    if (summaryStatus === 'idle' || summaryStatus === 'completed' || summaryStatus === 'error') {
+      setStreamingSummary(null);
+    }
+  }, [summaryStatus]);
+
   // Helper to get status message
   const getSummaryStatusMessage = useCallback((status: SummaryStatus) => {
     switch (status) {
@@ -109,8 +145,6 @@ export function useSummaryGeneration({
         model: modelConfig.provider,
         modelName: modelConfig.model,
         meetingId: meeting.id,
-        chunkSize: 40000,
-        overlap: 1000,
         customPrompt: customPrompt,
         templateId: selectedTemplate,
       }) as any;
@@ -609,6 +643,7 @@ export function useSummaryGeneration({
   return {
     summaryStatus,
     summaryError,
+    streamingSummary,
     handleGenerateSummary,
     handleRegenerateSummary,
     handleStopGeneration,
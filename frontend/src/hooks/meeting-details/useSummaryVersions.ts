@@ -0,0 +1,109 @@
+import { useState, useCallback } from 'react';
+import { invoke as invokeTauri } from '@tauri-apps/api/core';
+import { toast } from 'sonner';
+import { Summary } from '@/types';
+import Analytics from '@/lib/analytics';
+
+export interface SummaryVersion {
+  id: string;
+  meeting_id: string;
+  version: number;
+  source: 'generated' | 'edited' | 'restored';
+  result: string;
+  provider: string | null;
+  model: string | null;
+  template_id: string | null;
+  prompt_hash: string | null;
+  restored_from: number | null;
+  created_at: string;
+}
+
+export interface SectionDiff {
+  title: string;
+  change: 'added' | 'removed' | 'changed' | 'unchanged';
+  old_content: string | null;
+  new_content: string | null;
+  lines: Array<{ change: 'added' | 'removed' | 'unchanged'; text: string }>;
+}
+
+interface UseSummaryVersionsProps {
+  meetingId: string;
+  setAiSummary: (summary: Summary | null) => void;
+}
+
+export function useSummaryVersions({ meetingId, setAiSummary }: UseSummaryVersionsProps) {
+  const [versions, setVersions] = useState<SummaryVersion[]>([]);
+  const [isLoading, setIsLoading] = useState(false);
+  const [isRestoring, setIsRestoring] = useState(false);
+
+  const loadVersions = useCallback(async () => {
+    setIsLoading(true);
+    try {
+      const list = await invokeTauri('api_list_summary_versions', { meetingId }) as SummaryVersion[];
+      setVersions(list);
+    } catch (error) {
+      console.error('Failed to load summary versions:', error);
+      toast.error('No se pudo cargar el historial de versiones', {
+        description: String(error),
+      });
+    } finally {
+      setIsLoading(false);
+    }
+  }, [meetingId]);
+
+  const diffVersions = useCallback(async (fromVersion: number, toVersion: number) => {
+    try {
+      return await invokeTauri('api_diff_summary_versions', {
+        meetingId,
+        fromVersion,
+        toVersion,
+      }) as SectionDiff[];
+    } catch (error) {
+      console.error('Failed to compare summary versions:', error);
+      toast.error('No se pudieron comparar las versiones', {
+        description: String(error),
+      });
+      return null;
+    }
+  }, [meetingId]);
+
+  // Restore a version as the current summary and reload it in the editor
+  const restoreVersion = useCallback(async (version: number) => {
+    setIsRestoring(true);
+    try {
+      const restored = await invokeTauri('api_restore_summary_version', {
+        meetingId,
+        version,
+      }) as SummaryVersion;
+
+      const summary = await invokeTauri('api_get_summary', { meetingId }) as any;
+      if (summary?.data) {
+        setAiSummary(summary.data);
+      }
+      await loadVersions();
+
+      toast.success('Versión restaurada', {
+        description: `La versión ${version} es ahora el resumen actual (versión ${restored.version})`,
+      });
+      Analytics.trackFeatureUsed('summary_version_restored');
+      return true;
+    } catch (error) {
+      console.error('Failed to restore summary version:', error);
+      toast.error('No se pudo restaurar la versión', {
+        description: String(error),
+      });
+      return false;
+    } finally {
+      setIsRestoring(false);
+    }
+  }, [meetingId, setAiSummary, loadVersions]);
+
+  return {
+    versions,
+    isLoading,
+    isRestoring,
+    loadVersions,
+    diffVersions,
+    restoreVersion,
+  };
+}
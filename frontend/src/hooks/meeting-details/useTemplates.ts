@@ -11,23 +11,24 @@ export function useTemplates() {
   }>>([]);
   const [selectedTemplate, setSelectedTemplate] = useState<string>('standard_meeting');
 
+  const fetchTemplates = useCallback(async () => {
+    try {
+      const templates = await invokeTauri('api_list_templates') as Array<{
+        id: string;
+        name: string;
+        description: string;
+      }>;
+      console.log('Available templates:', templates);
+      setAvailableTemplates(templates);
+    } catch (error) {
+      console.error('Failed to fetch templates:', error);
+    }
+  }, []);
+
   // Fetch available templates on mount
   useEffect(() => {
-    const fetchTemplates = async () => {
-      try {
-        const templates = await invokeTauri('api_list_templates') as Array<{
-          id: string;
-          name: string;
-          description: string;
-        }>;
-        console.log('Available templates:', templates);
-        setAvailableTemplates(templates);
-      } catch (error) {
-        console.error('Failed to fetch templates:', error);
-      }
-    };
     fetchTemplates();
-  }, []);
+  }, [fetchTemplates]);
 
   // Handle template selection
   const handleTemplateSelection = useCallback((templateId: string, templateName: string) => {
@@ -38,9 +39,55 @@ export function useTemplates() {
     Analytics.trackFeatureUsed('template_selected');
   }, []);
 
+  // Import a template from a JSON file and select it
+  const handleImportTemplate = useCallback(async () => {
+    try {
+      const imported = await invokeTauri('api_import_template') as {
+        id: string;
+        name: string;
+        description: string;
+      } | null;
+      if (!imported) return;
+
+      await fetchTemplates();
+      setSelectedTemplate(imported.id);
+      toast.success('Plantilla importada', {
+        description: `"${imported.name}" está lista para generar resúmenes`,
+      });
+      Analytics.trackFeatureUsed('template_imported');
+    } catch (error) {
+      console.error('Failed to import template:', error);
+      toast.error('No se pudo importar la plantilla', {
+        description: String(error),
+      });
+    }
+  }, [fetchTemplates]);
+
+  // Export the selected template to a JSON file
+  const handleExportTemplate = useCallback(async () => {
+    try {
+      const path = await invokeTauri('api_export_template', {
+        templateId: selectedTemplate,
+      }) as string | null;
+      if (!path) return;
+
+      toast.success('Plantilla exportada', {
+        description: path,
+      });
+      Analytics.trackFeatureUsed('template_exported');
+    } catch (error) {
+      console.error('Failed to export template:', error);
+      toast.error('No se pudo exportar la plantilla', {
+        description: String(error),
+      });
+    }
+  }, [selectedTemplate]);
+
   return {
     availableTemplates,
     selectedTemplate,
     handleTemplateSelection,
+    handleImportTemplate,
+    handleExportTemplate,
   };
 }
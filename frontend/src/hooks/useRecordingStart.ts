@@ -595,7 +595,7 @@ export function useRecordingStart(
 
     const intervalId = setInterval(async () => {
       try {
-        const event = await invoke<{ type: string; device_name?: string; device_type?: string } | null>('poll_audio_device_events');
+        const event = await invoke<{ type: string; device_name?: string; device_type?: string; switched?: boolean } | null>('poll_audio_device_events');
         if (!event) return;
 
         if (event.type === 'DeviceDisconnected') {
@@ -608,6 +608,11 @@ export function useRecordingStart(
             description: `${event.device_name || 'Dispositivo'} se reconectó correctamente.`,
             duration: 5000,
           });
+        } else if (event.type === 'DefaultDeviceChanged' && event.switched) {
+          toast.info('Dispositivo predeterminado cambiado', {
+            description: `La grabación continúa con ${event.device_name || 'el nuevo dispositivo predeterminado'}.`,
+            duration: 5000,
+          });
         } else if (event.type === 'DeviceListChanged') {
           toast.info('Cambio en dispositivos de audio', {
             description: 'Se detectó un cambio en los dispositivos de audio disponibles.',
@@ -622,6 +627,31 @@ export function useRecordingStart(
     return () => clearInterval(intervalId);
   }, [isRecording]);
 
+  // Auto-stop / auto-pause rules: offer to keep recording before the rule takes effect
+  useEffect(() => {
+    if (!isRecording) return;
+
+    let unlisten: (() => void) | undefined;
+    recordingService.onAutoRulePending((pending) => {
+      toast.warning(pending.action === 'stop' ? 'La grabación se detendrá' : 'La grabación se pausará', {
+        description: pending.message,
+        duration: pending.grace_period_seconds * 1000,
+        action: {
+          label: 'Continuar grabando',
+          onClick: () => {
+            recordingService.continueAfterAutoRule().catch((error) => {
+              console.error('Failed to cancel auto rule:', error);
+            });
+          },
+        },
+      });
+    }).then((fn) => {
+      unlisten = fn;
+    });
+
+    return () => unlisten?.();
+  }, [isRecording]);
+
   return {
     handleRecordingStart,
     isAutoStarting,
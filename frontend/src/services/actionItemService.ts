@@ -0,0 +1,56 @@
+/**
+ * Action Item Service
+ *
+ * Handles action item Tauri backend calls and events.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke/listen calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import {
+  ActionItem,
+  ActionItemFilter,
+  ActionItemUpdate,
+  ActionItemsUpdatedPayload,
+} from '@/types/summary';
+
+/**
+ * Action Item Service
+ * Singleton service for listing and updating action items across meetings
+ */
+export class ActionItemService {
+  /**
+   * List action items across all meetings (pending first, then by due date)
+   * @param filter - Optional filter; all fields are combined with AND
+   * @returns Promise<ActionItem[]>
+   */
+  async listActionItems(filter?: ActionItemFilter): Promise<ActionItem[]> {
+    return invoke<ActionItem[]>('api_list_action_items', { filter });
+  }
+
+  /**
+   * Update the description, owner, due date or status of an action item
+   * @param id - Action item ID
+   * @param update - Fields to change; an empty owner or due date clears it
+   * @returns Promise<ActionItem> - The updated item
+   */
+  async updateActionItem(id: string, update: ActionItemUpdate): Promise<ActionItem> {
+    return invoke<ActionItem>('api_update_action_item', { id, update });
+  }
+
+  /**
+   * Listen for action items being (re-)extracted after a summary completes
+   * @param callback - Function to call with the meeting ID and item count
+   * @returns Promise that resolves to unlisten function
+   */
+  async onActionItemsUpdated(
+    callback: (payload: ActionItemsUpdatedPayload) => void
+  ): Promise<UnlistenFn> {
+    return listen<ActionItemsUpdatedPayload>('action-items-updated', (event) => {
+      callback(event.payload);
+    });
+  }
+}
+
+// Export singleton instance
+export const actionItemService = new ActionItemService();
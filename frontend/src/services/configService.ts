@@ -9,6 +9,7 @@ import { invoke } from '@tauri-apps/api/core';
 import type { TranscriptModelProps } from '@/types/transcript';
 import type { ModelConfig, CustomOpenAIConfig } from '@/types/models';
 import type { DevicePreferences } from '@/types/audio';
+import type { GlobalShortcutSettings, ShortcutConflict, ShortcutRegistrationReport } from '@/types/config';
 
 export type { ModelConfig, CustomOpenAIConfig };
 
@@ -91,6 +92,32 @@ export class ConfigService {
       model,
     });
   }
+
+  /**
+   * Get global keyboard shortcut settings
+   * @returns Promise with the shortcut bindings
+   */
+  async getGlobalShortcuts(): Promise<GlobalShortcutSettings> {
+    return invoke<GlobalShortcutSettings>('get_global_shortcuts');
+  }
+
+  /**
+   * Check shortcut bindings for invalid or duplicate accelerators
+   * @param settings - Bindings to check
+   * @returns Promise with the conflicts found (empty when valid)
+   */
+  async checkGlobalShortcutConflicts(settings: GlobalShortcutSettings): Promise<ShortcutConflict[]> {
+    return invoke<ShortcutConflict[]>('check_global_shortcut_conflicts', { settings });
+  }
+
+  /**
+   * Save and register global keyboard shortcuts
+   * @param settings - Bindings to apply
+   * @returns Promise with registered actions and shortcuts taken by other apps
+   */
+  async setGlobalShortcuts(settings: GlobalShortcutSettings): Promise<ShortcutRegistrationReport> {
+    return invoke<ShortcutRegistrationReport>('set_global_shortcuts', { settings });
+  }
 }
 
 // Export singleton instance
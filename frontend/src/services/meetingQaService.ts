@@ -0,0 +1,48 @@
+/**
+ * Meeting Q&A Service
+ *
+ * Handles "ask your meetings" Tauri backend calls and events.
+ * Pure 1-to-1 wrapper - no error handling changes, exact same behavior as direct invoke/listen calls.
+ */
+
+import { invoke } from '@tauri-apps/api/core';
+import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import { MeetingAnswer, MeetingIndexProgress } from '@/types/summary';
+
+/**
+ * Meeting Q&A Service
+ * Singleton service for answering questions over all meeting transcripts
+ */
+export class MeetingQaService {
+  /**
+   * Ask a question over all meetings (unindexed or changed meetings are embedded first)
+   * @param question - Natural language question
+   * @param topK - Optional number of transcript segments to retrieve (default 6, max 20)
+   * @returns Promise<MeetingAnswer> - Answer with numbered citations
+   */
+  async askMeetings(question: string, topK?: number): Promise<MeetingAnswer> {
+    return invoke<MeetingAnswer>('api_ask_meetings', { question, topK });
+  }
+
+  /**
+   * Embed every meeting that is not indexed for the current embedding model or changed since
+   * @returns Promise<number> - Number of meetings indexed
+   */
+  async indexMeetings(): Promise<number> {
+    return invoke<number>('api_index_meetings_for_qa');
+  }
+
+  /**
+   * Listen for indexing progress
+   * @param callback - Function to call with indexed/total meeting counts
+   * @returns Promise that resolves to unlisten function
+   */
+  async onIndexProgress(callback: (progress: MeetingIndexProgress) => void): Promise<UnlistenFn> {
+    return listen<MeetingIndexProgress>('meeting-index-progress', (event) => {
+      callback(event.payload);
+    });
+  }
+}
+
+// Export singleton instance
+export const meetingQaService = new MeetingQaService();
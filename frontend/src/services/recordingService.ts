@@ -7,6 +7,14 @@
 
 import { invoke } from '@tauri-apps/api/core';
 import { listen, UnlistenFn } from '@tauri-apps/api/event';
+import type {
+  AudioQualityWarning,
+  AutoRule,
+  AutoRulePending,
+  Bookmark,
+  LiveSummary,
+  LiveSummarySettings,
+} from '@/types/audio';
 
 export interface RecordingState {
   is_recording: boolean;
@@ -105,6 +113,65 @@ export class RecordingService {
     return invoke('resume_recording');
   }
 
+  /**
+   * Keep recording: cancel the pending auto-stop / auto-pause rule
+   * @returns Promise with the cancelled rule, or null if none was pending
+   */
+  async continueAfterAutoRule(): Promise<AutoRule | null> {
+    return invoke<AutoRule | null>('continue_recording_after_auto_rule');
+  }
+
+  /**
+   * Bookmark the current moment of the recording
+   * @param label - Bookmark label (e.g. "important", "action item", "follow up")
+   * @param note - Optional note
+   * @returns Promise with the created bookmark
+   */
+  async addBookmark(label: string, note?: string): Promise<Bookmark> {
+    return invoke<Bookmark>('add_recording_bookmark', { label, note: note ?? null });
+  }
+
+  /**
+   * Get bookmarks added during the current recording
+   * @returns Promise with bookmarks in creation order
+   */
+  async getBookmarks(): Promise<Bookmark[]> {
+    return invoke<Bookmark[]>('get_recording_bookmarks');
+  }
+
+  /**
+   * Get the live summary settings (applied from the next recording)
+   * @returns Promise with the current settings
+   */
+  async getLiveSummarySettings(): Promise<LiveSummarySettings> {
+    return invoke<LiveSummarySettings>('get_live_summary_settings');
+  }
+
+  /**
+   * Update the live summary settings
+   * @param settings - New settings (interval and segment threshold are clamped)
+   * @returns Promise with the settings as saved
+   */
+  async setLiveSummarySettings(settings: LiveSummarySettings): Promise<LiveSummarySettings> {
+    return invoke<LiveSummarySettings>('set_live_summary_settings', { settings });
+  }
+
+  /**
+   * Get the latest rolling summary of the current recording
+   * @returns Promise with the summary, or null before the first update
+   */
+  async getLiveSummary(): Promise<LiveSummary | null> {
+    return invoke<LiveSummary | null>('api_get_live_summary');
+  }
+
+  /**
+   * Stop the rolling summary of the current recording (the recording continues)
+   * @returns Promise<boolean> - Whether a live summary was running
+   */
+  async cancelLiveSummary(): Promise<boolean> {
+    return invoke<boolean>('api_cancel_live_summary');
+  }
+
   // Event Listeners
 
   /**
@@ -156,6 +223,50 @@ export class RecordingService {
     });
   }
 
+  /**
+   * Listen for audio-quality-warning event (clipping, silence, dropouts, noise, narrowband)
+   * @param callback - Function to call with each quality warning
+   * @returns Promise that resolves to unlisten function
+   */
+  async onAudioQualityWarning(callback: (warning: AudioQualityWarning) => void): Promise<UnlistenFn> {
+    return listen<AudioQualityWarning>('audio-quality-warning', (event) => {
+      callback(event.payload);
+    });
+  }
+
+  /**
+   * Listen for recording-auto-rule-pending event (auto-stop / auto-pause announced)
+   * @param callback - Function to call with the pending rule
+   * @returns Promise that resolves to unlisten function
+   */
+  async onAutoRulePending(callback: (pending: AutoRulePending) => void): Promise<UnlistenFn> {
+    return listen<AutoRulePending>('recording-auto-rule-pending', (event) => {
+      callback(event.payload);
+    });
+  }
+
+  /**
+   * Listen for live-summary-update event (rolling summary refreshed)
+   * @param callback - Function to call with the updated summary
+   * @returns Promise that resolves to unlisten function
+   */
+  async onLiveSummaryUpdate(callback: (summary: LiveSummary) => void): Promise<UnlistenFn> {
+    return listen<LiveSummary>('live-summary-update', (event) => {
+      callback(event.payload);
+    });
+  }
+
+  /**
+   * Listen for recording-bookmark-added event (from the UI or the tray)
+   * @param callback - Function to call with the new bookmark
+   * @returns Promise that resolves to unlisten function
+   */
+  async onBookmarkAdded(callback: (bookmark: Bookmark) => void): Promise<UnlistenFn> {
+    return listen<Bookmark>('recording-bookmark-added', (event) => {
+      callback(event.payload);
+    });
+  }
+
   /**
    * Listen for speech-detected event (VAD)
    * @param callback - Function to call when speech is detected
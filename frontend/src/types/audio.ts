@@ -33,6 +33,96 @@ export interface RecordingPreferences {
   file_format: string;
   preferred_mic_device: string | null;
   preferred_system_device: string | null;
+  vad?: VadSettings;
+  follow_default_devices?: FollowDefaultDevices;
+  auto_rules?: AutoRecordingRules;
+  live_summary?: LiveSummarySettings;
+}
+
+// Auto-stop / auto-pause rules (see get_auto_recording_rules / set_auto_recording_rules)
+export interface AutoRecordingRules {
+  stop_after_silence_minutes: number | null;
+  stop_when_meeting_app_exits: boolean;
+  max_duration_minutes: number | null;
+  pause_after_silence_minutes: number | null;
+  grace_period_seconds: number;
+}
+
+export type AutoRule = 'silence_stop' | 'meeting_app_exited' | 'max_duration' | 'silence_pause';
+
+// Payload of the recording-auto-rule-pending event
+export interface AutoRulePending {
+  rule: AutoRule;
+  action: 'stop' | 'pause';
+  message: string;
+  grace_period_seconds: number;
+}
+
+// Rolling "so far" summary while recording (see get_live_summary_settings / set_live_summary_settings)
+export interface LiveSummarySettings {
+  enabled: boolean;
+  interval_minutes: number; // 1-60
+  min_new_segments: number; // 5-500, refresh earlier once this many segments arrived
+  notify_on_update: boolean;
+}
+
+// Payload of the live-summary-update event
+export interface LiveSummary {
+  markdown: string;
+  covered_until_seconds: number; // Recording time the summary covers
+  segments_covered: number;
+  updated_at: string;
+}
+
+// Moment flagged during a recording (see add_recording_bookmark)
+export interface Bookmark {
+  id: string;
+  label: string;
+  note?: string;
+  timestamp: number; // Seconds from recording start, same clock as transcript audio_start_time
+  created_at: string;
+}
+
+// Bookmark stored for a saved meeting (see api_get_meeting_bookmarks)
+export interface MeetingBookmark {
+  id: string;
+  meeting_id: string;
+  label: string;
+  note: string | null;
+  timestamp: number;
+  created_at: string;
+}
+
+// Switch to the OS default device when it changes mid-recording (opt-in)
+export interface FollowDefaultDevices {
+  microphone: boolean;
+  system_audio: boolean;
+}
+
+export type VadEngineKind = 'silero' | 'energy';
+
+// VAD tuning for one channel (see get_vad_settings / set_vad_settings)
+export interface ChannelVadSettings {
+  threshold: number;
+  min_speech_ms: number;
+  redemption_ms: number;
+  max_segment_ms: number;
+}
+
+export interface VadSettings {
+  engine: VadEngineKind;
+  microphone: ChannelVadSettings;
+  system: ChannelVadSettings;
+}
+
+// Payload of the audio-quality-warning event
+export interface AudioQualityWarning {
+  channel: 'microphone' | 'system';
+  issue: 'clipping' | 'digital_silence' | 'dropout' | 'low_snr' | 'narrowband';
+  severity: 'warning' | 'critical';
+  message: string;
+  value: number;
+  timestamp: number;
 }
 
 // Subset of RecordingPreferences for device-only config
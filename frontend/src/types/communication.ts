@@ -87,3 +87,38 @@ export interface CommunicationFeedback {
     sugerencia: string;
   }>;
 }
+
+/**
+ * Objective per-speaker metrics computed from the transcript timestamps (no LLM)
+ * Matches the Rust SpeakerMetrics struct
+ */
+export interface SpeakerMetrics {
+  /** "user", "interlocutor" or "unknown" */
+  speaker: string;
+  talk_time_seconds: number;
+  /** Share of the total talk time (0-1) */
+  talk_time_share: number;
+  word_count: number;
+  /** Null when the speaker's segments have no timestamps */
+  words_per_minute: number | null;
+  longest_monologue_seconds: number;
+  turns: number;
+  /** Times the speaker started talking while the other was still talking */
+  interruptions: number;
+  questions: number;
+  filler_words: number;
+  /** Filler words per 100 words */
+  filler_rate: number;
+}
+
+/**
+ * Objective conversation metrics
+ * Matches the Rust ConversationMetrics struct
+ */
+export interface ConversationMetrics {
+  duration_seconds: number;
+  total_turns: number;
+  overlap_count: number;
+  overlap_seconds: number;
+  speakers: SpeakerMetrics[];
+}
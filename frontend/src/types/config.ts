@@ -24,3 +24,26 @@ export interface NotificationSettings {
     meeting_reminder_minutes: number[];
   };
 }
+
+// Global keyboard shortcuts (see get_global_shortcuts / set_global_shortcuts)
+export type ShortcutAction = 'toggle_recording' | 'pause_resume' | 'stop_recording' | 'bookmark';
+
+export interface GlobalShortcutSettings {
+  enabled: boolean;
+  toggle_recording: string | null; // Accelerator, e.g. "CmdOrCtrl+Alt+Shift+R"
+  pause_resume: string | null;
+  stop_recording: string | null;
+  bookmark: string | null;
+}
+
+export interface ShortcutConflict {
+  action: ShortcutAction;
+  accelerator: string;
+  reason: 'invalid' | 'duplicate' | 'in_use';
+  conflicting_action?: ShortcutAction;
+}
+
+export interface ShortcutRegistrationReport {
+  registered: ShortcutAction[];
+  conflicts: ShortcutConflict[];
+}
@@ -1,5 +1,5 @@
 export interface ModelConfig {
-  provider: 'ollama' | 'groq' | 'claude' | 'openrouter' | 'openai' | 'builtin-ai' | 'custom-openai';
+  provider: 'ollama' | 'groq' | 'claude' | 'gemini' | 'openrouter' | 'openai' | 'builtin-ai' | 'custom-openai';
   model: string;
   whisperModel: string;
   apiKey?: string | null;
@@ -13,6 +13,12 @@ export interface ModelConfig {
   topP?: number | null;
 }
 
+// One provider of the summary fallback chain (tried when the configured one keeps failing)
+export interface FallbackProviderConfig {
+  provider: string; // Provider id (e.g., 'ollama', 'groq')
+  model: string;
+}
+
 export interface CustomOpenAIConfig {
   endpoint: string;
   apiKey: string | null;
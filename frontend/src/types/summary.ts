@@ -20,3 +20,93 @@ export interface ProcessRequest {
         duration?: number;
     };
 }
+
+// Payload of the summary-progress event (final report streamed from the LLM)
+export interface SummaryProgress {
+    meeting_id: string;
+    partial_markdown: string;
+    done: boolean;
+}
+
+// Validated summary stored under `structured` in the summary result (one entry per template section)
+export type StructuredSectionContent =
+    | { kind: 'text'; text: string }
+    | { kind: 'list'; items: string[] }
+    | { kind: 'table'; columns: string[]; rows: string[][] };
+
+export interface StructuredSection {
+    key: string;
+    title: string;
+    content: StructuredSectionContent;
+}
+
+export interface StructuredSummary {
+    title: string;
+    sections: StructuredSection[];
+}
+
+// Action item tracked across meetings (see api_list_action_items)
+export type ActionItemStatus = 'open' | 'in_progress' | 'done' | 'cancelled';
+
+export interface ActionItem {
+    id: string;
+    meeting_id: string;
+    meeting_title: string;
+    description: string;
+    owner?: string | null;
+    due_date?: string | null;
+    status: ActionItemStatus;
+    source_segment_id?: string | null;
+    source_timestamp?: number | null;
+    edited_by_user: boolean;
+    created_at: string;
+    updated_at: string;
+}
+
+// All fields optional, combined with AND
+export interface ActionItemFilter {
+    status?: ActionItemStatus[];
+    meeting_id?: string;
+    meeting_title?: string;
+    owner?: string;
+    due_before?: string;
+    search?: string;
+}
+
+// Fields left out are kept; an empty owner or due date clears it
+export interface ActionItemUpdate {
+    description?: string;
+    owner?: string;
+    due_date?: string;
+    status?: ActionItemStatus;
+}
+
+// Payload of the action-items-updated event
+export interface ActionItemsUpdatedPayload {
+    meeting_id: string;
+    count: number;
+}
+
+// Transcript segment an answer from api_ask_meetings was grounded on
+export interface MeetingCitation {
+    index: number; // Referenced as [index] in the answer
+    meeting_id: string;
+    meeting_title: string;
+    speaker?: string | null;
+    text: string;
+    audio_start_time?: number | null;
+    audio_end_time?: number | null;
+    score: number;
+    cited: boolean;
+}
+
+export interface MeetingAnswer {
+    answer: string;
+    citations: MeetingCitation[];
+}
+
+// Payload of the meeting-index-progress event
+export interface MeetingIndexProgress {
+    indexed: number;
+    total: number;
+}
@@ -8,7 +8,7 @@ use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
 use anyhow::{Context, Result};
 use encoding_rs;
-use llama_cpp_2::context::params::LlamaContextParams;
+use llama_cpp_2::context::params::{LlamaContextParams, LlamaPoolingType};
 use llama_cpp_2::llama_backend::LlamaBackend;
 use llama_cpp_2::llama_batch::LlamaBatch;
 use llama_cpp_2::model::params::LlamaModelParams;
@@ -32,6 +32,23 @@ enum Request {
         top_k: Option<i32>,
         top_p: Option<f32>,
         stop_tokens: Option<Vec<String>>,
+        // Emit `Token` messages while generating (final `Response` is still sent)
+        #[serde(default)]
+        stream: bool,
+        // GBNF grammar constraining the output (e.g. a JSON schema for structured summaries)
+        grammar: Option<String>,
+    },
+    // Mean-pooled, L2-normalized embeddings (one vector per text)
+    Embed {
+        texts: Vec<String>,
+        context_size: Option<u32>,
+        model_path: Option<String>,
+    },
+    // Token count of each text with the model's own tokenizer (no BOS)
+    Tokenize {
+        texts: Vec<String>,
+        context_size: Option<u32>,
+        model_path: Option<String>,
     },
     Ping,
     Shutdown,
@@ -40,7 +57,10 @@ enum Request {
 #[derive(Debug, Serialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
 enum Response {
+    Token { text: String },
     Response { text: String, error: Option<String> },
+    Embeddings { embeddings: Vec<Vec<f32>>, error: Option<String> },
+    TokenCounts { counts: Vec<usize>, error: Option<String> },
     Pong,
     Goodbye,
     Error { message: String },
@@ -197,6 +217,34 @@ fn get_default_gpu_layers(model_path: &PathBuf, context_size: u32) -> u32 {
     calculate_gpu_layers(model_path, estimated_layers, vram, context_size)
 }
 
+/// Context size used for embeddings (texts are truncated to fit)
+const EMBEDDING_CONTEXT_SIZE: u32 = 2048;
+
+/// Thread count for inference (conservative default: max(1, (Cores / 2) + 2))
+/// This ensures the UI thread is never starved
+fn inference_threads() -> i32 {
+    std::thread::available_parallelism()
+        .map(|n| {
+            let cores = n.get() as i32;
+            ((cores / 2) + 2).max(1)
+        })
+        .unwrap_or(2)
+}
+
+/// Length of `output` that can be streamed without leaking the start of a stop token
+fn stream_safe_len(output: &str, stop_tokens: &[String]) -> usize {
+    let mut safe_len = output.len();
+    for stop_token in stop_tokens {
+        // Longest suffix of the output that is a proper prefix of the stop token
+        for (prefix_len, _) in stop_token.char_indices().skip(1) {
+            if output.ends_with(&stop_token[..prefix_len]) {
+                safe_len = safe_len.min(output.len() - prefix_len);
+            }
+        }
+    }
+    safe_len
+}
+
 // ============================================================================
 // Model State Management
 // ============================================================================
@@ -276,18 +324,13 @@ impl ModelState {
         top_k: i32,
         top_p: f32,
         stop_tokens: Vec<String>,
+        grammar: Option<String>,
+        mut on_token: impl FnMut(&str) -> Result<()>,
     ) -> Result<String> {
         let start_time = Instant::now();
         let model = self.model.as_ref().context("Model not loaded")?;
 
-        // Calculate thread count (conservative default: max(1, (Cores / 2) + 2))
-        // This ensures the UI thread is never starved
-        let threads: i32 = std::thread::available_parallelism()
-            .map(|n| {
-                let cores = n.get() as i32;
-                ((cores / 2) + 2).max(1)
-            })
-            .unwrap_or(2);
+        let threads = inference_threads();
 
         let ctx_params = LlamaContextParams::default()
             .with_n_ctx(Some(
@@ -326,6 +369,41 @@ impl ModelState {
         let mut n_cur = n_prompt_tokens;
         let mut decoder = encoding_rs::UTF_8.new_decoder();
         let mut output = String::new();
+        let mut streamed_len = 0;
+
+        use llama_cpp_2::sampling::LlamaSampler;
+
+        // The grammar sampler tracks the parse state, so the chain lives for the whole
+        // generation (sample() also accepts the token into every sampler of the chain)
+        let mut samplers = Vec::new();
+        if let Some(grammar) = grammar.as_deref() {
+            eprintln!("📐 Constraining output with GBNF grammar ({} chars)", grammar.len());
+            samplers.push(
+                LlamaSampler::grammar(model, grammar, "root")
+                    .map_err(|e| anyhow::anyhow!("Invalid grammar: {:?}", e))?,
+            );
+        }
+
+        if temperature <= 0.0 {
+            // Greedy sampling for temp <= 0
+            samplers.push(LlamaSampler::greedy());
+        } else {
+            // Random sampling with temperature/top_k/top_p
+            let seed = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as u32;
+
+            samplers.extend([
+                LlamaSampler::top_k(top_k),
+                LlamaSampler::top_p(top_p, 1),
+                LlamaSampler::temp(temperature),
+                LlamaSampler::dist(seed),
+            ]);
+        }
+
+        let sampler = LlamaSampler::chain_simple(samplers);
+        let mut sampler = pin!(sampler);
 
         eprintln!("🔄 Starting generation (max_tokens: {})", max_tokens);
 
@@ -336,29 +414,7 @@ impl ModelState {
                 break;
             }
 
-            use llama_cpp_2::sampling::LlamaSampler;
-
-            let sampler = if temperature <= 0.0 {
-                // Greedy sampling for temp <= 0
-                LlamaSampler::chain_simple([LlamaSampler::greedy()])
-            } else {
-                // Random sampling with temperature/top_k/top_p
-                let seed = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .as_millis() as u32;
-
-                LlamaSampler::chain_simple([
-                    LlamaSampler::top_k(top_k),
-                    LlamaSampler::top_p(top_p, 1),
-                    LlamaSampler::temp(temperature),
-                    LlamaSampler::dist(seed),
-                ])
-            };
-
-            let mut sampler = pin!(sampler);
             let token = sampler.as_mut().sample(&ctx, batch.n_tokens() - 1);
-            sampler.as_mut().accept(token);
 
             if model.is_eog_token(token) {
                 eprintln!(
@@ -395,6 +451,13 @@ impl ModelState {
                 break;
             }
 
+            // Stream the new text, holding back a possible partial stop token
+            let safe_len = stream_safe_len(&output, &stop_tokens);
+            if safe_len > streamed_len {
+                on_token(&output[streamed_len..safe_len])?;
+                streamed_len = safe_len;
+            }
+
             batch.clear();
             batch
                 .add(token, n_cur, &[0], true)
@@ -426,6 +489,84 @@ impl ModelState {
         self.update_activity();
         Ok(output)
     }
+
+    fn embed(&mut self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let start_time = Instant::now();
+        let model = self.model.as_ref().context("Model not loaded")?;
+
+        let threads = inference_threads();
+        let n_ctx = EMBEDDING_CONTEXT_SIZE.min(self.context_size);
+
+        // The whole text is decoded in one ubatch so mean pooling sees every token
+        let ctx_params = LlamaContextParams::default()
+            .with_n_ctx(Some(NonZeroU32::new(n_ctx).context("Invalid ctx size")?))
+            .with_n_batch(n_ctx)
+            .with_n_ubatch(n_ctx)
+            .with_n_threads(threads)
+            .with_n_threads_batch(threads)
+            .with_embeddings(true)
+            .with_pooling_type(LlamaPoolingType::Mean);
+
+        let mut ctx = model
+            .new_context(&self.backend, ctx_params)
+            .context("unable to create the embedding context")?;
+
+        let mut batch = LlamaBatch::new(n_ctx as usize, 1);
+        let mut embeddings = Vec::with_capacity(texts.len());
+
+        for text in texts {
+            let mut tokens = model
+                .str_to_token(text, AddBos::Always)
+                .with_context(|| "failed to tokenize text")?;
+            tokens.truncate(n_ctx as usize);
+
+            batch.clear();
+            batch
+                .add_sequence(&tokens, 0, false)
+                .context("Failed to add tokens to batch")?;
+
+            ctx.clear_kv_cache();
+            ctx.decode(&mut batch).context("llama_decode() failed")?;
+
+            let embedding = ctx
+                .embeddings_seq_ith(0)
+                .map_err(|e| anyhow::anyhow!("Failed to read embeddings: {:?}", e))?;
+
+            // Normalize so cosine similarity is a plain dot product
+            let norm = embedding.iter().map(|v| v * v).sum::<f32>().sqrt();
+            embeddings.push(if norm > 0.0 {
+                embedding.iter().map(|v| v / norm).collect()
+            } else {
+                embedding.to_vec()
+            });
+        }
+
+        eprintln!(
+            "📐 Embedded {} texts in {:.2}s",
+            texts.len(),
+            start_time.elapsed().as_secs_f64()
+        );
+
+        self.update_activity();
+        Ok(embeddings)
+    }
+
+    fn count_tokens(&self, texts: &[String]) -> Result<Vec<usize>> {
+        let model = self.model.as_ref().context("Model not loaded")?;
+
+        let counts = texts
+            .iter()
+            .map(|text| {
+                model
+                    .str_to_token(text, AddBos::Never)
+                    .map(|tokens| tokens.len())
+                    .with_context(|| "failed to tokenize text")
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        self.update_activity();
+        Ok(counts)
+    }
 }
 
 // ============================================================================
@@ -490,6 +631,8 @@ fn main() -> Result<()> {
                         top_k,
                         top_p,
                         stop_tokens,
+                        stream,
+                        grammar,
                     }) => {
                         let max_tokens = max_tokens.unwrap_or(512);
                         let context_size = context_size.unwrap_or(2048);
@@ -520,6 +663,13 @@ fn main() -> Result<()> {
                             top_k,
                             top_p,
                             stop_tokens,
+                            grammar,
+                            |text| {
+                                if stream {
+                                    send_response(&Response::Token { text: text.to_string() })?;
+                                }
+                                Ok(())
+                            },
                         ) {
                             Ok(text) => {
                                 send_response(&Response::Response { text, error: None })?;
@@ -532,6 +682,74 @@ fn main() -> Result<()> {
                             }
                         }
                     }
+                    Ok(Request::Embed {
+                        texts,
+                        context_size,
+                        model_path,
+                    }) => {
+                        let context_size = context_size.unwrap_or(2048);
+
+                        // Load model if path provided
+                        if let Some(path_str) = model_path {
+                            let path = PathBuf::from(path_str);
+                            if let Err(e) = state.load_model_if_needed(path, context_size) {
+                                send_response(&Response::Embeddings {
+                                    embeddings: Vec::new(),
+                                    error: Some(format!("Failed to load model: {}", e)),
+                                })?;
+                                continue;
+                            }
+                        }
+
+                        match state.embed(&texts) {
+                            Ok(embeddings) => {
+                                send_response(&Response::Embeddings {
+                                    embeddings,
+                                    error: None,
+                                })?;
+                            }
+                            Err(e) => {
+                                send_response(&Response::Embeddings {
+                                    embeddings: Vec::new(),
+                                    error: Some(format!("Embedding failed: {}", e)),
+                                })?;
+                            }
+                        }
+                    }
+                    Ok(Request::Tokenize {
+                        texts,
+                        context_size,
+                        model_path,
+                    }) => {
+                        let context_size = context_size.unwrap_or(2048);
+
+                        // Load model if path provided
+                        if let Some(path_str) = model_path {
+                            let path = PathBuf::from(path_str);
+                            if let Err(e) = state.load_model_if_needed(path, context_size) {
+                                send_response(&Response::TokenCounts {
+                                    counts: Vec::new(),
+                                    error: Some(format!("Failed to load model: {}", e)),
+                                })?;
+                                continue;
+                            }
+                        }
+
+                        match state.count_tokens(&texts) {
+                            Ok(counts) => {
+                                send_response(&Response::TokenCounts {
+                                    counts,
+                                    error: None,
+                                })?;
+                            }
+                            Err(e) => {
+                                send_response(&Response::TokenCounts {
+                                    counts: Vec::new(),
+                                    error: Some(format!("Tokenization failed: {}", e)),
+                                })?;
+                            }
+                        }
+                    }
                     Ok(Request::Ping) => {
                         state.update_activity();
                         send_response(&Response::Pong)?;